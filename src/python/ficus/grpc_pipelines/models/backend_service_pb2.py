@@ -34,7 +34,7 @@ from google.protobuf import empty_pb2 as google_dot_protobuf_dot_empty__pb2
 
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x15\x62\x61\x63kend_service.proto\x12\x05\x66icus\x1a\x1bpipelines_and_context.proto\x1a\nutil.proto\x1a\x1bgoogle/protobuf/empty.proto\"f\n\x1aGrpcGetContextValueRequest\x12$\n\x0b\x65xecutionId\x18\x02 \x01(\x0b\x32\x0f.ficus.GrpcGuid\x12\"\n\x03key\x18\x01 \x01(\x0b\x32\x15.ficus.GrpcContextKey\"y\n\x1cGrpcPipelineExecutionRequest\x12%\n\x08pipeline\x18\x01 \x01(\x0b\x32\x13.ficus.GrpcPipeline\x12\x32\n\x0einitialContext\x18\x02 \x03(\x0b\x32\x1a.ficus.GrpcContextKeyValue\"\xd8\x01\n\x1fGrpcPipelinePartExecutionResult\x12\x35\n\x0b\x66inalResult\x18\x01 \x01(\x0b\x32\x1e.ficus.GrpcPipelineFinalResultH\x00\x12;\n\x12pipelinePartResult\x18\x02 \x01(\x0b\x32\x1d.ficus.GrpcPipelinePartResultH\x00\x12\x37\n\nlogMessage\x18\x03 \x01(\x0b\x32!.ficus.GrpcPipelinePartLogMessageH\x00\x42\x08\n\x06result\"-\n\x1aGrpcPipelinePartLogMessage\x12\x0f\n\x07message\x18\x01 \x01(\t\"r\n\x16GrpcPipelinePartResult\x12\x39\n\rcontextValues\x18\x01 \x03(\x0b\x32\".ficus.GrpcContextValueWithKeyName\x12\x1d\n\x04uuid\x18\x02 \x01(\x0b\x32\x0f.ficus.GrpcUuid\"W\n\x1bGrpcContextValueWithKeyName\x12\x10\n\x08key_name\x18\x01 \x01(\t\x12&\n\x05value\x18\x02 \x01(\x0b\x32\x17.ficus.GrpcContextValue\"a\n\x17GrpcPipelineFinalResult\x12\"\n\x07success\x18\x01 \x01(\x0b\x32\x0f.ficus.GrpcGuidH\x00\x12\x0f\n\x05\x65rror\x18\x02 \x01(\tH\x00\x42\x11\n\x0f\x65xecutionResult\"l\n\x19GrpcGetContextValueResult\x12(\n\x05value\x18\x01 \x01(\x0b\x32\x17.ficus.GrpcContextValueH\x00\x12\x0f\n\x05\x65rror\x18\x02 \x01(\tH\x00\x42\x14\n\x12\x63ontextValueResult2\x8e\x02\n\x12GrpcBackendService\x12`\n\x0f\x45xecutePipeline\x12#.ficus.GrpcPipelineExecutionRequest\x1a&.ficus.GrpcPipelinePartExecutionResult0\x01\x12V\n\x0fGetContextValue\x12!.ficus.GrpcGetContextValueRequest\x1a .ficus.GrpcGetContextValueResult\x12>\n\x13\x44ropExecutionResult\x12\x0f.ficus.GrpcGuid\x1a\x16.google.protobuf.Emptyb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x15\x62\x61\x63kend_service.proto\x12\x05\x66icus\x1a\x1bpipelines_and_context.proto\x1a\nutil.proto\x1a\x1bgoogle/protobuf/empty.proto\"f\n\x1aGrpcGetContextValueRequest\x12$\n\x0b\x65xecutionId\x18\x02 \x01(\x0b\x32\x0f.ficus.GrpcGuid\x12\"\n\x03key\x18\x01 \x01(\x0b\x32\x15.ficus.GrpcContextKey\"\xb6\x01\n\x1cGrpcPipelineExecutionRequest\x12%\n\x08pipeline\x18\x01 \x01(\x0b\x32\x13.ficus.GrpcPipeline\x12\x32\n\x0einitialContext\x18\x02 \x03(\x0b\x32\x1a.ficus.GrpcContextKeyValue\x12)\n\x10initialContextId\x18\x03 \x01(\x0b\x32\x0f.ficus.GrpcGuid\x12\x10\n\x08useCache\x18\x04 \x01(\x08\"\xd8\x01\n\x1fGrpcPipelinePartExecutionResult\x12\x35\n\x0b\x66inalResult\x18\x01 \x01(\x0b\x32\x1e.ficus.GrpcPipelineFinalResultH\x00\x12;\n\x12pipelinePartResult\x18\x02 \x01(\x0b\x32\x1d.ficus.GrpcPipelinePartResultH\x00\x12\x37\n\nlogMessage\x18\x03 \x01(\x0b\x32!.ficus.GrpcPipelinePartLogMessageH\x00\x42\x08\n\x06result\"-\n\x1aGrpcPipelinePartLogMessage\x12\x0f\n\x07message\x18\x01 \x01(\t\"r\n\x16GrpcPipelinePartResult\x12\x39\n\rcontextValues\x18\x01 \x03(\x0b\x32\".ficus.GrpcContextValueWithKeyName\x12\x1d\n\x04uuid\x18\x02 \x01(\x0b\x32\x0f.ficus.GrpcUuid\"W\n\x1bGrpcContextValueWithKeyName\x12\x10\n\x08key_name\x18\x01 \x01(\t\x12&\n\x05value\x18\x02 \x01(\x0b\x32\x17.ficus.GrpcContextValue\"a\n\x17GrpcPipelineFinalResult\x12\"\n\x07success\x18\x01 \x01(\x0b\x32\x0f.ficus.GrpcGuidH\x00\x12\x0f\n\x05\x65rror\x18\x02 \x01(\tH\x00\x42\x11\n\x0f\x65xecutionResult\"l\n\x19GrpcGetContextValueResult\x12(\n\x05value\x18\x01 \x01(\x0b\x32\x17.ficus.GrpcContextValueH\x00\x12\x0f\n\x05\x65rror\x18\x02 \x01(\tH\x00\x42\x14\n\x12\x63ontextValueResult\"N\n\x19GrpcExecutionContextsList\x12\x31\n\x08\x63ontexts\x18\x01 \x03(\x0b\x32\x1f.ficus.GrpcExecutionContextInfo\"y\n\x18GrpcExecutionContextInfo\x12$\n\x0b\x65xecutionId\x18\x01 \x01(\x0b\x32\x0f.ficus.GrpcGuid\x12\x11\n\tsizeBytes\x18\x02 \x01(\x04\x12\x12\n\nageSeconds\x18\x03 \x01(\x04\x12\x10\n\x08inMemory\x18\x04 \x01(\x08\"1\n\x11GrpcEventLogChunk\x12\x0e\n\x06\x66ormat\x18\x01 \x01(\t\x12\x0c\n\x04\x64\x61ta\x18\x02 \x01(\x0c\"Y\n\x18GrpcUploadEventLogResult\x12\"\n\x07success\x18\x01 \x01(\x0b\x32\x0f.ficus.GrpcGuidH\x00\x12\x0f\n\x05\x65rror\x18\x02 \x01(\tH\x00\x42\x08\n\x06result\"Q\n\x1bGrpcPipelineCacheStatistics\x12\x0c\n\x04hits\x18\x01 \x01(\x04\x12\x0e\n\x06misses\x18\x02 \x01(\x04\x12\x14\n\x0c\x65ntriesCount\x18\x03 \x01(\x04\"8\n\"GrpcInvalidatePipelineCacheRequest\x12\x12\n\npartsNames\x18\x01 \x03(\t2\xe8\x04\n\x12GrpcBackendService\x12`\n\x0f\x45xecutePipeline\x12#.ficus.GrpcPipelineExecutionRequest\x1a&.ficus.GrpcPipelinePartExecutionResult0\x01\x12V\n\x0fGetContextValue\x12!.ficus.GrpcGetContextValueRequest\x1a .ficus.GrpcGetContextValueResult\x12>\n\x13\x44ropExecutionResult\x12\x0f.ficus.GrpcGuid\x1a\x16.google.protobuf.Empty\x12Q\n\x15ListExecutionContexts\x12\x16.google.protobuf.Empty\x1a .ficus.GrpcExecutionContextsList\x12M\n\x0eUploadEventLog\x12\x18.ficus.GrpcEventLogChunk\x1a\x1f.ficus.GrpcUploadEventLogResult(\x01\x12X\n\x1aGetPipelineCacheStatistics\x12\x16.google.protobuf.Empty\x1a\".ficus.GrpcPipelineCacheStatistics\x12\\\n\x17InvalidatePipelineCache\x12).ficus.GrpcInvalidatePipelineCacheRequest\x1a\x16.google.protobuf.Emptyb\x06proto3')
 
 
 
@@ -52,36 +52,60 @@ if _descriptor._USE_C_DESCRIPTORS == False:
 
   _globals['_GRPCGETCONTEXTVALUEREQUEST']._serialized_end=204
 
-  _globals['_GRPCPIPELINEEXECUTIONREQUEST']._serialized_start=206
+  _globals['_GRPCPIPELINEEXECUTIONREQUEST']._serialized_start=207
 
-  _globals['_GRPCPIPELINEEXECUTIONREQUEST']._serialized_end=327
+  _globals['_GRPCPIPELINEEXECUTIONREQUEST']._serialized_end=389
 
-  _globals['_GRPCPIPELINEPARTEXECUTIONRESULT']._serialized_start=330
+  _globals['_GRPCPIPELINEPARTEXECUTIONRESULT']._serialized_start=392
 
-  _globals['_GRPCPIPELINEPARTEXECUTIONRESULT']._serialized_end=546
+  _globals['_GRPCPIPELINEPARTEXECUTIONRESULT']._serialized_end=608
 
-  _globals['_GRPCPIPELINEPARTLOGMESSAGE']._serialized_start=548
+  _globals['_GRPCPIPELINEPARTLOGMESSAGE']._serialized_start=610
 
-  _globals['_GRPCPIPELINEPARTLOGMESSAGE']._serialized_end=593
+  _globals['_GRPCPIPELINEPARTLOGMESSAGE']._serialized_end=655
 
-  _globals['_GRPCPIPELINEPARTRESULT']._serialized_start=595
+  _globals['_GRPCPIPELINEPARTRESULT']._serialized_start=657
 
-  _globals['_GRPCPIPELINEPARTRESULT']._serialized_end=709
+  _globals['_GRPCPIPELINEPARTRESULT']._serialized_end=771
 
-  _globals['_GRPCCONTEXTVALUEWITHKEYNAME']._serialized_start=711
+  _globals['_GRPCCONTEXTVALUEWITHKEYNAME']._serialized_start=773
 
-  _globals['_GRPCCONTEXTVALUEWITHKEYNAME']._serialized_end=798
+  _globals['_GRPCCONTEXTVALUEWITHKEYNAME']._serialized_end=860
 
-  _globals['_GRPCPIPELINEFINALRESULT']._serialized_start=800
+  _globals['_GRPCPIPELINEFINALRESULT']._serialized_start=862
 
-  _globals['_GRPCPIPELINEFINALRESULT']._serialized_end=897
+  _globals['_GRPCPIPELINEFINALRESULT']._serialized_end=959
 
-  _globals['_GRPCGETCONTEXTVALUERESULT']._serialized_start=899
+  _globals['_GRPCGETCONTEXTVALUERESULT']._serialized_start=961
 
-  _globals['_GRPCGETCONTEXTVALUERESULT']._serialized_end=1007
+  _globals['_GRPCGETCONTEXTVALUERESULT']._serialized_end=1069
 
-  _globals['_GRPCBACKENDSERVICE']._serialized_start=1010
+  _globals['_GRPCEXECUTIONCONTEXTSLIST']._serialized_start=1071
 
-  _globals['_GRPCBACKENDSERVICE']._serialized_end=1280
+  _globals['_GRPCEXECUTIONCONTEXTSLIST']._serialized_end=1149
+
+  _globals['_GRPCEXECUTIONCONTEXTINFO']._serialized_start=1151
+
+  _globals['_GRPCEXECUTIONCONTEXTINFO']._serialized_end=1272
+
+  _globals['_GRPCEVENTLOGCHUNK']._serialized_start=1274
+
+  _globals['_GRPCEVENTLOGCHUNK']._serialized_end=1323
+
+  _globals['_GRPCUPLOADEVENTLOGRESULT']._serialized_start=1325
+
+  _globals['_GRPCUPLOADEVENTLOGRESULT']._serialized_end=1414
+
+  _globals['_GRPCPIPELINECACHESTATISTICS']._serialized_start=1416
+
+  _globals['_GRPCPIPELINECACHESTATISTICS']._serialized_end=1497
+
+  _globals['_GRPCINVALIDATEPIPELINECACHEREQUEST']._serialized_start=1499
+
+  _globals['_GRPCINVALIDATEPIPELINECACHEREQUEST']._serialized_end=1555
+
+  _globals['_GRPCBACKENDSERVICE']._serialized_start=1558
+
+  _globals['_GRPCBACKENDSERVICE']._serialized_end=2174
 
 # @@protoc_insertion_point(module_scope)
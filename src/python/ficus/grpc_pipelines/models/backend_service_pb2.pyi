@@ -17,12 +17,16 @@ class GrpcGetContextValueRequest(_message.Message):
     def __init__(self, executionId: _Optional[_Union[_util_pb2.GrpcGuid, _Mapping]] = ..., key: _Optional[_Union[_pipelines_and_context_pb2.GrpcContextKey, _Mapping]] = ...) -> None: ...
 
 class GrpcPipelineExecutionRequest(_message.Message):
-    __slots__ = ["pipeline", "initialContext"]
+    __slots__ = ["pipeline", "initialContext", "initialContextId", "useCache"]
     PIPELINE_FIELD_NUMBER: _ClassVar[int]
     INITIALCONTEXT_FIELD_NUMBER: _ClassVar[int]
+    INITIALCONTEXTID_FIELD_NUMBER: _ClassVar[int]
+    USECACHE_FIELD_NUMBER: _ClassVar[int]
     pipeline: _pipelines_and_context_pb2.GrpcPipeline
     initialContext: _containers.RepeatedCompositeFieldContainer[_pipelines_and_context_pb2.GrpcContextKeyValue]
-    def __init__(self, pipeline: _Optional[_Union[_pipelines_and_context_pb2.GrpcPipeline, _Mapping]] = ..., initialContext: _Optional[_Iterable[_Union[_pipelines_and_context_pb2.GrpcContextKeyValue, _Mapping]]] = ...) -> None: ...
+    initialContextId: _util_pb2.GrpcGuid
+    useCache: bool
+    def __init__(self, pipeline: _Optional[_Union[_pipelines_and_context_pb2.GrpcPipeline, _Mapping]] = ..., initialContext: _Optional[_Iterable[_Union[_pipelines_and_context_pb2.GrpcContextKeyValue, _Mapping]]] = ..., initialContextId: _Optional[_Union[_util_pb2.GrpcGuid, _Mapping]] = ..., useCache: _Optional[bool] = ...) -> None: ...
 
 class GrpcPipelinePartExecutionResult(_message.Message):
     __slots__ = ["finalResult", "pipelinePartResult", "logMessage"]
@@ -71,3 +75,53 @@ class GrpcGetContextValueResult(_message.Message):
     value: _pipelines_and_context_pb2.GrpcContextValue
     error: str
     def __init__(self, value: _Optional[_Union[_pipelines_and_context_pb2.GrpcContextValue, _Mapping]] = ..., error: _Optional[str] = ...) -> None: ...
+
+class GrpcExecutionContextsList(_message.Message):
+    __slots__ = ["contexts"]
+    CONTEXTS_FIELD_NUMBER: _ClassVar[int]
+    contexts: _containers.RepeatedCompositeFieldContainer[GrpcExecutionContextInfo]
+    def __init__(self, contexts: _Optional[_Iterable[_Union[GrpcExecutionContextInfo, _Mapping]]] = ...) -> None: ...
+
+class GrpcExecutionContextInfo(_message.Message):
+    __slots__ = ["executionId", "sizeBytes", "ageSeconds", "inMemory"]
+    EXECUTIONID_FIELD_NUMBER: _ClassVar[int]
+    SIZEBYTES_FIELD_NUMBER: _ClassVar[int]
+    AGESECONDS_FIELD_NUMBER: _ClassVar[int]
+    INMEMORY_FIELD_NUMBER: _ClassVar[int]
+    executionId: _util_pb2.GrpcGuid
+    sizeBytes: int
+    ageSeconds: int
+    inMemory: bool
+    def __init__(self, executionId: _Optional[_Union[_util_pb2.GrpcGuid, _Mapping]] = ..., sizeBytes: _Optional[int] = ..., ageSeconds: _Optional[int] = ..., inMemory: _Optional[bool] = ...) -> None: ...
+
+class GrpcEventLogChunk(_message.Message):
+    __slots__ = ["format", "data"]
+    FORMAT_FIELD_NUMBER: _ClassVar[int]
+    DATA_FIELD_NUMBER: _ClassVar[int]
+    format: str
+    data: bytes
+    def __init__(self, format: _Optional[str] = ..., data: _Optional[bytes] = ...) -> None: ...
+
+class GrpcUploadEventLogResult(_message.Message):
+    __slots__ = ["success", "error"]
+    SUCCESS_FIELD_NUMBER: _ClassVar[int]
+    ERROR_FIELD_NUMBER: _ClassVar[int]
+    success: _util_pb2.GrpcGuid
+    error: str
+    def __init__(self, success: _Optional[_Union[_util_pb2.GrpcGuid, _Mapping]] = ..., error: _Optional[str] = ...) -> None: ...
+
+class GrpcPipelineCacheStatistics(_message.Message):
+    __slots__ = ["hits", "misses", "entriesCount"]
+    HITS_FIELD_NUMBER: _ClassVar[int]
+    MISSES_FIELD_NUMBER: _ClassVar[int]
+    ENTRIESCOUNT_FIELD_NUMBER: _ClassVar[int]
+    hits: int
+    misses: int
+    entriesCount: int
+    def __init__(self, hits: _Optional[int] = ..., misses: _Optional[int] = ..., entriesCount: _Optional[int] = ...) -> None: ...
+
+class GrpcInvalidatePipelineCacheRequest(_message.Message):
+    __slots__ = ["partsNames"]
+    PARTSNAMES_FIELD_NUMBER: _ClassVar[int]
+    partsNames: _containers.RepeatedScalarFieldContainer[str]
+    def __init__(self, partsNames: _Optional[_Iterable[str]] = ...) -> None: ...
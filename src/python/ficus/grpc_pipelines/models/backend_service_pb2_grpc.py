@@ -64,6 +64,46 @@ class GrpcBackendServiceStub(object):
 
                 )
 
+        self.ListExecutionContexts = channel.unary_unary(
+
+                '/ficus.GrpcBackendService/ListExecutionContexts',
+
+                request_serializer=google_dot_protobuf_dot_empty__pb2.Empty.SerializeToString,
+
+                response_deserializer=backend__service__pb2.GrpcExecutionContextsList.FromString,
+
+                )
+
+        self.UploadEventLog = channel.stream_unary(
+
+                '/ficus.GrpcBackendService/UploadEventLog',
+
+                request_serializer=backend__service__pb2.GrpcEventLogChunk.SerializeToString,
+
+                response_deserializer=backend__service__pb2.GrpcUploadEventLogResult.FromString,
+
+                )
+
+        self.GetPipelineCacheStatistics = channel.unary_unary(
+
+                '/ficus.GrpcBackendService/GetPipelineCacheStatistics',
+
+                request_serializer=google_dot_protobuf_dot_empty__pb2.Empty.SerializeToString,
+
+                response_deserializer=backend__service__pb2.GrpcPipelineCacheStatistics.FromString,
+
+                )
+
+        self.InvalidatePipelineCache = channel.unary_unary(
+
+                '/ficus.GrpcBackendService/InvalidatePipelineCache',
+
+                request_serializer=backend__service__pb2.GrpcInvalidatePipelineCacheRequest.SerializeToString,
+
+                response_deserializer=google_dot_protobuf_dot_empty__pb2.Empty.FromString,
+
+                )
+
 
 
 
@@ -110,6 +150,54 @@ class GrpcBackendServiceServicer(object):
 
 
 
+    def ListExecutionContexts(self, request, context):
+
+        """Missing associated documentation comment in .proto file."""
+
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+
+        context.set_details('Method not implemented!')
+
+        raise NotImplementedError('Method not implemented!')
+
+
+
+    def UploadEventLog(self, request_iterator, context):
+
+        """Missing associated documentation comment in .proto file."""
+
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+
+        context.set_details('Method not implemented!')
+
+        raise NotImplementedError('Method not implemented!')
+
+
+
+    def GetPipelineCacheStatistics(self, request, context):
+
+        """Missing associated documentation comment in .proto file."""
+
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+
+        context.set_details('Method not implemented!')
+
+        raise NotImplementedError('Method not implemented!')
+
+
+
+    def InvalidatePipelineCache(self, request, context):
+
+        """Missing associated documentation comment in .proto file."""
+
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+
+        context.set_details('Method not implemented!')
+
+        raise NotImplementedError('Method not implemented!')
+
+
+
 
 
 def add_GrpcBackendServiceServicer_to_server(servicer, server):
@@ -146,6 +234,46 @@ def add_GrpcBackendServiceServicer_to_server(servicer, server):
 
             ),
 
+            'ListExecutionContexts': grpc.unary_unary_rpc_method_handler(
+
+                    servicer.ListExecutionContexts,
+
+                    request_deserializer=google_dot_protobuf_dot_empty__pb2.Empty.FromString,
+
+                    response_serializer=backend__service__pb2.GrpcExecutionContextsList.SerializeToString,
+
+            ),
+
+            'UploadEventLog': grpc.stream_unary_rpc_method_handler(
+
+                    servicer.UploadEventLog,
+
+                    request_deserializer=backend__service__pb2.GrpcEventLogChunk.FromString,
+
+                    response_serializer=backend__service__pb2.GrpcUploadEventLogResult.SerializeToString,
+
+            ),
+
+            'GetPipelineCacheStatistics': grpc.unary_unary_rpc_method_handler(
+
+                    servicer.GetPipelineCacheStatistics,
+
+                    request_deserializer=google_dot_protobuf_dot_empty__pb2.Empty.FromString,
+
+                    response_serializer=backend__service__pb2.GrpcPipelineCacheStatistics.SerializeToString,
+
+            ),
+
+            'InvalidatePipelineCache': grpc.unary_unary_rpc_method_handler(
+
+                    servicer.InvalidatePipelineCache,
+
+                    request_deserializer=backend__service__pb2.GrpcInvalidatePipelineCacheRequest.FromString,
+
+                    response_serializer=google_dot_protobuf_dot_empty__pb2.Empty.SerializeToString,
+
+            ),
+
     }
 
     generic_handler = grpc.method_handlers_generic_handler(
@@ -265,3 +393,139 @@ class GrpcBackendService(object):
             options, channel_credentials,
 
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+
+
+    @staticmethod
+
+    def ListExecutionContexts(request,
+
+            target,
+
+            options=(),
+
+            channel_credentials=None,
+
+            call_credentials=None,
+
+            insecure=False,
+
+            compression=None,
+
+            wait_for_ready=None,
+
+            timeout=None,
+
+            metadata=None):
+
+        return grpc.experimental.unary_unary(request, target, '/ficus.GrpcBackendService/ListExecutionContexts',
+
+            google_dot_protobuf_dot_empty__pb2.Empty.SerializeToString,
+
+            backend__service__pb2.GrpcExecutionContextsList.FromString,
+
+            options, channel_credentials,
+
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+
+
+    @staticmethod
+
+    def UploadEventLog(request_iterator,
+
+            target,
+
+            options=(),
+
+            channel_credentials=None,
+
+            call_credentials=None,
+
+            insecure=False,
+
+            compression=None,
+
+            wait_for_ready=None,
+
+            timeout=None,
+
+            metadata=None):
+
+        return grpc.experimental.stream_unary(request_iterator, target, '/ficus.GrpcBackendService/UploadEventLog',
+
+            backend__service__pb2.GrpcEventLogChunk.SerializeToString,
+
+            backend__service__pb2.GrpcUploadEventLogResult.FromString,
+
+            options, channel_credentials,
+
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+
+
+    @staticmethod
+
+    def GetPipelineCacheStatistics(request,
+
+            target,
+
+            options=(),
+
+            channel_credentials=None,
+
+            call_credentials=None,
+
+            insecure=False,
+
+            compression=None,
+
+            wait_for_ready=None,
+
+            timeout=None,
+
+            metadata=None):
+
+        return grpc.experimental.unary_unary(request, target, '/ficus.GrpcBackendService/GetPipelineCacheStatistics',
+
+            google_dot_protobuf_dot_empty__pb2.Empty.SerializeToString,
+
+            backend__service__pb2.GrpcPipelineCacheStatistics.FromString,
+
+            options, channel_credentials,
+
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+
+
+    @staticmethod
+
+    def InvalidatePipelineCache(request,
+
+            target,
+
+            options=(),
+
+            channel_credentials=None,
+
+            call_credentials=None,
+
+            insecure=False,
+
+            compression=None,
+
+            wait_for_ready=None,
+
+            timeout=None,
+
+            metadata=None):
+
+        return grpc.experimental.unary_unary(request, target, '/ficus.GrpcBackendService/InvalidatePipelineCache',
+
+            backend__service__pb2.GrpcInvalidatePipelineCacheRequest.SerializeToString,
+
+            google_dot_protobuf_dot_empty__pb2.Empty.FromString,
+
+            options, channel_credentials,
+
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
@@ -32,7 +32,7 @@ import ficus.grpc_pipelines.models.util_pb2 as util__pb2
 
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x1bpipelines_and_context.proto\x12\x05\x66icus\x1a\x0fpm_models.proto\x1a\nutil.proto\"\x1e\n\x0eGrpcContextKey\x12\x0c\n\x04name\x18\x01 \x01(\t\"\xb4\x07\n\x10GrpcContextValue\x12\x10\n\x06string\x18\x01 \x01(\tH\x00\x12;\n\nhashes_log\x18\x02 \x01(\x0b\x32%.ficus.GrpcHashesEventLogContextValueH\x00\x12\x39\n\tnames_log\x18\x03 \x01(\x0b\x32$.ficus.GrpcNamesEventLogContextValueH\x00\x12\x10\n\x06uint32\x18\x04 \x01(\rH\x00\x12J\n\x11traces_sub_arrays\x18\x05 \x01(\x0b\x32-.ficus.GrpcEventLogTraceSubArraysContextValueH\x00\x12P\n\x16trace_index_sub_arrays\x18\x06 \x01(\x0b\x32..ficus.GrpcSubArraysWithTraceIndexContextValueH\x00\x12\x0e\n\x04\x62ool\x18\x07 \x01(\x08H\x00\x12=\n\rxes_event_log\x18\x08 \x01(\x0b\x32$.ficus.GrpcNamesEventLogContextValueH\x00\x12/\n\ncolors_log\x18\t \x01(\x0b\x32\x19.ficus.GrpcColorsEventLogH\x00\x12\x1f\n\x04\x65num\x18\n \x01(\x0b\x32\x0f.ficus.GrpcEnumH\x00\x12\x31\n\x0e\x65vent_log_info\x18\x0b \x01(\x0b\x32\x17.ficus.GrpcEventLogInfoH\x00\x12%\n\x07strings\x18\x0c \x01(\x0b\x32\x12.ficus.GrpcStringsH\x00\x12\'\n\x08pipeline\x18\r \x01(\x0b\x32\x13.ficus.GrpcPipelineH\x00\x12\'\n\x08petriNet\x18\x0e \x01(\x0b\x32\x13.ficus.GrpcPetriNetH\x00\x12!\n\x05graph\x18\x0f \x01(\x0b\x32\x10.ficus.GrpcGraphH\x00\x12\x0f\n\x05\x66loat\x18\x10 \x01(\x02H\x00\x12\x36\n\x10\x63ount_annotation\x18\x11 \x01(\x0b\x32\x1a.ficus.GrpcCountAnnotationH\x00\x12@\n\x14\x66requency_annotation\x18\x12 \x01(\x0b\x32 .ficus.GrpcFrequenciesAnnotationH\x00\x12%\n\x07\x64\x61taset\x18\x13 \x01(\x0b\x32\x12.ficus.GrpcDatasetH\x00\x12\x34\n\x0flabeled_dataset\x18\x14 \x01(\x0b\x32\x19.ficus.GrpcLabeledDatasetH\x00\x42\x0e\n\x0c\x63ontextValue\"a\n\x13GrpcContextKeyValue\x12\"\n\x03key\x18\x01 \x01(\x0b\x32\x15.ficus.GrpcContextKey\x12&\n\x05value\x18\x02 \x01(\x0b\x32\x17.ficus.GrpcContextValue\"H\n\x1eGrpcHashesEventLogContextValue\x12&\n\x03log\x18\x01 \x01(\x0b\x32\x19.ficus.GrpcHashesEventLog\"F\n\x1dGrpcNamesEventLogContextValue\x12%\n\x03log\x18\x01 \x01(\x0b\x32\x18.ficus.GrpcNamesEventLog\"^\n&GrpcEventLogTraceSubArraysContextValue\x12\x34\n\x11traces_sub_arrays\x18\x01 \x03(\x0b\x32\x19.ficus.GrpcTraceSubArrays\"/\n\x11GrpcTraceSubArray\x12\r\n\x05start\x18\x01 \x01(\r\x12\x0b\n\x03\x65nd\x18\x02 \x01(\r\"B\n\x12GrpcTraceSubArrays\x12,\n\nsub_arrays\x18\x01 \x03(\x0b\x32\x18.ficus.GrpcTraceSubArray\"^\n\x1aGrpcSubArrayWithTraceIndex\x12+\n\tsub_array\x18\x01 \x01(\x0b\x32\x18.ficus.GrpcTraceSubArray\x12\x13\n\x0btrace_index\x18\x02 \x01(\r\"`\n\'GrpcSubArraysWithTraceIndexContextValue\x12\x35\n\nsub_arrays\x18\x01 \x03(\x0b\x32!.ficus.GrpcSubArrayWithTraceIndex\"<\n\x12GrpcColorsEventLog\x12&\n\x06traces\x18\x01 \x03(\x0b\x32\x16.ficus.GrpcColorsTrace\"D\n\x0fGrpcColorsTrace\x12\x31\n\x0c\x65vent_colors\x18\x02 \x03(\x0b\x32\x1b.ficus.GrpcColoredRectangle\"j\n\x14GrpcColoredRectangle\x12\x1f\n\x05\x63olor\x18\x01 \x01(\x0b\x32\x10.ficus.GrpcColor\x12\x13\n\x0bstart_index\x18\x02 \x01(\r\x12\x0e\n\x06length\x18\x03 \x01(\r\x12\x0c\n\x04name\x18\x04 \x01(\t\"+\n\x08GrpcEnum\x12\x10\n\x08\x65numType\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t\"[\n\x10GrpcEventLogInfo\x12\x14\n\x0c\x65vents_count\x18\x01 \x01(\r\x12\x14\n\x0ctraces_count\x18\x02 \x01(\r\x12\x1b\n\x13\x65vent_classes_count\x18\x03 \x01(\r\"\x1e\n\x0bGrpcStrings\x12\x0f\n\x07strings\x18\x01 \x03(\t\":\n\x0cGrpcPipeline\x12*\n\x05parts\x18\x01 \x03(\x0b\x32\x1b.ficus.GrpcPipelinePartBase\"\xab\x02\n\x14GrpcPipelinePartBase\x12.\n\x0b\x64\x65\x66\x61ultPart\x18\x01 \x01(\x0b\x32\x17.ficus.GrpcPipelinePartH\x00\x12\x37\n\x0cparallelPart\x18\x02 \x01(\x0b\x32\x1f.ficus.GrpcParallelPipelinePartH\x00\x12O\n\x18simpleContextRequestPart\x18\x03 \x01(\x0b\x32+.ficus.GrpcSimpleContextRequestPipelinePartH\x00\x12Q\n\x19\x63omplexContextRequestPart\x18\x04 \x01(\x0b\x32,.ficus.GrpcComplexContextRequestPipelinePartH\x00\x42\x06\n\x04part\"]\n\x10GrpcPipelinePart\x12\x0c\n\x04name\x18\x01 \x01(\t\x12;\n\rconfiguration\x18\x02 \x01(\x0b\x32$.ficus.GrpcPipelinePartConfiguration\"\\\n\x1dGrpcPipelinePartConfiguration\x12;\n\x17\x63onfigurationParameters\x18\x01 \x03(\x0b\x32\x1a.ficus.GrpcContextKeyValue\"N\n\x18GrpcParallelPipelinePart\x12\x32\n\rpipelineParts\x18\x01 \x03(\x0b\x32\x1b.ficus.GrpcPipelinePartBase\"N\n\x19GrpcParallelPipelineParts\x12\x31\n\x08pipeline\x18\x01 \x03(\x0b\x32\x1f.ficus.GrpcParallelPipelinePart\"u\n$GrpcSimpleContextRequestPipelinePart\x12\"\n\x03key\x18\x01 \x01(\x0b\x32\x15.ficus.GrpcContextKey\x12)\n\x10\x66rontendPartUuid\x18\x02 \x01(\x0b\x32\x0f.ficus.GrpcUuid\"\xac\x01\n%GrpcComplexContextRequestPipelinePart\x12#\n\x04keys\x18\x01 \x03(\x0b\x32\x15.ficus.GrpcContextKey\x12\x33\n\x12\x62\x65\x66orePipelinePart\x18\x02 \x01(\x0b\x32\x17.ficus.GrpcPipelinePart\x12)\n\x10\x66rontendPartUuid\x18\x03 \x01(\x0b\x32\x0f.ficus.GrpcUuid\"U\n\tGrpcGraph\x12#\n\x05nodes\x18\x01 \x03(\x0b\x32\x14.ficus.GrpcGraphNode\x12#\n\x05\x65\x64ges\x18\x02 \x03(\x0b\x32\x14.ficus.GrpcGraphEdge\")\n\rGrpcGraphNode\x12\n\n\x02id\x18\x01 \x01(\x04\x12\x0c\n\x04\x64\x61ta\x18\x02 \x01(\t\"A\n\rGrpcGraphEdge\x12\x11\n\tfrom_node\x18\x01 \x01(\x04\x12\x0f\n\x07to_node\x18\x02 \x01(\x04\x12\x0c\n\x04\x64\x61ta\x18\x03 \x01(\tb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x1bpipelines_and_context.proto\x12\x05\x66icus\x1a\x0fpm_models.proto\x1a\nutil.proto\"\x1e\n\x0eGrpcContextKey\x12\x0c\n\x04name\x18\x01 \x01(\t\"\x85\n\n\x10GrpcContextValue\x12\x10\n\x06string\x18\x01 \x01(\tH\x00\x12;\n\nhashes_log\x18\x02 \x01(\x0b\x32%.ficus.GrpcHashesEventLogContextValueH\x00\x12\x39\n\tnames_log\x18\x03 \x01(\x0b\x32$.ficus.GrpcNamesEventLogContextValueH\x00\x12\x10\n\x06uint32\x18\x04 \x01(\rH\x00\x12J\n\x11traces_sub_arrays\x18\x05 \x01(\x0b\x32-.ficus.GrpcEventLogTraceSubArraysContextValueH\x00\x12P\n\x16trace_index_sub_arrays\x18\x06 \x01(\x0b\x32..ficus.GrpcSubArraysWithTraceIndexContextValueH\x00\x12\x0e\n\x04\x62ool\x18\x07 \x01(\x08H\x00\x12=\n\rxes_event_log\x18\x08 \x01(\x0b\x32$.ficus.GrpcNamesEventLogContextValueH\x00\x12/\n\ncolors_log\x18\t \x01(\x0b\x32\x19.ficus.GrpcColorsEventLogH\x00\x12\x1f\n\x04\x65num\x18\n \x01(\x0b\x32\x0f.ficus.GrpcEnumH\x00\x12\x31\n\x0e\x65vent_log_info\x18\x0b \x01(\x0b\x32\x17.ficus.GrpcEventLogInfoH\x00\x12%\n\x07strings\x18\x0c \x01(\x0b\x32\x12.ficus.GrpcStringsH\x00\x12\'\n\x08pipeline\x18\r \x01(\x0b\x32\x13.ficus.GrpcPipelineH\x00\x12\'\n\x08petriNet\x18\x0e \x01(\x0b\x32\x13.ficus.GrpcPetriNetH\x00\x12!\n\x05graph\x18\x0f \x01(\x0b\x32\x10.ficus.GrpcGraphH\x00\x12\x0f\n\x05\x66loat\x18\x10 \x01(\x02H\x00\x12\x36\n\x10\x63ount_annotation\x18\x11 \x01(\x0b\x32\x1a.ficus.GrpcCountAnnotationH\x00\x12@\n\x14\x66requency_annotation\x18\x12 \x01(\x0b\x32 .ficus.GrpcFrequenciesAnnotationH\x00\x12%\n\x07\x64\x61taset\x18\x13 \x01(\x0b\x32\x12.ficus.GrpcDatasetH\x00\x12\x34\n\x0flabeled_dataset\x18\x14 \x01(\x0b\x32\x19.ficus.GrpcLabeledDatasetH\x00\x12\x31\n\x0e\x66ull_event_log\x18\x15 \x01(\x0b\x32\x17.ficus.GrpcFullEventLogH\x00\x12\x41\n\x16hierarchical_petri_net\x18\x16 \x01(\x0b\x32\x1f.ficus.GrpcHierarchicalPetriNetH\x00\x12<\n\x13sequential_patterns\x18\x17 \x01(\x0b\x32\x1d.ficus.GrpcSequentialPatternsH\x00\x12\x30\n\rdeclare_model\x18\x18 \x01(\x0b\x32\x17.ficus.GrpcDeclareModelH\x00\x12<\n\x13\x64\x65\x63lare_conformance\x18\x19 \x01(\x0b\x32\x1d.ficus.GrpcDeclareConformanceH\x00\x12+\n\ndendrogram\x18\x1a \x01(\x0b\x32\x15.ficus.GrpcDendrogramH\x00\x42\x0e\n\x0c\x63ontextValue\"a\n\x13GrpcContextKeyValue\x12\"\n\x03key\x18\x01 \x01(\x0b\x32\x15.ficus.GrpcContextKey\x12&\n\x05value\x18\x02 \x01(\x0b\x32\x17.ficus.GrpcContextValue\"H\n\x1eGrpcHashesEventLogContextValue\x12&\n\x03log\x18\x01 \x01(\x0b\x32\x19.ficus.GrpcHashesEventLog\"F\n\x1dGrpcNamesEventLogContextValue\x12%\n\x03log\x18\x01 \x01(\x0b\x32\x18.ficus.GrpcNamesEventLog\"^\n&GrpcEventLogTraceSubArraysContextValue\x12\x34\n\x11traces_sub_arrays\x18\x01 \x03(\x0b\x32\x19.ficus.GrpcTraceSubArrays\"/\n\x11GrpcTraceSubArray\x12\r\n\x05start\x18\x01 \x01(\r\x12\x0b\n\x03\x65nd\x18\x02 \x01(\r\"B\n\x12GrpcTraceSubArrays\x12,\n\nsub_arrays\x18\x01 \x03(\x0b\x32\x18.ficus.GrpcTraceSubArray\"^\n\x1aGrpcSubArrayWithTraceIndex\x12+\n\tsub_array\x18\x01 \x01(\x0b\x32\x18.ficus.GrpcTraceSubArray\x12\x13\n\x0btrace_index\x18\x02 \x01(\r\"`\n\'GrpcSubArraysWithTraceIndexContextValue\x12\x35\n\nsub_arrays\x18\x01 \x03(\x0b\x32!.ficus.GrpcSubArrayWithTraceIndex\"r\n\x15GrpcSequentialPattern\x12\x10\n\x08\x65lements\x18\x01 \x03(\x04\x12\x0f\n\x07support\x18\x02 \x01(\r\x12\x36\n\x0boccurrences\x18\x03 \x03(\x0b\x32!.ficus.GrpcSubArrayWithTraceIndex\"H\n\x16GrpcSequentialPatterns\x12.\n\x08patterns\x18\x01 \x03(\x0b\x32\x1c.ficus.GrpcSequentialPattern\"<\n\x12GrpcColorsEventLog\x12&\n\x06traces\x18\x01 \x03(\x0b\x32\x16.ficus.GrpcColorsTrace\"D\n\x0fGrpcColorsTrace\x12\x31\n\x0c\x65vent_colors\x18\x02 \x03(\x0b\x32\x1b.ficus.GrpcColoredRectangle\"j\n\x14GrpcColoredRectangle\x12\x1f\n\x05\x63olor\x18\x01 \x01(\x0b\x32\x10.ficus.GrpcColor\x12\x13\n\x0bstart_index\x18\x02 \x01(\r\x12\x0e\n\x06length\x18\x03 \x01(\r\x12\x0c\n\x04name\x18\x04 \x01(\t\"+\n\x08GrpcEnum\x12\x10\n\x08\x65numType\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t\"[\n\x10GrpcEventLogInfo\x12\x14\n\x0c\x65vents_count\x18\x01 \x01(\r\x12\x14\n\x0ctraces_count\x18\x02 \x01(\r\x12\x1b\n\x13\x65vent_classes_count\x18\x03 \x01(\r\"\x1e\n\x0bGrpcStrings\x12\x0f\n\x07strings\x18\x01 \x03(\t\":\n\x0cGrpcPipeline\x12*\n\x05parts\x18\x01 \x03(\x0b\x32\x1b.ficus.GrpcPipelinePartBase\"\xda\x03\n\x14GrpcPipelinePartBase\x12.\n\x0b\x64\x65\x66\x61ultPart\x18\x01 \x01(\x0b\x32\x17.ficus.GrpcPipelinePartH\x00\x12\x37\n\x0cparallelPart\x18\x02 \x01(\x0b\x32\x1f.ficus.GrpcParallelPipelinePartH\x00\x12O\n\x18simpleContextRequestPart\x18\x03 \x01(\x0b\x32+.ficus.GrpcSimpleContextRequestPipelinePartH\x00\x12Q\n\x19\x63omplexContextRequestPart\x18\x04 \x01(\x0b\x32,.ficus.GrpcComplexContextRequestPipelinePartH\x00\x12+\n\x06ifPart\x18\x05 \x01(\x0b\x32\x19.ficus.GrpcIfPipelinePartH\x00\x12\x31\n\twhilePart\x18\x06 \x01(\x0b\x32\x1c.ficus.GrpcWhilePipelinePartH\x00\x12M\n\x17\x66orEachTraceClusterPart\x18\x07 \x01(\x0b\x32*.ficus.GrpcForEachTraceClusterPipelinePartH\x00\x42\x06\n\x04part\"]\n\x10GrpcPipelinePart\x12\x0c\n\x04name\x18\x01 \x01(\t\x12;\n\rconfiguration\x18\x02 \x01(\x0b\x32$.ficus.GrpcPipelinePartConfiguration\"\\\n\x1dGrpcPipelinePartConfiguration\x12;\n\x17\x63onfigurationParameters\x18\x01 \x03(\x0b\x32\x1a.ficus.GrpcContextKeyValue\"N\n\x18GrpcParallelPipelinePart\x12\x32\n\rpipelineParts\x18\x01 \x03(\x0b\x32\x1b.ficus.GrpcPipelinePartBase\"N\n\x19GrpcParallelPipelineParts\x12\x31\n\x08pipeline\x18\x01 \x03(\x0b\x32\x1f.ficus.GrpcParallelPipelinePart\"u\n$GrpcSimpleContextRequestPipelinePart\x12\"\n\x03key\x18\x01 \x01(\x0b\x32\x15.ficus.GrpcContextKey\x12)\n\x10\x66rontendPartUuid\x18\x02 \x01(\x0b\x32\x0f.ficus.GrpcUuid\"\xac\x01\n%GrpcComplexContextRequestPipelinePart\x12#\n\x04keys\x18\x01 \x03(\x0b\x32\x15.ficus.GrpcContextKey\x12\x33\n\x12\x62\x65\x66orePipelinePart\x18\x02 \x01(\x0b\x32\x17.ficus.GrpcPipelinePart\x12)\n\x10\x66rontendPartUuid\x18\x03 \x01(\x0b\x32\x0f.ficus.GrpcUuid\"\x8b\x01\n\x15GrpcPipelinePredicate\x12\x13\n\tlogMetric\x18\x01 \x01(\tH\x00\x12+\n\ncontextKey\x18\x02 \x01(\x0b\x32\x15.ficus.GrpcContextKeyH\x00\x12\x12\n\ncomparison\x18\x03 \x01(\t\x12\x11\n\tthreshold\x18\x04 \x01(\x01\x42\t\n\x07operand\"\x9b\x01\n\x12GrpcIfPipelinePart\x12/\n\tpredicate\x18\x01 \x01(\x0b\x32\x1c.ficus.GrpcPipelinePredicate\x12)\n\x0cthenPipeline\x18\x02 \x01(\x0b\x32\x13.ficus.GrpcPipeline\x12)\n\x0c\x65lsePipeline\x18\x03 \x01(\x0b\x32\x13.ficus.GrpcPipeline\"\x82\x01\n\x15GrpcWhilePipelinePart\x12/\n\tpredicate\x18\x01 \x01(\x0b\x32\x1c.ficus.GrpcPipelinePredicate\x12!\n\x04\x62ody\x18\x02 \x01(\x0b\x32\x13.ficus.GrpcPipeline\x12\x15\n\rmaxIterations\x18\x03 \x01(\r\"L\n#GrpcForEachTraceClusterPipelinePart\x12%\n\x08pipeline\x18\x01 \x01(\x0b\x32\x13.ficus.GrpcPipeline\"U\n\tGrpcGraph\x12#\n\x05nodes\x18\x01 \x03(\x0b\x32\x14.ficus.GrpcGraphNode\x12#\n\x05\x65\x64ges\x18\x02 \x03(\x0b\x32\x14.ficus.GrpcGraphEdge\"P\n\rGrpcGraphNode\x12\n\n\x02id\x18\x01 \x01(\x04\x12\x0c\n\x04\x64\x61ta\x18\x02 \x01(\t\x12%\n\x06layout\x18\x03 \x01(\x0b\x32\x15.ficus.GrpcNodeLayout\"h\n\rGrpcGraphEdge\x12\x11\n\tfrom_node\x18\x01 \x01(\x04\x12\x0f\n\x07to_node\x18\x02 \x01(\x04\x12\x0c\n\x04\x64\x61ta\x18\x03 \x01(\t\x12%\n\x0b\x62\x65nd_points\x18\x04 \x03(\x0b\x32\x10.ficus.GrpcPointb\x06proto3')
 
 
 
@@ -52,106 +52,130 @@ if _descriptor._USE_C_DESCRIPTORS == False:
 
   _globals['_GRPCCONTEXTVALUE']._serialized_start=100
 
-  _globals['_GRPCCONTEXTVALUE']._serialized_end=1048
+  _globals['_GRPCCONTEXTVALUE']._serialized_end=1385
 
-  _globals['_GRPCCONTEXTKEYVALUE']._serialized_start=1050
+  _globals['_GRPCCONTEXTKEYVALUE']._serialized_start=1387
 
-  _globals['_GRPCCONTEXTKEYVALUE']._serialized_end=1147
+  _globals['_GRPCCONTEXTKEYVALUE']._serialized_end=1484
 
-  _globals['_GRPCHASHESEVENTLOGCONTEXTVALUE']._serialized_start=1149
+  _globals['_GRPCHASHESEVENTLOGCONTEXTVALUE']._serialized_start=1486
 
-  _globals['_GRPCHASHESEVENTLOGCONTEXTVALUE']._serialized_end=1221
+  _globals['_GRPCHASHESEVENTLOGCONTEXTVALUE']._serialized_end=1558
 
-  _globals['_GRPCNAMESEVENTLOGCONTEXTVALUE']._serialized_start=1223
+  _globals['_GRPCNAMESEVENTLOGCONTEXTVALUE']._serialized_start=1560
 
-  _globals['_GRPCNAMESEVENTLOGCONTEXTVALUE']._serialized_end=1293
+  _globals['_GRPCNAMESEVENTLOGCONTEXTVALUE']._serialized_end=1630
 
-  _globals['_GRPCEVENTLOGTRACESUBARRAYSCONTEXTVALUE']._serialized_start=1295
+  _globals['_GRPCEVENTLOGTRACESUBARRAYSCONTEXTVALUE']._serialized_start=1632
 
-  _globals['_GRPCEVENTLOGTRACESUBARRAYSCONTEXTVALUE']._serialized_end=1389
+  _globals['_GRPCEVENTLOGTRACESUBARRAYSCONTEXTVALUE']._serialized_end=1726
 
-  _globals['_GRPCTRACESUBARRAY']._serialized_start=1391
+  _globals['_GRPCTRACESUBARRAY']._serialized_start=1728
 
-  _globals['_GRPCTRACESUBARRAY']._serialized_end=1438
+  _globals['_GRPCTRACESUBARRAY']._serialized_end=1775
 
-  _globals['_GRPCTRACESUBARRAYS']._serialized_start=1440
+  _globals['_GRPCTRACESUBARRAYS']._serialized_start=1777
 
-  _globals['_GRPCTRACESUBARRAYS']._serialized_end=1506
+  _globals['_GRPCTRACESUBARRAYS']._serialized_end=1843
 
-  _globals['_GRPCSUBARRAYWITHTRACEINDEX']._serialized_start=1508
+  _globals['_GRPCSUBARRAYWITHTRACEINDEX']._serialized_start=1845
 
-  _globals['_GRPCSUBARRAYWITHTRACEINDEX']._serialized_end=1602
+  _globals['_GRPCSUBARRAYWITHTRACEINDEX']._serialized_end=1939
 
-  _globals['_GRPCSUBARRAYSWITHTRACEINDEXCONTEXTVALUE']._serialized_start=1604
+  _globals['_GRPCSUBARRAYSWITHTRACEINDEXCONTEXTVALUE']._serialized_start=1941
 
-  _globals['_GRPCSUBARRAYSWITHTRACEINDEXCONTEXTVALUE']._serialized_end=1700
+  _globals['_GRPCSUBARRAYSWITHTRACEINDEXCONTEXTVALUE']._serialized_end=2037
 
-  _globals['_GRPCCOLORSEVENTLOG']._serialized_start=1702
+  _globals['_GRPCSEQUENTIALPATTERN']._serialized_start=2039
 
-  _globals['_GRPCCOLORSEVENTLOG']._serialized_end=1762
+  _globals['_GRPCSEQUENTIALPATTERN']._serialized_end=2153
 
-  _globals['_GRPCCOLORSTRACE']._serialized_start=1764
+  _globals['_GRPCSEQUENTIALPATTERNS']._serialized_start=2155
 
-  _globals['_GRPCCOLORSTRACE']._serialized_end=1832
+  _globals['_GRPCSEQUENTIALPATTERNS']._serialized_end=2227
 
-  _globals['_GRPCCOLOREDRECTANGLE']._serialized_start=1834
+  _globals['_GRPCCOLORSEVENTLOG']._serialized_start=2229
 
-  _globals['_GRPCCOLOREDRECTANGLE']._serialized_end=1940
+  _globals['_GRPCCOLORSEVENTLOG']._serialized_end=2289
 
-  _globals['_GRPCENUM']._serialized_start=1942
+  _globals['_GRPCCOLORSTRACE']._serialized_start=2291
 
-  _globals['_GRPCENUM']._serialized_end=1985
+  _globals['_GRPCCOLORSTRACE']._serialized_end=2359
 
-  _globals['_GRPCEVENTLOGINFO']._serialized_start=1987
+  _globals['_GRPCCOLOREDRECTANGLE']._serialized_start=2361
 
-  _globals['_GRPCEVENTLOGINFO']._serialized_end=2078
+  _globals['_GRPCCOLOREDRECTANGLE']._serialized_end=2467
 
-  _globals['_GRPCSTRINGS']._serialized_start=2080
+  _globals['_GRPCENUM']._serialized_start=2469
 
-  _globals['_GRPCSTRINGS']._serialized_end=2110
+  _globals['_GRPCENUM']._serialized_end=2512
 
-  _globals['_GRPCPIPELINE']._serialized_start=2112
+  _globals['_GRPCEVENTLOGINFO']._serialized_start=2514
 
-  _globals['_GRPCPIPELINE']._serialized_end=2170
+  _globals['_GRPCEVENTLOGINFO']._serialized_end=2605
 
-  _globals['_GRPCPIPELINEPARTBASE']._serialized_start=2173
+  _globals['_GRPCSTRINGS']._serialized_start=2607
 
-  _globals['_GRPCPIPELINEPARTBASE']._serialized_end=2472
+  _globals['_GRPCSTRINGS']._serialized_end=2637
 
-  _globals['_GRPCPIPELINEPART']._serialized_start=2474
+  _globals['_GRPCPIPELINE']._serialized_start=2639
 
-  _globals['_GRPCPIPELINEPART']._serialized_end=2567
+  _globals['_GRPCPIPELINE']._serialized_end=2697
 
-  _globals['_GRPCPIPELINEPARTCONFIGURATION']._serialized_start=2569
+  _globals['_GRPCPIPELINEPARTBASE']._serialized_start=2700
 
-  _globals['_GRPCPIPELINEPARTCONFIGURATION']._serialized_end=2661
+  _globals['_GRPCPIPELINEPARTBASE']._serialized_end=3174
 
-  _globals['_GRPCPARALLELPIPELINEPART']._serialized_start=2663
+  _globals['_GRPCPIPELINEPART']._serialized_start=3176
 
-  _globals['_GRPCPARALLELPIPELINEPART']._serialized_end=2741
+  _globals['_GRPCPIPELINEPART']._serialized_end=3269
 
-  _globals['_GRPCPARALLELPIPELINEPARTS']._serialized_start=2743
+  _globals['_GRPCPIPELINEPARTCONFIGURATION']._serialized_start=3271
 
-  _globals['_GRPCPARALLELPIPELINEPARTS']._serialized_end=2821
+  _globals['_GRPCPIPELINEPARTCONFIGURATION']._serialized_end=3363
 
-  _globals['_GRPCSIMPLECONTEXTREQUESTPIPELINEPART']._serialized_start=2823
+  _globals['_GRPCPARALLELPIPELINEPART']._serialized_start=3365
 
-  _globals['_GRPCSIMPLECONTEXTREQUESTPIPELINEPART']._serialized_end=2940
+  _globals['_GRPCPARALLELPIPELINEPART']._serialized_end=3443
 
-  _globals['_GRPCCOMPLEXCONTEXTREQUESTPIPELINEPART']._serialized_start=2943
+  _globals['_GRPCPARALLELPIPELINEPARTS']._serialized_start=3445
 
-  _globals['_GRPCCOMPLEXCONTEXTREQUESTPIPELINEPART']._serialized_end=3115
+  _globals['_GRPCPARALLELPIPELINEPARTS']._serialized_end=3523
 
-  _globals['_GRPCGRAPH']._serialized_start=3117
+  _globals['_GRPCSIMPLECONTEXTREQUESTPIPELINEPART']._serialized_start=3525
 
-  _globals['_GRPCGRAPH']._serialized_end=3202
+  _globals['_GRPCSIMPLECONTEXTREQUESTPIPELINEPART']._serialized_end=3642
 
-  _globals['_GRPCGRAPHNODE']._serialized_start=3204
+  _globals['_GRPCCOMPLEXCONTEXTREQUESTPIPELINEPART']._serialized_start=3645
 
-  _globals['_GRPCGRAPHNODE']._serialized_end=3245
+  _globals['_GRPCCOMPLEXCONTEXTREQUESTPIPELINEPART']._serialized_end=3817
 
-  _globals['_GRPCGRAPHEDGE']._serialized_start=3247
+  _globals['_GRPCPIPELINEPREDICATE']._serialized_start=3820
 
-  _globals['_GRPCGRAPHEDGE']._serialized_end=3312
+  _globals['_GRPCPIPELINEPREDICATE']._serialized_end=3959
+
+  _globals['_GRPCIFPIPELINEPART']._serialized_start=3962
+
+  _globals['_GRPCIFPIPELINEPART']._serialized_end=4117
+
+  _globals['_GRPCWHILEPIPELINEPART']._serialized_start=4120
+
+  _globals['_GRPCWHILEPIPELINEPART']._serialized_end=4250
+
+  _globals['_GRPCFOREACHTRACECLUSTERPIPELINEPART']._serialized_start=4252
+
+  _globals['_GRPCFOREACHTRACECLUSTERPIPELINEPART']._serialized_end=4328
+
+  _globals['_GRPCGRAPH']._serialized_start=4330
+
+  _globals['_GRPCGRAPH']._serialized_end=4415
+
+  _globals['_GRPCGRAPHNODE']._serialized_start=4417
+
+  _globals['_GRPCGRAPHNODE']._serialized_end=4497
+
+  _globals['_GRPCGRAPHEDGE']._serialized_start=4499
+
+  _globals['_GRPCGRAPHEDGE']._serialized_end=4603
 
 # @@protoc_insertion_point(module_scope)
@@ -14,7 +14,7 @@ class GrpcContextKey(_message.Message):
     def __init__(self, name: _Optional[str] = ...) -> None: ...
 
 class GrpcContextValue(_message.Message):
-    __slots__ = ["string", "hashes_log", "names_log", "uint32", "traces_sub_arrays", "trace_index_sub_arrays", "bool", "xes_event_log", "colors_log", "enum", "event_log_info", "strings", "pipeline", "petriNet", "graph", "float", "count_annotation", "frequency_annotation", "dataset", "labeled_dataset"]
+    __slots__ = ["string", "hashes_log", "names_log", "uint32", "traces_sub_arrays", "trace_index_sub_arrays", "bool", "xes_event_log", "colors_log", "enum", "event_log_info", "strings", "pipeline", "petriNet", "graph", "float", "count_annotation", "frequency_annotation", "dataset", "labeled_dataset", "full_event_log", "hierarchical_petri_net", "sequential_patterns", "declare_model", "declare_conformance", "dendrogram"]
     STRING_FIELD_NUMBER: _ClassVar[int]
     HASHES_LOG_FIELD_NUMBER: _ClassVar[int]
     NAMES_LOG_FIELD_NUMBER: _ClassVar[int]
@@ -35,6 +35,12 @@ class GrpcContextValue(_message.Message):
     FREQUENCY_ANNOTATION_FIELD_NUMBER: _ClassVar[int]
     DATASET_FIELD_NUMBER: _ClassVar[int]
     LABELED_DATASET_FIELD_NUMBER: _ClassVar[int]
+    FULL_EVENT_LOG_FIELD_NUMBER: _ClassVar[int]
+    HIERARCHICAL_PETRI_NET_FIELD_NUMBER: _ClassVar[int]
+    SEQUENTIAL_PATTERNS_FIELD_NUMBER: _ClassVar[int]
+    DECLARE_MODEL_FIELD_NUMBER: _ClassVar[int]
+    DECLARE_CONFORMANCE_FIELD_NUMBER: _ClassVar[int]
+    DENDROGRAM_FIELD_NUMBER: _ClassVar[int]
     string: str
     hashes_log: GrpcHashesEventLogContextValue
     names_log: GrpcNamesEventLogContextValue
@@ -55,7 +61,13 @@ class GrpcContextValue(_message.Message):
     frequency_annotation: _pm_models_pb2.GrpcFrequenciesAnnotation
     dataset: _pm_models_pb2.GrpcDataset
     labeled_dataset: _pm_models_pb2.GrpcLabeledDataset
-    def __init__(self, string: _Optional[str] = ..., hashes_log: _Optional[_Union[GrpcHashesEventLogContextValue, _Mapping]] = ..., names_log: _Optional[_Union[GrpcNamesEventLogContextValue, _Mapping]] = ..., uint32: _Optional[int] = ..., traces_sub_arrays: _Optional[_Union[GrpcEventLogTraceSubArraysContextValue, _Mapping]] = ..., trace_index_sub_arrays: _Optional[_Union[GrpcSubArraysWithTraceIndexContextValue, _Mapping]] = ..., bool: bool = ..., xes_event_log: _Optional[_Union[GrpcNamesEventLogContextValue, _Mapping]] = ..., colors_log: _Optional[_Union[GrpcColorsEventLog, _Mapping]] = ..., enum: _Optional[_Union[GrpcEnum, _Mapping]] = ..., event_log_info: _Optional[_Union[GrpcEventLogInfo, _Mapping]] = ..., strings: _Optional[_Union[GrpcStrings, _Mapping]] = ..., pipeline: _Optional[_Union[GrpcPipeline, _Mapping]] = ..., petriNet: _Optional[_Union[_pm_models_pb2.GrpcPetriNet, _Mapping]] = ..., graph: _Optional[_Union[GrpcGraph, _Mapping]] = ..., float: _Optional[float] = ..., count_annotation: _Optional[_Union[_pm_models_pb2.GrpcCountAnnotation, _Mapping]] = ..., frequency_annotation: _Optional[_Union[_pm_models_pb2.GrpcFrequenciesAnnotation, _Mapping]] = ..., dataset: _Optional[_Union[_pm_models_pb2.GrpcDataset, _Mapping]] = ..., labeled_dataset: _Optional[_Union[_pm_models_pb2.GrpcLabeledDataset, _Mapping]] = ...) -> None: ...
+    full_event_log: _pm_models_pb2.GrpcFullEventLog
+    hierarchical_petri_net: _pm_models_pb2.GrpcHierarchicalPetriNet
+    sequential_patterns: GrpcSequentialPatterns
+    declare_model: _pm_models_pb2.GrpcDeclareModel
+    declare_conformance: _pm_models_pb2.GrpcDeclareConformance
+    dendrogram: _pm_models_pb2.GrpcDendrogram
+    def __init__(self, string: _Optional[str] = ..., hashes_log: _Optional[_Union[GrpcHashesEventLogContextValue, _Mapping]] = ..., names_log: _Optional[_Union[GrpcNamesEventLogContextValue, _Mapping]] = ..., uint32: _Optional[int] = ..., traces_sub_arrays: _Optional[_Union[GrpcEventLogTraceSubArraysContextValue, _Mapping]] = ..., trace_index_sub_arrays: _Optional[_Union[GrpcSubArraysWithTraceIndexContextValue, _Mapping]] = ..., bool: bool = ..., xes_event_log: _Optional[_Union[GrpcNamesEventLogContextValue, _Mapping]] = ..., colors_log: _Optional[_Union[GrpcColorsEventLog, _Mapping]] = ..., enum: _Optional[_Union[GrpcEnum, _Mapping]] = ..., event_log_info: _Optional[_Union[GrpcEventLogInfo, _Mapping]] = ..., strings: _Optional[_Union[GrpcStrings, _Mapping]] = ..., pipeline: _Optional[_Union[GrpcPipeline, _Mapping]] = ..., petriNet: _Optional[_Union[_pm_models_pb2.GrpcPetriNet, _Mapping]] = ..., graph: _Optional[_Union[GrpcGraph, _Mapping]] = ..., float: _Optional[float] = ..., count_annotation: _Optional[_Union[_pm_models_pb2.GrpcCountAnnotation, _Mapping]] = ..., frequency_annotation: _Optional[_Union[_pm_models_pb2.GrpcFrequenciesAnnotation, _Mapping]] = ..., dataset: _Optional[_Union[_pm_models_pb2.GrpcDataset, _Mapping]] = ..., labeled_dataset: _Optional[_Union[_pm_models_pb2.GrpcLabeledDataset, _Mapping]] = ..., full_event_log: _Optional[_Union[_pm_models_pb2.GrpcFullEventLog, _Mapping]] = ..., hierarchical_petri_net: _Optional[_Union[_pm_models_pb2.GrpcHierarchicalPetriNet, _Mapping]] = ..., sequential_patterns: _Optional[_Union[GrpcSequentialPatterns, _Mapping]] = ..., declare_model: _Optional[_Union[_pm_models_pb2.GrpcDeclareModel, _Mapping]] = ..., declare_conformance: _Optional[_Union[_pm_models_pb2.GrpcDeclareConformance, _Mapping]] = ..., dendrogram: _Optional[_Union[_pm_models_pb2.GrpcDendrogram, _Mapping]] = ...) -> None: ...
 
 class GrpcContextKeyValue(_message.Message):
     __slots__ = ["key", "value"]
@@ -111,6 +123,22 @@ class GrpcSubArraysWithTraceIndexContextValue(_message.Message):
     sub_arrays: _containers.RepeatedCompositeFieldContainer[GrpcSubArrayWithTraceIndex]
     def __init__(self, sub_arrays: _Optional[_Iterable[_Union[GrpcSubArrayWithTraceIndex, _Mapping]]] = ...) -> None: ...
 
+class GrpcSequentialPattern(_message.Message):
+    __slots__ = ["elements", "support", "occurrences"]
+    ELEMENTS_FIELD_NUMBER: _ClassVar[int]
+    SUPPORT_FIELD_NUMBER: _ClassVar[int]
+    OCCURRENCES_FIELD_NUMBER: _ClassVar[int]
+    elements: _containers.RepeatedScalarFieldContainer[int]
+    support: int
+    occurrences: _containers.RepeatedCompositeFieldContainer[GrpcSubArrayWithTraceIndex]
+    def __init__(self, elements: _Optional[_Iterable[int]] = ..., support: _Optional[int] = ..., occurrences: _Optional[_Iterable[_Union[GrpcSubArrayWithTraceIndex, _Mapping]]] = ...) -> None: ...
+
+class GrpcSequentialPatterns(_message.Message):
+    __slots__ = ["patterns"]
+    PATTERNS_FIELD_NUMBER: _ClassVar[int]
+    patterns: _containers.RepeatedCompositeFieldContainer[GrpcSequentialPattern]
+    def __init__(self, patterns: _Optional[_Iterable[_Union[GrpcSequentialPattern, _Mapping]]] = ...) -> None: ...
+
 class GrpcColorsEventLog(_message.Message):
     __slots__ = ["traces"]
     TRACES_FIELD_NUMBER: _ClassVar[int]
@@ -166,16 +194,22 @@ class GrpcPipeline(_message.Message):
     def __init__(self, parts: _Optional[_Iterable[_Union[GrpcPipelinePartBase, _Mapping]]] = ...) -> None: ...
 
 class GrpcPipelinePartBase(_message.Message):
-    __slots__ = ["defaultPart", "parallelPart", "simpleContextRequestPart", "complexContextRequestPart"]
+    __slots__ = ["defaultPart", "parallelPart", "simpleContextRequestPart", "complexContextRequestPart", "ifPart", "whilePart", "forEachTraceClusterPart"]
     DEFAULTPART_FIELD_NUMBER: _ClassVar[int]
     PARALLELPART_FIELD_NUMBER: _ClassVar[int]
     SIMPLECONTEXTREQUESTPART_FIELD_NUMBER: _ClassVar[int]
     COMPLEXCONTEXTREQUESTPART_FIELD_NUMBER: _ClassVar[int]
+    IFPART_FIELD_NUMBER: _ClassVar[int]
+    WHILEPART_FIELD_NUMBER: _ClassVar[int]
+    FOREACHTRACECLUSTERPART_FIELD_NUMBER: _ClassVar[int]
     defaultPart: GrpcPipelinePart
     parallelPart: GrpcParallelPipelinePart
     simpleContextRequestPart: GrpcSimpleContextRequestPipelinePart
     complexContextRequestPart: GrpcComplexContextRequestPipelinePart
-    def __init__(self, defaultPart: _Optional[_Union[GrpcPipelinePart, _Mapping]] = ..., parallelPart: _Optional[_Union[GrpcParallelPipelinePart, _Mapping]] = ..., simpleContextRequestPart: _Optional[_Union[GrpcSimpleContextRequestPipelinePart, _Mapping]] = ..., complexContextRequestPart: _Optional[_Union[GrpcComplexContextRequestPipelinePart, _Mapping]] = ...) -> None: ...
+    ifPart: GrpcIfPipelinePart
+    whilePart: GrpcWhilePipelinePart
+    forEachTraceClusterPart: GrpcForEachTraceClusterPipelinePart
+    def __init__(self, defaultPart: _Optional[_Union[GrpcPipelinePart, _Mapping]] = ..., parallelPart: _Optional[_Union[GrpcParallelPipelinePart, _Mapping]] = ..., simpleContextRequestPart: _Optional[_Union[GrpcSimpleContextRequestPipelinePart, _Mapping]] = ..., complexContextRequestPart: _Optional[_Union[GrpcComplexContextRequestPipelinePart, _Mapping]] = ..., ifPart: _Optional[_Union[GrpcIfPipelinePart, _Mapping]] = ..., whilePart: _Optional[_Union[GrpcWhilePipelinePart, _Mapping]] = ..., forEachTraceClusterPart: _Optional[_Union[GrpcForEachTraceClusterPipelinePart, _Mapping]] = ...) -> None: ...
 
 class GrpcPipelinePart(_message.Message):
     __slots__ = ["name", "configuration"]
@@ -221,6 +255,44 @@ class GrpcComplexContextRequestPipelinePart(_message.Message):
     frontendPartUuid: _util_pb2.GrpcUuid
     def __init__(self, keys: _Optional[_Iterable[_Union[GrpcContextKey, _Mapping]]] = ..., beforePipelinePart: _Optional[_Union[GrpcPipelinePart, _Mapping]] = ..., frontendPartUuid: _Optional[_Union[_util_pb2.GrpcUuid, _Mapping]] = ...) -> None: ...
 
+class GrpcPipelinePredicate(_message.Message):
+    __slots__ = ["logMetric", "contextKey", "comparison", "threshold"]
+    LOGMETRIC_FIELD_NUMBER: _ClassVar[int]
+    CONTEXTKEY_FIELD_NUMBER: _ClassVar[int]
+    COMPARISON_FIELD_NUMBER: _ClassVar[int]
+    THRESHOLD_FIELD_NUMBER: _ClassVar[int]
+    logMetric: str
+    contextKey: GrpcContextKey
+    comparison: str
+    threshold: float
+    def __init__(self, logMetric: _Optional[str] = ..., contextKey: _Optional[_Union[GrpcContextKey, _Mapping]] = ..., comparison: _Optional[str] = ..., threshold: _Optional[float] = ...) -> None: ...
+
+class GrpcIfPipelinePart(_message.Message):
+    __slots__ = ["predicate", "thenPipeline", "elsePipeline"]
+    PREDICATE_FIELD_NUMBER: _ClassVar[int]
+    THENPIPELINE_FIELD_NUMBER: _ClassVar[int]
+    ELSEPIPELINE_FIELD_NUMBER: _ClassVar[int]
+    predicate: GrpcPipelinePredicate
+    thenPipeline: GrpcPipeline
+    elsePipeline: GrpcPipeline
+    def __init__(self, predicate: _Optional[_Union[GrpcPipelinePredicate, _Mapping]] = ..., thenPipeline: _Optional[_Union[GrpcPipeline, _Mapping]] = ..., elsePipeline: _Optional[_Union[GrpcPipeline, _Mapping]] = ...) -> None: ...
+
+class GrpcWhilePipelinePart(_message.Message):
+    __slots__ = ["predicate", "body", "maxIterations"]
+    PREDICATE_FIELD_NUMBER: _ClassVar[int]
+    BODY_FIELD_NUMBER: _ClassVar[int]
+    MAXITERATIONS_FIELD_NUMBER: _ClassVar[int]
+    predicate: GrpcPipelinePredicate
+    body: GrpcPipeline
+    maxIterations: int
+    def __init__(self, predicate: _Optional[_Union[GrpcPipelinePredicate, _Mapping]] = ..., body: _Optional[_Union[GrpcPipeline, _Mapping]] = ..., maxIterations: _Optional[int] = ...) -> None: ...
+
+class GrpcForEachTraceClusterPipelinePart(_message.Message):
+    __slots__ = ["pipeline"]
+    PIPELINE_FIELD_NUMBER: _ClassVar[int]
+    pipeline: GrpcPipeline
+    def __init__(self, pipeline: _Optional[_Union[GrpcPipeline, _Mapping]] = ...) -> None: ...
+
 class GrpcGraph(_message.Message):
     __slots__ = ["nodes", "edges"]
     NODES_FIELD_NUMBER: _ClassVar[int]
@@ -230,19 +302,23 @@ class GrpcGraph(_message.Message):
     def __init__(self, nodes: _Optional[_Iterable[_Union[GrpcGraphNode, _Mapping]]] = ..., edges: _Optional[_Iterable[_Union[GrpcGraphEdge, _Mapping]]] = ...) -> None: ...
 
 class GrpcGraphNode(_message.Message):
-    __slots__ = ["id", "data"]
+    __slots__ = ["id", "data", "layout"]
     ID_FIELD_NUMBER: _ClassVar[int]
     DATA_FIELD_NUMBER: _ClassVar[int]
+    LAYOUT_FIELD_NUMBER: _ClassVar[int]
     id: int
     data: str
-    def __init__(self, id: _Optional[int] = ..., data: _Optional[str] = ...) -> None: ...
+    layout: _pm_models_pb2.GrpcNodeLayout
+    def __init__(self, id: _Optional[int] = ..., data: _Optional[str] = ..., layout: _Optional[_Union[_pm_models_pb2.GrpcNodeLayout, _Mapping]] = ...) -> None: ...
 
 class GrpcGraphEdge(_message.Message):
-    __slots__ = ["from_node", "to_node", "data"]
+    __slots__ = ["from_node", "to_node", "data", "bend_points"]
     FROM_NODE_FIELD_NUMBER: _ClassVar[int]
     TO_NODE_FIELD_NUMBER: _ClassVar[int]
     DATA_FIELD_NUMBER: _ClassVar[int]
+    BEND_POINTS_FIELD_NUMBER: _ClassVar[int]
     from_node: int
     to_node: int
     data: str
-    def __init__(self, from_node: _Optional[int] = ..., to_node: _Optional[int] = ..., data: _Optional[str] = ...) -> None: ...
+    bend_points: _containers.RepeatedCompositeFieldContainer[_pm_models_pb2.GrpcPoint]
+    def __init__(self, from_node: _Optional[int] = ..., to_node: _Optional[int] = ..., data: _Optional[str] = ..., bend_points: _Optional[_Iterable[_Union[_pm_models_pb2.GrpcPoint, _Mapping]]] = ...) -> None: ...
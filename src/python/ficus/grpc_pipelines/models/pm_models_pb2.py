@@ -32,7 +32,7 @@ import ficus.grpc_pipelines.models.util_pb2 as util__pb2
 
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0fpm_models.proto\x12\x05\x66icus\x1a\x1fgoogle/protobuf/timestamp.proto\x1a\nutil.proto\"<\n\x12GrpcSimpleEventLog\x12&\n\x06traces\x18\x01 \x03(\x0b\x32\x16.ficus.GrpcSimpleTrace\"3\n\x0fGrpcSimpleTrace\x12 \n\x06\x65vents\x18\x01 \x03(\x0b\x32\x10.ficus.GrpcEvent\"?\n\tGrpcEvent\x12\x0c\n\x04name\x18\x01 \x01(\t\x12$\n\x05stamp\x18\x02 \x01(\x0b\x32\x15.ficus.GrpcEventStamp\"V\n\x0eGrpcEventStamp\x12*\n\x04\x64\x61te\x18\x01 \x01(\x0b\x32\x1a.google.protobuf.TimestampH\x00\x12\x0f\n\x05order\x18\x02 \x01(\x04H\x00\x42\x07\n\x05stamp\"?\n\x12GrpcHashesEventLog\x12)\n\x06traces\x18\x02 \x03(\x0b\x32\x19.ficus.GrpcHashesLogTrace\"$\n\x12GrpcHashesLogTrace\x12\x0e\n\x06\x65vents\x18\x01 \x03(\x04\":\n\x11GrpcNamesEventLog\x12%\n\x06traces\x18\x01 \x03(\x0b\x32\x15.ficus.GrpcNamesTrace\" \n\x0eGrpcNamesTrace\x12\x0e\n\x06\x65vents\x18\x01 \x03(\t\"\xd4\x01\n\x0cGrpcPetriNet\x12(\n\x06places\x18\x01 \x03(\x0b\x32\x18.ficus.GrpcPetriNetPlace\x12\x32\n\x0btransitions\x18\x02 \x03(\x0b\x32\x1d.ficus.GrpcPetriNetTransition\x12\x33\n\x0finitial_marking\x18\x03 \x01(\x0b\x32\x1a.ficus.GrpcPetriNetMarking\x12\x31\n\rfinal_marking\x18\x04 \x01(\x0b\x32\x1a.ficus.GrpcPetriNetMarking\"-\n\x11GrpcPetriNetPlace\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x0c\n\x04name\x18\x02 \x01(\t\"\x8e\x01\n\x16GrpcPetriNetTransition\x12\n\n\x02id\x18\x01 \x01(\x03\x12,\n\x0cincomingArcs\x18\x02 \x03(\x0b\x32\x16.ficus.GrpcPetriNetArc\x12,\n\x0coutgoingArcs\x18\x03 \x03(\x0b\x32\x16.ficus.GrpcPetriNetArc\x12\x0c\n\x04\x64\x61ta\x18\x04 \x01(\t\"D\n\x0fGrpcPetriNetArc\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x0f\n\x07placeId\x18\x02 \x01(\x03\x12\x14\n\x0ctokens_count\x18\x03 \x01(\x03\"N\n\x13GrpcPetriNetMarking\x12\x37\n\x08markings\x18\x01 \x03(\x0b\x32%.ficus.GrpcPetriNetSinglePlaceMarking\"F\n\x1eGrpcPetriNetSinglePlaceMarking\x12\x0f\n\x07placeId\x18\x01 \x01(\x03\x12\x13\n\x0btokensCount\x18\x02 \x01(\x03\"L\n\x13GrpcCountAnnotation\x12\x35\n\x0b\x61nnotations\x18\x01 \x03(\x0b\x32 .ficus.GrpcEntityCountAnnotation\"<\n\x19GrpcEntityCountAnnotation\x12\x10\n\x08\x65ntityId\x18\x01 \x01(\x03\x12\r\n\x05\x63ount\x18\x02 \x01(\x03\"V\n\x19GrpcFrequenciesAnnotation\x12\x39\n\x0b\x61nnotations\x18\x02 \x03(\x0b\x32$.ficus.GrpcEntityFrequencyAnnotation\"D\n\x1dGrpcEntityFrequencyAnnotation\x12\x10\n\x08\x65ntityId\x18\x01 \x01(\x03\x12\x11\n\tfrequency\x18\x02 \x01(\x02\"/\n\nGrpcMatrix\x12!\n\x04rows\x18\x01 \x03(\x0b\x32\x13.ficus.GrpcMatixRow\"\x1e\n\x0cGrpcMatixRow\x12\x0e\n\x06values\x18\x01 \x03(\x02\"X\n\x0bGrpcDataset\x12!\n\x06matrix\x18\x01 \x01(\x0b\x32\x11.ficus.GrpcMatrix\x12\x14\n\x0c\x63olumnsNames\x18\x02 \x03(\t\x12\x10\n\x08rowNames\x18\x03 \x03(\t\"q\n\x12GrpcLabeledDataset\x12#\n\x07\x64\x61taset\x18\x01 \x01(\x0b\x32\x12.ficus.GrpcDataset\x12\x0e\n\x06labels\x18\x02 \x03(\x05\x12&\n\x0clabelsColors\x18\x03 \x03(\x0b\x32\x10.ficus.GrpcColorb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0fpm_models.proto\x12\x05\x66icus\x1a\x1fgoogle/protobuf/timestamp.proto\x1a\nutil.proto\"<\n\x12GrpcSimpleEventLog\x12&\n\x06traces\x18\x01 \x03(\x0b\x32\x16.ficus.GrpcSimpleTrace\"3\n\x0fGrpcSimpleTrace\x12 \n\x06\x65vents\x18\x01 \x03(\x0b\x32\x10.ficus.GrpcEvent\"?\n\tGrpcEvent\x12\x0c\n\x04name\x18\x01 \x01(\t\x12$\n\x05stamp\x18\x02 \x01(\x0b\x32\x15.ficus.GrpcEventStamp\"V\n\x0eGrpcEventStamp\x12*\n\x04\x64\x61te\x18\x01 \x01(\x0b\x32\x1a.google.protobuf.TimestampH\x00\x12\x0f\n\x05order\x18\x02 \x01(\x04H\x00\x42\x07\n\x05stamp\"d\n\x10GrpcFullEventLog\x12$\n\x06traces\x18\x01 \x03(\x0b\x32\x14.ficus.GrpcFullTrace\x12*\n\x07globals\x18\x02 \x03(\x0b\x32\x19.ficus.GrpcEventLogGlobal\"d\n\rGrpcFullTrace\x12$\n\x06\x65vents\x18\x01 \x03(\x0b\x32\x14.ficus.GrpcFullEvent\x12-\n\nattributes\x18\x02 \x03(\x0b\x32\x19.ficus.GrpcEventAttribute\"{\n\rGrpcFullEvent\x12\x0c\n\x04name\x18\x01 \x01(\t\x12-\n\ttimestamp\x18\x02 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12-\n\nattributes\x18\x03 \x03(\x0b\x32\x19.ficus.GrpcEventAttribute\"P\n\x12GrpcEventLogGlobal\x12\r\n\x05scope\x18\x01 \x01(\t\x12+\n\x08\x64\x65\x66\x61ults\x18\x02 \x03(\x0b\x32\x19.ficus.GrpcEventAttribute\"P\n\x12GrpcEventAttribute\x12\x0b\n\x03key\x18\x01 \x01(\t\x12-\n\x05value\x18\x02 \x01(\x0b\x32\x1e.ficus.GrpcEventAttributeValue\"\xb7\x02\n\x17GrpcEventAttributeValue\x12*\n\x04\x64\x61te\x18\x01 \x01(\x0b\x32\x1a.google.protobuf.TimestampH\x00\x12\x10\n\x06string\x18\x02 \x01(\tH\x00\x12\x0e\n\x04\x62ool\x18\x03 \x01(\x08H\x00\x12\x0f\n\x05int32\x18\x04 \x01(\x05H\x00\x12\x0f\n\x05int64\x18\x05 \x01(\x03H\x00\x12\x11\n\x07\x66loat32\x18\x06 \x01(\x02H\x00\x12\x11\n\x07\x66loat64\x18\x07 \x01(\x01H\x00\x12\x10\n\x06uint32\x18\x08 \x01(\rH\x00\x12\x10\n\x06uint64\x18\t \x01(\x04H\x00\x12\x0e\n\x04guid\x18\n \x01(\tH\x00\x12\x13\n\ttimestamp\x18\x0b \x01(\x03H\x00\x12\x1b\n\x11standardLifecycle\x18\x0c \x01(\tH\x00\x12\x17\n\rbrafLifecycle\x18\r \x01(\tH\x00\x42\x07\n\x05value\"?\n\x12GrpcHashesEventLog\x12)\n\x06traces\x18\x02 \x03(\x0b\x32\x19.ficus.GrpcHashesLogTrace\"$\n\x12GrpcHashesLogTrace\x12\x0e\n\x06\x65vents\x18\x01 \x03(\x04\":\n\x11GrpcNamesEventLog\x12%\n\x06traces\x18\x01 \x03(\x0b\x32\x15.ficus.GrpcNamesTrace\" \n\x0eGrpcNamesTrace\x12\x0e\n\x06\x65vents\x18\x01 \x03(\t\"\xd4\x01\n\x0cGrpcPetriNet\x12(\n\x06places\x18\x01 \x03(\x0b\x32\x18.ficus.GrpcPetriNetPlace\x12\x32\n\x0btransitions\x18\x02 \x03(\x0b\x32\x1d.ficus.GrpcPetriNetTransition\x12\x33\n\x0finitial_marking\x18\x03 \x01(\x0b\x32\x1a.ficus.GrpcPetriNetMarking\x12\x31\n\rfinal_marking\x18\x04 \x01(\x0b\x32\x1a.ficus.GrpcPetriNetMarking\"\x86\x01\n\x18GrpcHierarchicalPetriNet\x12\x0c\n\x04name\x18\x01 \x01(\t\x12%\n\x08petriNet\x18\x02 \x01(\x0b\x32\x13.ficus.GrpcPetriNet\x12\x35\n\x0csubprocesses\x18\x03 \x03(\x0b\x32\x1f.ficus.GrpcHierarchicalPetriNet\"T\n\x11GrpcPetriNetPlace\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x0c\n\x04name\x18\x02 \x01(\t\x12%\n\x06layout\x18\x03 \x01(\x0b\x32\x15.ficus.GrpcNodeLayout\"\xb5\x01\n\x16GrpcPetriNetTransition\x12\n\n\x02id\x18\x01 \x01(\x03\x12,\n\x0cincomingArcs\x18\x02 \x03(\x0b\x32\x16.ficus.GrpcPetriNetArc\x12,\n\x0coutgoingArcs\x18\x03 \x03(\x0b\x32\x16.ficus.GrpcPetriNetArc\x12\x0c\n\x04\x64\x61ta\x18\x04 \x01(\t\x12%\n\x06layout\x18\x05 \x01(\x0b\x32\x15.ficus.GrpcNodeLayout\"k\n\x0fGrpcPetriNetArc\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x0f\n\x07placeId\x18\x02 \x01(\x03\x12\x14\n\x0ctokens_count\x18\x03 \x01(\x03\x12%\n\x0b\x62\x65nd_points\x18\x04 \x03(\x0b\x32\x10.ficus.GrpcPoint\"E\n\x0eGrpcNodeLayout\x12\t\n\x01x\x18\x01 \x01(\x01\x12\t\n\x01y\x18\x02 \x01(\x01\x12\r\n\x05width\x18\x03 \x01(\x01\x12\x0e\n\x06height\x18\x04 \x01(\x01\"!\n\tGrpcPoint\x12\t\n\x01x\x18\x01 \x01(\x01\x12\t\n\x01y\x18\x02 \x01(\x01\"N\n\x13GrpcPetriNetMarking\x12\x37\n\x08markings\x18\x01 \x03(\x0b\x32%.ficus.GrpcPetriNetSinglePlaceMarking\"F\n\x1eGrpcPetriNetSinglePlaceMarking\x12\x0f\n\x07placeId\x18\x01 \x01(\x03\x12\x13\n\x0btokensCount\x18\x02 \x01(\x03\"L\n\x13GrpcCountAnnotation\x12\x35\n\x0b\x61nnotations\x18\x01 \x03(\x0b\x32 .ficus.GrpcEntityCountAnnotation\"<\n\x19GrpcEntityCountAnnotation\x12\x10\n\x08\x65ntityId\x18\x01 \x01(\x03\x12\r\n\x05\x63ount\x18\x02 \x01(\x03\"V\n\x19GrpcFrequenciesAnnotation\x12\x39\n\x0b\x61nnotations\x18\x02 \x03(\x0b\x32$.ficus.GrpcEntityFrequencyAnnotation\"D\n\x1dGrpcEntityFrequencyAnnotation\x12\x10\n\x08\x65ntityId\x18\x01 \x01(\x03\x12\x11\n\tfrequency\x18\x02 \x01(\x02\"/\n\nGrpcMatrix\x12!\n\x04rows\x18\x01 \x03(\x0b\x32\x13.ficus.GrpcMatixRow\"\x1e\n\x0cGrpcMatixRow\x12\x0e\n\x06values\x18\x01 \x03(\x02\"X\n\x0bGrpcDataset\x12!\n\x06matrix\x18\x01 \x01(\x0b\x32\x11.ficus.GrpcMatrix\x12\x14\n\x0c\x63olumnsNames\x18\x02 \x03(\t\x12\x10\n\x08rowNames\x18\x03 \x03(\t\"q\n\x12GrpcLabeledDataset\x12#\n\x07\x64\x61taset\x18\x01 \x01(\x0b\x32\x12.ficus.GrpcDataset\x12\x0e\n\x06labels\x18\x02 \x03(\x05\x12&\n\x0clabelsColors\x18\x03 \x03(\x0b\x32\x10.ficus.GrpcColor\"Q\n\x0eGrpcDendrogram\x12\x13\n\x0bleavesNames\x18\x01 \x03(\t\x12*\n\x06merges\x18\x02 \x03(\x0b\x32\x1a.ficus.GrpcDendrogramMerge\"T\n\x13GrpcDendrogramMerge\x12\r\n\x05\x66irst\x18\x01 \x01(\r\x12\x0e\n\x06second\x18\x02 \x01(\r\x12\x10\n\x08\x64istance\x18\x03 \x01(\x01\x12\x0c\n\x04size\x18\x04 \x01(\r\"C\n\x1cGrpcDeclareConstraintMetrics\x12\x0f\n\x07support\x18\x01 \x01(\x01\x12\x12\n\nconfidence\x18\x02 \x01(\x01\"s\n\x15GrpcDeclareConstraint\x12\x10\n\x08template\x18\x01 \x01(\t\x12\x12\n\nparameters\x18\x02 \x03(\t\x12\x34\n\x07metrics\x18\x03 \x01(\x0b\x32#.ficus.GrpcDeclareConstraintMetrics\"E\n\x10GrpcDeclareModel\x12\x31\n\x0b\x63onstraints\x18\x01 \x03(\x0b\x32\x1c.ficus.GrpcDeclareConstraint\"`\n GrpcDeclareConstraintCheckResult\x12\x13\n\x0b\x61\x63tivations\x18\x01 \x01(\r\x12\x13\n\x0b\x66ulfilments\x18\x02 \x01(\r\x12\x12\n\nviolations\x18\x03 \x01(\r\"s\n\x1bGrpcDeclareTraceConformance\x12\x43\n\x12\x63onstraintsResults\x18\x01 \x03(\x0b\x32\'.ficus.GrpcDeclareConstraintCheckResult\x12\x0f\n\x07\x66itness\x18\x02 \x01(\x01\"\x85\x01\n\x16GrpcDeclareConformance\x12&\n\x05model\x18\x01 \x01(\x0b\x32\x17.ficus.GrpcDeclareModel\x12\x32\n\x06traces\x18\x02 \x03(\x0b\x32\".ficus.GrpcDeclareTraceConformance\x12\x0f\n\x07\x66itness\x18\x03 \x01(\x01\x62\x06proto3')
 
 
 
@@ -62,76 +62,144 @@ if _descriptor._USE_C_DESCRIPTORS == False:
 
   _globals['_GRPCEVENTSTAMP']._serialized_end=337
 
-  _globals['_GRPCHASHESEVENTLOG']._serialized_start=339
+  _globals['_GRPCFULLEVENTLOG']._serialized_start=339
 
-  _globals['_GRPCHASHESEVENTLOG']._serialized_end=402
+  _globals['_GRPCFULLEVENTLOG']._serialized_end=439
 
-  _globals['_GRPCHASHESLOGTRACE']._serialized_start=404
+  _globals['_GRPCFULLTRACE']._serialized_start=441
 
-  _globals['_GRPCHASHESLOGTRACE']._serialized_end=440
+  _globals['_GRPCFULLTRACE']._serialized_end=541
 
-  _globals['_GRPCNAMESEVENTLOG']._serialized_start=442
+  _globals['_GRPCFULLEVENT']._serialized_start=543
 
-  _globals['_GRPCNAMESEVENTLOG']._serialized_end=500
+  _globals['_GRPCFULLEVENT']._serialized_end=666
 
-  _globals['_GRPCNAMESTRACE']._serialized_start=502
+  _globals['_GRPCEVENTLOGGLOBAL']._serialized_start=668
 
-  _globals['_GRPCNAMESTRACE']._serialized_end=534
+  _globals['_GRPCEVENTLOGGLOBAL']._serialized_end=748
 
-  _globals['_GRPCPETRINET']._serialized_start=537
+  _globals['_GRPCEVENTATTRIBUTE']._serialized_start=750
 
-  _globals['_GRPCPETRINET']._serialized_end=749
+  _globals['_GRPCEVENTATTRIBUTE']._serialized_end=830
 
-  _globals['_GRPCPETRINETPLACE']._serialized_start=751
+  _globals['_GRPCEVENTATTRIBUTEVALUE']._serialized_start=833
 
-  _globals['_GRPCPETRINETPLACE']._serialized_end=796
+  _globals['_GRPCEVENTATTRIBUTEVALUE']._serialized_end=1144
 
-  _globals['_GRPCPETRINETTRANSITION']._serialized_start=799
+  _globals['_GRPCHASHESEVENTLOG']._serialized_start=1146
 
-  _globals['_GRPCPETRINETTRANSITION']._serialized_end=941
+  _globals['_GRPCHASHESEVENTLOG']._serialized_end=1209
 
-  _globals['_GRPCPETRINETARC']._serialized_start=943
+  _globals['_GRPCHASHESLOGTRACE']._serialized_start=1211
 
-  _globals['_GRPCPETRINETARC']._serialized_end=1011
+  _globals['_GRPCHASHESLOGTRACE']._serialized_end=1247
 
-  _globals['_GRPCPETRINETMARKING']._serialized_start=1013
+  _globals['_GRPCNAMESEVENTLOG']._serialized_start=1249
 
-  _globals['_GRPCPETRINETMARKING']._serialized_end=1091
+  _globals['_GRPCNAMESEVENTLOG']._serialized_end=1307
 
-  _globals['_GRPCPETRINETSINGLEPLACEMARKING']._serialized_start=1093
+  _globals['_GRPCNAMESTRACE']._serialized_start=1309
 
-  _globals['_GRPCPETRINETSINGLEPLACEMARKING']._serialized_end=1163
+  _globals['_GRPCNAMESTRACE']._serialized_end=1341
 
-  _globals['_GRPCCOUNTANNOTATION']._serialized_start=1165
+  _globals['_GRPCPETRINET']._serialized_start=1344
 
-  _globals['_GRPCCOUNTANNOTATION']._serialized_end=1241
+  _globals['_GRPCPETRINET']._serialized_end=1556
 
-  _globals['_GRPCENTITYCOUNTANNOTATION']._serialized_start=1243
+  _globals['_GRPCHIERARCHICALPETRINET']._serialized_start=1559
 
-  _globals['_GRPCENTITYCOUNTANNOTATION']._serialized_end=1303
+  _globals['_GRPCHIERARCHICALPETRINET']._serialized_end=1693
 
-  _globals['_GRPCFREQUENCIESANNOTATION']._serialized_start=1305
+  _globals['_GRPCPETRINETPLACE']._serialized_start=1695
 
-  _globals['_GRPCFREQUENCIESANNOTATION']._serialized_end=1391
+  _globals['_GRPCPETRINETPLACE']._serialized_end=1779
 
-  _globals['_GRPCENTITYFREQUENCYANNOTATION']._serialized_start=1393
+  _globals['_GRPCPETRINETTRANSITION']._serialized_start=1782
 
-  _globals['_GRPCENTITYFREQUENCYANNOTATION']._serialized_end=1461
+  _globals['_GRPCPETRINETTRANSITION']._serialized_end=1963
 
-  _globals['_GRPCMATRIX']._serialized_start=1463
+  _globals['_GRPCPETRINETARC']._serialized_start=1965
 
-  _globals['_GRPCMATRIX']._serialized_end=1510
+  _globals['_GRPCPETRINETARC']._serialized_end=2072
 
-  _globals['_GRPCMATIXROW']._serialized_start=1512
+  _globals['_GRPCNODELAYOUT']._serialized_start=2074
 
-  _globals['_GRPCMATIXROW']._serialized_end=1542
+  _globals['_GRPCNODELAYOUT']._serialized_end=2143
 
-  _globals['_GRPCDATASET']._serialized_start=1544
+  _globals['_GRPCPOINT']._serialized_start=2145
 
-  _globals['_GRPCDATASET']._serialized_end=1632
+  _globals['_GRPCPOINT']._serialized_end=2178
 
-  _globals['_GRPCLABELEDDATASET']._serialized_start=1634
+  _globals['_GRPCPETRINETMARKING']._serialized_start=2180
 
-  _globals['_GRPCLABELEDDATASET']._serialized_end=1747
+  _globals['_GRPCPETRINETMARKING']._serialized_end=2258
+
+  _globals['_GRPCPETRINETSINGLEPLACEMARKING']._serialized_start=2260
+
+  _globals['_GRPCPETRINETSINGLEPLACEMARKING']._serialized_end=2330
+
+  _globals['_GRPCCOUNTANNOTATION']._serialized_start=2332
+
+  _globals['_GRPCCOUNTANNOTATION']._serialized_end=2408
+
+  _globals['_GRPCENTITYCOUNTANNOTATION']._serialized_start=2410
+
+  _globals['_GRPCENTITYCOUNTANNOTATION']._serialized_end=2470
+
+  _globals['_GRPCFREQUENCIESANNOTATION']._serialized_start=2472
+
+  _globals['_GRPCFREQUENCIESANNOTATION']._serialized_end=2558
+
+  _globals['_GRPCENTITYFREQUENCYANNOTATION']._serialized_start=2560
+
+  _globals['_GRPCENTITYFREQUENCYANNOTATION']._serialized_end=2628
+
+  _globals['_GRPCMATRIX']._serialized_start=2630
+
+  _globals['_GRPCMATRIX']._serialized_end=2677
+
+  _globals['_GRPCMATIXROW']._serialized_start=2679
+
+  _globals['_GRPCMATIXROW']._serialized_end=2709
+
+  _globals['_GRPCDATASET']._serialized_start=2711
+
+  _globals['_GRPCDATASET']._serialized_end=2799
+
+  _globals['_GRPCLABELEDDATASET']._serialized_start=2801
+
+  _globals['_GRPCLABELEDDATASET']._serialized_end=2914
+
+  _globals['_GRPCDENDROGRAM']._serialized_start=2916
+
+  _globals['_GRPCDENDROGRAM']._serialized_end=2997
+
+  _globals['_GRPCDENDROGRAMMERGE']._serialized_start=2999
+
+  _globals['_GRPCDENDROGRAMMERGE']._serialized_end=3083
+
+  _globals['_GRPCDECLARECONSTRAINTMETRICS']._serialized_start=3085
+
+  _globals['_GRPCDECLARECONSTRAINTMETRICS']._serialized_end=3152
+
+  _globals['_GRPCDECLARECONSTRAINT']._serialized_start=3154
+
+  _globals['_GRPCDECLARECONSTRAINT']._serialized_end=3269
+
+  _globals['_GRPCDECLAREMODEL']._serialized_start=3271
+
+  _globals['_GRPCDECLAREMODEL']._serialized_end=3340
+
+  _globals['_GRPCDECLARECONSTRAINTCHECKRESULT']._serialized_start=3342
+
+  _globals['_GRPCDECLARECONSTRAINTCHECKRESULT']._serialized_end=3438
+
+  _globals['_GRPCDECLARETRACECONFORMANCE']._serialized_start=3440
+
+  _globals['_GRPCDECLARETRACECONFORMANCE']._serialized_end=3555
+
+  _globals['_GRPCDECLARECONFORMANCE']._serialized_start=3558
+
+  _globals['_GRPCDECLARECONFORMANCE']._serialized_end=3691
 
 # @@protoc_insertion_point(module_scope)
@@ -35,6 +35,78 @@ class GrpcEventStamp(_message.Message):
     order: int
     def __init__(self, date: _Optional[_Union[_timestamp_pb2.Timestamp, _Mapping]] = ..., order: _Optional[int] = ...) -> None: ...
 
+class GrpcFullEventLog(_message.Message):
+    __slots__ = ["traces", "globals"]
+    TRACES_FIELD_NUMBER: _ClassVar[int]
+    GLOBALS_FIELD_NUMBER: _ClassVar[int]
+    traces: _containers.RepeatedCompositeFieldContainer[GrpcFullTrace]
+    globals: _containers.RepeatedCompositeFieldContainer[GrpcEventLogGlobal]
+    def __init__(self, traces: _Optional[_Iterable[_Union[GrpcFullTrace, _Mapping]]] = ..., globals: _Optional[_Iterable[_Union[GrpcEventLogGlobal, _Mapping]]] = ...) -> None: ...
+
+class GrpcFullTrace(_message.Message):
+    __slots__ = ["events", "attributes"]
+    EVENTS_FIELD_NUMBER: _ClassVar[int]
+    ATTRIBUTES_FIELD_NUMBER: _ClassVar[int]
+    events: _containers.RepeatedCompositeFieldContainer[GrpcFullEvent]
+    attributes: _containers.RepeatedCompositeFieldContainer[GrpcEventAttribute]
+    def __init__(self, events: _Optional[_Iterable[_Union[GrpcFullEvent, _Mapping]]] = ..., attributes: _Optional[_Iterable[_Union[GrpcEventAttribute, _Mapping]]] = ...) -> None: ...
+
+class GrpcFullEvent(_message.Message):
+    __slots__ = ["name", "timestamp", "attributes"]
+    NAME_FIELD_NUMBER: _ClassVar[int]
+    TIMESTAMP_FIELD_NUMBER: _ClassVar[int]
+    ATTRIBUTES_FIELD_NUMBER: _ClassVar[int]
+    name: str
+    timestamp: _timestamp_pb2.Timestamp
+    attributes: _containers.RepeatedCompositeFieldContainer[GrpcEventAttribute]
+    def __init__(self, name: _Optional[str] = ..., timestamp: _Optional[_Union[_timestamp_pb2.Timestamp, _Mapping]] = ..., attributes: _Optional[_Iterable[_Union[GrpcEventAttribute, _Mapping]]] = ...) -> None: ...
+
+class GrpcEventLogGlobal(_message.Message):
+    __slots__ = ["scope", "defaults"]
+    SCOPE_FIELD_NUMBER: _ClassVar[int]
+    DEFAULTS_FIELD_NUMBER: _ClassVar[int]
+    scope: str
+    defaults: _containers.RepeatedCompositeFieldContainer[GrpcEventAttribute]
+    def __init__(self, scope: _Optional[str] = ..., defaults: _Optional[_Iterable[_Union[GrpcEventAttribute, _Mapping]]] = ...) -> None: ...
+
+class GrpcEventAttribute(_message.Message):
+    __slots__ = ["key", "value"]
+    KEY_FIELD_NUMBER: _ClassVar[int]
+    VALUE_FIELD_NUMBER: _ClassVar[int]
+    key: str
+    value: GrpcEventAttributeValue
+    def __init__(self, key: _Optional[str] = ..., value: _Optional[_Union[GrpcEventAttributeValue, _Mapping]] = ...) -> None: ...
+
+class GrpcEventAttributeValue(_message.Message):
+    __slots__ = ["date", "string", "bool", "int32", "int64", "float32", "float64", "uint32", "uint64", "guid", "timestamp", "standardLifecycle", "brafLifecycle"]
+    DATE_FIELD_NUMBER: _ClassVar[int]
+    STRING_FIELD_NUMBER: _ClassVar[int]
+    BOOL_FIELD_NUMBER: _ClassVar[int]
+    INT32_FIELD_NUMBER: _ClassVar[int]
+    INT64_FIELD_NUMBER: _ClassVar[int]
+    FLOAT32_FIELD_NUMBER: _ClassVar[int]
+    FLOAT64_FIELD_NUMBER: _ClassVar[int]
+    UINT32_FIELD_NUMBER: _ClassVar[int]
+    UINT64_FIELD_NUMBER: _ClassVar[int]
+    GUID_FIELD_NUMBER: _ClassVar[int]
+    TIMESTAMP_FIELD_NUMBER: _ClassVar[int]
+    STANDARDLIFECYCLE_FIELD_NUMBER: _ClassVar[int]
+    BRAFLIFECYCLE_FIELD_NUMBER: _ClassVar[int]
+    date: _timestamp_pb2.Timestamp
+    string: str
+    bool: bool
+    int32: int
+    int64: int
+    float32: float
+    float64: float
+    uint32: int
+    uint64: int
+    guid: str
+    timestamp: int
+    standardLifecycle: str
+    brafLifecycle: str
+    def __init__(self, date: _Optional[_Union[_timestamp_pb2.Timestamp, _Mapping]] = ..., string: _Optional[str] = ..., bool: bool = ..., int32: _Optional[int] = ..., int64: _Optional[int] = ..., float32: _Optional[float] = ..., float64: _Optional[float] = ..., uint32: _Optional[int] = ..., uint64: _Optional[int] = ..., guid: _Optional[str] = ..., timestamp: _Optional[int] = ..., standardLifecycle: _Optional[str] = ..., brafLifecycle: _Optional[str] = ...) -> None: ...
+
 class GrpcHashesEventLog(_message.Message):
     __slots__ = ["traces"]
     TRACES_FIELD_NUMBER: _ClassVar[int]
@@ -71,35 +143,71 @@ class GrpcPetriNet(_message.Message):
     final_marking: GrpcPetriNetMarking
     def __init__(self, places: _Optional[_Iterable[_Union[GrpcPetriNetPlace, _Mapping]]] = ..., transitions: _Optional[_Iterable[_Union[GrpcPetriNetTransition, _Mapping]]] = ..., initial_marking: _Optional[_Union[GrpcPetriNetMarking, _Mapping]] = ..., final_marking: _Optional[_Union[GrpcPetriNetMarking, _Mapping]] = ...) -> None: ...
 
+class GrpcHierarchicalPetriNet(_message.Message):
+    __slots__ = ["name", "petriNet", "subprocesses"]
+    NAME_FIELD_NUMBER: _ClassVar[int]
+    PETRINET_FIELD_NUMBER: _ClassVar[int]
+    SUBPROCESSES_FIELD_NUMBER: _ClassVar[int]
+    name: str
+    petriNet: GrpcPetriNet
+    subprocesses: _containers.RepeatedCompositeFieldContainer[GrpcHierarchicalPetriNet]
+    def __init__(self, name: _Optional[str] = ..., petriNet: _Optional[_Union[GrpcPetriNet, _Mapping]] = ..., subprocesses: _Optional[_Iterable[_Union[GrpcHierarchicalPetriNet, _Mapping]]] = ...) -> None: ...
+
 class GrpcPetriNetPlace(_message.Message):
-    __slots__ = ["id", "name"]
+    __slots__ = ["id", "name", "layout"]
     ID_FIELD_NUMBER: _ClassVar[int]
     NAME_FIELD_NUMBER: _ClassVar[int]
+    LAYOUT_FIELD_NUMBER: _ClassVar[int]
     id: int
     name: str
-    def __init__(self, id: _Optional[int] = ..., name: _Optional[str] = ...) -> None: ...
+    layout: GrpcNodeLayout
+    def __init__(self, id: _Optional[int] = ..., name: _Optional[str] = ..., layout: _Optional[_Union[GrpcNodeLayout, _Mapping]] = ...) -> None: ...
 
 class GrpcPetriNetTransition(_message.Message):
-    __slots__ = ["id", "incomingArcs", "outgoingArcs", "data"]
+    __slots__ = ["id", "incomingArcs", "outgoingArcs", "data", "layout"]
     ID_FIELD_NUMBER: _ClassVar[int]
     INCOMINGARCS_FIELD_NUMBER: _ClassVar[int]
     OUTGOINGARCS_FIELD_NUMBER: _ClassVar[int]
     DATA_FIELD_NUMBER: _ClassVar[int]
+    LAYOUT_FIELD_NUMBER: _ClassVar[int]
     id: int
     incomingArcs: _containers.RepeatedCompositeFieldContainer[GrpcPetriNetArc]
     outgoingArcs: _containers.RepeatedCompositeFieldContainer[GrpcPetriNetArc]
     data: str
-    def __init__(self, id: _Optional[int] = ..., incomingArcs: _Optional[_Iterable[_Union[GrpcPetriNetArc, _Mapping]]] = ..., outgoingArcs: _Optional[_Iterable[_Union[GrpcPetriNetArc, _Mapping]]] = ..., data: _Optional[str] = ...) -> None: ...
+    layout: GrpcNodeLayout
+    def __init__(self, id: _Optional[int] = ..., incomingArcs: _Optional[_Iterable[_Union[GrpcPetriNetArc, _Mapping]]] = ..., outgoingArcs: _Optional[_Iterable[_Union[GrpcPetriNetArc, _Mapping]]] = ..., data: _Optional[str] = ..., layout: _Optional[_Union[GrpcNodeLayout, _Mapping]] = ...) -> None: ...
 
 class GrpcPetriNetArc(_message.Message):
-    __slots__ = ["id", "placeId", "tokens_count"]
+    __slots__ = ["id", "placeId", "tokens_count", "bend_points"]
     ID_FIELD_NUMBER: _ClassVar[int]
     PLACEID_FIELD_NUMBER: _ClassVar[int]
     TOKENS_COUNT_FIELD_NUMBER: _ClassVar[int]
+    BEND_POINTS_FIELD_NUMBER: _ClassVar[int]
     id: int
     placeId: int
     tokens_count: int
-    def __init__(self, id: _Optional[int] = ..., placeId: _Optional[int] = ..., tokens_count: _Optional[int] = ...) -> None: ...
+    bend_points: _containers.RepeatedCompositeFieldContainer[GrpcPoint]
+    def __init__(self, id: _Optional[int] = ..., placeId: _Optional[int] = ..., tokens_count: _Optional[int] = ..., bend_points: _Optional[_Iterable[_Union[GrpcPoint, _Mapping]]] = ...) -> None: ...
+
+class GrpcNodeLayout(_message.Message):
+    __slots__ = ["x", "y", "width", "height"]
+    X_FIELD_NUMBER: _ClassVar[int]
+    Y_FIELD_NUMBER: _ClassVar[int]
+    WIDTH_FIELD_NUMBER: _ClassVar[int]
+    HEIGHT_FIELD_NUMBER: _ClassVar[int]
+    x: float
+    y: float
+    width: float
+    height: float
+    def __init__(self, x: _Optional[float] = ..., y: _Optional[float] = ..., width: _Optional[float] = ..., height: _Optional[float] = ...) -> None: ...
+
+class GrpcPoint(_message.Message):
+    __slots__ = ["x", "y"]
+    X_FIELD_NUMBER: _ClassVar[int]
+    Y_FIELD_NUMBER: _ClassVar[int]
+    x: float
+    y: float
+    def __init__(self, x: _Optional[float] = ..., y: _Optional[float] = ...) -> None: ...
 
 class GrpcPetriNetMarking(_message.Message):
     __slots__ = ["markings"]
@@ -174,3 +282,75 @@ class GrpcLabeledDataset(_message.Message):
     labels: _containers.RepeatedScalarFieldContainer[int]
     labelsColors: _containers.RepeatedCompositeFieldContainer[_util_pb2.GrpcColor]
     def __init__(self, dataset: _Optional[_Union[GrpcDataset, _Mapping]] = ..., labels: _Optional[_Iterable[int]] = ..., labelsColors: _Optional[_Iterable[_Union[_util_pb2.GrpcColor, _Mapping]]] = ...) -> None: ...
+
+class GrpcDendrogram(_message.Message):
+    __slots__ = ["leavesNames", "merges"]
+    LEAVESNAMES_FIELD_NUMBER: _ClassVar[int]
+    MERGES_FIELD_NUMBER: _ClassVar[int]
+    leavesNames: _containers.RepeatedScalarFieldContainer[str]
+    merges: _containers.RepeatedCompositeFieldContainer[GrpcDendrogramMerge]
+    def __init__(self, leavesNames: _Optional[_Iterable[str]] = ..., merges: _Optional[_Iterable[_Union[GrpcDendrogramMerge, _Mapping]]] = ...) -> None: ...
+
+class GrpcDendrogramMerge(_message.Message):
+    __slots__ = ["first", "second", "distance", "size"]
+    FIRST_FIELD_NUMBER: _ClassVar[int]
+    SECOND_FIELD_NUMBER: _ClassVar[int]
+    DISTANCE_FIELD_NUMBER: _ClassVar[int]
+    SIZE_FIELD_NUMBER: _ClassVar[int]
+    first: int
+    second: int
+    distance: float
+    size: int
+    def __init__(self, first: _Optional[int] = ..., second: _Optional[int] = ..., distance: _Optional[float] = ..., size: _Optional[int] = ...) -> None: ...
+
+class GrpcDeclareConstraintMetrics(_message.Message):
+    __slots__ = ["support", "confidence"]
+    SUPPORT_FIELD_NUMBER: _ClassVar[int]
+    CONFIDENCE_FIELD_NUMBER: _ClassVar[int]
+    support: float
+    confidence: float
+    def __init__(self, support: _Optional[float] = ..., confidence: _Optional[float] = ...) -> None: ...
+
+class GrpcDeclareConstraint(_message.Message):
+    __slots__ = ["template", "parameters", "metrics"]
+    TEMPLATE_FIELD_NUMBER: _ClassVar[int]
+    PARAMETERS_FIELD_NUMBER: _ClassVar[int]
+    METRICS_FIELD_NUMBER: _ClassVar[int]
+    template: str
+    parameters: _containers.RepeatedScalarFieldContainer[str]
+    metrics: GrpcDeclareConstraintMetrics
+    def __init__(self, template: _Optional[str] = ..., parameters: _Optional[_Iterable[str]] = ..., metrics: _Optional[_Union[GrpcDeclareConstraintMetrics, _Mapping]] = ...) -> None: ...
+
+class GrpcDeclareModel(_message.Message):
+    __slots__ = ["constraints"]
+    CONSTRAINTS_FIELD_NUMBER: _ClassVar[int]
+    constraints: _containers.RepeatedCompositeFieldContainer[GrpcDeclareConstraint]
+    def __init__(self, constraints: _Optional[_Iterable[_Union[GrpcDeclareConstraint, _Mapping]]] = ...) -> None: ...
+
+class GrpcDeclareConstraintCheckResult(_message.Message):
+    __slots__ = ["activations", "fulfilments", "violations"]
+    ACTIVATIONS_FIELD_NUMBER: _ClassVar[int]
+    FULFILMENTS_FIELD_NUMBER: _ClassVar[int]
+    VIOLATIONS_FIELD_NUMBER: _ClassVar[int]
+    activations: int
+    fulfilments: int
+    violations: int
+    def __init__(self, activations: _Optional[int] = ..., fulfilments: _Optional[int] = ..., violations: _Optional[int] = ...) -> None: ...
+
+class GrpcDeclareTraceConformance(_message.Message):
+    __slots__ = ["constraintsResults", "fitness"]
+    CONSTRAINTSRESULTS_FIELD_NUMBER: _ClassVar[int]
+    FITNESS_FIELD_NUMBER: _ClassVar[int]
+    constraintsResults: _containers.RepeatedCompositeFieldContainer[GrpcDeclareConstraintCheckResult]
+    fitness: float
+    def __init__(self, constraintsResults: _Optional[_Iterable[_Union[GrpcDeclareConstraintCheckResult, _Mapping]]] = ..., fitness: _Optional[float] = ...) -> None: ...
+
+class GrpcDeclareConformance(_message.Message):
+    __slots__ = ["model", "traces", "fitness"]
+    MODEL_FIELD_NUMBER: _ClassVar[int]
+    TRACES_FIELD_NUMBER: _ClassVar[int]
+    FITNESS_FIELD_NUMBER: _ClassVar[int]
+    model: GrpcDeclareModel
+    traces: _containers.RepeatedCompositeFieldContainer[GrpcDeclareTraceConformance]
+    fitness: float
+    def __init__(self, model: _Optional[_Union[GrpcDeclareModel, _Mapping]] = ..., traces: _Optional[_Iterable[_Union[GrpcDeclareTraceConformance, _Mapping]]] = ..., fitness: _Optional[float] = ...) -> None: ...
@@ -7,14 +7,23 @@ use crate::event_log::{
     },
 };
 
-use super::{utils, xes_log_trace_reader::TraceXesEventLogIterator};
+use super::{
+    utils,
+    xes_log_trace_reader::{TraceXesEventLogIterator, XesReader},
+};
 use crate::event_log::xes::constants::*;
 use quick_xml::{events::BytesStart, Reader};
-use std::{cell::RefCell, collections::HashMap, fs::File, io::BufReader, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    rc::Rc,
+};
 
 pub struct FromFileXesEventLogReader {
     storage: Rc<RefCell<Vec<u8>>>,
-    reader: Rc<RefCell<Reader<BufReader<File>>>>,
+    reader: Rc<RefCell<XesReader>>,
     seen_globals: Rc<RefCell<HashMap<String, HashMap<String, EventPayloadValue>>>>,
 }
 
@@ -30,6 +39,10 @@ pub fn read_event_log(file_path: &str) -> Option<XesEventLogImpl> {
     XesEventLogImpl::new(FromFileXesEventLogReader::new(file_path)?)
 }
 
+pub fn read_event_log_from_reader(reader: Box<dyn BufRead>) -> Option<XesEventLogImpl> {
+    XesEventLogImpl::new(FromFileXesEventLogReader::from_reader(reader))
+}
+
 impl Iterator for FromFileXesEventLogReader {
     type Item = XesEventLogItem;
 
@@ -88,16 +101,20 @@ impl Iterator for FromFileXesEventLogReader {
 
 impl FromFileXesEventLogReader {
     pub fn new(file_path: &str) -> Option<FromFileXesEventLogReader> {
-        match Reader::from_file(file_path) {
-            Ok(reader) => Some(FromFileXesEventLogReader {
-                reader: Rc::new(RefCell::new(reader)),
-                storage: Rc::new(RefCell::new(Vec::new())),
-                seen_globals: Rc::new(RefCell::new(HashMap::new())),
-            }),
+        match File::open(file_path) {
+            Ok(file) => Some(Self::from_reader(Box::new(BufReader::new(file)))),
             Err(_) => None,
         }
     }
 
+    pub fn from_reader(reader: Box<dyn BufRead>) -> FromFileXesEventLogReader {
+        FromFileXesEventLogReader {
+            reader: Rc::new(RefCell::new(Reader::from_reader(reader))),
+            storage: Rc::new(RefCell::new(Vec::new())),
+            seen_globals: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
     fn try_read_scope_name(tag: &BytesStart) -> Option<String> {
         let mut scope_name: Option<String> = None;
 
@@ -154,7 +171,7 @@ impl FromFileXesEventLogReader {
         }
     }
 
-    fn try_read_global(reader: &mut Reader<BufReader<File>>, storage: &mut Vec<u8>) -> Option<HashMap<String, EventPayloadValue>> {
+    fn try_read_global(reader: &mut XesReader, storage: &mut Vec<u8>) -> Option<HashMap<String, EventPayloadValue>> {
         let mut map: Option<HashMap<String, EventPayloadValue>> = None;
 
         loop {
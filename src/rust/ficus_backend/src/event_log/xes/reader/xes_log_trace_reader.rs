@@ -4,14 +4,17 @@ use crate::event_log::xes::constants::*;
 
 use chrono::{DateTime, Utc};
 use quick_xml::Reader;
-use std::{cell::RefCell, collections::HashMap, fs::File, io::BufReader, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, io::BufRead, rc::Rc};
 
 use super::utils;
 
+pub(crate) type XesReader = Reader<Box<dyn BufRead>>;
+
 pub struct TraceXesEventLogIterator {
     buffer: Vec<u8>,
-    reader: Rc<RefCell<Reader<BufReader<File>>>>,
+    reader: Rc<RefCell<XesReader>>,
     globals: Rc<RefCell<HashMap<String, HashMap<String, EventPayloadValue>>>>,
+    metadata: HashMap<String, EventPayloadValue>,
 }
 
 impl Iterator for TraceXesEventLogIterator {
@@ -28,6 +31,14 @@ impl Iterator for TraceXesEventLogIterator {
                     },
                     _ => continue,
                 },
+                Ok(quick_xml::events::Event::Empty(empty)) => {
+                    if let Some(descriptor) = utils::read_payload_like_tag(&empty) {
+                        let payload_type = descriptor.payload_type.as_str().as_bytes();
+                        if let Some(value) = utils::extract_payload_value(payload_type, descriptor.value.as_str()) {
+                            self.metadata.insert(descriptor.key, value);
+                        }
+                    }
+                }
                 Ok(quick_xml::events::Event::End(e)) => match e.name().0 {
                     TRACE_TAG_NAME => return None,
                     _ => continue,
@@ -41,16 +52,21 @@ impl Iterator for TraceXesEventLogIterator {
 
 impl TraceXesEventLogIterator {
     pub(crate) fn new(
-        reader: Rc<RefCell<Reader<BufReader<File>>>>,
+        reader: Rc<RefCell<XesReader>>,
         seen_globals: Rc<RefCell<HashMap<String, HashMap<String, EventPayloadValue>>>>,
     ) -> TraceXesEventLogIterator {
         TraceXesEventLogIterator {
             reader,
             buffer: Vec::new(),
             globals: seen_globals,
+            metadata: HashMap::new(),
         }
     }
 
+    pub fn take_metadata(&mut self) -> HashMap<String, EventPayloadValue> {
+        std::mem::take(&mut self.metadata)
+    }
+
     fn try_parse_event_from(&mut self) -> Option<XesEventImpl> {
         let mut name = None;
         let mut date = None;
@@ -94,8 +94,11 @@ impl XesEventLogImpl {
 
         for item in event_log_reader {
             match item {
-                XesEventLogItem::Trace(trace_reader) => match XesTraceImpl::new(trace_reader) {
-                    Some(trace) => traces.push(Rc::new(RefCell::new(trace))),
+                XesEventLogItem::Trace(mut trace_reader) => match XesTraceImpl::new(&mut trace_reader) {
+                    Some(mut trace) => {
+                        *trace.metadata_mut() = trace_reader.take_metadata();
+                        traces.push(Rc::new(RefCell::new(trace)));
+                    }
                     None => continue,
                 },
                 XesEventLogItem::Global(global) => _ = globals.insert(global.scope, global.default_values),
@@ -1,7 +1,10 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::event_log::core::{
-    event::events_holder::{EventSequenceInfo, EventsHolder, EventsPositions},
+    event::{
+        event::EventPayloadValue,
+        events_holder::{EventSequenceInfo, EventsHolder, EventsPositions},
+    },
     trace::trace::Trace,
 };
 
@@ -9,6 +12,7 @@ use super::xes_event::XesEventImpl;
 
 pub struct XesTraceImpl {
     events_holder: EventsHolder<XesEventImpl>,
+    metadata: HashMap<String, EventPayloadValue>,
 }
 
 impl XesTraceImpl {
@@ -23,14 +27,24 @@ impl XesTraceImpl {
 
         Some(XesTraceImpl {
             events_holder: EventsHolder::new(events),
+            metadata: HashMap::new(),
         })
     }
+
+    pub fn metadata(&self) -> &HashMap<String, EventPayloadValue> {
+        &self.metadata
+    }
+
+    pub fn metadata_mut(&mut self) -> &mut HashMap<String, EventPayloadValue> {
+        &mut self.metadata
+    }
 }
 
 impl Clone for XesTraceImpl {
     fn clone(&self) -> Self {
         Self {
             events_holder: self.events_holder.clone(),
+            metadata: self.metadata.clone(),
         }
     }
 }
@@ -43,6 +57,7 @@ impl Trace for XesTraceImpl {
     fn empty() -> Self {
         Self {
             events_holder: EventsHolder::empty(),
+            metadata: HashMap::new(),
         }
     }
 
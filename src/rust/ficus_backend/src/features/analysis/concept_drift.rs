@@ -0,0 +1,501 @@
+use std::{cell::RefCell, collections::HashMap, hash::Hash, rc::Rc, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::{
+    event_log::core::{event::event::Event, event_log::EventLog, trace::trace::Trace},
+    utils::dataset::dataset::FicusDataset,
+};
+
+use super::constants::{FAKE_EVENT_END_NAME, FAKE_EVENT_START_NAME};
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum DriftFeature {
+    DfgRelations,
+    EventClasses,
+    Entropy,
+}
+
+impl FromStr for DriftFeature {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "DfgRelations" => Ok(Self::DfgRelations),
+            "EventClasses" => Ok(Self::EventClasses),
+            "Entropy" => Ok(Self::Entropy),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct ConceptDriftParams {
+    pub feature: DriftFeature,
+    pub window_size: usize,
+    pub significance_level: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChangePoint {
+    position: usize,
+    p_value: f64,
+}
+
+impl ChangePoint {
+    //Position in the time-ordered log, the change happens before the trace with this position
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    //Adjusted for the count of tested positions
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+pub struct ConceptDriftResult {
+    ordered_traces: Vec<usize>,
+    p_values: Vec<(usize, f64)>,
+    adjusted_p_values: Vec<(usize, f64)>,
+    change_points: Vec<ChangePoint>,
+}
+
+impl ConceptDriftResult {
+    //Indices of traces of the original log ordered by their start time
+    pub fn ordered_traces(&self) -> &Vec<usize> {
+        &self.ordered_traces
+    }
+
+    pub fn p_values(&self) -> &Vec<(usize, f64)> {
+        &self.p_values
+    }
+
+    pub fn adjusted_p_values(&self) -> &Vec<(usize, f64)> {
+        &self.adjusted_p_values
+    }
+
+    pub fn change_points(&self) -> &Vec<ChangePoint> {
+        &self.change_points
+    }
+
+    //Each row describes one tested position of the time-ordered log
+    pub fn to_dataset(&self) -> FicusDataset {
+        let columns = ["p_value", "adjusted_p_value", "change_point"];
+        let values = self
+            .p_values
+            .iter()
+            .zip(self.adjusted_p_values.iter())
+            .map(|((position, p_value), (_, adjusted_p_value))| {
+                let is_change_point = self.change_points.iter().any(|point| point.position == *position);
+                vec![*p_value, *adjusted_p_value, if is_change_point { 1.0 } else { 0.0 }]
+            })
+            .collect();
+
+        let rows = self.p_values.iter().map(|(position, _)| format!("Trace_{}", position)).collect();
+        FicusDataset::new(values, columns.iter().map(|x| x.to_string()).collect(), rows)
+    }
+}
+
+const PERMUTATIONS_COUNT: usize = 100;
+const PERMUTATIONS_SEED: u64 = 42;
+const PERMUTATIONS_EXCEEDANCES_LIMIT: usize = 10;
+
+//Adjacent windows of time-ordered traces are compared at each position, p-values get the Bonferroni correction
+pub fn detect_concept_drift<TLog: EventLog>(log: &TLog, params: &ConceptDriftParams) -> ConceptDriftResult {
+    let ordered_traces = order_traces_by_start_time(log);
+    let window_size = params.window_size.max(1);
+    let tests_count = (ordered_traces.len() + 1).saturating_sub(2 * window_size);
+
+    let p_values = match params.feature {
+        DriftFeature::DfgRelations => {
+            calculate_chi_square_p_values(&create_traces_counts(log, &ordered_traces, count_dfg_relations), window_size)
+        }
+        DriftFeature::EventClasses => {
+            calculate_chi_square_p_values(&create_traces_counts(log, &ordered_traces, count_event_classes), window_size)
+        }
+        DriftFeature::Entropy => {
+            let traces_counts = create_traces_counts(log, &ordered_traces, count_entropy_relations);
+            let permutations_count = calculate_permutations_count(tests_count, params.significance_level);
+
+            (window_size..(window_size + tests_count))
+                .map(|position| {
+                    let windows_counts = &traces_counts[(position - window_size)..(position + window_size)];
+                    (position, entropy_permutation_test(windows_counts, permutations_count))
+                })
+                .collect()
+        }
+    };
+
+    let adjusted_p_values = p_values
+        .iter()
+        .map(|(position, p_value)| (*position, (p_value * tests_count as f64).min(1.0)))
+        .collect::<Vec<(usize, f64)>>();
+
+    let change_points = find_change_points(&adjusted_p_values, params.significance_level, window_size);
+
+    ConceptDriftResult {
+        ordered_traces,
+        p_values,
+        adjusted_p_values,
+        change_points,
+    }
+}
+
+//Sub-logs are formed from time-ordered traces between consecutive change points
+pub fn split_log_by_change_points<TLog: EventLog>(log: &TLog, result: &ConceptDriftResult) -> Vec<TLog> {
+    let mut boundaries = result.change_points.iter().map(|point| point.position).collect::<Vec<usize>>();
+    boundaries.push(result.ordered_traces.len());
+
+    let mut sub_logs = vec![];
+    let mut start = 0;
+    for end in boundaries {
+        sub_logs.push(create_window_log(log, &result.ordered_traces[start..end]));
+        start = end;
+    }
+
+    sub_logs
+}
+
+pub(crate) fn order_traces_by_start_time(log: &impl EventLog) -> Vec<usize> {
+    let start_times = log
+        .traces()
+        .iter()
+        .map(|trace| trace.borrow().events().first().map(|event| *event.borrow().timestamp()))
+        .collect::<Vec<Option<DateTime<Utc>>>>();
+
+    let mut ordered_traces = (0..start_times.len()).collect::<Vec<usize>>();
+    ordered_traces.sort_by_key(|index| (start_times[*index].is_none(), start_times[*index]));
+
+    ordered_traces
+}
+
+pub(crate) fn create_window_log<TLog: EventLog>(log: &TLog, traces: &[usize]) -> TLog {
+    let mut window_log = TLog::empty();
+    for index in traces {
+        let trace_copy = log.traces()[*index].borrow().clone();
+        window_log.push(Rc::new(RefCell::new(trace_copy)));
+    }
+
+    window_log
+}
+
+fn create_traces_counts<TLog: EventLog, TKey>(
+    log: &TLog,
+    ordered_traces: &[usize],
+    counter: impl Fn(&[String]) -> HashMap<TKey, f64>,
+) -> Vec<HashMap<TKey, f64>> {
+    ordered_traces
+        .iter()
+        .map(|index| {
+            let trace = log.traces()[*index].borrow();
+            let names = trace
+                .events()
+                .iter()
+                .map(|event| event.borrow().name().to_owned())
+                .collect::<Vec<String>>();
+
+            counter(&names)
+        })
+        .collect()
+}
+
+fn count_event_classes(trace: &[String]) -> HashMap<String, f64> {
+    let mut counts = HashMap::new();
+    for name in trace {
+        *counts.entry(name.to_owned()).or_default() += 1.0;
+    }
+
+    counts
+}
+
+fn count_dfg_relations(trace: &[String]) -> HashMap<String, f64> {
+    let mut counts = HashMap::new();
+    for pair in trace.windows(2) {
+        *counts.entry(format!("{} -> {}", pair[0], pair[1])).or_default() += 1.0;
+    }
+
+    counts
+}
+
+//Relations with artificial start and end events are counted too, as in `calculate_default_dfg_entropy`
+fn count_entropy_relations(trace: &[String]) -> HashMap<(String, String), f64> {
+    let mut counts = HashMap::new();
+    if trace.is_empty() {
+        return counts;
+    }
+
+    let names = [FAKE_EVENT_START_NAME]
+        .into_iter()
+        .chain(trace.iter().map(|name| name.as_str()))
+        .chain([FAKE_EVENT_END_NAME])
+        .collect::<Vec<&str>>();
+
+    for pair in names.windows(2) {
+        *counts.entry((pair[0].to_owned(), pair[1].to_owned())).or_default() += 1.0;
+    }
+
+    counts
+}
+
+//Categories whose counts become zero are removed, so that they are absent in windows
+fn update_counts<TKey: Hash + Eq + Clone>(counts: &mut HashMap<TKey, f64>, trace_counts: &HashMap<TKey, f64>, sign: f64) {
+    for (key, count) in trace_counts {
+        let new_count = counts.get(key).copied().unwrap_or_default() + sign * count;
+        if new_count == 0.0 {
+            counts.remove(key);
+        } else {
+            counts.insert(key.clone(), new_count);
+        }
+    }
+}
+
+fn sum_counts<'a, TKey: Hash + Eq + Clone + 'a>(traces_counts: impl Iterator<Item = &'a HashMap<TKey, f64>>) -> HashMap<TKey, f64> {
+    let mut counts = HashMap::new();
+    for trace_counts in traces_counts {
+        update_counts(&mut counts, trace_counts, 1.0);
+    }
+
+    counts
+}
+
+//Windows are [position - window_size, position) and [position, position + window_size)
+fn calculate_chi_square_p_values(traces_counts: &[HashMap<String, f64>], window_size: usize) -> Vec<(usize, f64)> {
+    let mut p_values = vec![];
+    if traces_counts.len() < 2 * window_size {
+        return p_values;
+    }
+
+    let mut reference = sum_counts(traces_counts[..window_size].iter());
+    let mut detection = sum_counts(traces_counts[window_size..(2 * window_size)].iter());
+
+    for position in window_size..=(traces_counts.len() - window_size) {
+        if position > window_size {
+            update_counts(&mut reference, &traces_counts[position - window_size - 1], -1.0);
+            update_counts(&mut reference, &traces_counts[position - 1], 1.0);
+            update_counts(&mut detection, &traces_counts[position - 1], -1.0);
+            update_counts(&mut detection, &traces_counts[position + window_size - 1], 1.0);
+        }
+
+        p_values.push((position, chi_square_homogeneity_test(&reference, &detection)));
+    }
+
+    p_values
+}
+
+//Chi-square test of homogeneity for the 2 x k contingency table, categories which are absent in both windows are ignored
+fn chi_square_homogeneity_test(first: &HashMap<String, f64>, second: &HashMap<String, f64>) -> f64 {
+    let mut categories = first.keys().chain(second.keys()).collect::<Vec<&String>>();
+    categories.sort();
+    categories.dedup();
+
+    let first_total = first.values().sum::<f64>();
+    let second_total = second.values().sum::<f64>();
+    let total = first_total + second_total;
+    if categories.len() < 2 || first_total == 0.0 || second_total == 0.0 {
+        return 1.0;
+    }
+
+    let mut statistic = 0.0;
+    for category in &categories {
+        let first_count = first.get(*category).copied().unwrap_or_default();
+        let second_count = second.get(*category).copied().unwrap_or_default();
+        let category_total = first_count + second_count;
+
+        for (count, window_total) in [(first_count, first_total), (second_count, second_total)] {
+            let expected = category_total * window_total / total;
+            statistic += (count - expected).powi(2) / expected;
+        }
+    }
+
+    chi_square_survival(statistic, (categories.len() - 1) as f64)
+}
+
+//The least p-value of the permutation test must remain significant after the adjustment
+fn calculate_permutations_count(tests_count: usize, significance_level: f64) -> usize {
+    if significance_level <= 0.0 {
+        return PERMUTATIONS_COUNT;
+    }
+
+    PERMUTATIONS_COUNT.max((tests_count as f64 / significance_level).ceil() as usize)
+}
+
+//The entropy of an event class is the entropy of its followers plus the entropy of its predecessors
+fn calculate_dfg_entropies(relations: &HashMap<(String, String), f64>) -> HashMap<String, f64> {
+    let mut followers_counts: HashMap<&String, f64> = HashMap::new();
+    let mut predecessors_counts: HashMap<&String, f64> = HashMap::new();
+    for ((first, second), count) in relations {
+        *followers_counts.entry(first).or_default() += count;
+        *predecessors_counts.entry(second).or_default() += count;
+    }
+
+    let mut entropies: HashMap<String, f64> = HashMap::new();
+    for ((first, second), count) in relations {
+        if first != FAKE_EVENT_START_NAME {
+            let probability = count / followers_counts[first];
+            *entropies.entry(first.to_owned()).or_default() -= probability * probability.log2();
+        }
+
+        if second != FAKE_EVENT_END_NAME {
+            let probability = count / predecessors_counts[second];
+            *entropies.entry(second.to_owned()).or_default() -= probability * probability.log2();
+        }
+    }
+
+    entropies
+}
+
+fn entropy_distance(first: &HashMap<String, f64>, second: &HashMap<String, f64>) -> f64 {
+    let mut distance = 0.0;
+    for (class, entropy) in first {
+        distance += (entropy - second.get(class).copied().unwrap_or_default()).abs();
+    }
+
+    for (class, entropy) in second {
+        if !first.contains_key(class) {
+            distance += entropy.abs();
+        }
+    }
+
+    distance
+}
+
+//L1 distance between DFG entropies of halves, stopped early by the sequential (Besag-Clifford) rule
+fn entropy_permutation_test(traces_counts: &[HashMap<(String, String), f64>], permutations_count: usize) -> f64 {
+    let half = traces_counts.len() / 2;
+    let statistic = |traces: &[usize]| {
+        let first = calculate_dfg_entropies(&sum_counts(traces[..half].iter().map(|index| &traces_counts[*index])));
+        let second = calculate_dfg_entropies(&sum_counts(traces[half..].iter().map(|index| &traces_counts[*index])));
+        entropy_distance(&first, &second)
+    };
+
+    let mut permuted = (0..traces_counts.len()).collect::<Vec<usize>>();
+    let observed = statistic(&permuted);
+    let mut rng = StdRng::seed_from_u64(PERMUTATIONS_SEED);
+    let mut not_less_count = 0;
+
+    for permutation in 1..=permutations_count {
+        permuted.shuffle(&mut rng);
+        if statistic(&permuted) >= observed - 1e-12 {
+            not_less_count += 1;
+            if not_less_count == PERMUTATIONS_EXCEEDANCES_LIMIT {
+                return not_less_count as f64 / permutation as f64;
+            }
+        }
+    }
+
+    (not_less_count + 1) as f64 / (permutations_count + 1) as f64
+}
+
+fn find_change_points(p_values: &Vec<(usize, f64)>, significance_level: f64, window_size: usize) -> Vec<ChangePoint> {
+    let mut candidates: Vec<ChangePoint> = vec![];
+    let mut current: Option<ChangePoint> = None;
+
+    for (position, p_value) in p_values {
+        if *p_value < significance_level {
+            if current.is_none_or(|point| *p_value < point.p_value) {
+                current = Some(ChangePoint {
+                    position: *position,
+                    p_value: *p_value,
+                });
+            }
+        } else if let Some(point) = current.take() {
+            candidates.push(point);
+        }
+    }
+
+    if let Some(point) = current {
+        candidates.push(point);
+    }
+
+    let mut change_points: Vec<ChangePoint> = vec![];
+    for candidate in candidates {
+        match change_points.last_mut() {
+            Some(last) if candidate.position - last.position < window_size => {
+                if candidate.p_value < last.p_value {
+                    *last = candidate;
+                }
+            }
+            _ => change_points.push(candidate),
+        }
+    }
+
+    change_points
+}
+
+fn chi_square_survival(statistic: f64, degrees_of_freedom: f64) -> f64 {
+    if statistic <= 0.0 {
+        return 1.0;
+    }
+
+    regularized_upper_gamma(degrees_of_freedom / 2.0, statistic / 2.0)
+}
+
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut series = 1.000000000190015;
+    for (index, coefficient) in COEFFICIENTS.iter().enumerate() {
+        series += coefficient / (x + 1.0 + index as f64);
+    }
+
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+//Q(a, x) is calculated with the series for x < a + 1 and with the continued fraction otherwise
+fn regularized_upper_gamma(a: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-14;
+    const MAX_ITERATIONS: usize = 1000;
+
+    let log_prefix = -x + a * x.ln() - ln_gamma(a);
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+
+        return (1.0 - sum * log_prefix.exp()).clamp(0.0, 1.0);
+    }
+
+    let tiny = f64::MIN_POSITIVE / EPSILON;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    (log_prefix.exp() * h).clamp(0.0, 1.0)
+}
@@ -136,6 +136,25 @@ impl EventLogInfo {
             }
         }
 
+        EventLogInfo::from_counts(
+            events_count,
+            events_counts,
+            dfg_pairs,
+            log.traces().len(),
+            start_event_classes,
+            end_event_classes,
+        )
+    }
+
+    //Creates info from already calculated counts, e.g. maintained over a stream of events
+    pub fn from_counts(
+        events_count: usize,
+        event_classes_counts: HashMap<String, usize>,
+        dfg_pairs: HashMap<String, HashMap<String, usize>>,
+        traces_count: usize,
+        start_event_classes: HashSet<String>,
+        end_event_classes: HashSet<String>,
+    ) -> EventLogInfo {
         let mut followed_events: HashMap<String, HashMap<String, usize>> = HashMap::new();
         let mut precedes_events: HashMap<String, HashMap<String, usize>> = HashMap::new();
         let mut events_with_single_follower = HashSet::new();
@@ -168,14 +187,14 @@ impl EventLogInfo {
 
         EventLogInfo {
             events_count,
-            event_classes_counts: events_counts,
+            event_classes_counts,
             dfg_info: DfgInfo {
                 dfg_pairs,
                 followed_events,
                 precedes_events,
                 events_with_single_follower,
             },
-            traces_count: log.traces().len(),
+            traces_count,
             start_event_classes,
             end_event_classes,
         }
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::utils::dataset::dataset::FicusDataset;
+use crate::utils::graph::graph::Graph;
+
+pub const IN_DEGREE: &'static str = "in_degree";
+pub const OUT_DEGREE: &'static str = "out_degree";
+pub const BETWEENNESS: &'static str = "betweenness";
+pub const STRONGLY_CONNECTED_COMPONENT: &'static str = "strongly_connected_component";
+pub const IN_CYCLE: &'static str = "in_cycle";
+pub const REACHABLE_NODES: &'static str = "reachable_nodes";
+
+//Each row describes one node of the graph (rows are named with nodes data and ordered by ids): degrees, betweenness
+//centrality (high values point to bottleneck activities), index of the strongly connected component, whether the
+//node lies on a cycle (i.e. takes part in rework) and the number of other nodes reachable from it
+pub fn analyze_graph<TNodeData, TEdgeData>(graph: &Graph<TNodeData, TEdgeData>) -> FicusDataset
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    let mut nodes = graph.all_nodes();
+    nodes.sort_by(|first, second| first.id().cmp(second.id()));
+
+    let betweenness = graph.nodes_betweenness();
+    let nodes_in_cycles = graph.nodes_in_cycles();
+
+    let mut components = HashMap::new();
+    for (index, component) in graph.strongly_connected_components().iter().enumerate() {
+        for node in component {
+            components.insert(*node, index);
+        }
+    }
+
+    let mut values = vec![];
+    let mut row_names = vec![];
+    for node in nodes {
+        let id = node.id();
+        let in_cycle = match nodes_in_cycles.contains(id) {
+            true => 1.0,
+            false => 0.0,
+        };
+
+        values.push(vec![
+            graph.incoming_edges(id).len() as f64,
+            graph.outgoing_nodes(id).len() as f64,
+            *betweenness.get(id).unwrap(),
+            *components.get(id).unwrap() as f64,
+            in_cycle,
+            (graph.reachable_nodes(id).len() - 1) as f64,
+        ]);
+
+        row_names.push(match node.data() {
+            Some(data) => data.to_string(),
+            None => id.to_string(),
+        });
+    }
+
+    let columns_names = [
+        IN_DEGREE,
+        OUT_DEGREE,
+        BETWEENNESS,
+        STRONGLY_CONNECTED_COMPONENT,
+        IN_CYCLE,
+        REACHABLE_NODES,
+    ]
+    .iter()
+    .map(|name| name.to_string())
+    .collect();
+
+    FicusDataset::new(values, columns_names, row_names)
+}
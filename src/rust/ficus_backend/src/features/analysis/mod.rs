@@ -1,5 +1,7 @@
+pub mod concept_drift;
 pub mod constants;
 pub mod directly_follows_graph;
 pub mod entropy;
 pub mod event_log_info;
+pub mod graph_analysis;
 pub mod patterns;
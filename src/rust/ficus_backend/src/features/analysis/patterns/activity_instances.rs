@@ -31,7 +31,7 @@ pub enum SubTraceKind<'a> {
     Unattached(usize, usize),
 }
 
-#[derive(PartialOrd, PartialEq, Copy, Clone)]
+#[derive(PartialOrd, PartialEq, Copy, Clone, Hash)]
 pub enum ActivityNarrowingKind {
     DontNarrow,
     StayTheSame,
@@ -53,7 +53,7 @@ impl FromStr for ActivityNarrowingKind {
     }
 }
 
-#[derive(Copy, Clone, PartialOrd, PartialEq)]
+#[derive(Copy, Clone, PartialOrd, PartialEq, Hash)]
 pub enum ActivityInTraceFilterKind {
     NoFilter,
     DefaultFilter,
@@ -314,7 +314,7 @@ pub enum UndefActivityHandlingStrategy<TEvent> {
     InsertAllEvents,
 }
 
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Hash)]
 pub enum AdjustingMode {
     FromAllLog,
     FromUnattachedSubTraces,
@@ -516,6 +516,33 @@ fn create_activities_logs_from_log<TLog: EventLog>(log: &TLog) -> HashMap<String
     activities_to_logs
 }
 
+pub fn create_logs_for_activities_immediate_events<TLog: EventLog>(log: &TLog) -> HashMap<String, Rc<RefCell<TLog>>> {
+    let mut activities_to_logs: HashMap<String, Rc<RefCell<TLog>>> = HashMap::new();
+    let key = unsafe { KEYS.underlying_events_key::<TLog::TEvent>() };
+
+    for trace in log.traces() {
+        for event in trace.borrow().events() {
+            if event.borrow_mut().user_data().get::<Vec<Rc<RefCell<TLog::TEvent>>>>(&key).is_none() {
+                continue;
+            }
+
+            let name = event.borrow().name().to_owned();
+            let mut new_trace = TLog::TTrace::empty();
+            for underlying_event in create_vector_of_immediate_underlying_events::<TLog>(event) {
+                new_trace.push(underlying_event);
+            }
+
+            activities_to_logs
+                .entry(name)
+                .or_insert_with(|| Rc::new(RefCell::new(TLog::empty())))
+                .borrow_mut()
+                .push(Rc::new(RefCell::new(new_trace)));
+        }
+    }
+
+    activities_to_logs
+}
+
 fn create_log_from_traces_activities<TLog: EventLog>(
     log: &TLog,
     activities: &Vec<Vec<ActivityInTraceInfo>>,
@@ -0,0 +1,431 @@
+use crate::utils::suffix_tree::{
+    suffix_tree_patterns::SuffixTree,
+    suffix_tree_slice::{MultipleWordsSuffixTreeSlice, SuffixTreeSlice},
+};
+
+use super::{contexts::PatternsDiscoveryStrategy, tandem_arrays::SubArrayInTraceInfo};
+
+#[derive(Clone, Copy)]
+enum ApproximationKind {
+    Mismatches(usize),
+    Gaps(usize),
+}
+
+impl ApproximationKind {
+    fn min_pattern_length(&self) -> usize {
+        match self {
+            ApproximationKind::Mismatches(max_mismatches) => min_approximate_pattern_length(*max_mismatches),
+            ApproximationKind::Gaps(max_gap) => max_gap + 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Window {
+    trace_index: usize,
+    start_index: usize,
+    length: usize,
+}
+
+impl Window {
+    fn end_index(&self) -> usize {
+        self.start_index + self.length
+    }
+
+    fn contains(&self, other: &Window) -> bool {
+        self.trace_index == other.trace_index && self.start_index <= other.start_index && other.end_index() <= self.end_index()
+    }
+
+    fn elements<'a>(&self, traces: &Vec<&'a [u64]>) -> &'a [u64] {
+        &traces[self.trace_index][self.start_index..self.end_index()]
+    }
+}
+
+struct ApproximateRepeat {
+    first: Window,
+    second: Window,
+    matched_count: usize,
+}
+
+impl ApproximateRepeat {
+    fn representative(&self) -> Window {
+        if self.second.length < self.first.length {
+            self.second
+        } else {
+            self.first
+        }
+    }
+}
+
+//Every occurrence of the seed is extended only against the nearest following occurrences, so the number of
+//extensions is linear in the number of occurrences, repeats found from farther pairs are mostly similar to the nearest ones
+const MAX_EXTENDED_PARTNERS_PER_OCCURRENCE: usize = 4;
+
+fn min_approximate_pattern_length(max_mismatches: usize) -> usize {
+    (2 * max_mismatches + 1).max(2)
+}
+
+pub fn find_hamming_repeats(
+    log: &Vec<Vec<u64>>,
+    strategy: &PatternsDiscoveryStrategy,
+    max_mismatches: usize,
+) -> Vec<Vec<SubArrayInTraceInfo>> {
+    find_approximate_repeats(log, strategy, ApproximationKind::Mismatches(max_mismatches))
+}
+
+pub fn find_gapped_repeats(log: &Vec<Vec<u64>>, strategy: &PatternsDiscoveryStrategy, max_gap: usize) -> Vec<Vec<SubArrayInTraceInfo>> {
+    find_approximate_repeats(log, strategy, ApproximationKind::Gaps(max_gap))
+}
+
+fn find_approximate_repeats(
+    log: &Vec<Vec<u64>>,
+    strategy: &PatternsDiscoveryStrategy,
+    kind: ApproximationKind,
+) -> Vec<Vec<SubArrayInTraceInfo>> {
+    let mut result = vec![vec![]; log.len()];
+
+    match strategy {
+        PatternsDiscoveryStrategy::FromAllTraces => {
+            for (trace_index, trace) in log.iter().enumerate() {
+                for window in find_approximate_repeats_in_traces(&vec![trace.as_slice()], kind) {
+                    result[trace_index].push(SubArrayInTraceInfo::new(window.start_index, window.length));
+                }
+            }
+        }
+        PatternsDiscoveryStrategy::FromSingleMergedTrace => {
+            let traces = log.iter().map(|trace| trace.as_slice()).collect();
+            for window in find_approximate_repeats_in_traces(&traces, kind) {
+                result[window.trace_index].push(SubArrayInTraceInfo::new(window.start_index, window.length));
+            }
+        }
+    }
+
+    result
+}
+
+fn find_approximate_repeats_in_traces(traces: &Vec<&[u64]>, kind: ApproximationKind) -> Vec<Window> {
+    let slice = MultipleWordsSuffixTreeSlice::new(traces.clone());
+    let mut tree = SuffixTree::new(&slice);
+    tree.build_tree();
+
+    let mut candidates = vec![];
+    for (start, end) in tree.find_maximal_repeats() {
+        let seed = match slice.sub_slice(start, end) {
+            Some(seed) if !seed.is_empty() => seed,
+            _ => continue,
+        };
+
+        let mut occurrences: Vec<(usize, usize)> = match tree.find_patterns(seed) {
+            None => continue,
+            Some(patterns) => patterns
+                .iter()
+                .filter_map(|pattern| match slice.get_slice_info_for(pattern.0) {
+                    Some((trace_index, Some(start_index))) => Some((trace_index, start_index)),
+                    _ => None,
+                })
+                .collect(),
+        };
+
+        occurrences.sort();
+
+        for i in 0..occurrences.len() {
+            let mut partners_count = 0;
+            for j in (i + 1)..occurrences.len() {
+                let (first, second) = (occurrences[i], occurrences[j]);
+                if first.0 == second.0 && first.1 + seed.len() > second.1 {
+                    continue;
+                }
+
+                if partners_count == MAX_EXTENDED_PARTNERS_PER_OCCURRENCE {
+                    break;
+                }
+
+                partners_count += 1;
+
+                let repeat = match kind {
+                    ApproximationKind::Mismatches(max_mismatches) => {
+                        extend_with_mismatches(traces, first, second, seed.len(), max_mismatches)
+                    }
+                    ApproximationKind::Gaps(max_gap) => extend_with_gaps(traces, first, second, seed.len(), max_gap),
+                };
+
+                if repeat.matched_count >= kind.min_pattern_length() {
+                    candidates.push(repeat);
+                }
+            }
+        }
+    }
+
+    select_representatives(traces, candidates, kind)
+}
+
+fn extend_with_mismatches(
+    traces: &Vec<&[u64]>,
+    first: (usize, usize),
+    second: (usize, usize),
+    seed_length: usize,
+    max_mismatches: usize,
+) -> ApproximateRepeat {
+    let first_trace = traces[first.0];
+    let second_trace = traces[second.0];
+    let same_trace = first.0 == second.0;
+
+    let mut right = seed_length;
+    let mut right_mismatches = 0;
+    let mut mismatches = 0;
+    let mut current = seed_length;
+    loop {
+        let first_index = first.1 + current;
+        let second_index = second.1 + current;
+        if first_index >= first_trace.len() || second_index >= second_trace.len() || (same_trace && first_index >= second.1) {
+            break;
+        }
+
+        if first_trace[first_index] != second_trace[second_index] {
+            if mismatches == max_mismatches {
+                break;
+            }
+
+            mismatches += 1;
+        } else {
+            right = current + 1;
+            right_mismatches = mismatches;
+        }
+
+        current += 1;
+    }
+
+    let mut left = 0;
+    let mut left_mismatches = 0;
+    mismatches = right_mismatches;
+    current = 0;
+    loop {
+        if current + 1 > first.1 || current + 1 > second.1 || (same_trace && second.1 - current - 1 < first.1 + right) {
+            break;
+        }
+
+        if first_trace[first.1 - current - 1] != second_trace[second.1 - current - 1] {
+            if mismatches == max_mismatches {
+                break;
+            }
+
+            mismatches += 1;
+        } else {
+            left = current + 1;
+            left_mismatches = mismatches - right_mismatches;
+        }
+
+        current += 1;
+    }
+
+    let length = left + right;
+    ApproximateRepeat {
+        first: Window {
+            trace_index: first.0,
+            start_index: first.1 - left,
+            length,
+        },
+        second: Window {
+            trace_index: second.0,
+            start_index: second.1 - left,
+            length,
+        },
+        matched_count: length - right_mismatches - left_mismatches,
+    }
+}
+
+fn extend_with_gaps(
+    traces: &Vec<&[u64]>,
+    first: (usize, usize),
+    second: (usize, usize),
+    seed_length: usize,
+    max_gap: usize,
+) -> ApproximateRepeat {
+    let first_trace = traces[first.0];
+    let second_trace = traces[second.0];
+    let first_limit = if first.0 == second.0 { second.1 } else { first_trace.len() };
+
+    let mut first_index = first.1 + seed_length;
+    let mut second_index = second.1 + seed_length;
+    let mut first_end = first_index;
+    let mut second_end = second_index;
+    let mut matched_count = seed_length;
+    let mut gaps = 0;
+
+    while first_index < first_limit && second_index < second_trace.len() {
+        if first_trace[first_index] == second_trace[second_index] {
+            first_index += 1;
+            second_index += 1;
+            first_end = first_index;
+            second_end = second_index;
+            matched_count += 1;
+            continue;
+        }
+
+        let mut skipped = false;
+        for skip in 1..=(max_gap - gaps) {
+            if first_index + skip < first_limit && first_trace[first_index + skip] == second_trace[second_index] {
+                first_index += skip;
+            } else if second_index + skip < second_trace.len() && first_trace[first_index] == second_trace[second_index + skip] {
+                second_index += skip;
+            } else {
+                continue;
+            }
+
+            gaps += skip;
+            skipped = true;
+            break;
+        }
+
+        if !skipped {
+            break;
+        }
+    }
+
+    ApproximateRepeat {
+        first: Window {
+            trace_index: first.0,
+            start_index: first.1,
+            length: first_end - first.1,
+        },
+        second: Window {
+            trace_index: second.0,
+            start_index: second.1,
+            length: second_end - second.1,
+        },
+        matched_count,
+    }
+}
+
+fn select_representatives(traces: &Vec<&[u64]>, mut candidates: Vec<ApproximateRepeat>, kind: ApproximationKind) -> Vec<Window> {
+    candidates.sort_by(|first, second| {
+        second
+            .matched_count
+            .cmp(&first.matched_count)
+            .then_with(|| first.first.trace_index.cmp(&second.first.trace_index))
+            .then_with(|| first.first.start_index.cmp(&second.first.start_index))
+            .then_with(|| first.second.trace_index.cmp(&second.second.trace_index))
+            .then_with(|| first.second.start_index.cmp(&second.second.start_index))
+    });
+
+    let mut representatives: Vec<Window> = vec![];
+    let mut covered: Vec<Window> = vec![];
+
+    for candidate in &candidates {
+        let is_covered = |window: &Window| covered.iter().any(|covering| covering.contains(window));
+        if is_covered(&candidate.first) && is_covered(&candidate.second) {
+            continue;
+        }
+
+        let representative = candidate.representative();
+        let elements = representative.elements(traces);
+        let is_similar = representatives
+            .iter()
+            .any(|existing| are_similar(existing.elements(traces), elements, kind));
+
+        if !is_similar {
+            representatives.push(representative);
+        }
+
+        covered.push(candidate.first);
+        covered.push(candidate.second);
+    }
+
+    representatives.sort_by(|first, second| {
+        first
+            .trace_index
+            .cmp(&second.trace_index)
+            .then_with(|| first.start_index.cmp(&second.start_index))
+            .then_with(|| first.length.cmp(&second.length))
+    });
+
+    representatives
+}
+
+fn are_similar(first: &[u64], second: &[u64], kind: ApproximationKind) -> bool {
+    match kind {
+        ApproximationKind::Mismatches(max_mismatches) => first.len() == second.len() && hamming_distance(first, second) <= max_mismatches,
+        ApproximationKind::Gaps(max_gap) => first.len() + second.len() - 2 * longest_common_subsequence(first, second) <= max_gap,
+    }
+}
+
+fn hamming_distance(first: &[u64], second: &[u64]) -> usize {
+    first.iter().zip(second.iter()).filter(|(first, second)| first != second).count()
+}
+
+fn longest_common_subsequence(first: &[u64], second: &[u64]) -> usize {
+    let mut previous = vec![0; second.len() + 1];
+    let mut current = vec![0; second.len() + 1];
+
+    for i in 0..first.len() {
+        for j in 0..second.len() {
+            current[j + 1] = if first[i] == second[j] {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[second.len()]
+}
+
+pub fn find_approximate_tandem_arrays(
+    log: &Vec<Vec<u64>>,
+    max_tandem_array_length: usize,
+    max_mismatches: usize,
+) -> Vec<Vec<SubArrayInTraceInfo>> {
+    let mut result = vec![];
+    let min_length = (max_mismatches + 1).max(2);
+
+    for trace in log {
+        let mut trace_arrays = vec![];
+
+        for length in min_length..=(max_tandem_array_length.min(trace.len() / 2)) {
+            let mut arrays_bounds: Vec<(usize, usize)> = vec![];
+            for i in 0..(trace.len() - length) {
+                let unit = &trace[i..(i + length)];
+                if !is_primitive(unit) {
+                    continue;
+                }
+
+                if arrays_bounds
+                    .iter()
+                    .any(|(start, end)| *start < i && i < *end && (i - start) % length == 0)
+                {
+                    continue;
+                }
+
+                let mut repeat_count = 1;
+                let mut current_index = i + length;
+                while current_index + length <= trace.len()
+                    && hamming_distance(&trace[current_index..(current_index + length)], unit) <= max_mismatches
+                {
+                    repeat_count += 1;
+                    current_index += length;
+                }
+
+                if repeat_count > 1 {
+                    arrays_bounds.push((i, current_index));
+                    trace_arrays.push(SubArrayInTraceInfo::new(i, length));
+                }
+            }
+        }
+
+        result.push(trace_arrays);
+    }
+
+    result
+}
+
+fn is_primitive(unit: &[u64]) -> bool {
+    for period in 1..=(unit.len() / 2) {
+        if unit.len() % period == 0 && (period..unit.len()).all(|i| unit[i] == unit[i % period]) {
+            return false;
+        }
+    }
+
+    true
+}
@@ -10,7 +10,7 @@ use super::{
     repeat_sets::SubArrayWithTraceIndex,
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Hash)]
 pub enum PatternsDiscoveryStrategy {
     FromAllTraces,
     FromSingleMergedTrace,
@@ -28,6 +28,42 @@ impl FromStr for PatternsDiscoveryStrategy {
     }
 }
 
+#[derive(Clone, Copy, Hash)]
+pub enum PatternsSearchBackend {
+    SuffixTree,
+    SuffixArray,
+}
+
+impl FromStr for PatternsSearchBackend {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SuffixTree" => Ok(PatternsSearchBackend::SuffixTree),
+            "SuffixArray" => Ok(PatternsSearchBackend::SuffixArray),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Hash)]
+pub enum EpisodeKind {
+    Serial,
+    Parallel,
+}
+
+impl FromStr for EpisodeKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Serial" => Ok(EpisodeKind::Serial),
+            "Parallel" => Ok(EpisodeKind::Parallel),
+            _ => Err(()),
+        }
+    }
+}
+
 pub struct PatternsDiscoveryContext<TClassExtractor, TLog>
 where
     TLog: EventLog,
@@ -5,6 +5,7 @@ use crate::features::analysis::patterns::activity_instances::ActivitiesLogSource
 
 use super::{
     activity_instances::{self, create_new_log_from_activities_instances, extract_activities_instances, ActivityInTraceInfo},
+    approximate_patterns::{find_approximate_tandem_arrays, find_gapped_repeats, find_hamming_repeats},
     contexts::{ActivitiesDiscoveryContext, ActivitiesInstancesDiscoveryContext, PatternsDiscoveryContext},
     repeat_sets::{build_repeat_set_tree_from_repeats, build_repeat_sets, ActivityNode, SubArrayWithTraceIndex},
     repeats::{find_maximal_repeats, find_near_super_maximal_repeats, find_super_maximal_repeats},
@@ -19,6 +20,10 @@ pub enum PatternsKind {
     MaximalRepeats,
     SuperMaximalRepeats,
     NearSuperMaximalRepeats,
+
+    HammingRepeats(usize),
+    GappedRepeats(usize),
+    ApproximateTandemArrays(usize, usize),
 }
 
 pub fn find_patterns<TClassExtractor, TLog>(context: &PatternsDiscoveryContext<TClassExtractor, TLog>) -> Vec<Vec<SubArrayInTraceInfo>>
@@ -33,6 +38,9 @@ where
         PatternsKind::NearSuperMaximalRepeats => find_near_super_maximal_repeats(log, &context.strategy),
         PatternsKind::PrimitiveTandemArrays(length) => find_primitive_tandem_arrays(log, *length),
         PatternsKind::MaximalTandemArrays(length) => find_maximal_tandem_arrays(log, *length),
+        PatternsKind::HammingRepeats(max_mismatches) => find_hamming_repeats(log, &context.strategy, *max_mismatches),
+        PatternsKind::GappedRepeats(max_gap) => find_gapped_repeats(log, &context.strategy, *max_gap),
+        PatternsKind::ApproximateTandemArrays(length, max_mismatches) => find_approximate_tandem_arrays(log, *length, *max_mismatches),
     }
 }
 
@@ -0,0 +1,181 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::{
+    contexts::EpisodeKind, repeat_sets::SubArrayWithTraceIndex, sequential_patterns::SequentialPattern, tandem_arrays::SubArrayInTraceInfo,
+};
+
+pub struct EpisodesDiscoveryParams {
+    pub kind: EpisodeKind,
+    //The same units as timestamps, the pipeline part passes milliseconds
+    pub time_window: i64,
+    pub min_support: usize,
+    pub max_episode_length: usize,
+}
+
+//Frequent episodes are mined level-wise (MINEPI): the support of an episode is the number of its minimal
+//occurrences, i.e. time intervals which contain the episode and do not contain a shorter interval with it,
+//whose duration does not exceed the time window. Serial episodes preserve the order of the elements while
+//parallel episodes are multisets of elements (their elements are returned sorted). Timestamps are given for
+//each event of the log, only episodes of at least two elements are returned
+pub fn find_frequent_episodes(log: &Vec<Vec<u64>>, timestamps: &Vec<Vec<i64>>, params: &EpisodesDiscoveryParams) -> Vec<SequentialPattern> {
+    let mut elements = BTreeSet::new();
+    for trace in log {
+        elements.extend(trace.iter().copied());
+    }
+
+    let mut frequent_elements = vec![];
+    let mut current_level = vec![];
+    for element in elements {
+        if let Some(episode) = evaluate_episode(log, timestamps, vec![element], params) {
+            frequent_elements.push(element);
+            current_level.push(episode);
+        }
+    }
+
+    let mut result = vec![];
+    for _ in 1..params.max_episode_length {
+        let frequent_episodes: BTreeSet<Vec<u64>> = current_level.iter().map(|episode| episode.elements().clone()).collect();
+
+        let mut next_level = vec![];
+        for episode in &frequent_episodes {
+            for element in &frequent_elements {
+                if let EpisodeKind::Parallel = params.kind {
+                    if element < episode.last().unwrap() {
+                        continue;
+                    }
+                }
+
+                let mut candidate = episode.clone();
+                candidate.push(*element);
+
+                if !all_sub_episodes_are_frequent(&candidate, &frequent_episodes, params.kind) {
+                    continue;
+                }
+
+                if let Some(episode) = evaluate_episode(log, timestamps, candidate, params) {
+                    next_level.push(episode);
+                }
+            }
+        }
+
+        if next_level.is_empty() {
+            break;
+        }
+
+        result.extend(next_level.iter().cloned());
+        current_level = next_level;
+    }
+
+    result
+}
+
+fn all_sub_episodes_are_frequent(candidate: &Vec<u64>, frequent_episodes: &BTreeSet<Vec<u64>>, kind: EpisodeKind) -> bool {
+    match kind {
+        EpisodeKind::Serial => frequent_episodes.contains(&candidate[1..].to_vec()),
+        EpisodeKind::Parallel => (0..candidate.len()).all(|index| {
+            let mut sub_episode = candidate.clone();
+            sub_episode.remove(index);
+            frequent_episodes.contains(&sub_episode)
+        }),
+    }
+}
+
+fn evaluate_episode(
+    log: &Vec<Vec<u64>>,
+    timestamps: &Vec<Vec<i64>>,
+    episode: Vec<u64>,
+    params: &EpisodesDiscoveryParams,
+) -> Option<SequentialPattern> {
+    let mut occurrences = vec![];
+    for (trace_index, (trace, trace_timestamps)) in log.iter().zip(timestamps.iter()).enumerate() {
+        for (start, end) in find_minimal_occurrences(trace, &episode, params.kind) {
+            if trace_timestamps[end] - trace_timestamps[start] <= params.time_window {
+                occurrences.push(SubArrayWithTraceIndex::new(
+                    SubArrayInTraceInfo::new(start, end - start + 1),
+                    trace_index,
+                ));
+            }
+        }
+    }
+
+    match occurrences.len() >= params.min_support {
+        true => Some(SequentialPattern::new(episode, occurrences.len(), occurrences)),
+        false => None,
+    }
+}
+
+//For each start the earliest end of an occurrence is found, ends do not decrease with starts, so the
+//occurrence is minimal if the occurrence with the next start ends later
+fn find_minimal_occurrences(trace: &Vec<u64>, episode: &Vec<u64>, kind: EpisodeKind) -> Vec<(usize, usize)> {
+    let mut occurrences: Vec<(usize, usize)> = vec![];
+    for (start, element) in trace.iter().enumerate() {
+        let is_start_of_occurrence = match kind {
+            EpisodeKind::Serial => *element == episode[0],
+            EpisodeKind::Parallel => episode.contains(element),
+        };
+
+        if !is_start_of_occurrence {
+            continue;
+        }
+
+        let end = match kind {
+            EpisodeKind::Serial => find_serial_occurrence_end(trace, episode, start),
+            EpisodeKind::Parallel => find_parallel_occurrence_end(trace, episode, start),
+        };
+
+        let end = match end {
+            Some(end) => end,
+            None => break,
+        };
+
+        if let Some(last) = occurrences.last() {
+            if last.1 == end {
+                occurrences.pop();
+            }
+        }
+
+        occurrences.push((start, end));
+    }
+
+    occurrences
+}
+
+fn find_serial_occurrence_end(trace: &Vec<u64>, episode: &Vec<u64>, start: usize) -> Option<usize> {
+    let mut matched_count = 1;
+    let mut position = start;
+    while matched_count < episode.len() {
+        position += 1;
+        if position >= trace.len() {
+            return None;
+        }
+
+        if trace[position] == episode[matched_count] {
+            matched_count += 1;
+        }
+    }
+
+    Some(position)
+}
+
+fn find_parallel_occurrence_end(trace: &Vec<u64>, episode: &Vec<u64>, start: usize) -> Option<usize> {
+    let mut remaining: HashMap<u64, usize> = HashMap::new();
+    for element in episode {
+        *remaining.entry(*element).or_insert(0) += 1;
+    }
+
+    let mut remaining_count = episode.len();
+    for (position, element) in trace.iter().enumerate().skip(start) {
+        if let Some(count) = remaining.get_mut(element) {
+            if *count > 0 {
+                *count -= 1;
+                remaining_count -= 1;
+
+                if remaining_count == 0 {
+                    return Some(position);
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -1,6 +1,9 @@
 pub mod activity_instances;
+pub mod approximate_patterns;
 pub mod contexts;
 pub mod entry_points;
+pub mod episodes;
 pub mod repeat_sets;
 pub mod repeats;
+pub mod sequential_patterns;
 pub mod tandem_arrays;
@@ -1,14 +1,63 @@
 use std::vec;
 
 use crate::utils::suffix_tree::{
+    suffix_array::SuffixArray,
     suffix_tree_patterns::SuffixTree,
-    suffix_tree_slice::{MultipleWordsSuffixTreeSlice, SingleWordSuffixTreeSlice},
+    suffix_tree_slice::{MultipleWordsSuffixTreeSlice, SingleWordSuffixTreeSlice, SuffixTreeSlice},
 };
 
-use super::{contexts::PatternsDiscoveryStrategy, tandem_arrays::SubArrayInTraceInfo};
+use super::{
+    contexts::{PatternsDiscoveryStrategy, PatternsSearchBackend},
+    tandem_arrays::SubArrayInTraceInfo,
+};
+
+enum RepeatType {
+    MaximalRepeat,
+    SuperMaximalRepeat,
+    NearSuperMaximalRepeat,
+}
 
 pub fn find_maximal_repeats(log: &Vec<Vec<u64>>, strategy: &PatternsDiscoveryStrategy) -> Vec<Vec<SubArrayInTraceInfo>> {
-    find_repeats(log, strategy, |tree| tree.find_maximal_repeats())
+    find_maximal_repeats_with_backend(log, strategy, &PatternsSearchBackend::SuffixTree)
+}
+
+pub fn find_maximal_repeats_with_backend(
+    log: &Vec<Vec<u64>>,
+    strategy: &PatternsDiscoveryStrategy,
+    backend: &PatternsSearchBackend,
+) -> Vec<Vec<SubArrayInTraceInfo>> {
+    find_repeats(log, strategy, |slice| {
+        find_repeats_in_slice(slice, backend, RepeatType::MaximalRepeat)
+    })
+}
+
+fn find_repeats_in_slice(
+    slice: &dyn SuffixTreeSlice<u64>,
+    backend: &PatternsSearchBackend,
+    repeat_type: RepeatType,
+) -> Vec<(usize, usize)> {
+    match backend {
+        PatternsSearchBackend::SuffixTree => {
+            let mut tree = SuffixTree::new(slice);
+            tree.build_tree();
+
+            match repeat_type {
+                RepeatType::MaximalRepeat => tree.find_maximal_repeats(),
+                RepeatType::SuperMaximalRepeat => tree.find_super_maximal_repeats(),
+                RepeatType::NearSuperMaximalRepeat => tree.find_near_super_maximal_repeats(),
+            }
+        }
+        PatternsSearchBackend::SuffixArray => {
+            let mut array = SuffixArray::new(slice);
+            array.build_array();
+
+            match repeat_type {
+                RepeatType::MaximalRepeat => array.find_maximal_repeats(),
+                RepeatType::SuperMaximalRepeat => array.find_super_maximal_repeats(),
+                RepeatType::NearSuperMaximalRepeat => array.find_near_super_maximal_repeats(),
+            }
+        }
+    }
 }
 
 fn find_repeats<TRepeatsFinder>(
@@ -17,7 +66,7 @@ fn find_repeats<TRepeatsFinder>(
     finder: TRepeatsFinder,
 ) -> Vec<Vec<SubArrayInTraceInfo>>
 where
-    TRepeatsFinder: Fn(&SuffixTree<u64>) -> Vec<(usize, usize)>,
+    TRepeatsFinder: Fn(&dyn SuffixTreeSlice<u64>) -> Vec<(usize, usize)>,
 {
     let mut repeats = vec![];
 
@@ -44,20 +93,18 @@ where
 
 fn find_from_all_traces<TFinder, TRepeatsPusher>(log: &Vec<Vec<u64>>, finder: &TFinder, pusher: &mut TRepeatsPusher)
 where
-    TFinder: Fn(&SuffixTree<u64>) -> Vec<(usize, usize)>,
+    TFinder: Fn(&dyn SuffixTreeSlice<u64>) -> Vec<(usize, usize)>,
     TRepeatsPusher: FnMut(&[(usize, usize)]) -> (),
 {
     for trace in log {
         let slice = SingleWordSuffixTreeSlice::new(trace.as_slice());
-        let mut tree = SuffixTree::new(&slice);
-        tree.build_tree();
-        pusher(finder(&tree).as_slice());
+        pusher(finder(&slice).as_slice());
     }
 }
 
 fn find_from_single_merged_trace<TFinder, TRepeatsPusher>(log: &Vec<Vec<u64>>, finder: &TFinder, pusher: &mut TRepeatsPusher)
 where
-    TFinder: Fn(&SuffixTree<u64>) -> Vec<(usize, usize)>,
+    TFinder: Fn(&dyn SuffixTreeSlice<u64>) -> Vec<(usize, usize)>,
     TRepeatsPusher: FnMut(&[(usize, usize)]) -> (),
 {
     let mut single_trace = vec![];
@@ -66,11 +113,7 @@ where
     }
 
     let slice = MultipleWordsSuffixTreeSlice::new(single_trace.clone());
-    let mut tree = SuffixTree::new(&slice);
-
-    tree.build_tree();
-
-    let mut patterns = finder(&tree);
+    let mut patterns = finder(&slice);
     let mut traces_patterns = vec![vec![]; log.len()];
 
     for pattern in &mut patterns {
@@ -94,9 +137,29 @@ where
 }
 
 pub fn find_super_maximal_repeats(log: &Vec<Vec<u64>>, strategy: &PatternsDiscoveryStrategy) -> Vec<Vec<SubArrayInTraceInfo>> {
-    find_repeats(log, strategy, |tree| tree.find_super_maximal_repeats())
+    find_super_maximal_repeats_with_backend(log, strategy, &PatternsSearchBackend::SuffixTree)
+}
+
+pub fn find_super_maximal_repeats_with_backend(
+    log: &Vec<Vec<u64>>,
+    strategy: &PatternsDiscoveryStrategy,
+    backend: &PatternsSearchBackend,
+) -> Vec<Vec<SubArrayInTraceInfo>> {
+    find_repeats(log, strategy, |slice| {
+        find_repeats_in_slice(slice, backend, RepeatType::SuperMaximalRepeat)
+    })
 }
 
 pub fn find_near_super_maximal_repeats(log: &Vec<Vec<u64>>, strategy: &PatternsDiscoveryStrategy) -> Vec<Vec<SubArrayInTraceInfo>> {
-    find_repeats(log, strategy, |tree| tree.find_near_super_maximal_repeats())
+    find_near_super_maximal_repeats_with_backend(log, strategy, &PatternsSearchBackend::SuffixTree)
+}
+
+pub fn find_near_super_maximal_repeats_with_backend(
+    log: &Vec<Vec<u64>>,
+    strategy: &PatternsDiscoveryStrategy,
+    backend: &PatternsSearchBackend,
+) -> Vec<Vec<SubArrayInTraceInfo>> {
+    find_repeats(log, strategy, |slice| {
+        find_repeats_in_slice(slice, backend, RepeatType::NearSuperMaximalRepeat)
+    })
 }
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use super::{repeat_sets::SubArrayWithTraceIndex, tandem_arrays::SubArrayInTraceInfo};
+
+pub struct SequentialPatternsDiscoveryParams {
+    pub min_support: usize,
+    pub max_gap: usize,
+    pub max_pattern_length: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct SequentialPattern {
+    elements: Vec<u64>,
+    support: usize,
+    occurrences: Vec<SubArrayWithTraceIndex>,
+}
+
+impl SequentialPattern {
+    pub fn new(elements: Vec<u64>, support: usize, occurrences: Vec<SubArrayWithTraceIndex>) -> Self {
+        Self {
+            elements,
+            support,
+            occurrences,
+        }
+    }
+
+    pub fn elements(&self) -> &Vec<u64> {
+        &self.elements
+    }
+
+    pub fn support(&self) -> usize {
+        self.support
+    }
+
+    pub fn occurrences(&self) -> &Vec<SubArrayWithTraceIndex> {
+        &self.occurrences
+    }
+}
+
+//Embeddings of a pattern in a trace are keyed by the position of the last matched event, as
+//the gap constraint only depends on it; for each such position the most compact embedding is kept
+type ProjectedTrace = BTreeMap<usize, usize>;
+type ProjectedLog = BTreeMap<usize, ProjectedTrace>;
+
+//PrefixSpan with a max-gap constraint: max_gap is the maximum number of events which may be skipped
+//between two consecutive elements of a pattern, support is the number of traces containing the pattern.
+//Only patterns of at least two elements are returned
+pub fn find_sequential_patterns(log: &Vec<Vec<u64>>, params: &SequentialPatternsDiscoveryParams) -> Vec<SequentialPattern> {
+    let mut initial_projections: BTreeMap<u64, ProjectedLog> = BTreeMap::new();
+    for (trace_index, trace) in log.iter().enumerate() {
+        for (index, element) in trace.iter().enumerate() {
+            initial_projections
+                .entry(*element)
+                .or_default()
+                .entry(trace_index)
+                .or_default()
+                .insert(index, index);
+        }
+    }
+
+    let mut patterns = vec![];
+    for (element, projection) in initial_projections {
+        if projection.len() >= params.min_support {
+            mine_patterns(log, vec![element], &projection, params, &mut patterns);
+        }
+    }
+
+    patterns
+}
+
+fn mine_patterns(
+    log: &Vec<Vec<u64>>,
+    prefix: Vec<u64>,
+    projection: &ProjectedLog,
+    params: &SequentialPatternsDiscoveryParams,
+    patterns: &mut Vec<SequentialPattern>,
+) {
+    if prefix.len() > 1 {
+        patterns.push(SequentialPattern::new(
+            prefix.clone(),
+            projection.len(),
+            create_occurrences(projection),
+        ));
+    }
+
+    if prefix.len() >= params.max_pattern_length {
+        return;
+    }
+
+    let mut extensions: BTreeMap<u64, ProjectedLog> = BTreeMap::new();
+    for (trace_index, embeddings) in projection {
+        let trace = log.get(*trace_index).unwrap();
+        for (last_position, start) in embeddings {
+            for (position, element) in trace.iter().enumerate().skip(last_position + 1).take(params.max_gap + 1) {
+                let embeddings = extensions.entry(*element).or_default().entry(*trace_index).or_default();
+                let current_start = embeddings.entry(position).or_insert(*start);
+                *current_start = (*current_start).max(*start);
+            }
+        }
+    }
+
+    for (element, extension_projection) in extensions {
+        if extension_projection.len() >= params.min_support {
+            let mut extended_prefix = prefix.clone();
+            extended_prefix.push(element);
+
+            mine_patterns(log, extended_prefix, &extension_projection, params, patterns);
+        }
+    }
+}
+
+//Occurrences in each trace are chosen greedily from left to right so that they do not overlap
+fn create_occurrences(projection: &ProjectedLog) -> Vec<SubArrayWithTraceIndex> {
+    let mut occurrences = vec![];
+    for (trace_index, embeddings) in projection {
+        let mut last_end: Option<usize> = None;
+        for (end, start) in embeddings {
+            if let Some(last_end) = last_end {
+                if *start <= last_end {
+                    continue;
+                }
+            }
+
+            occurrences.push(SubArrayWithTraceIndex::new(
+                SubArrayInTraceInfo::new(*start, end - start + 1),
+                *trace_index,
+            ));
+            last_end = Some(*end);
+        }
+    }
+
+    occurrences
+}
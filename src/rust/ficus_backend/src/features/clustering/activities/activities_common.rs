@@ -22,9 +22,11 @@ use crate::{
         clustering::{
             common::{scale_raw_dataset_min_max, MyDataset},
             error::ClusteringError,
+            featurisation::{featurise_traces, TracesFeaturisation},
         },
     },
     pipelines::aliases::TracesActivities,
+    utils::dataset::dataset::FicusDataset,
 };
 
 use super::activities_params::{ActivitiesVisualizationParams, ActivityRepresentationSource};
@@ -255,3 +257,91 @@ pub(super) fn create_dataset_from_activities_classes<TLog: EventLog>(
         },
     )
 }
+
+//Activities of the given level are sorted by names, each activity is represented as a sequence of event classes of
+//its sub-trace (or as a sorted set of event classes for the `EventClasses` representation source)
+pub(super) fn create_activities_sequences<TLog: EventLog>(
+    params: &ActivitiesVisualizationParams<TLog>,
+) -> Result<(Vec<Rc<RefCell<ActivityNode>>>, Vec<Vec<String>>), ClusteringError> {
+    let regex_hasher = match params.common_vis_params.class_extractor.as_ref() {
+        Some(class_extractor) => Some(RegexEventHasher::new(class_extractor).ok().unwrap()),
+        None => None,
+    };
+
+    let (activities, activities_events) = create_activities_events(params)?;
+
+    let mut sequences = vec![];
+    for events in &activities_events {
+        let mut sequence = events
+            .iter()
+            .map(|event| match regex_hasher.as_ref() {
+                Some(regex_hasher) => regex_hasher.transform(event.borrow().name()).to_owned(),
+                None => event.borrow().name().to_owned(),
+            })
+            .collect::<Vec<String>>();
+
+        if params.activities_repr_source == ActivityRepresentationSource::EventClasses {
+            sequence.sort();
+            sequence.dedup();
+        }
+
+        sequences.push(sequence);
+    }
+
+    Ok((activities, sequences))
+}
+
+//Rows of the dataset are activities sorted by names, sub-traces of activities are featurised as traces,
+//the `EventClasses` representation source is treated as `SubTraces`
+pub fn create_activities_features_dataset<TLog: EventLog>(
+    params: &ActivitiesVisualizationParams<TLog>,
+    featurisation: &TracesFeaturisation,
+) -> Result<FicusDataset, ClusteringError> {
+    let (activities, activities_events) = create_activities_events(params)?;
+
+    let class_extractor = params.common_vis_params.class_extractor.as_ref();
+    let (values, features_names) = featurise_traces(&activities_events, class_extractor, featurisation);
+    let activities_names = activities.iter().map(|activity| activity.borrow().name.to_owned()).collect();
+
+    Ok(FicusDataset::new(values, features_names, activities_names))
+}
+
+fn create_activities_events<TLog: EventLog>(
+    params: &ActivitiesVisualizationParams<TLog>,
+) -> Result<(Vec<Rc<RefCell<ActivityNode>>>, Vec<Vec<Rc<RefCell<TLog::TEvent>>>>), ClusteringError> {
+    let mut activities: HashMap<String, Rc<RefCell<ActivityNode>>> = HashMap::new();
+    for trace_activities in params.traces_activities.iter() {
+        for activity in trace_activities {
+            let node = activity.node.borrow();
+            if node.level == params.activity_level && !activities.contains_key(&node.name) {
+                activities.insert(node.name.to_owned(), activity.node.clone());
+            }
+        }
+    }
+
+    let mut activities = activities.into_values().collect::<Vec<Rc<RefCell<ActivityNode>>>>();
+    activities.sort_by(|first, second| first.borrow().name.cmp(&second.borrow().name));
+
+    let mut activities_events = vec![];
+    for activity in &activities {
+        let activity = activity.borrow();
+        let repeat_set = match activity.repeat_set.as_ref() {
+            Some(repeat_set) => repeat_set,
+            None => return Err(ClusteringError::NoRepeatSet),
+        };
+
+        let trace = params.common_vis_params.log.traces().get(repeat_set.trace_index).unwrap();
+        let trace = trace.borrow();
+        let array = &repeat_set.sub_array;
+        let events = &trace.events()[array.start_index..(array.start_index + array.length)];
+
+        activities_events.push(match params.activities_repr_source {
+            ActivityRepresentationSource::EventClasses | ActivityRepresentationSource::SubTraces => events.to_vec(),
+            ActivityRepresentationSource::SubTracesUnderlyingEvents => {
+                events.iter().flat_map(create_vector_of_underlying_events::<TLog>).collect()
+            }
+        });
+    }
+
+    Ok((activities, activities_events))
+}
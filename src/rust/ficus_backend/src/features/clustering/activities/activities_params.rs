@@ -5,7 +5,7 @@ use crate::{
     utils::distance::distance::FicusDistance,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
 pub enum ActivityRepresentationSource {
     EventClasses,
     SubTraces,
@@ -49,7 +49,7 @@ where
     TLog: EventLog,
 {
     pub fn new(vis_params: ActivitiesVisualizationParams<'a, TLog>, tolerance: f64, distance: FicusDistance) -> Option<Self> {
-        if distance == FicusDistance::Levenshtein {
+        if distance.is_sequence_distance() {
             None
         } else {
             Some(Self {
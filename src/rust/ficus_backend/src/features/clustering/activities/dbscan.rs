@@ -5,17 +5,19 @@ use linfa_nn::KdTree;
 use crate::{
     event_log::core::event_log::EventLog,
     features::clustering::{
-        common::{create_colors_vector, transform_to_ficus_dataset},
-        error::ClusteringResult,
+        common::{create_colors_vector, create_features_distance, transform_to_ficus_dataset},
+        distance_matrix::{search_distance_matrix_clustering, DistanceMatrixClustering},
+        error::{ClusteringError, ClusteringResult},
+        quality::{calculate_features_distances, ClusteringQualityIndex, ClusteringScores, ClusteringSearchParameter},
     },
-    utils::{dataset::dataset::LabeledDataset, distance::distance::DistanceWrapper},
+    utils::dataset::dataset::LabeledDataset,
 };
 
 use super::{activities_common::create_dataset, activities_params::ActivitiesClusteringParams, merging::merge_activities};
 
 pub fn clusterize_activities_dbscan<TLog: EventLog>(params: &mut ActivitiesClusteringParams<TLog>, min_points: usize) -> ClusteringResult {
     let (dataset, processed, classes_names) = create_dataset(&params.vis_params)?;
-    let clusters = Dbscan::params_with(min_points, DistanceWrapper::new(params.distance), KdTree)
+    let clusters = Dbscan::params_with(min_points, create_features_distance(params.distance)?, KdTree)
         .tolerance(params.tolerance)
         .transform(dataset.records())
         .unwrap();
@@ -42,3 +44,33 @@ pub fn clusterize_activities_dbscan<TLog: EventLog>(params: &mut ActivitiesClust
     let colors = create_colors_vector(&labels, params.vis_params.common_vis_params.colors_holder);
     Ok(LabeledDataset::new(ficus_dataset, labels, colors))
 }
+
+//The search is performed over the precomputed matrix of distances between activities features, then activities
+//are clusterized with the best tolerance and min points
+pub fn search_activities_dbscan_clustering<TLog: EventLog>(
+    params: &mut ActivitiesClusteringParams<TLog>,
+    min_points: usize,
+    parameter: ClusteringSearchParameter,
+    index: ClusteringQualityIndex,
+) -> Result<(LabeledDataset, ClusteringScores), ClusteringError> {
+    let (dataset, processed, classes_names) = create_dataset(&params.vis_params)?;
+    let objects = processed.iter().map(|x| x.0.borrow().name.to_owned()).collect::<Vec<String>>();
+    let ficus_dataset = transform_to_ficus_dataset(&dataset, objects.clone(), classes_names);
+    let distances = calculate_features_distances(ficus_dataset.values(), params.distance)?;
+
+    let clustering = DistanceMatrixClustering::Dbscan {
+        tolerance: params.tolerance,
+        min_points,
+    };
+
+    let (best_clustering, scores) = search_distance_matrix_clustering(&distances, &objects, &clustering, parameter, index)?;
+    let min_points = match best_clustering {
+        DistanceMatrixClustering::Dbscan { tolerance, min_points } => {
+            params.tolerance = tolerance;
+            min_points
+        }
+        _ => min_points,
+    };
+
+    Ok((clusterize_activities_dbscan(params, min_points)?, scores))
+}
@@ -0,0 +1,81 @@
+use std::{cell::RefCell, rc::Rc};
+
+use ndarray::Array1;
+
+use crate::{
+    event_log::core::event_log::EventLog,
+    features::{
+        analysis::patterns::repeat_sets::ActivityNode,
+        clustering::{
+            agglomerative::Dendrogram,
+            common::create_colors_vector,
+            distance_matrix::{
+                build_sequences_distance_matrix, clusterize_distance_matrix, search_distance_matrix_clustering, DistanceMatrixClustering,
+            },
+            error::ClusteringError,
+            quality::{ClusteringQualityIndex, ClusteringScores, ClusteringSearchParameter},
+        },
+    },
+    utils::{
+        dataset::dataset::{FicusDataset, LabeledDataset},
+        distance::distance::FicusDistance,
+    },
+};
+
+use super::{activities_common::create_activities_sequences, activities_params::ActivitiesVisualizationParams, merging::merge_activities};
+
+//Unlike `ActivitiesClusteringParams` any distance is allowed here, as activities are compared as raw sequences
+//of event classes, the labeled dataset contains the distance matrix, label 0 is reserved for noise
+pub fn clusterize_activities_distance_matrix<TLog: EventLog>(
+    params: &mut ActivitiesVisualizationParams<TLog>,
+    distance: FicusDistance,
+    clustering: &DistanceMatrixClustering,
+) -> Result<(LabeledDataset, Option<Dendrogram>), ClusteringError> {
+    let (activities, sequences) = create_activities_sequences(params)?;
+    let distances = build_sequences_distance_matrix(&sequences, distance);
+
+    Ok(clusterize_activities_with_distance_matrix(
+        params, activities, distances, clustering,
+    ))
+}
+
+//The clustering is performed with the best configuration found by the search
+pub fn search_activities_distance_matrix_clustering<TLog: EventLog>(
+    params: &mut ActivitiesVisualizationParams<TLog>,
+    distance: FicusDistance,
+    clustering: &DistanceMatrixClustering,
+    parameter: ClusteringSearchParameter,
+    index: ClusteringQualityIndex,
+) -> Result<(LabeledDataset, Option<Dendrogram>, ClusteringScores), ClusteringError> {
+    let (activities, sequences) = create_activities_sequences(params)?;
+    let distances = build_sequences_distance_matrix(&sequences, distance);
+
+    let objects = activities.iter().map(|x| x.borrow().name.to_owned()).collect::<Vec<String>>();
+    let (best_clustering, scores) = search_distance_matrix_clustering(&distances, &objects, clustering, parameter, index)?;
+    let (labeled_dataset, dendrogram) = clusterize_activities_with_distance_matrix(params, activities, distances, &best_clustering);
+
+    Ok((labeled_dataset, dendrogram, scores))
+}
+
+fn clusterize_activities_with_distance_matrix<TLog: EventLog>(
+    params: &mut ActivitiesVisualizationParams<TLog>,
+    activities: Vec<Rc<RefCell<ActivityNode>>>,
+    distances: Vec<Vec<f64>>,
+    clustering: &DistanceMatrixClustering,
+) -> (LabeledDataset, Option<Dendrogram>) {
+    let objects = activities.iter().map(|x| x.borrow().name.to_owned()).collect::<Vec<String>>();
+
+    let (labels, dendrogram) = clusterize_distance_matrix(&distances, objects.clone(), clustering);
+    merge_activities(
+        params.common_vis_params.log,
+        params.traces_activities,
+        &activities,
+        &Array1::from_vec(labels.clone()),
+    );
+
+    let labels: Vec<usize> = labels.iter().map(|x| if x.is_none() { 0 } else { x.unwrap() + 1 }).collect();
+    let colors = create_colors_vector(&labels, params.common_vis_params.colors_holder);
+    let dataset = FicusDataset::new(distances, objects.clone(), objects);
+
+    (LabeledDataset::new(dataset, labels, colors), dendrogram)
+}
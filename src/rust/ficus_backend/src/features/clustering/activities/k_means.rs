@@ -1,9 +1,6 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use linfa::{
-    metrics::SilhouetteScore,
-    traits::{Fit, Predict},
-};
+use linfa::traits::{Fit, Predict};
 use linfa_clustering::KMeans;
 
 use crate::{
@@ -11,8 +8,9 @@ use crate::{
     features::{
         analysis::patterns::repeat_sets::ActivityNode,
         clustering::{
-            common::{create_colors_vector, transform_to_ficus_dataset, ClusteredDataset, MyDataset},
+            common::{create_colors_vector, create_features_distance, transform_to_ficus_dataset, ClusteredDataset, MyDataset},
             error::{ClusteringError, ClusteringResult},
+            quality::{calculate_clustering_quality, calculate_features_distances, ClusteringQualityIndex, ClusteringScores},
         },
     },
     utils::{
@@ -30,7 +28,7 @@ pub fn clusterize_activities_k_means<TLog: EventLog>(
     iterations_count: usize,
 ) -> ClusteringResult {
     let (dataset, processed, classes_names) = create_dataset(&params.vis_params)?;
-    let model = create_k_means_model(clusters_count, iterations_count as u64, params.tolerance, &dataset, params.distance);
+    let model = create_k_means_model(clusters_count, iterations_count as u64, params.tolerance, &dataset, params.distance)?;
 
     let clustered_dataset = model.predict(dataset.clone());
     merge_activities(
@@ -75,39 +73,50 @@ fn create_k_means_model(
     tolerance: f64,
     dataset: &MyDataset,
     distance: FicusDistance,
-) -> KMeans<f64, DistanceWrapper> {
-    KMeans::params_with(clusters_count, rand::thread_rng(), DistanceWrapper::new(distance))
-        .max_n_iterations(iterations_count)
-        .tolerance(tolerance)
-        .fit(&dataset)
-        .expect("KMeans fitted")
+) -> Result<KMeans<f64, DistanceWrapper>, ClusteringError> {
+    Ok(
+        KMeans::params_with(clusters_count, rand::thread_rng(), create_features_distance(distance)?)
+            .max_n_iterations(iterations_count)
+            .tolerance(tolerance)
+            .fit(&dataset)
+            .expect("KMeans fitted"),
+    )
 }
 
+//Clusters counts from 2 to the number of activities are tried, the one with the best quality index is chosen
 pub fn clusterize_activities_k_means_grid_search<TLog: EventLog>(
     params: &mut ActivitiesClusteringParams<TLog>,
     iterations_count: usize,
-) -> ClusteringResult {
+    index: ClusteringQualityIndex,
+) -> Result<(LabeledDataset, ClusteringScores), ClusteringError> {
     let (dataset, processed, classes_names) = create_dataset(&params.vis_params)?;
+    let ficus_dataset = transform_to_ficus_dataset(
+        &dataset,
+        processed.iter().map(|x| x.0.borrow().name.to_owned()).collect(),
+        classes_names,
+    );
 
-    let mut best_metric = -1f64;
-    let mut best_labels = None;
+    let distances = calculate_features_distances(ficus_dataset.values(), params.distance)?;
+
+    let mut scores = ClusteringScores::default();
+    let mut all_labels = vec![];
 
     for clusters_count in 2..processed.len() {
-        let model = create_k_means_model(clusters_count, iterations_count as u64, params.tolerance, &dataset, params.distance);
+        let model = create_k_means_model(clusters_count, iterations_count as u64, params.tolerance, &dataset, params.distance)?;
 
         let clustered_dataset = model.predict(dataset.clone());
-        let score = match clustered_dataset.silhouette_score() {
-            Ok(score) => score,
-            Err(_) => return Err(ClusteringError::FailedToCalculateSilhouetteScore),
-        };
-
-        if score > best_metric {
-            best_labels = Some(clustered_dataset.targets);
-            best_metric = score;
-        }
+        let labels: Vec<Option<usize>> = clustered_dataset.targets.iter().map(|x| Some(*x)).collect();
+
+        scores.push(
+            format!("KMeans(clusters_count={})", clusters_count),
+            calculate_clustering_quality(&distances, &labels),
+        );
+
+        all_labels.push(clustered_dataset.targets);
     }
 
-    if let Some(best_labels) = best_labels.as_ref() {
+    if let Some(best) = scores.best(index) {
+        let best_labels = &all_labels[best];
         merge_activities(
             params.vis_params.common_vis_params.log,
             params.vis_params.traces_activities,
@@ -115,14 +124,11 @@ pub fn clusterize_activities_k_means_grid_search<TLog: EventLog>(
             &best_labels.map(|x| Some(*x)),
         );
 
-        let ficus_dataset = transform_to_ficus_dataset(
-            &dataset,
-            processed.iter().map(|x| x.0.borrow().name.to_owned()).collect(),
-            classes_names,
-        );
-
         let colors = create_colors_vector(&best_labels.to_vec(), params.vis_params.common_vis_params.colors_holder);
-        Ok(LabeledDataset::new(ficus_dataset, best_labels.clone().into_raw_vec(), colors))
+        Ok((
+            LabeledDataset::new(ficus_dataset, best_labels.clone().into_raw_vec(), colors),
+            scores,
+        ))
     } else {
         Err(ClusteringError::RawError(
             "Failed to find best labels in K-means grid search".to_owned(),
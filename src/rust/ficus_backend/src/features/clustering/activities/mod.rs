@@ -1,5 +1,6 @@
 pub mod activities_common;
 pub mod activities_params;
 pub mod dbscan;
+pub mod distance_matrix;
 pub mod k_means;
 pub mod merging;
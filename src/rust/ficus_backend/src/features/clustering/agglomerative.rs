@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum AgglomerativeClusteringLinkage {
+    Single,
+    Complete,
+    Average,
+    Ward,
+}
+
+impl FromStr for AgglomerativeClusteringLinkage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Single" => Ok(Self::Single),
+            "Complete" => Ok(Self::Complete),
+            "Average" => Ok(Self::Average),
+            "Ward" => Ok(Self::Ward),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AgglomerativeClusteringLinkage {
+    //Lance-Williams formula: distance from the cluster k to the union of clusters i and j
+    fn merged_distance(&self, ik: f64, jk: f64, ij: f64, i_size: usize, j_size: usize, k_size: usize) -> f64 {
+        let (i_size, j_size, k_size) = (i_size as f64, j_size as f64, k_size as f64);
+        match self {
+            Self::Single => ik.min(jk),
+            Self::Complete => ik.max(jk),
+            Self::Average => (i_size * ik + j_size * jk) / (i_size + j_size),
+            Self::Ward => {
+                let numerator = (i_size + k_size) * ik * ik + (j_size + k_size) * jk * jk - k_size * ij * ij;
+                (numerator.max(0.0) / (i_size + j_size + k_size)).sqrt()
+            }
+        }
+    }
+}
+
+//Leaves have ids from 0 to n - 1, the cluster created by the i-th merge has the id n + i
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DendrogramMerge {
+    first: usize,
+    second: usize,
+    distance: f64,
+    size: usize,
+}
+
+impl DendrogramMerge {
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    pub fn second(&self) -> usize {
+        self.second
+    }
+
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DendrogramCut {
+    ClustersCount(usize),
+    Distance(f64),
+}
+
+#[derive(Clone, Debug)]
+pub struct Dendrogram {
+    leaves_names: Vec<String>,
+    merges: Vec<DendrogramMerge>,
+}
+
+impl Dendrogram {
+    pub fn leaves_names(&self) -> &Vec<String> {
+        &self.leaves_names
+    }
+
+    pub fn merges(&self) -> &Vec<DendrogramMerge> {
+        &self.merges
+    }
+
+    //Returns a label for each leaf, labels start from zero and are assigned in the order of leaves
+    pub fn cut(&self, cut: &DendrogramCut) -> Vec<usize> {
+        let leaves_count = self.leaves_names.len();
+        let applied_merges_count = match cut {
+            DendrogramCut::ClustersCount(count) => leaves_count.saturating_sub((*count).max(1)),
+            DendrogramCut::Distance(threshold) => self.merges.iter().take_while(|merge| merge.distance <= *threshold).count(),
+        };
+
+        let mut parents: Vec<usize> = (0..(leaves_count + applied_merges_count)).collect();
+        for (index, merge) in self.merges.iter().take(applied_merges_count).enumerate() {
+            parents[merge.first] = leaves_count + index;
+            parents[merge.second] = leaves_count + index;
+        }
+
+        let mut labels = vec![];
+        let mut roots_to_labels = HashMap::new();
+        for leaf in 0..leaves_count {
+            let mut root = leaf;
+            while parents[root] != root {
+                root = parents[root];
+            }
+
+            let next_label = roots_to_labels.len();
+            labels.push(*roots_to_labels.entry(root).or_insert(next_label));
+        }
+
+        labels
+    }
+}
+
+//Nearest-neighbour chain algorithm, O(n^2) time over the full symmetric distance matrix. All supported linkages
+//are reducible, so merges found by the chain are the same as the ones of the greedy closest pair algorithm, they are
+//sorted by distance afterwards and ids of clusters are assigned in the sorted order
+pub fn build_dendrogram(distances: &Vec<Vec<f64>>, leaves_names: Vec<String>, linkage: AgglomerativeClusteringLinkage) -> Dendrogram {
+    let leaves_count = distances.len();
+    let mut distances = distances.clone();
+    let mut active = vec![true; leaves_count];
+    let mut sizes = vec![1; leaves_count];
+    let mut chain: Vec<usize> = vec![];
+    let mut chain_merges = vec![];
+
+    while chain_merges.len() + 1 < leaves_count {
+        if chain.is_empty() {
+            chain.push((0..leaves_count).find(|i| active[*i]).unwrap());
+        }
+
+        let (i, j) = loop {
+            let current = *chain.last().unwrap();
+            let previous = match chain.len() {
+                0 | 1 => None,
+                len => Some(chain[len - 2]),
+            };
+
+            let mut nearest: Option<usize> = None;
+            for k in (0..leaves_count).filter(|k| active[*k] && *k != current) {
+                if nearest.is_none() || distances[current][k] < distances[current][nearest.unwrap()] {
+                    nearest = Some(k);
+                }
+            }
+
+            //The previous element of the chain is preferred in case of ties, otherwise the chain may cycle
+            let nearest = match previous {
+                Some(previous) if distances[current][previous] <= distances[current][nearest.unwrap()] => previous,
+                _ => nearest.unwrap(),
+            };
+
+            if Some(nearest) == previous {
+                chain.truncate(chain.len() - 2);
+                break (nearest.min(current), nearest.max(current));
+            }
+
+            chain.push(nearest);
+        };
+
+        let distance = distances[i][j];
+        chain_merges.push((i, j, distance));
+
+        for k in (0..leaves_count).filter(|k| active[*k] && *k != i && *k != j) {
+            let new_distance = linkage.merged_distance(distances[i][k], distances[j][k], distance, sizes[i], sizes[j], sizes[k]);
+            distances[i][k] = new_distance;
+            distances[k][i] = new_distance;
+        }
+
+        sizes[i] += sizes[j];
+        active[j] = false;
+    }
+
+    chain_merges.sort_by(|first, second| first.2.total_cmp(&second.2));
+
+    Dendrogram {
+        leaves_names,
+        merges: create_dendrogram_merges(leaves_count, &chain_merges),
+    }
+}
+
+//Merges found by the chain reference clusters by one of their leaves, ids of clusters are restored with union-find
+fn create_dendrogram_merges(leaves_count: usize, chain_merges: &Vec<(usize, usize, f64)>) -> Vec<DendrogramMerge> {
+    let mut parents: Vec<usize> = (0..leaves_count).collect();
+    let mut ids: Vec<usize> = (0..leaves_count).collect();
+    let mut sizes = vec![1; leaves_count];
+
+    let find_root = |parents: &mut Vec<usize>, mut leaf: usize| {
+        while parents[leaf] != leaf {
+            parents[leaf] = parents[parents[leaf]];
+            leaf = parents[leaf];
+        }
+
+        leaf
+    };
+
+    let mut merges = vec![];
+    for (step, (i, j, distance)) in chain_merges.iter().enumerate() {
+        let first_root = find_root(&mut parents, *i);
+        let second_root = find_root(&mut parents, *j);
+
+        merges.push(DendrogramMerge {
+            first: ids[first_root].min(ids[second_root]),
+            second: ids[first_root].max(ids[second_root]),
+            distance: *distance,
+            size: sizes[first_root] + sizes[second_root],
+        });
+
+        parents[second_root] = first_root;
+        sizes[first_root] += sizes[second_root];
+        ids[first_root] = leaves_count + step;
+    }
+
+    merges
+}
@@ -6,9 +6,12 @@ use crate::{
     utils::{
         colors::{Color, ColorsHolder},
         dataset::dataset::FicusDataset,
+        distance::distance::{DistanceWrapper, FicusDistance},
     },
 };
 
+use super::error::ClusteringError;
+
 pub(super) type MyDataset = DatasetBase<ArrayBase<OwnedRepr<f64>, Dim<[usize; 2]>>, Array1<()>>;
 pub(super) type ClusteredDataset = DatasetBase<ArrayBase<OwnedRepr<f64>, Dim<[usize; 2]>>, ArrayBase<OwnedRepr<usize>, Dim<[usize; 1]>>>;
 
@@ -45,6 +48,16 @@ pub(super) fn create_colors_vector(labels: &Vec<usize>, colors_holder: &mut Colo
         .collect()
 }
 
+pub(super) fn create_features_distance(distance: FicusDistance) -> Result<DistanceWrapper, ClusteringError> {
+    match DistanceWrapper::new(distance) {
+        Some(distance) => Ok(distance),
+        None => Err(ClusteringError::RawError(format!(
+            "{:?} distance can not be calculated over features, distance matrix clustering should be used",
+            distance
+        ))),
+    }
+}
+
 pub fn scale_raw_dataset_min_max(vector: &mut Vec<f64>, objects_count: usize, features_count: usize) {
     for i in 0..features_count {
         let mut max = f64::MIN;
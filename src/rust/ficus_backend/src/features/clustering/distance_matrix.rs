@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::thread;
+
+use crate::utils::distance::{
+    distance::{calculate_sequence_distance, FicusDistance},
+    sequence_distance::ClassesWeightedEditCosts,
+};
+
+use super::{
+    agglomerative::{build_dendrogram, AgglomerativeClusteringLinkage, Dendrogram, DendrogramCut},
+    error::ClusteringError,
+    quality::{calculate_clustering_quality, ClusteringQualityIndex, ClusteringScores, ClusteringSearchParameter},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum DistanceMatrixClusteringMethod {
+    Dbscan,
+    KMedoids,
+    Agglomerative,
+}
+
+impl FromStr for DistanceMatrixClusteringMethod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Dbscan" => Ok(Self::Dbscan),
+            "KMedoids" => Ok(Self::KMedoids),
+            "Agglomerative" => Ok(Self::Agglomerative),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistanceMatrixClustering {
+    Dbscan {
+        tolerance: f64,
+        min_points: usize,
+    },
+    KMedoids {
+        clusters_count: usize,
+        iterations_count: usize,
+    },
+    Agglomerative {
+        linkage: AgglomerativeClusteringLinkage,
+        cut: DendrogramCut,
+    },
+}
+
+impl Display for DistanceMatrixClustering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dbscan { tolerance, min_points } => write!(f, "Dbscan(tolerance={}, min_points={})", tolerance, min_points),
+            Self::KMedoids {
+                clusters_count,
+                iterations_count,
+            } => write!(
+                f,
+                "KMedoids(clusters_count={}, iterations_count={})",
+                clusters_count, iterations_count
+            ),
+            Self::Agglomerative { linkage, cut } => write!(f, "Agglomerative(linkage={:?}, cut={:?})", linkage, cut),
+        }
+    }
+}
+
+//Event classes are encoded with ids starting from 1 in the order of their first appearance
+pub fn encode_sequences(sequences: &Vec<Vec<String>>) -> Vec<Vec<u64>> {
+    let mut classes_ids: HashMap<&String, u64> = HashMap::new();
+    let mut encoded = vec![];
+    for sequence in sequences {
+        let mut encoded_sequence = vec![];
+        for class in sequence {
+            let next_id = classes_ids.len() as u64 + 1;
+            encoded_sequence.push(*classes_ids.entry(class).or_insert(next_id));
+        }
+
+        encoded.push(encoded_sequence);
+    }
+
+    encoded
+}
+
+pub fn build_sequences_distance_matrix(sequences: &Vec<Vec<String>>, distance: FicusDistance) -> Vec<Vec<f64>> {
+    let sequences = encode_sequences(sequences);
+    let weights = match distance {
+        FicusDistance::WeightedEdit => ClassesWeightedEditCosts::from_frequencies(&sequences),
+        _ => ClassesWeightedEditCosts::uniform(),
+    };
+
+    build_distance_matrix(&sequences, |first, second| {
+        calculate_sequence_distance(distance, first, second, &weights)
+    })
+}
+
+//Only the upper triangle is calculated, rows are distributed between threads in a round-robin fashion
+//as rows at the top of the matrix contain more pairs
+pub fn build_distance_matrix<T: Sync>(objects: &[T], distance: impl Fn(&T, &T) -> f64 + Sync) -> Vec<Vec<f64>> {
+    let objects_count = objects.len();
+    let threads_count = match thread::available_parallelism() {
+        Ok(count) => count.get().min(objects_count.max(1)),
+        Err(_) => 1,
+    };
+
+    let distance = &distance;
+    let rows: Vec<(usize, Vec<f64>)> = thread::scope(|scope| {
+        let handles = (0..threads_count)
+            .map(|thread_index| {
+                scope.spawn(move || {
+                    let mut rows = vec![];
+                    for i in (thread_index..objects_count).step_by(threads_count) {
+                        let row = ((i + 1)..objects_count).map(|j| distance(&objects[i], &objects[j])).collect();
+                        rows.push((i, row));
+                    }
+
+                    rows
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    });
+
+    let mut matrix = vec![vec![0.0; objects_count]; objects_count];
+    for (i, row) in rows {
+        for (offset, value) in row.into_iter().enumerate() {
+            let j = i + 1 + offset;
+            matrix[i][j] = value;
+            matrix[j][i] = value;
+        }
+    }
+
+    matrix
+}
+
+//Returns labels of objects, None means that object is noise. An object is a core one if there are at least
+//`min_points` objects (including itself) within the `tolerance` distance
+pub fn dbscan_on_distance_matrix(distances: &Vec<Vec<f64>>, tolerance: f64, min_points: usize) -> Vec<Option<usize>> {
+    let objects_count = distances.len();
+    let neighbours = |object: usize| (0..objects_count).filter(move |other| distances[object][*other] <= tolerance);
+
+    let mut labels = vec![None; objects_count];
+    let mut visited = vec![false; objects_count];
+    let mut next_label = 0;
+
+    for object in 0..objects_count {
+        if visited[object] || neighbours(object).count() < min_points {
+            continue;
+        }
+
+        let mut queue = vec![object];
+        visited[object] = true;
+
+        while let Some(current) = queue.pop() {
+            labels[current] = Some(next_label);
+
+            let current_neighbours = neighbours(current).collect::<Vec<usize>>();
+            if current_neighbours.len() < min_points {
+                continue;
+            }
+
+            for neighbour in current_neighbours {
+                if labels[neighbour].is_none() && !visited[neighbour] {
+                    visited[neighbour] = true;
+                    queue.push(neighbour);
+                }
+            }
+        }
+
+        next_label += 1;
+    }
+
+    labels
+}
+
+//Partitioning Around Medoids: greedy BUILD phase followed by SWAP iterations, labels are indices of medoids
+//in the order of their selection
+pub fn k_medoids(distances: &Vec<Vec<f64>>, clusters_count: usize, iterations_count: usize) -> Vec<usize> {
+    let objects_count = distances.len();
+    let clusters_count = clusters_count.min(objects_count);
+    if clusters_count == 0 {
+        return vec![0; objects_count];
+    }
+
+    let total_cost = |medoids: &Vec<usize>| -> f64 {
+        (0..objects_count)
+            .map(|object| {
+                medoids
+                    .iter()
+                    .map(|medoid| distances[object][*medoid])
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum()
+    };
+
+    let mut medoids: Vec<usize> = vec![];
+    while medoids.len() < clusters_count {
+        let mut best: Option<(usize, f64)> = None;
+        for candidate in (0..objects_count).filter(|object| !medoids.contains(object)) {
+            let mut new_medoids = medoids.clone();
+            new_medoids.push(candidate);
+
+            let cost = total_cost(&new_medoids);
+            let is_better = match best {
+                None => true,
+                Some((_, best_cost)) => cost < best_cost,
+            };
+
+            if is_better {
+                best = Some((candidate, cost));
+            }
+        }
+
+        medoids.push(best.unwrap().0);
+    }
+
+    let mut current_cost = total_cost(&medoids);
+    for _ in 0..iterations_count {
+        let mut best_swap: Option<(usize, usize, f64)> = None;
+        for medoid_index in 0..clusters_count {
+            for candidate in (0..objects_count).filter(|object| !medoids.contains(object)) {
+                let mut new_medoids = medoids.clone();
+                new_medoids[medoid_index] = candidate;
+
+                let cost = total_cost(&new_medoids);
+                let best_cost = match best_swap {
+                    None => current_cost,
+                    Some((_, _, cost)) => cost,
+                };
+
+                if cost < best_cost {
+                    best_swap = Some((medoid_index, candidate, cost));
+                }
+            }
+        }
+
+        match best_swap {
+            Some((medoid_index, candidate, cost)) => {
+                medoids[medoid_index] = candidate;
+                current_cost = cost;
+            }
+            None => break,
+        }
+    }
+
+    (0..objects_count)
+        .map(|object| {
+            let mut closest = 0;
+            for (index, medoid) in medoids.iter().enumerate() {
+                if distances[object][*medoid] < distances[object][medoids[closest]] {
+                    closest = index;
+                }
+            }
+
+            closest
+        })
+        .collect()
+}
+
+//Only DBSCAN can produce noise (None labels), the dendrogram is returned for the agglomerative clustering
+pub fn clusterize_distance_matrix(
+    distances: &Vec<Vec<f64>>,
+    objects_names: Vec<String>,
+    clustering: &DistanceMatrixClustering,
+) -> (Vec<Option<usize>>, Option<Dendrogram>) {
+    match clustering {
+        DistanceMatrixClustering::Dbscan { tolerance, min_points } => (dbscan_on_distance_matrix(distances, *tolerance, *min_points), None),
+        DistanceMatrixClustering::KMedoids {
+            clusters_count,
+            iterations_count,
+        } => {
+            let labels = k_medoids(distances, *clusters_count, *iterations_count);
+            (labels.into_iter().map(Some).collect(), None)
+        }
+        DistanceMatrixClustering::Agglomerative { linkage, cut } => {
+            let dendrogram = build_dendrogram(distances, objects_names, *linkage);
+            let labels = dendrogram.cut(cut).into_iter().map(Some).collect();
+
+            (labels, Some(dendrogram))
+        }
+    }
+}
+
+const TOLERANCE_CANDIDATES_COUNT: usize = 20;
+const MAX_MIN_POINTS_CANDIDATE: usize = 10;
+
+//Tries all candidates of the searched parameter (other parameters are taken from `clustering`) and returns the
+//configuration with the best quality index together with scores of all tried configurations. Tolerance candidates
+//are quantiles of pairwise distances, min points candidates are 2..=10, clusters counts candidates are 2..objects_count
+pub fn search_distance_matrix_clustering(
+    distances: &Vec<Vec<f64>>,
+    objects_names: &Vec<String>,
+    clustering: &DistanceMatrixClustering,
+    parameter: ClusteringSearchParameter,
+    index: ClusteringQualityIndex,
+) -> Result<(DistanceMatrixClustering, ClusteringScores), ClusteringError> {
+    let candidates = create_search_candidates(distances, clustering, parameter)?;
+    let dendrogram = match clustering {
+        DistanceMatrixClustering::Agglomerative { linkage, .. } => Some(build_dendrogram(distances, objects_names.clone(), *linkage)),
+        _ => None,
+    };
+
+    let mut scores = ClusteringScores::default();
+    for candidate in &candidates {
+        let labels = match (candidate, dendrogram.as_ref()) {
+            (DistanceMatrixClustering::Agglomerative { cut, .. }, Some(dendrogram)) => dendrogram.cut(cut).into_iter().map(Some).collect(),
+            _ => clusterize_distance_matrix(distances, objects_names.clone(), candidate).0,
+        };
+
+        scores.push(candidate.to_string(), calculate_clustering_quality(distances, &labels));
+    }
+
+    match scores.best(index) {
+        Some(best) => Ok((candidates[best], scores)),
+        None => Err(ClusteringError::RawError(format!(
+            "None of configurations has defined {:?} index",
+            index
+        ))),
+    }
+}
+
+fn create_search_candidates(
+    distances: &Vec<Vec<f64>>,
+    clustering: &DistanceMatrixClustering,
+    parameter: ClusteringSearchParameter,
+) -> Result<Vec<DistanceMatrixClustering>, ClusteringError> {
+    let objects_count = distances.len();
+    let clusters_counts = 2..objects_count;
+
+    Ok(match (*clustering, parameter) {
+        (DistanceMatrixClustering::Dbscan { min_points, .. }, ClusteringSearchParameter::Tolerance) => {
+            create_tolerance_candidates(distances)
+                .into_iter()
+                .map(|tolerance| DistanceMatrixClustering::Dbscan { tolerance, min_points })
+                .collect()
+        }
+        (DistanceMatrixClustering::Dbscan { tolerance, .. }, ClusteringSearchParameter::MinPoints) => (2..=MAX_MIN_POINTS_CANDIDATE)
+            .map(|min_points| DistanceMatrixClustering::Dbscan { tolerance, min_points })
+            .collect(),
+        (DistanceMatrixClustering::KMedoids { iterations_count, .. }, ClusteringSearchParameter::ClustersCount) => clusters_counts
+            .map(|clusters_count| DistanceMatrixClustering::KMedoids {
+                clusters_count,
+                iterations_count,
+            })
+            .collect(),
+        (DistanceMatrixClustering::Agglomerative { linkage, .. }, ClusteringSearchParameter::ClustersCount) => clusters_counts
+            .map(|count| DistanceMatrixClustering::Agglomerative {
+                linkage,
+                cut: DendrogramCut::ClustersCount(count),
+            })
+            .collect(),
+        (DistanceMatrixClustering::Agglomerative { linkage, .. }, ClusteringSearchParameter::Tolerance) => {
+            create_tolerance_candidates(distances)
+                .into_iter()
+                .map(|tolerance| DistanceMatrixClustering::Agglomerative {
+                    linkage,
+                    cut: DendrogramCut::Distance(tolerance),
+                })
+                .collect()
+        }
+        _ => {
+            let message = format!("{:?} can not be searched for {}", parameter, clustering);
+            return Err(ClusteringError::RawError(message));
+        }
+    })
+}
+
+fn create_tolerance_candidates(distances: &Vec<Vec<f64>>) -> Vec<f64> {
+    let mut all_distances = vec![];
+    for (i, row) in distances.iter().enumerate() {
+        for distance in row.iter().skip(i + 1) {
+            all_distances.push(*distance);
+        }
+    }
+
+    if all_distances.is_empty() {
+        return vec![];
+    }
+
+    all_distances.sort_by(|first, second| first.total_cmp(second));
+
+    let mut candidates = (1..=TOLERANCE_CANDIDATES_COUNT)
+        .map(|i| all_distances[(all_distances.len() - 1) * i / TOLERANCE_CANDIDATES_COUNT])
+        .collect::<Vec<f64>>();
+
+    candidates.dedup();
+    candidates
+}
@@ -0,0 +1,429 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+    str::FromStr,
+};
+
+use chrono::{Datelike, Timelike};
+
+use crate::event_log::core::event::{
+    event::{Event, EventPayloadValue},
+    event_hasher::RegexEventHasher,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FeaturesScaling {
+    None,
+    MinMax,
+    ZScore,
+}
+
+impl FromStr for FeaturesScaling {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(Self::None),
+            "MinMax" => Ok(Self::MinMax),
+            "ZScore" => Ok(Self::ZScore),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TracesFeatureSet {
+    //Number of events of each event class
+    Counts,
+    //Number of occurrences of each n-gram of event classes
+    NGrams(usize),
+    //TF-IDF weights of n-grams of event classes, traces are documents
+    TfIdf(usize),
+    //Relative frequencies of directly-follows pairs of event classes
+    Transitions,
+    //Duration, mean and max time between consecutive events, hour and weekday of the first event
+    Time,
+    //Mean and sum of numeric payload attributes
+    Attributes(Vec<String>),
+}
+
+impl FromStr for TracesFeatureSet {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arguments) = match s.find('(') {
+            Some(index) => match s.strip_suffix(')') {
+                Some(stripped) => (&stripped[..index], Some(&stripped[index + 1..])),
+                None => return Err(()),
+            },
+            None => (s, None),
+        };
+
+        let parse_n = |arguments: Option<&str>| match arguments {
+            Some(n) => match n.trim().parse::<usize>() {
+                Ok(n) if n > 0 => Ok(n),
+                _ => Err(()),
+            },
+            None => Err(()),
+        };
+
+        match (name.trim(), arguments) {
+            ("Counts", None) => Ok(Self::Counts),
+            ("NGrams", arguments) => Ok(Self::NGrams(parse_n(arguments)?)),
+            ("TfIdf", arguments) => Ok(Self::TfIdf(parse_n(arguments)?)),
+            ("Transitions", None) => Ok(Self::Transitions),
+            ("Time", None) => Ok(Self::Time),
+            ("Attributes", Some(arguments)) => {
+                let attributes = arguments
+                    .split(',')
+                    .map(|x| x.trim().to_owned())
+                    .filter(|x| !x.is_empty())
+                    .collect::<Vec<String>>();
+
+                if attributes.is_empty() {
+                    Err(())
+                } else {
+                    Ok(Self::Attributes(attributes))
+                }
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TracesFeatureSetConfig {
+    pub feature_set: TracesFeatureSet,
+    pub scaling: FeaturesScaling,
+}
+
+//Format is "<FeatureSet>[:<Scaling>]", e.g. "NGrams(2):ZScore", min-max scaling is the default
+impl FromStr for TracesFeatureSetConfig {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (feature_set, scaling) = match s.rsplit_once(':') {
+            Some((feature_set, scaling)) => (feature_set, FeaturesScaling::from_str(scaling.trim())?),
+            None => (s, FeaturesScaling::MinMax),
+        };
+
+        Ok(Self {
+            feature_set: TracesFeatureSet::from_str(feature_set.trim())?,
+            scaling,
+        })
+    }
+}
+
+//Features of all configs are concatenated, the default featurisation is min-max scaled event classes counts
+#[derive(Clone, Debug, PartialEq)]
+pub struct TracesFeaturisation {
+    configs: Vec<TracesFeatureSetConfig>,
+}
+
+impl Default for TracesFeaturisation {
+    fn default() -> Self {
+        Self {
+            configs: vec![TracesFeatureSetConfig {
+                feature_set: TracesFeatureSet::Counts,
+                scaling: FeaturesScaling::MinMax,
+            }],
+        }
+    }
+}
+
+impl TracesFeaturisation {
+    pub fn new(configs: Vec<TracesFeatureSetConfig>) -> Self {
+        Self { configs }
+    }
+
+    //Returns the first config which can not be parsed in case of error
+    pub fn parse(configs: &Vec<String>) -> Result<Self, String> {
+        let mut parsed_configs = vec![];
+        for config in configs {
+            match TracesFeatureSetConfig::from_str(config) {
+                Ok(parsed_config) => parsed_configs.push(parsed_config),
+                Err(_) => return Err(config.to_owned()),
+            }
+        }
+
+        Ok(Self { configs: parsed_configs })
+    }
+
+    pub fn configs(&self) -> &Vec<TracesFeatureSetConfig> {
+        &self.configs
+    }
+}
+
+//Returns features values (one row for each trace) and features names
+pub fn featurise_traces<TEvent: Event>(
+    traces: &Vec<Vec<Rc<RefCell<TEvent>>>>,
+    class_extractor: Option<&String>,
+    featurisation: &TracesFeaturisation,
+) -> (Vec<Vec<f64>>, Vec<String>) {
+    let regex_hasher = match class_extractor.as_ref() {
+        Some(class_extractor) => Some(RegexEventHasher::new(class_extractor).ok().unwrap()),
+        None => None,
+    };
+
+    let sequences = traces
+        .iter()
+        .map(|trace| {
+            trace
+                .iter()
+                .map(|event| match regex_hasher.as_ref() {
+                    Some(regex_hasher) => regex_hasher.transform(event.borrow().name()).to_owned(),
+                    None => event.borrow().name().to_owned(),
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let mut values = vec![vec![]; traces.len()];
+    let mut names = vec![];
+
+    for config in &featurisation.configs {
+        let (mut set_values, set_names) = match &config.feature_set {
+            TracesFeatureSet::Counts => create_n_grams_features(&sequences, 1, |ngram| ngram[0].to_owned()),
+            TracesFeatureSet::NGrams(n) => create_n_grams_features(&sequences, *n, |ngram| format!("({})", ngram.join(", "))),
+            TracesFeatureSet::TfIdf(n) => create_tf_idf_features(&sequences, *n),
+            TracesFeatureSet::Transitions => create_transitions_features(&sequences),
+            TracesFeatureSet::Time => create_time_features(traces),
+            TracesFeatureSet::Attributes(attributes) => create_attributes_features(traces, attributes),
+        };
+
+        scale_features(&mut set_values, set_names.len(), config.scaling);
+
+        for (row, set_row) in values.iter_mut().zip(set_values) {
+            row.extend(set_row);
+        }
+
+        names.extend(set_names);
+    }
+
+    (values, names)
+}
+
+fn count_n_grams(sequences: &Vec<Vec<String>>, n: usize) -> Vec<HashMap<&[String], usize>> {
+    sequences
+        .iter()
+        .map(|sequence| {
+            let mut counts = HashMap::new();
+            for ngram in sequence.windows(n) {
+                *counts.entry(ngram).or_default() += 1;
+            }
+
+            counts
+        })
+        .collect()
+}
+
+fn collect_sorted_n_grams<'a>(counts: &Vec<HashMap<&'a [String], usize>>) -> Vec<&'a [String]> {
+    let mut all_ngrams = HashSet::new();
+    for trace_counts in counts {
+        all_ngrams.extend(trace_counts.keys().copied());
+    }
+
+    let mut all_ngrams = all_ngrams.into_iter().collect::<Vec<&[String]>>();
+    all_ngrams.sort();
+
+    all_ngrams
+}
+
+fn create_n_grams_features(
+    sequences: &Vec<Vec<String>>,
+    n: usize,
+    name_creator: impl Fn(&[String]) -> String,
+) -> (Vec<Vec<f64>>, Vec<String>) {
+    let counts = count_n_grams(sequences, n);
+    let all_ngrams = collect_sorted_n_grams(&counts);
+
+    let values = counts
+        .iter()
+        .map(|trace_counts| {
+            all_ngrams
+                .iter()
+                .map(|ngram| trace_counts.get(ngram).copied().unwrap_or_default() as f64)
+                .collect()
+        })
+        .collect();
+
+    (values, all_ngrams.into_iter().map(name_creator).collect())
+}
+
+//Smoothed idf is used: ln((1 + traces_count) / (1 + ngram_traces_count)) + 1
+fn create_tf_idf_features(sequences: &Vec<Vec<String>>, n: usize) -> (Vec<Vec<f64>>, Vec<String>) {
+    let counts = count_n_grams(sequences, n);
+    let all_ngrams = collect_sorted_n_grams(&counts);
+
+    let traces_count = sequences.len() as f64;
+    let idfs = all_ngrams
+        .iter()
+        .map(|ngram| {
+            let ngram_traces_count = counts.iter().filter(|trace_counts| trace_counts.contains_key(ngram)).count() as f64;
+            ((1.0 + traces_count) / (1.0 + ngram_traces_count)).ln() + 1.0
+        })
+        .collect::<Vec<f64>>();
+
+    let values = counts
+        .iter()
+        .map(|trace_counts| {
+            let ngrams_count = trace_counts.values().sum::<usize>() as f64;
+            all_ngrams
+                .iter()
+                .zip(idfs.iter())
+                .map(|(ngram, idf)| match trace_counts.get(ngram) {
+                    Some(count) => *count as f64 / ngrams_count * idf,
+                    None => 0.0,
+                })
+                .collect()
+        })
+        .collect();
+
+    let names = all_ngrams.iter().map(|ngram| format!("tfidf({})", ngram.join(", "))).collect();
+
+    (values, names)
+}
+
+fn create_transitions_features(sequences: &Vec<Vec<String>>) -> (Vec<Vec<f64>>, Vec<String>) {
+    let counts = count_n_grams(sequences, 2);
+    let all_transitions = collect_sorted_n_grams(&counts);
+
+    let values = counts
+        .iter()
+        .zip(sequences)
+        .map(|(trace_counts, sequence)| {
+            let transitions_count = sequence.len().max(2) - 1;
+            all_transitions
+                .iter()
+                .map(|transition| trace_counts.get(transition).copied().unwrap_or_default() as f64 / transitions_count as f64)
+                .collect()
+        })
+        .collect();
+
+    let names = all_transitions
+        .iter()
+        .map(|transition| format!("{} -> {}", transition[0], transition[1]))
+        .collect();
+
+    (values, names)
+}
+
+//All times are measured in seconds, empty traces have all time features equal to zero
+fn create_time_features<TEvent: Event>(traces: &Vec<Vec<Rc<RefCell<TEvent>>>>) -> (Vec<Vec<f64>>, Vec<String>) {
+    let names = vec!["duration", "mean_waiting_time", "max_waiting_time", "start_hour", "start_weekday"];
+
+    let values = traces
+        .iter()
+        .map(|trace| {
+            if trace.is_empty() {
+                return vec![0.0; names.len()];
+            }
+
+            let stamps = trace.iter().map(|event| *event.borrow().timestamp()).collect::<Vec<_>>();
+            let gaps = stamps
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).num_milliseconds() as f64 / 1000.0)
+                .collect::<Vec<f64>>();
+
+            let duration = (stamps[stamps.len() - 1] - stamps[0]).num_milliseconds() as f64 / 1000.0;
+            let mean_gap = if gaps.is_empty() {
+                0.0
+            } else {
+                gaps.iter().sum::<f64>() / gaps.len() as f64
+            };
+            let max_gap = gaps.iter().copied().fold(0.0, f64::max);
+
+            vec![
+                duration,
+                mean_gap,
+                max_gap,
+                stamps[0].hour() as f64,
+                stamps[0].weekday().num_days_from_monday() as f64,
+            ]
+        })
+        .collect();
+
+    (values, names.iter().map(|x| x.to_string()).collect())
+}
+
+fn to_numeric_value(value: &EventPayloadValue) -> Option<f64> {
+    match value {
+        EventPayloadValue::Int32(value) => Some(*value as f64),
+        EventPayloadValue::Int64(value) => Some(*value as f64),
+        EventPayloadValue::Float32(value) => Some(*value as f64),
+        EventPayloadValue::Float64(value) => Some(*value),
+        EventPayloadValue::Uint32(value) => Some(*value as f64),
+        EventPayloadValue::Uint64(value) => Some(*value as f64),
+        EventPayloadValue::Timestamp(value) => Some(*value as f64),
+        _ => None,
+    }
+}
+
+//Events without the attribute or with non-numeric value of the attribute are skipped
+fn create_attributes_features<TEvent: Event>(
+    traces: &Vec<Vec<Rc<RefCell<TEvent>>>>,
+    attributes: &Vec<String>,
+) -> (Vec<Vec<f64>>, Vec<String>) {
+    let values = traces
+        .iter()
+        .map(|trace| {
+            let mut row = vec![];
+            for attribute in attributes {
+                let mut sum = 0.0;
+                let mut count = 0;
+                for event in trace {
+                    let event = event.borrow();
+                    if let Some(payload) = event.payload_map() {
+                        if let Some(value) = payload.get(attribute).and_then(to_numeric_value) {
+                            sum += value;
+                            count += 1;
+                        }
+                    }
+                }
+
+                row.push(if count == 0 { 0.0 } else { sum / count as f64 });
+                row.push(sum);
+            }
+
+            row
+        })
+        .collect();
+
+    let names = attributes
+        .iter()
+        .flat_map(|attribute| vec![format!("mean({})", attribute), format!("sum({})", attribute)])
+        .collect();
+
+    (values, names)
+}
+
+//Constant features become 1 after min-max scaling and 0 after z-score scaling
+fn scale_features(values: &mut Vec<Vec<f64>>, features_count: usize, scaling: FeaturesScaling) {
+    if values.is_empty() {
+        return;
+    }
+
+    for feature in 0..features_count {
+        match scaling {
+            FeaturesScaling::None => {}
+            FeaturesScaling::MinMax => {
+                let min = values.iter().map(|row| row[feature]).fold(f64::MAX, f64::min);
+                let max = values.iter().map(|row| row[feature]).fold(f64::MIN, f64::max);
+
+                for row in values.iter_mut() {
+                    row[feature] = if max == min { 1.0 } else { (row[feature] - min) / (max - min) };
+                }
+            }
+            FeaturesScaling::ZScore => {
+                let count = values.len() as f64;
+                let mean = values.iter().map(|row| row[feature]).sum::<f64>() / count;
+                let std = (values.iter().map(|row| (row[feature] - mean).powi(2)).sum::<f64>() / count).sqrt();
+
+                for row in values.iter_mut() {
+                    row[feature] = if std == 0.0 { 0.0 } else { (row[feature] - mean) / std };
+                }
+            }
+        }
+    }
+}
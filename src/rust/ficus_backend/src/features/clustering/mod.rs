@@ -1,4 +1,8 @@
 pub mod activities;
+pub mod agglomerative;
 pub mod common;
+pub mod distance_matrix;
 pub mod error;
+pub mod featurisation;
+pub mod quality;
 pub mod traces;
@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use linfa_nn::distance::Distance;
+use ndarray::ArrayView1;
+
+use crate::utils::{
+    dataset::dataset::{FicusDataset, LabeledDataset},
+    distance::distance::FicusDistance,
+};
+
+use super::{common::create_features_distance, distance_matrix::build_distance_matrix, error::ClusteringError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum ClusteringQualityIndex {
+    Silhouette,
+    DaviesBouldin,
+    CalinskiHarabasz,
+}
+
+impl FromStr for ClusteringQualityIndex {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Silhouette" => Ok(Self::Silhouette),
+            "DaviesBouldin" => Ok(Self::DaviesBouldin),
+            "CalinskiHarabasz" => Ok(Self::CalinskiHarabasz),
+            _ => Err(()),
+        }
+    }
+}
+
+impl ClusteringQualityIndex {
+    //Davies-Bouldin index is the only one which should be minimized, undefined (NaN) values are never better
+    pub fn is_better(&self, candidate: f64, best: f64) -> bool {
+        if candidate.is_nan() {
+            return false;
+        }
+
+        if best.is_nan() {
+            return true;
+        }
+
+        match self {
+            Self::Silhouette | Self::CalinskiHarabasz => candidate > best,
+            Self::DaviesBouldin => candidate < best,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum ClusteringSearchParameter {
+    ClustersCount,
+    Tolerance,
+    MinPoints,
+}
+
+impl FromStr for ClusteringSearchParameter {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ClustersCount" => Ok(Self::ClustersCount),
+            "Tolerance" => Ok(Self::Tolerance),
+            "MinPoints" => Ok(Self::MinPoints),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ClusteringQuality {
+    clusters_count: usize,
+    noise_count: usize,
+    silhouette: f64,
+    davies_bouldin: f64,
+    calinski_harabasz: f64,
+}
+
+impl ClusteringQuality {
+    pub fn clusters_count(&self) -> usize {
+        self.clusters_count
+    }
+
+    pub fn noise_count(&self) -> usize {
+        self.noise_count
+    }
+
+    pub fn silhouette(&self) -> f64 {
+        self.silhouette
+    }
+
+    pub fn davies_bouldin(&self) -> f64 {
+        self.davies_bouldin
+    }
+
+    pub fn calinski_harabasz(&self) -> f64 {
+        self.calinski_harabasz
+    }
+
+    pub fn index(&self, index: ClusteringQualityIndex) -> f64 {
+        match index {
+            ClusteringQualityIndex::Silhouette => self.silhouette,
+            ClusteringQualityIndex::DaviesBouldin => self.davies_bouldin,
+            ClusteringQualityIndex::CalinskiHarabasz => self.calinski_harabasz,
+        }
+    }
+}
+
+//Scores of all tried configurations, each row of the dataset describes one configuration
+#[derive(Clone, Debug, Default)]
+pub struct ClusteringScores {
+    configurations: Vec<String>,
+    qualities: Vec<ClusteringQuality>,
+}
+
+impl ClusteringScores {
+    pub fn push(&mut self, configuration: String, quality: ClusteringQuality) {
+        self.configurations.push(configuration);
+        self.qualities.push(quality);
+    }
+
+    pub fn configurations(&self) -> &Vec<String> {
+        &self.configurations
+    }
+
+    pub fn qualities(&self) -> &Vec<ClusteringQuality> {
+        &self.qualities
+    }
+
+    //Returns the index of the best configuration, the first one is chosen in case of ties
+    pub fn best(&self, index: ClusteringQualityIndex) -> Option<usize> {
+        let mut best: Option<usize> = None;
+        for (i, quality) in self.qualities.iter().enumerate() {
+            let is_better = match best {
+                None => !quality.index(index).is_nan(),
+                Some(best) => index.is_better(quality.index(index), self.qualities[best].index(index)),
+            };
+
+            if is_better {
+                best = Some(i);
+            }
+        }
+
+        best
+    }
+
+    pub fn to_dataset(&self) -> FicusDataset {
+        let columns = vec!["clusters_count", "noise_count", "silhouette", "davies_bouldin", "calinski_harabasz"];
+        let values = self
+            .qualities
+            .iter()
+            .map(|quality| {
+                vec![
+                    quality.clusters_count as f64,
+                    quality.noise_count as f64,
+                    quality.silhouette,
+                    quality.davies_bouldin,
+                    quality.calinski_harabasz,
+                ]
+            })
+            .collect();
+
+        FicusDataset::new(values, columns.iter().map(|x| x.to_string()).collect(), self.configurations.clone())
+    }
+}
+
+pub fn calculate_features_distances(values: &Vec<Vec<f64>>, distance: FicusDistance) -> Result<Vec<Vec<f64>>, ClusteringError> {
+    let distance = create_features_distance(distance)?;
+    Ok(build_distance_matrix(values, |first, second| {
+        distance.distance(ArrayView1::from(first.as_slice()), ArrayView1::from(second.as_slice()))
+    }))
+}
+
+//Values of the dataset are either features (then `distance` should be supplied) or a distance matrix,
+//when `has_noise` is true the label 0 denotes noise
+pub fn calculate_labeled_dataset_quality(
+    dataset: &LabeledDataset,
+    distance: Option<FicusDistance>,
+    has_noise: bool,
+) -> Result<ClusteringQuality, ClusteringError> {
+    let labels = dataset
+        .labels()
+        .iter()
+        .map(|label| match has_noise {
+            true => label.checked_sub(1),
+            false => Some(*label),
+        })
+        .collect::<Vec<Option<usize>>>();
+
+    Ok(match distance {
+        Some(distance) => calculate_clustering_quality(&calculate_features_distances(dataset.dataset().values(), distance)?, &labels),
+        None => calculate_clustering_quality(dataset.dataset().values(), &labels),
+    })
+}
+
+//All indices are calculated from distances only, so that they are applicable to any distance: Calinski-Harabasz index
+//uses sums of squared pairwise distances (which is exact for the Euclidean distance), Davies-Bouldin index uses medoids
+//instead of centroids. Noise objects (None labels) are ignored, indices are NaN when there are less than two clusters
+pub fn calculate_clustering_quality(distances: &Vec<Vec<f64>>, labels: &Vec<Option<usize>>) -> ClusteringQuality {
+    let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (object, label) in labels.iter().enumerate() {
+        if let Some(label) = label {
+            clusters.entry(*label).or_default().push(object);
+        }
+    }
+
+    let mut clusters = clusters.into_iter().collect::<Vec<(usize, Vec<usize>)>>();
+    clusters.sort_by(|first, second| first.0.cmp(&second.0));
+    let clusters = clusters.into_iter().map(|(_, objects)| objects).collect::<Vec<Vec<usize>>>();
+
+    let labeled_count = clusters.iter().map(|objects| objects.len()).sum::<usize>();
+    let noise_count = labels.len() - labeled_count;
+
+    let (silhouette, davies_bouldin, calinski_harabasz) = if clusters.len() < 2 || clusters.len() >= labeled_count {
+        (f64::NAN, f64::NAN, f64::NAN)
+    } else {
+        (
+            calculate_silhouette(distances, &clusters),
+            calculate_davies_bouldin(distances, &clusters),
+            calculate_calinski_harabasz(distances, &clusters),
+        )
+    };
+
+    ClusteringQuality {
+        clusters_count: clusters.len(),
+        noise_count,
+        silhouette,
+        davies_bouldin,
+        calinski_harabasz,
+    }
+}
+
+fn mean_distance(distances: &Vec<Vec<f64>>, object: usize, cluster: &Vec<usize>) -> f64 {
+    let others = cluster.iter().filter(|other| **other != object);
+    let count = others.clone().count();
+
+    others.map(|other| distances[object][*other]).sum::<f64>() / count as f64
+}
+
+fn calculate_silhouette(distances: &Vec<Vec<f64>>, clusters: &Vec<Vec<usize>>) -> f64 {
+    let mut sum = 0.0;
+    let mut count = 0;
+
+    for (index, cluster) in clusters.iter().enumerate() {
+        for object in cluster {
+            count += 1;
+            if cluster.len() < 2 {
+                continue;
+            }
+
+            let inner = mean_distance(distances, *object, cluster);
+            let outer = clusters
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .map(|(_, other)| mean_distance(distances, *object, other))
+                .fold(f64::INFINITY, f64::min);
+
+            let max = inner.max(outer);
+            if max > 0.0 {
+                sum += (outer - inner) / max;
+            }
+        }
+    }
+
+    sum / count as f64
+}
+
+fn find_medoid(distances: &Vec<Vec<f64>>, cluster: &Vec<usize>) -> usize {
+    let sum = |object: usize| cluster.iter().map(|other| distances[object][*other]).sum::<f64>();
+
+    let mut medoid = cluster[0];
+    for object in cluster {
+        if sum(*object) < sum(medoid) {
+            medoid = *object;
+        }
+    }
+
+    medoid
+}
+
+fn calculate_davies_bouldin(distances: &Vec<Vec<f64>>, clusters: &Vec<Vec<usize>>) -> f64 {
+    let medoids = clusters
+        .iter()
+        .map(|cluster| find_medoid(distances, cluster))
+        .collect::<Vec<usize>>();
+    let scatters = clusters
+        .iter()
+        .zip(medoids.iter())
+        .map(|(cluster, medoid)| cluster.iter().map(|object| distances[*object][*medoid]).sum::<f64>() / cluster.len() as f64)
+        .collect::<Vec<f64>>();
+
+    let mut sum = 0.0;
+    for i in 0..clusters.len() {
+        let mut max_ratio = 0f64;
+        for j in (0..clusters.len()).filter(|j| *j != i) {
+            let medoids_distance = distances[medoids[i]][medoids[j]];
+            if medoids_distance > 0.0 {
+                max_ratio = max_ratio.max((scatters[i] + scatters[j]) / medoids_distance);
+            }
+        }
+
+        sum += max_ratio;
+    }
+
+    sum / clusters.len() as f64
+}
+
+fn squared_distances_sum(distances: &Vec<Vec<f64>>, objects: &Vec<usize>) -> f64 {
+    let mut sum = 0.0;
+    for first in objects {
+        for second in objects {
+            sum += distances[*first][*second].powi(2);
+        }
+    }
+
+    sum / (2.0 * objects.len() as f64)
+}
+
+//Clusters without inner dispersion are perfectly separated (the index is infinite), unless all objects coincide,
+//then there is no dispersion at all and the index is undefined
+fn calculate_calinski_harabasz(distances: &Vec<Vec<f64>>, clusters: &Vec<Vec<usize>>) -> f64 {
+    let all_objects = clusters.iter().flatten().copied().collect::<Vec<usize>>();
+
+    let total = squared_distances_sum(distances, &all_objects);
+    let within = clusters
+        .iter()
+        .map(|cluster| squared_distances_sum(distances, cluster))
+        .sum::<f64>();
+
+    let between = total - within;
+    if within == 0.0 {
+        return if between > 0.0 { f64::INFINITY } else { f64::NAN };
+    }
+
+    let clusters_count = clusters.len() as f64;
+    let objects_count = all_objects.len() as f64;
+
+    (between / (clusters_count - 1.0)) / (within / (objects_count - clusters_count))
+}
@@ -0,0 +1,57 @@
+use crate::{
+    event_log::core::event_log::EventLog,
+    features::clustering::{
+        agglomerative::{build_dendrogram, AgglomerativeClusteringLinkage, Dendrogram, DendrogramCut},
+        common::{create_colors_vector, transform_to_ficus_dataset},
+        distance_matrix::DistanceMatrixClustering,
+        error::ClusteringError,
+        quality::calculate_features_distances,
+    },
+    utils::dataset::dataset::LabeledDataset,
+};
+
+use super::{
+    distance_matrix::clusterize_log_by_traces_distance_matrix,
+    traces_common::{create_logs_from_labels, create_traces_dataset},
+    traces_params::TracesClusteringParams,
+};
+
+pub fn clusterize_log_by_traces_agglomerative<TLog: EventLog>(
+    params: &mut TracesClusteringParams<TLog>,
+    linkage: AgglomerativeClusteringLinkage,
+    cut: DendrogramCut,
+) -> Result<(Vec<TLog>, LabeledDataset, Dendrogram), ClusteringError> {
+    if !params.distance.is_features_distance() {
+        let clustering = DistanceMatrixClustering::Agglomerative { linkage, cut };
+        let (new_logs, labeled_dataset, dendrogram) = clusterize_log_by_traces_distance_matrix(params, &clustering)?;
+
+        return Ok((
+            new_logs,
+            labeled_dataset,
+            dendrogram.expect("Agglomerative clustering produces a dendrogram"),
+        ));
+    }
+
+    let class_extractor = params.vis_params.class_extractor.as_ref();
+    let traces_dataset = create_traces_dataset(
+        params.vis_params.log,
+        &params.distance,
+        class_extractor,
+        &params.repr_source,
+        params.featurisation.as_ref(),
+    );
+
+    let (dataset, objects, features) = traces_dataset?;
+
+    let values = dataset.records().outer_iter().map(|row| row.to_vec()).collect::<Vec<Vec<f64>>>();
+    let distances = calculate_features_distances(&values, params.distance)?;
+
+    let dendrogram = build_dendrogram(&distances, objects.clone(), linkage);
+    let labels = dendrogram.cut(&cut);
+
+    let ficus_dataset = transform_to_ficus_dataset(&dataset, objects, features);
+    let new_logs = create_logs_from_labels(params.vis_params.log, &labels);
+    let colors = create_colors_vector(&labels, &mut params.vis_params.colors_holder);
+
+    Ok((new_logs, LabeledDataset::new(ficus_dataset, labels, colors), dendrogram))
+}
@@ -0,0 +1,79 @@
+use crate::{
+    event_log::core::event_log::EventLog,
+    features::clustering::{
+        agglomerative::Dendrogram,
+        common::create_colors_vector,
+        distance_matrix::{
+            build_sequences_distance_matrix, clusterize_distance_matrix, search_distance_matrix_clustering, DistanceMatrixClustering,
+        },
+        error::ClusteringError,
+        quality::{calculate_features_distances, ClusteringQualityIndex, ClusteringScores, ClusteringSearchParameter},
+    },
+    utils::dataset::dataset::{FicusDataset, LabeledDataset},
+};
+
+use super::{
+    traces_common::{create_logs_from_labels, create_traces_features, create_traces_sequences},
+    traces_params::TracesClusteringParams,
+};
+
+//Traces are compared as raw sequences of event classes, the labeled dataset contains the distance matrix,
+//label 0 is reserved for noise
+pub fn clusterize_log_by_traces_distance_matrix<TLog: EventLog>(
+    params: &mut TracesClusteringParams<TLog>,
+    clustering: &DistanceMatrixClustering,
+) -> Result<(Vec<TLog>, LabeledDataset, Option<Dendrogram>), ClusteringError> {
+    let (distances, objects) = create_traces_distance_matrix(params)?;
+    Ok(clusterize_traces_with_distance_matrix(params, distances, objects, clustering))
+}
+
+//The clustering is performed with the best configuration found by the search
+pub fn search_log_traces_distance_matrix_clustering<TLog: EventLog>(
+    params: &mut TracesClusteringParams<TLog>,
+    clustering: &DistanceMatrixClustering,
+    parameter: ClusteringSearchParameter,
+    index: ClusteringQualityIndex,
+) -> Result<(Vec<TLog>, LabeledDataset, Option<Dendrogram>, ClusteringScores), ClusteringError> {
+    let (distances, objects) = create_traces_distance_matrix(params)?;
+    let (best_clustering, scores) = search_distance_matrix_clustering(&distances, &objects, clustering, parameter, index)?;
+    let (new_logs, labeled_dataset, dendrogram) = clusterize_traces_with_distance_matrix(params, distances, objects, &best_clustering);
+
+    Ok((new_logs, labeled_dataset, dendrogram, scores))
+}
+
+//Traces features are used instead of sequences when the featurisation is specified for a vector distance
+fn create_traces_distance_matrix<TLog: EventLog>(
+    params: &TracesClusteringParams<TLog>,
+) -> Result<(Vec<Vec<f64>>, Vec<String>), ClusteringError> {
+    let class_extractor = params.vis_params.class_extractor.as_ref();
+    let distances = match params.featurisation.as_ref() {
+        Some(featurisation) if !params.distance.is_sequence_distance() => {
+            let (values, _) = create_traces_features(params.vis_params.log, class_extractor, &params.repr_source, featurisation);
+            calculate_features_distances(&values, params.distance)?
+        }
+        _ => {
+            let sequences = create_traces_sequences(params.vis_params.log, class_extractor, &params.repr_source);
+            build_sequences_distance_matrix(&sequences, params.distance)
+        }
+    };
+
+    let objects = (0..distances.len()).map(|x| format!("Trace_{}", x)).collect::<Vec<String>>();
+
+    Ok((distances, objects))
+}
+
+fn clusterize_traces_with_distance_matrix<TLog: EventLog>(
+    params: &mut TracesClusteringParams<TLog>,
+    distances: Vec<Vec<f64>>,
+    objects: Vec<String>,
+    clustering: &DistanceMatrixClustering,
+) -> (Vec<TLog>, LabeledDataset, Option<Dendrogram>) {
+    let (labels, dendrogram) = clusterize_distance_matrix(&distances, objects.clone(), clustering);
+    let labels: Vec<usize> = labels.iter().map(|x| if x.is_none() { 0 } else { x.unwrap() + 1 }).collect();
+
+    let new_logs = create_logs_from_labels(params.vis_params.log, &labels);
+    let colors = create_colors_vector(&labels, &mut params.vis_params.colors_holder);
+    let dataset = FicusDataset::new(distances, objects.clone(), objects);
+
+    (new_logs, LabeledDataset::new(dataset, labels, colors), dendrogram)
+}
@@ -1,2 +1,6 @@
+pub mod agglomerative;
 pub mod dbscan;
+pub mod distance_matrix;
+pub mod model_driven;
+pub mod traces_common;
 pub mod traces_params;
@@ -0,0 +1,279 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::{
+    event_log::core::event_log::EventLog,
+    features::{
+        clustering::{common::create_colors_vector, error::ClusteringError},
+        discovery::petri_net::{
+            marking::ensure_initial_marking,
+            petri_net::DefaultPetriNet,
+            replay::{replay_trace_tokens, TokenReplayCounts},
+        },
+    },
+    utils::{
+        colors::ColorsHolder,
+        dataset::dataset::{FicusDataset, LabeledDataset},
+    },
+};
+
+pub struct ModelDrivenClusteringParams<'a, TLog>
+where
+    TLog: EventLog,
+{
+    pub log: &'a TLog,
+    pub colors_holder: &'a mut ColorsHolder,
+    pub miner: &'a dyn Fn(&TLog) -> DefaultPetriNet,
+    pub target_fitness: f64,
+    pub max_clusters_count: Option<usize>,
+    pub look_ahead_count: usize,
+}
+
+pub struct ModelDrivenClusters<TLog>
+where
+    TLog: EventLog,
+{
+    logs: Vec<TLog>,
+    petri_nets: Vec<DefaultPetriNet>,
+    residual_log: TLog,
+    dataset: LabeledDataset,
+}
+
+impl<TLog> ModelDrivenClusters<TLog>
+where
+    TLog: EventLog,
+{
+    pub fn logs(&self) -> &Vec<TLog> {
+        &self.logs
+    }
+
+    pub fn petri_nets(&self) -> &Vec<DefaultPetriNet> {
+        &self.petri_nets
+    }
+
+    pub fn residual_log(&self) -> &TLog {
+        &self.residual_log
+    }
+
+    pub fn dataset(&self) -> &LabeledDataset {
+        &self.dataset
+    }
+}
+
+struct Variant {
+    events: Vec<String>,
+    traces: Vec<usize>,
+}
+
+struct ModelCluster {
+    variants: Vec<usize>,
+    petri_net: DefaultPetriNet,
+    counts: TokenReplayCounts,
+}
+
+//ActiTraC-like clustering: variants are processed in the order of decreasing frequency, the most frequent
+//unprocessed variant becomes the seed of a new cluster, then other variants are added to the cluster one by one
+//if the cluster's model (current or re-discovered) replays the cluster with the fitness not less than the target one.
+//Seeds which can not reach the target fitness, empty traces and variants left after the maximum count of clusters
+//is reached go to the residual cluster (label 0), afterwards residual variants are assigned to the cluster whose model
+//replays them best if the target fitness is reached. Dataset contains the fitness of each trace against each model.
+//The model of a cluster is re-discovered until `look_ahead_count` candidates are rejected, afterwards only candidates
+//which are replayed by the current model are added to the cluster
+pub fn clusterize_log_by_traces_models<TLog: EventLog>(
+    params: &mut ModelDrivenClusteringParams<TLog>,
+) -> Result<ModelDrivenClusters<TLog>, ClusteringError> {
+    if !(0.0..=1.0).contains(&params.target_fitness) {
+        return Err(ClusteringError::RawError(format!(
+            "Target fitness should be in [0, 1], got {}",
+            params.target_fitness
+        )));
+    }
+
+    let variants = create_variants(params.log);
+    let mut clusters: Vec<ModelCluster> = vec![];
+    let mut processed = vec![false; variants.len()];
+    let mut residual = vec![];
+
+    for seed in 0..variants.len() {
+        if processed[seed] {
+            continue;
+        }
+
+        processed[seed] = true;
+        if variants[seed].events.is_empty() {
+            residual.push(seed);
+            continue;
+        }
+
+        if params.max_clusters_count.is_some_and(|max| clusters.len() >= max) {
+            residual.push(seed);
+            continue;
+        }
+
+        let (petri_net, counts) = discover_cluster_model(params, &variants, &vec![seed]);
+        if counts.fitness() < params.target_fitness {
+            residual.push(seed);
+            continue;
+        }
+
+        let mut cluster = ModelCluster {
+            variants: vec![seed],
+            petri_net,
+            counts,
+        };
+
+        let mut rejected_count = 0;
+        for candidate in (seed + 1)..variants.len() {
+            if processed[candidate] || variants[candidate].events.is_empty() {
+                continue;
+            }
+
+            let mut counts = cluster.counts;
+            counts.add(&replay_variant(&variants[candidate], &cluster.petri_net), 1);
+
+            if counts.fitness() >= params.target_fitness {
+                cluster.counts = counts;
+            } else {
+                if rejected_count >= params.look_ahead_count {
+                    continue;
+                }
+
+                let mut candidate_variants = cluster.variants.clone();
+                candidate_variants.push(candidate);
+
+                let (petri_net, counts) = discover_cluster_model(params, &variants, &candidate_variants);
+                if counts.fitness() < params.target_fitness {
+                    rejected_count += 1;
+                    continue;
+                }
+
+                cluster.petri_net = petri_net;
+                cluster.counts = counts;
+            }
+
+            cluster.variants.push(candidate);
+            processed[candidate] = true;
+        }
+
+        clusters.push(cluster);
+    }
+
+    let mut labels = vec![0; params.log.traces().len()];
+    for (index, cluster) in clusters.iter().enumerate() {
+        for variant in &cluster.variants {
+            for trace in &variants[*variant].traces {
+                labels[*trace] = index + 1;
+            }
+        }
+    }
+
+    for variant in residual {
+        if variants[variant].events.is_empty() {
+            continue;
+        }
+
+        let mut best: Option<(usize, f64)> = None;
+        for (index, cluster) in clusters.iter().enumerate() {
+            let fitness = replay_trace_tokens(&variants[variant].events, &cluster.petri_net).fitness();
+            if fitness >= params.target_fitness && best.is_none_or(|(_, best_fitness)| fitness > best_fitness) {
+                best = Some((index, fitness));
+            }
+        }
+
+        if let Some((index, _)) = best {
+            for trace in &variants[variant].traces {
+                labels[*trace] = index + 1;
+            }
+        }
+    }
+
+    let mut values = vec![vec![0.0; clusters.len()]; params.log.traces().len()];
+    for variant in &variants {
+        for (index, cluster) in clusters.iter().enumerate() {
+            let fitness = replay_trace_tokens(&variant.events, &cluster.petri_net).fitness();
+            for trace in &variant.traces {
+                values[*trace][index] = fitness;
+            }
+        }
+    }
+
+    let mut logs = (0..clusters.len()).map(|_| TLog::empty()).collect::<Vec<TLog>>();
+    let mut residual_log = TLog::empty();
+    for (trace, label) in params.log.traces().iter().zip(labels.iter()) {
+        let trace_copy = Rc::new(RefCell::new(trace.borrow().clone()));
+        match label {
+            0 => residual_log.push(trace_copy),
+            label => logs[label - 1].push(trace_copy),
+        }
+    }
+
+    let columns = (1..=clusters.len()).map(|x| format!("fitness_{}", x)).collect();
+    let rows = (0..labels.len()).map(|x| format!("Trace_{}", x)).collect();
+    let colors = create_colors_vector(&labels, params.colors_holder);
+
+    Ok(ModelDrivenClusters {
+        logs,
+        petri_nets: clusters.into_iter().map(|cluster| cluster.petri_net).collect(),
+        residual_log,
+        dataset: LabeledDataset::new(FicusDataset::new(values, columns, rows), labels, colors),
+    })
+}
+
+//Variants are sorted by frequency, ties are resolved by the first occurrence in the log
+fn create_variants(log: &impl EventLog) -> Vec<Variant> {
+    let mut indices: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut variants: Vec<Variant> = vec![];
+
+    for (trace, events) in log.to_raw_vector().into_iter().enumerate() {
+        match indices.get(&events) {
+            Some(index) => variants[*index].traces.push(trace),
+            None => {
+                indices.insert(events.clone(), variants.len());
+                variants.push(Variant {
+                    events,
+                    traces: vec![trace],
+                });
+            }
+        }
+    }
+
+    variants.sort_by(|first, second| {
+        second
+            .traces
+            .len()
+            .cmp(&first.traces.len())
+            .then(first.traces[0].cmp(&second.traces[0]))
+    });
+    variants
+}
+
+fn replay_variant(variant: &Variant, petri_net: &DefaultPetriNet) -> TokenReplayCounts {
+    let mut counts = TokenReplayCounts::default();
+    counts.add(&replay_trace_tokens(&variant.events, petri_net), variant.traces.len());
+    counts
+}
+
+fn discover_cluster_model<TLog: EventLog>(
+    params: &ModelDrivenClusteringParams<TLog>,
+    variants: &Vec<Variant>,
+    cluster_variants: &Vec<usize>,
+) -> (DefaultPetriNet, TokenReplayCounts) {
+    let mut cluster_log = TLog::empty();
+    for variant in cluster_variants {
+        for trace in &variants[*variant].traces {
+            let trace_copy = params.log.traces()[*trace].borrow().clone();
+            cluster_log.push(Rc::new(RefCell::new(trace_copy)));
+        }
+    }
+
+    let mut petri_net = (params.miner)(&cluster_log);
+    if petri_net.initial_marking().is_none() {
+        ensure_initial_marking(&cluster_log, &mut petri_net);
+    }
+
+    let mut counts = TokenReplayCounts::default();
+    for variant in cluster_variants {
+        counts.add(&replay_variant(&variants[*variant], &petri_net), 1);
+    }
+
+    (petri_net, counts)
+}
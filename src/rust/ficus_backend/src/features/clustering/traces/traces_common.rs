@@ -0,0 +1,229 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use linfa::DatasetBase;
+use ndarray::Array2;
+
+use crate::{
+    event_log::core::{
+        event::{event::Event, event_hasher::RegexEventHasher},
+        event_log::EventLog,
+        trace::trace::Trace,
+    },
+    features::{
+        analysis::patterns::activity_instances::{create_vector_of_immediate_underlying_events, create_vector_of_underlying_events},
+        clustering::{
+            common::MyDataset,
+            error::ClusteringError,
+            featurisation::{featurise_traces, TracesFeaturisation},
+        },
+    },
+    utils::distance::distance::FicusDistance,
+};
+
+use super::traces_params::TracesRepresentationSource;
+
+//Sub-logs are ordered by labels, each sub-log contains copies of traces with the same label
+pub(super) fn create_logs_from_labels<TLog: EventLog>(log: &TLog, labels: &Vec<usize>) -> Vec<TLog> {
+    let mut new_logs: HashMap<usize, TLog> = HashMap::new();
+    for (trace, label) in log.traces().iter().zip(labels) {
+        let trace_copy = trace.borrow().clone();
+        if let Some(cluster_log) = new_logs.get_mut(label) {
+            cluster_log.push(Rc::new(RefCell::new(trace_copy)));
+        } else {
+            let mut cluster_log = TLog::empty();
+            cluster_log.push(Rc::new(RefCell::new(trace_copy)));
+
+            new_logs.insert(label.to_owned(), cluster_log);
+        }
+    }
+
+    let mut new_logs: Vec<(usize, TLog)> = new_logs.into_iter().collect();
+    new_logs.sort_by(|first, second| first.0.cmp(&second.0));
+
+    new_logs.into_iter().map(|x| x.1).collect()
+}
+
+pub(super) fn create_traces_dataset<TLog: EventLog>(
+    log: &TLog,
+    distance: &FicusDistance,
+    class_extractor: Option<&String>,
+    trace_repr_source: &TracesRepresentationSource,
+    featurisation: Option<&TracesFeaturisation>,
+) -> Result<(MyDataset, Vec<String>, Vec<String>), ClusteringError> {
+    match distance {
+        FicusDistance::Cosine | FicusDistance::L1 | FicusDistance::L2 => match featurisation {
+            Some(featurisation) => create_traces_dataset_default(log, class_extractor, trace_repr_source, featurisation),
+            None => create_traces_dataset_default(log, class_extractor, trace_repr_source, &TracesFeaturisation::default()),
+        },
+        FicusDistance::Levenshtein | FicusDistance::WeightedEdit | FicusDistance::OptimalStringAlignment | FicusDistance::Dtw => {
+            create_traces_dataset_levenshtein(log, class_extractor, trace_repr_source)
+        }
+    }
+}
+
+//Each trace is represented as a sequence of event classes (names of events processed by the class extractor)
+pub(super) fn create_traces_sequences<TLog: EventLog>(
+    log: &TLog,
+    class_extractor: Option<&String>,
+    trace_repr_source: &TracesRepresentationSource,
+) -> Vec<Vec<String>> {
+    let regex_hasher = match class_extractor.as_ref() {
+        Some(class_extractor) => Some(RegexEventHasher::new(class_extractor).ok().unwrap()),
+        None => None,
+    };
+
+    let mut sequences = vec![];
+    for trace in log.traces() {
+        let trace = trace.borrow();
+        let sequence = create_trace_representation::<TLog>(&trace, trace_repr_source)
+            .iter()
+            .map(|event| match regex_hasher.as_ref() {
+                Some(regex_hasher) => regex_hasher.transform(event.borrow().name()).to_owned(),
+                None => event.borrow().name().to_owned(),
+            })
+            .collect();
+
+        sequences.push(sequence);
+    }
+
+    sequences
+}
+
+fn create_traces_dataset_default<TLog: EventLog>(
+    log: &TLog,
+    class_extractor: Option<&String>,
+    trace_repr_source: &TracesRepresentationSource,
+    featurisation: &TracesFeaturisation,
+) -> Result<(MyDataset, Vec<String>, Vec<String>), ClusteringError> {
+    let (values, features_names) = create_traces_features(log, class_extractor, trace_repr_source, featurisation);
+
+    let shape = (values.len(), features_names.len());
+    let array = match Array2::from_shape_vec(shape, values.into_iter().flatten().collect()) {
+        Ok(score) => score,
+        Err(_) => return Err(ClusteringError::FailedToCreateNdArray),
+    };
+
+    Ok((
+        DatasetBase::from(array),
+        (0..shape.0).map(|x| format!("Trace_{}", x)).collect(),
+        features_names,
+    ))
+}
+
+pub(super) fn create_traces_features<TLog: EventLog>(
+    log: &TLog,
+    class_extractor: Option<&String>,
+    trace_repr_source: &TracesRepresentationSource,
+    featurisation: &TracesFeaturisation,
+) -> (Vec<Vec<f64>>, Vec<String>) {
+    let traces: Vec<Vec<Rc<RefCell<TLog::TEvent>>>> = log
+        .traces()
+        .iter()
+        .map(|trace| create_trace_representation::<TLog>(&trace.borrow(), trace_repr_source))
+        .collect();
+
+    featurise_traces(&traces, class_extractor, featurisation)
+}
+
+fn create_trace_representation<TLog: EventLog>(
+    trace: &TLog::TTrace,
+    trace_repr_source: &TracesRepresentationSource,
+) -> Vec<Rc<RefCell<TLog::TEvent>>> {
+    match trace_repr_source {
+        TracesRepresentationSource::Events => trace.events().clone(),
+        TracesRepresentationSource::UnderlyingEvents => {
+            let mut events = vec![];
+            for event in trace.events() {
+                for event in create_vector_of_immediate_underlying_events::<TLog>(event) {
+                    events.push(event);
+                }
+            }
+
+            events
+        }
+        TracesRepresentationSource::DeepestUnderlyingEvents => {
+            let mut events = vec![];
+            for event in trace.events() {
+                for underlying_event in create_vector_of_underlying_events::<TLog>(event) {
+                    events.push(underlying_event);
+                }
+            }
+
+            events
+        }
+    }
+}
+
+fn create_traces_dataset_levenshtein<TLog: EventLog>(
+    log: &TLog,
+    class_extractor: Option<&String>,
+    trace_repr_source: &TracesRepresentationSource,
+) -> Result<(MyDataset, Vec<String>, Vec<String>), ClusteringError> {
+    create_traces_dataset_levenshtein_internal(log, class_extractor, |trace| {
+        create_trace_representation::<TLog>(trace, trace_repr_source)
+    })
+}
+
+fn create_traces_dataset_levenshtein_internal<TLog: EventLog>(
+    log: &TLog,
+    class_extractor: Option<&String>,
+    trace_repr_creator: impl Fn(&TLog::TTrace) -> Vec<Rc<RefCell<TLog::TEvent>>>,
+) -> Result<(MyDataset, Vec<String>, Vec<String>), ClusteringError> {
+    let regex_hasher = match class_extractor.as_ref() {
+        Some(class_extractor) => Some(RegexEventHasher::new(class_extractor).ok().unwrap()),
+        None => None,
+    };
+
+    let mut processed_traces = vec![];
+    for trace in log.traces() {
+        let trace = trace.borrow();
+        processed_traces.push(trace_repr_creator(&trace));
+    }
+
+    let mut all_event_classes = HashMap::new();
+    let mut max_length = 0;
+    for trace in &processed_traces {
+        max_length = max_length.max(trace.len() + 1);
+
+        for event in trace {
+            let event = event.borrow();
+            let processed_event_name = match regex_hasher.as_ref() {
+                Some(regex_hasher) => regex_hasher.transform(event.name()),
+                None => event.name(),
+            };
+
+            if !all_event_classes.contains_key(processed_event_name) {
+                all_event_classes.insert(processed_event_name.to_owned(), all_event_classes.len() + 1);
+            }
+        }
+    }
+
+    let mut raw_dataset = vec![];
+    for trace in &processed_traces {
+        for event in trace {
+            let event = event.borrow();
+            let processed_event_name = match regex_hasher.as_ref() {
+                Some(regex_hasher) => regex_hasher.transform(event.name()),
+                None => event.name(),
+            };
+
+            raw_dataset.push(*all_event_classes.get(processed_event_name).expect("Should be there") as f64);
+        }
+
+        for _ in trace.len()..max_length {
+            raw_dataset.push(0f64);
+        }
+    }
+
+    let shape = (processed_traces.len(), max_length);
+    let array = match Array2::from_shape_vec(shape, raw_dataset) {
+        Ok(score) => score,
+        Err(_) => return Err(ClusteringError::FailedToCreateNdArray),
+    };
+
+    Ok((
+        DatasetBase::from(array),
+        (0..processed_traces.len()).into_iter().map(|x| format!("Trace_{}", x)).collect(),
+        (0..max_length).into_iter().map(|x| format!("Symbol_{}", x)).collect(),
+    ))
+}
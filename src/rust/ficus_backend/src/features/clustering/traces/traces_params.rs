@@ -1,7 +1,9 @@
 use std::str::FromStr;
 
 use crate::{
-    event_log::core::event_log::EventLog, features::clustering::common::CommonVisualizationParams, utils::distance::distance::FicusDistance,
+    event_log::core::event_log::EventLog,
+    features::clustering::{common::CommonVisualizationParams, featurisation::TracesFeaturisation},
+    utils::distance::distance::FicusDistance,
 };
 
 pub struct TracesClusteringParams<'a, TLog>
@@ -12,9 +14,12 @@ where
     pub tolerance: f64,
     pub distance: FicusDistance,
     pub repr_source: TracesRepresentationSource,
+    //Used only with vector distances (cosine, L1, L2), when None traces are represented by min-max scaled
+    //event classes counts (clustering on a distance matrix uses raw counts)
+    pub featurisation: Option<TracesFeaturisation>,
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Hash)]
 pub enum TracesRepresentationSource {
     Events,
     UnderlyingEvents,
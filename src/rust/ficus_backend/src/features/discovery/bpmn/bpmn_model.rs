@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use crate::features::discovery::petri_net::ids::next_id;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BpmnNodeKind {
+    StartEvent,
+    EndEvent,
+    Task,
+    ExclusiveGateway,
+    ParallelGateway,
+}
+
+impl BpmnNodeKind {
+    pub fn is_gateway(&self) -> bool {
+        matches!(self, BpmnNodeKind::ExclusiveGateway | BpmnNodeKind::ParallelGateway)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BpmnNode {
+    id: u64,
+    kind: BpmnNodeKind,
+    name: Option<String>,
+}
+
+impl BpmnNode {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn kind(&self) -> BpmnNodeKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BpmnSequenceFlow {
+    id: u64,
+    source_id: u64,
+    target_id: u64,
+}
+
+impl BpmnSequenceFlow {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn source_id(&self) -> u64 {
+        self.source_id
+    }
+
+    pub fn target_id(&self) -> u64 {
+        self.target_id
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BpmnModel {
+    nodes: BTreeMap<u64, BpmnNode>,
+    flows: BTreeMap<u64, BpmnSequenceFlow>,
+}
+
+impl BpmnModel {
+    pub fn empty() -> Self {
+        Self {
+            nodes: BTreeMap::new(),
+            flows: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, kind: BpmnNodeKind, name: Option<String>) -> u64 {
+        let id = next_id();
+        self.nodes.insert(id, BpmnNode { id, kind, name });
+
+        id
+    }
+
+    pub fn connect(&mut self, source_id: u64, target_id: u64) {
+        let exists = self
+            .flows
+            .values()
+            .any(|flow| flow.source_id == source_id && flow.target_id == target_id);
+        if exists {
+            return;
+        }
+
+        let id = next_id();
+        self.flows.insert(id, BpmnSequenceFlow { id, source_id, target_id });
+    }
+
+    pub fn node(&self, id: u64) -> Option<&BpmnNode> {
+        self.nodes.get(&id)
+    }
+
+    pub fn nodes(&self) -> Vec<&BpmnNode> {
+        self.nodes.values().collect()
+    }
+
+    pub fn flows(&self) -> Vec<&BpmnSequenceFlow> {
+        self.flows.values().collect()
+    }
+
+    pub fn find_task_by_name(&self, name: &str) -> Option<&BpmnNode> {
+        self.nodes
+            .values()
+            .find(|node| node.kind == BpmnNodeKind::Task && node.name.as_deref() == Some(name))
+    }
+
+    pub fn nodes_count(&self, kind: BpmnNodeKind) -> usize {
+        self.nodes.values().filter(|node| node.kind == kind).count()
+    }
+
+    pub fn incoming_flows(&self, node_id: u64) -> Vec<&BpmnSequenceFlow> {
+        self.flows.values().filter(|flow| flow.target_id == node_id).collect()
+    }
+
+    pub fn outgoing_flows(&self, node_id: u64) -> Vec<&BpmnSequenceFlow> {
+        self.flows.values().filter(|flow| flow.source_id == node_id).collect()
+    }
+
+    //Gateways with a single incoming and a single outgoing flow do not route anything and are replaced
+    //with a direct flow between their neighbours
+    pub fn remove_redundant_gateways(&mut self) {
+        loop {
+            let redundant_gateway = self.nodes.values().find(|node| {
+                let incoming_flows = self.incoming_flows(node.id);
+                let outgoing_flows = self.outgoing_flows(node.id);
+
+                node.kind.is_gateway() && incoming_flows.len() == 1 && outgoing_flows.len() == 1 && incoming_flows[0].source_id != node.id
+            });
+
+            let gateway_id = match redundant_gateway {
+                Some(gateway) => gateway.id,
+                None => return,
+            };
+
+            let source_id = self.incoming_flows(gateway_id)[0].source_id;
+            let target_id = self.outgoing_flows(gateway_id)[0].target_id;
+
+            self.flows
+                .retain(|_, flow| flow.source_id != gateway_id && flow.target_id != gateway_id);
+            self.nodes.remove(&gateway_id);
+            self.connect(source_id, target_id);
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::io::Cursor;
+
+use quick_xml::Writer;
+
+use crate::utils::xml_utils::{StartEndElementCookie, XmlWriteError};
+
+use super::bpmn_model::{BpmnModel, BpmnNode, BpmnNodeKind};
+
+const DEFINITIONS_TAG_NAME: &'static str = "bpmn:definitions";
+const PROCESS_TAG_NAME: &'static str = "bpmn:process";
+const START_EVENT_TAG_NAME: &'static str = "bpmn:startEvent";
+const END_EVENT_TAG_NAME: &'static str = "bpmn:endEvent";
+const TASK_TAG_NAME: &'static str = "bpmn:task";
+const EXCLUSIVE_GATEWAY_TAG_NAME: &'static str = "bpmn:exclusiveGateway";
+const PARALLEL_GATEWAY_TAG_NAME: &'static str = "bpmn:parallelGateway";
+const SEQUENCE_FLOW_TAG_NAME: &'static str = "bpmn:sequenceFlow";
+const DIAGRAM_TAG_NAME: &'static str = "bpmndi:BPMNDiagram";
+const PLANE_TAG_NAME: &'static str = "bpmndi:BPMNPlane";
+const SHAPE_TAG_NAME: &'static str = "bpmndi:BPMNShape";
+const EDGE_TAG_NAME: &'static str = "bpmndi:BPMNEdge";
+const BOUNDS_TAG_NAME: &'static str = "dc:Bounds";
+const WAYPOINT_TAG_NAME: &'static str = "di:waypoint";
+
+const ID_ATTR_NAME: &'static str = "id";
+const NAME_ATTR_NAME: &'static str = "name";
+const SOURCE_REF_ATTR_NAME: &'static str = "sourceRef";
+const TARGET_REF_ATTR_NAME: &'static str = "targetRef";
+const BPMN_ELEMENT_ATTR_NAME: &'static str = "bpmnElement";
+const IS_EXECUTABLE_ATTR_NAME: &'static str = "isExecutable";
+const TARGET_NAMESPACE_ATTR_NAME: &'static str = "targetNamespace";
+const X_ATTR_NAME: &'static str = "x";
+const Y_ATTR_NAME: &'static str = "y";
+const WIDTH_ATTR_NAME: &'static str = "width";
+const HEIGHT_ATTR_NAME: &'static str = "height";
+
+const BPMN_NAMESPACE: &'static str = "http://www.omg.org/spec/BPMN/20100524/MODEL";
+const BPMNDI_NAMESPACE: &'static str = "http://www.omg.org/spec/BPMN/20100524/DI";
+const DC_NAMESPACE: &'static str = "http://www.omg.org/spec/DD/20100524/DC";
+const DI_NAMESPACE: &'static str = "http://www.omg.org/spec/DD/20100524/DI";
+const TARGET_NAMESPACE: &'static str = "http://ficus/bpmn";
+
+const PROCESS_ID: &'static str = "process";
+const DIAGRAM_ID: &'static str = "diagram";
+const PLANE_ID: &'static str = "plane";
+
+const LAYER_DISTANCE: i64 = 160;
+const NODES_DISTANCE: i64 = 120;
+const LAYOUT_OFFSET: i64 = 100;
+
+pub fn serialize_bpmn_to_file(model: &BpmnModel, save_path: &str) -> Result<(), XmlWriteError> {
+    match serialize_bpmn(model) {
+        Ok(content) => match fs::write(save_path, content) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(XmlWriteError::IOError(error)),
+        },
+        Err(error) => Err(error),
+    }
+}
+
+pub fn serialize_bpmn(model: &BpmnModel) -> Result<String, XmlWriteError> {
+    let writer = RefCell::new(Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2));
+
+    let definitions_cookie = StartEndElementCookie::new_with_attrs(
+        &writer,
+        DEFINITIONS_TAG_NAME,
+        &vec![
+            ("xmlns:bpmn", BPMN_NAMESPACE),
+            ("xmlns:bpmndi", BPMNDI_NAMESPACE),
+            ("xmlns:dc", DC_NAMESPACE),
+            ("xmlns:di", DI_NAMESPACE),
+            (ID_ATTR_NAME, "definitions"),
+            (TARGET_NAMESPACE_ATTR_NAME, TARGET_NAMESPACE),
+        ],
+    )?;
+
+    write_process(model, &writer)?;
+    write_diagram(model, &writer)?;
+
+    drop(definitions_cookie);
+
+    create_bpmn_string(&writer)
+}
+
+fn create_bpmn_string(writer: &RefCell<Writer<Cursor<Vec<u8>>>>) -> Result<String, XmlWriteError> {
+    let content = writer.borrow().get_ref().get_ref().clone();
+    match String::from_utf8(content) {
+        Ok(string) => Ok(string),
+        Err(error) => Err(XmlWriteError::FromUt8Error(error)),
+    }
+}
+
+fn write_process(model: &BpmnModel, writer: &RefCell<Writer<Cursor<Vec<u8>>>>) -> Result<(), XmlWriteError> {
+    let process_cookie = StartEndElementCookie::new_with_attrs(
+        writer,
+        PROCESS_TAG_NAME,
+        &vec![(ID_ATTR_NAME, PROCESS_ID), (IS_EXECUTABLE_ATTR_NAME, "false")],
+    )?;
+
+    for node in model.nodes() {
+        let id = create_node_id(node.id());
+        let mut attrs = vec![(ID_ATTR_NAME, id.as_str())];
+        if let Some(name) = node.name() {
+            attrs.push((NAME_ATTR_NAME, name.as_str()));
+        }
+
+        let _ = StartEndElementCookie::new_with_attrs(writer, node_tag_name(node.kind()), &attrs)?;
+    }
+
+    for flow in model.flows() {
+        let _ = StartEndElementCookie::new_with_attrs(
+            writer,
+            SEQUENCE_FLOW_TAG_NAME,
+            &vec![
+                (ID_ATTR_NAME, create_flow_id(flow.id()).as_str()),
+                (SOURCE_REF_ATTR_NAME, create_node_id(flow.source_id()).as_str()),
+                (TARGET_REF_ATTR_NAME, create_node_id(flow.target_id()).as_str()),
+            ],
+        )?;
+    }
+
+    drop(process_cookie);
+    Ok(())
+}
+
+fn node_tag_name(kind: BpmnNodeKind) -> &'static str {
+    match kind {
+        BpmnNodeKind::StartEvent => START_EVENT_TAG_NAME,
+        BpmnNodeKind::EndEvent => END_EVENT_TAG_NAME,
+        BpmnNodeKind::Task => TASK_TAG_NAME,
+        BpmnNodeKind::ExclusiveGateway => EXCLUSIVE_GATEWAY_TAG_NAME,
+        BpmnNodeKind::ParallelGateway => PARALLEL_GATEWAY_TAG_NAME,
+    }
+}
+
+fn node_size(kind: BpmnNodeKind) -> (i64, i64) {
+    match kind {
+        BpmnNodeKind::StartEvent | BpmnNodeKind::EndEvent => (36, 36),
+        BpmnNodeKind::Task => (100, 80),
+        BpmnNodeKind::ExclusiveGateway | BpmnNodeKind::ParallelGateway => (50, 50),
+    }
+}
+
+fn create_node_id(id: u64) -> String {
+    format!("node_{}", id)
+}
+
+fn create_flow_id(id: u64) -> String {
+    format!("flow_{}", id)
+}
+
+fn write_diagram(model: &BpmnModel, writer: &RefCell<Writer<Cursor<Vec<u8>>>>) -> Result<(), XmlWriteError> {
+    let diagram_cookie = StartEndElementCookie::new_with_attrs(writer, DIAGRAM_TAG_NAME, &vec![(ID_ATTR_NAME, DIAGRAM_ID)])?;
+    let plane_cookie = StartEndElementCookie::new_with_attrs(
+        writer,
+        PLANE_TAG_NAME,
+        &vec![(ID_ATTR_NAME, PLANE_ID), (BPMN_ELEMENT_ATTR_NAME, PROCESS_ID)],
+    )?;
+
+    let centers = calculate_layout(model);
+
+    for node in model.nodes() {
+        let id = create_node_id(node.id());
+        let shape_cookie = StartEndElementCookie::new_with_attrs(
+            writer,
+            SHAPE_TAG_NAME,
+            &vec![(ID_ATTR_NAME, format!("{}_di", id).as_str()), (BPMN_ELEMENT_ATTR_NAME, id.as_str())],
+        )?;
+
+        let (x, y) = *centers.get(&node.id()).unwrap();
+        let (width, height) = node_size(node.kind());
+
+        let _ = StartEndElementCookie::new_with_attrs(
+            writer,
+            BOUNDS_TAG_NAME,
+            &vec![
+                (X_ATTR_NAME, (x - width / 2).to_string().as_str()),
+                (Y_ATTR_NAME, (y - height / 2).to_string().as_str()),
+                (WIDTH_ATTR_NAME, width.to_string().as_str()),
+                (HEIGHT_ATTR_NAME, height.to_string().as_str()),
+            ],
+        )?;
+
+        drop(shape_cookie);
+    }
+
+    for flow in model.flows() {
+        let id = create_flow_id(flow.id());
+        let edge_cookie = StartEndElementCookie::new_with_attrs(
+            writer,
+            EDGE_TAG_NAME,
+            &vec![(ID_ATTR_NAME, format!("{}_di", id).as_str()), (BPMN_ELEMENT_ATTR_NAME, id.as_str())],
+        )?;
+
+        let source = model.node(flow.source_id()).unwrap();
+        let target = model.node(flow.target_id()).unwrap();
+
+        let (source_x, source_y) = *centers.get(&source.id()).unwrap();
+        let (target_x, target_y) = *centers.get(&target.id()).unwrap();
+
+        write_waypoint(writer, source_x + node_size(source.kind()).0 / 2, source_y)?;
+        write_waypoint(writer, target_x - node_size(target.kind()).0 / 2, target_y)?;
+
+        drop(edge_cookie);
+    }
+
+    drop(plane_cookie);
+    drop(diagram_cookie);
+
+    Ok(())
+}
+
+fn write_waypoint(writer: &RefCell<Writer<Cursor<Vec<u8>>>>, x: i64, y: i64) -> Result<(), XmlWriteError> {
+    let _ = StartEndElementCookie::new_with_attrs(
+        writer,
+        WAYPOINT_TAG_NAME,
+        &vec![(X_ATTR_NAME, x.to_string().as_str()), (Y_ATTR_NAME, y.to_string().as_str())],
+    )?;
+
+    Ok(())
+}
+
+//Nodes are put into layers by the breadth-first traversal from start events (nodes which are not reachable
+//from them are put into the last layer), returns centers of the nodes
+fn calculate_layout(model: &BpmnModel) -> HashMap<u64, (i64, i64)> {
+    let mut layers: HashMap<u64, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for node in model.nodes() {
+        if node.kind() == BpmnNodeKind::StartEvent {
+            layers.insert(node.id(), 0);
+            queue.push_back(node.id());
+        }
+    }
+
+    while let Some(node_id) = queue.pop_front() {
+        let layer = *layers.get(&node_id).unwrap();
+        for flow in model.outgoing_flows(node_id) {
+            if !layers.contains_key(&flow.target_id()) {
+                layers.insert(flow.target_id(), layer + 1);
+                queue.push_back(flow.target_id());
+            }
+        }
+    }
+
+    let unreachable_layer = layers.values().max().map_or(0, |max_layer| max_layer + 1);
+
+    let mut nodes_in_layers: HashMap<usize, Vec<&BpmnNode>> = HashMap::new();
+    for node in model.nodes() {
+        let layer = *layers.get(&node.id()).unwrap_or(&unreachable_layer);
+        nodes_in_layers.entry(layer).or_insert_with(Vec::new).push(node);
+    }
+
+    let mut centers = HashMap::new();
+    for (layer, nodes) in nodes_in_layers {
+        for (index, node) in nodes.iter().enumerate() {
+            let x = LAYOUT_OFFSET + layer as i64 * LAYER_DISTANCE;
+            let y = LAYOUT_OFFSET + index as i64 * NODES_DISTANCE;
+
+            centers.insert(node.id(), (x, y));
+        }
+    }
+
+    centers
+}
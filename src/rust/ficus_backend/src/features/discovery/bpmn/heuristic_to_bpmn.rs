@@ -0,0 +1,177 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::event_log::core::event_log::EventLog;
+use crate::features::analysis::event_log_info::{EventLogInfo, EventLogInfoCreationDto};
+use crate::features::discovery::alpha::providers::alpha_plus_provider::calculate_triangle_relations;
+use crate::features::discovery::alpha::providers::alpha_provider::DefaultAlphaRelationsProvider;
+use crate::features::discovery::heuristic::heuristic_miner::find_parallel_groups;
+use crate::features::discovery::heuristic::relations_provider::{AndOrXorRelation, HeuristicMinerRelationsProvider};
+
+use super::bpmn_model::{BpmnModel, BpmnNodeKind};
+
+//Each event class becomes a task, dependency relations of the heuristic miner become sequence flows. Outgoing
+//(incoming) flows of a task are routed through an exclusive gateway, followers (predecessors) which are in
+//AND relation are additionally grouped with parallel gateways
+pub fn discover_bpmn_heuristic(
+    log: &impl EventLog,
+    dependency_threshold: f64,
+    positive_observations_threshold: usize,
+    relative_to_best_threshold: f64,
+    and_threshold: f64,
+    loop_length_two_threshold: f64,
+) -> BpmnModel {
+    let info = EventLogInfo::create_from(EventLogInfoCreationDto::default(log));
+    let provider = HeuristicMinerRelationsProvider::new(
+        calculate_triangle_relations(log),
+        DefaultAlphaRelationsProvider::new(&info),
+        dependency_threshold,
+        positive_observations_threshold,
+        relative_to_best_threshold,
+        and_threshold,
+        loop_length_two_threshold,
+    );
+
+    let mut model = BpmnModel::empty();
+    let mut classes = info.all_event_classes();
+    classes.sort();
+
+    let start_event = model.add_node(BpmnNodeKind::StartEvent, None);
+
+    let mut classes_to_ids = HashMap::new();
+    let mut ids_to_classes = HashMap::new();
+    for class in &classes {
+        let id = model.add_node(BpmnNodeKind::Task, Some(class.to_string()));
+        classes_to_ids.insert(*class, id);
+        ids_to_classes.insert(id, *class);
+    }
+
+    let end_event = model.add_node(BpmnNodeKind::EndEvent, None);
+
+    let mut dependencies = vec![];
+    for first_class in &classes {
+        let first_id = *classes_to_ids.get(first_class).unwrap();
+        if info.start_event_classes().contains(*first_class) {
+            dependencies.push((start_event, first_id));
+        }
+
+        for second_class in &classes {
+            if provider.dependency_relation(first_class, second_class) {
+                dependencies.push((first_id, *classes_to_ids.get(second_class).unwrap()));
+            }
+        }
+
+        if info.end_event_classes().contains(*first_class) {
+            dependencies.push((first_id, end_event));
+        }
+    }
+
+    let mut nodes = vec![start_event];
+    nodes.extend(classes.iter().map(|class| *classes_to_ids.get(class).unwrap()));
+    nodes.push(end_event);
+
+    let mut split_nodes = HashMap::new();
+    let mut join_nodes = HashMap::new();
+    for node in nodes {
+        let relation = |first: &u64, second: &u64, is_join: bool| {
+            let classes = (ids_to_classes.get(&node), ids_to_classes.get(first), ids_to_classes.get(second));
+            match classes {
+                (Some(a), Some(b), Some(c)) => match is_join {
+                    true => provider.and_or_xor_join_relation(a, b, c) == AndOrXorRelation::And,
+                    false => provider.and_or_xor_relation(a, b, c) == AndOrXorRelation::And,
+                },
+                _ => false,
+            }
+        };
+
+        let followers: Vec<u64> = dependencies
+            .iter()
+            .filter(|(source, _)| *source == node)
+            .map(|(_, target)| *target)
+            .collect();
+        let splits = create_gateways(&mut model, node, &followers, |first, second| relation(first, second, false), false);
+        for (follower, split_node) in splits {
+            split_nodes.insert((node, follower), split_node);
+        }
+
+        let predecessors: Vec<u64> = dependencies
+            .iter()
+            .filter(|(_, target)| *target == node)
+            .map(|(source, _)| *source)
+            .collect();
+        let joins = create_gateways(&mut model, node, &predecessors, |first, second| relation(first, second, true), true);
+        for (predecessor, join_node) in joins {
+            join_nodes.insert((node, predecessor), join_node);
+        }
+    }
+
+    for (source, target) in dependencies {
+        model.connect(
+            *split_nodes.get(&(source, target)).unwrap(),
+            *join_nodes.get(&(target, source)).unwrap(),
+        );
+    }
+
+    model
+}
+
+//Returns for each neighbour the node (the node itself or one of the created gateways) which is connected to it
+fn create_gateways(
+    model: &mut BpmnModel,
+    node: u64,
+    neighbours: &[u64],
+    is_and: impl Fn(&u64, &u64) -> bool,
+    is_join: bool,
+) -> Vec<(u64, u64)> {
+    if neighbours.len() < 2 {
+        return neighbours.iter().map(|neighbour| (*neighbour, node)).collect();
+    }
+
+    let mut groups: Vec<Vec<u64>> = find_parallel_groups(neighbours, is_and)
+        .iter()
+        .map(|group| group.set().iter().copied().collect())
+        .collect();
+
+    groups.sort();
+
+    let mut grouped = HashSet::new();
+    let mut disjoint_groups = vec![];
+    for group in groups {
+        let group: Vec<u64> = group.into_iter().filter(|neighbour| !grouped.contains(neighbour)).collect();
+        if group.len() > 1 {
+            grouped.extend(group.iter().copied());
+            disjoint_groups.push(group);
+        }
+    }
+
+    if disjoint_groups.len() == 1 && grouped.len() == neighbours.len() {
+        let gateway = model.add_node(BpmnNodeKind::ParallelGateway, None);
+        connect_directed(model, node, gateway, is_join);
+
+        return neighbours.iter().map(|neighbour| (*neighbour, gateway)).collect();
+    }
+
+    let exclusive_gateway = model.add_node(BpmnNodeKind::ExclusiveGateway, None);
+    connect_directed(model, node, exclusive_gateway, is_join);
+
+    let mut result = vec![];
+    for group in &disjoint_groups {
+        let gateway = model.add_node(BpmnNodeKind::ParallelGateway, None);
+        connect_directed(model, exclusive_gateway, gateway, is_join);
+        result.extend(group.iter().map(|neighbour| (*neighbour, gateway)));
+    }
+
+    for neighbour in neighbours {
+        if !grouped.contains(neighbour) {
+            result.push((*neighbour, exclusive_gateway));
+        }
+    }
+
+    result
+}
+
+fn connect_directed(model: &mut BpmnModel, node: u64, gateway: u64, is_join: bool) {
+    match is_join {
+        true => model.connect(gateway, node),
+        false => model.connect(node, gateway),
+    }
+}
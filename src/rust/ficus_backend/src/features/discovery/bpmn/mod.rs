@@ -0,0 +1,5 @@
+pub mod bpmn_model;
+pub mod bpmn_serialization;
+pub mod heuristic_to_bpmn;
+pub mod petri_net_to_bpmn;
+pub mod process_tree_to_bpmn;
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::features::discovery::petri_net::petri_net::DefaultPetriNet;
+
+use super::bpmn_model::{BpmnModel, BpmnNodeKind};
+
+//Entry and exit nodes of the BPMN fragment which represents a place or a transition
+struct Fragment {
+    entry: u64,
+    exit: u64,
+}
+
+//Places become exclusive gateways (choices and merges), transitions become tasks (silent transitions become
+//parallel gateways) surrounded by parallel gateways when they synchronize or fork several places
+pub fn convert_petri_net_to_bpmn(net: &DefaultPetriNet) -> BpmnModel {
+    let mut model = BpmnModel::empty();
+
+    let mut places_fragments = HashMap::new();
+    for place in net.all_places() {
+        let incoming_count = net.get_incoming_transitions(&place.id()).len();
+        let outgoing_count = net.get_outgoing_transitions(&place.id()).len();
+
+        let fragment = create_fragment(&mut model, BpmnNodeKind::ExclusiveGateway, incoming_count, outgoing_count);
+        places_fragments.insert(place.id(), fragment);
+    }
+
+    for transition in net.all_transitions() {
+        let incoming_count = transition.incoming_arcs().len();
+        let outgoing_count = transition.outgoing_arcs().len();
+
+        let fragment = match *transition.is_silent() {
+            true => create_fragment(&mut model, BpmnNodeKind::ParallelGateway, incoming_count, outgoing_count),
+            false => {
+                let name = match transition.data() {
+                    Some(data) => data.to_owned(),
+                    None => transition.name().to_owned(),
+                };
+
+                let task = model.add_node(BpmnNodeKind::Task, Some(name));
+                let entry = surround_with_gateway(&mut model, task, incoming_count, true);
+                let exit = surround_with_gateway(&mut model, task, outgoing_count, false);
+
+                Fragment { entry, exit }
+            }
+        };
+
+        for arc in transition.incoming_arcs() {
+            model.connect(places_fragments.get(&arc.place_id()).unwrap().exit, fragment.entry);
+        }
+
+        for arc in transition.outgoing_arcs() {
+            model.connect(fragment.exit, places_fragments.get(&arc.place_id()).unwrap().entry);
+        }
+    }
+
+    let start_places: Vec<u64> = match net.initial_marking() {
+        Some(marking) => marking.active_places().iter().map(|marking| marking.place_id()).collect(),
+        None => find_places(net, |place_id| net.get_incoming_transitions(place_id).is_empty()),
+    };
+
+    let end_places: Vec<u64> = match net.final_marking() {
+        Some(marking) => marking.active_places().iter().map(|marking| marking.place_id()).collect(),
+        None => find_places(net, |place_id| net.get_outgoing_transitions(place_id).is_empty()),
+    };
+
+    let start_event = model.add_node(BpmnNodeKind::StartEvent, None);
+    let start_gateway = surround_with_gateway(&mut model, start_event, start_places.len(), false);
+    for place_id in start_places {
+        model.connect(start_gateway, places_fragments.get(&place_id).unwrap().entry);
+    }
+
+    let end_event = model.add_node(BpmnNodeKind::EndEvent, None);
+    let end_gateway = surround_with_gateway(&mut model, end_event, end_places.len(), true);
+    for place_id in end_places {
+        model.connect(places_fragments.get(&place_id).unwrap().exit, end_gateway);
+    }
+
+    model.remove_redundant_gateways();
+    model
+}
+
+fn find_places(net: &DefaultPetriNet, predicate: impl Fn(&u64) -> bool) -> Vec<u64> {
+    let mut places: Vec<u64> = net.all_places().iter().map(|place| place.id()).filter(|id| predicate(id)).collect();
+    places.sort();
+
+    places
+}
+
+fn create_fragment(model: &mut BpmnModel, kind: BpmnNodeKind, incoming_count: usize, outgoing_count: usize) -> Fragment {
+    let entry = model.add_node(kind, None);
+    if incoming_count > 1 && outgoing_count > 1 {
+        let exit = model.add_node(kind, None);
+        model.connect(entry, exit);
+
+        Fragment { entry, exit }
+    } else {
+        Fragment { entry, exit: entry }
+    }
+}
+
+//Adds a parallel join before (or a parallel split after) the node if it has several incoming (outgoing) flows
+fn surround_with_gateway(model: &mut BpmnModel, node: u64, flows_count: usize, is_join: bool) -> u64 {
+    if flows_count <= 1 {
+        return node;
+    }
+
+    let gateway = model.add_node(BpmnNodeKind::ParallelGateway, None);
+    match is_join {
+        true => model.connect(gateway, node),
+        false => model.connect(node, gateway),
+    }
+
+    gateway
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::features::discovery::local_process_models::process_tree::ProcessTreeNode;
+
+use super::bpmn_model::{BpmnModel, BpmnNodeKind};
+
+//Activities of the tree are hashes, their names are taken from the names map (the hash itself is used
+//when there is no name for it)
+pub fn convert_process_tree_to_bpmn(tree: &ProcessTreeNode, names: &HashMap<u64, String>) -> BpmnModel {
+    let mut model = BpmnModel::empty();
+
+    let start_event = model.add_node(BpmnNodeKind::StartEvent, None);
+    let (entry, exit) = convert_node(&mut model, tree, names);
+    let end_event = model.add_node(BpmnNodeKind::EndEvent, None);
+
+    model.connect(start_event, entry);
+    model.connect(exit, end_event);
+
+    model
+}
+
+//Returns entry and exit nodes of the BPMN fragment created for the tree node
+fn convert_node(model: &mut BpmnModel, node: &ProcessTreeNode, names: &HashMap<u64, String>) -> (u64, u64) {
+    match node {
+        ProcessTreeNode::Activity(activity) => {
+            let name = match names.get(activity) {
+                Some(name) => name.to_owned(),
+                None => activity.to_string(),
+            };
+
+            let task = model.add_node(BpmnNodeKind::Task, Some(name));
+            (task, task)
+        }
+        ProcessTreeNode::Sequence(children) => {
+            let fragments: Vec<(u64, u64)> = children.iter().map(|child| convert_node(model, child, names)).collect();
+            for (first, second) in fragments.iter().zip(fragments.iter().skip(1)) {
+                model.connect(first.1, second.0);
+            }
+
+            (fragments.first().unwrap().0, fragments.last().unwrap().1)
+        }
+        ProcessTreeNode::Choice(children) => convert_block(model, children, BpmnNodeKind::ExclusiveGateway, names),
+        ProcessTreeNode::Parallel(children) => convert_block(model, children, BpmnNodeKind::ParallelGateway, names),
+        ProcessTreeNode::Loop(do_node, redo_node) => {
+            let join = model.add_node(BpmnNodeKind::ExclusiveGateway, None);
+            let split = model.add_node(BpmnNodeKind::ExclusiveGateway, None);
+
+            let (do_entry, do_exit) = convert_node(model, do_node, names);
+            let (redo_entry, redo_exit) = convert_node(model, redo_node, names);
+
+            model.connect(join, do_entry);
+            model.connect(do_exit, split);
+            model.connect(split, redo_entry);
+            model.connect(redo_exit, join);
+
+            (join, split)
+        }
+    }
+}
+
+fn convert_block(model: &mut BpmnModel, children: &Vec<ProcessTreeNode>, kind: BpmnNodeKind, names: &HashMap<u64, String>) -> (u64, u64) {
+    let split = model.add_node(kind, None);
+    let join = model.add_node(kind, None);
+
+    for child in children {
+        let (entry, exit) = convert_node(model, child, names);
+        model.connect(split, entry);
+        model.connect(exit, join);
+    }
+
+    (split, join)
+}
@@ -0,0 +1,68 @@
+use crate::event_log::core::event_log::EventLog;
+
+use super::declare_model::{check_constraint, DeclareConstraintCheckResult, DeclareModel};
+
+#[derive(Clone, Debug)]
+pub struct DeclareTraceConformance {
+    constraints_results: Vec<DeclareConstraintCheckResult>,
+}
+
+impl DeclareTraceConformance {
+    //Results are in the same order as constraints of the checked model
+    pub fn constraints_results(&self) -> &Vec<DeclareConstraintCheckResult> {
+        &self.constraints_results
+    }
+
+    pub fn violated_constraints_count(&self) -> usize {
+        self.constraints_results.iter().filter(|result| !result.is_satisfied()).count()
+    }
+
+    pub fn fitness(&self) -> f64 {
+        match self.constraints_results.len() {
+            0 => 1.0,
+            count => 1.0 - self.violated_constraints_count() as f64 / count as f64,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeclareConformanceResult {
+    model: DeclareModel,
+    traces: Vec<DeclareTraceConformance>,
+}
+
+impl DeclareConformanceResult {
+    pub fn model(&self) -> &DeclareModel {
+        &self.model
+    }
+
+    pub fn traces(&self) -> &Vec<DeclareTraceConformance> {
+        &self.traces
+    }
+
+    pub fn fitness(&self) -> f64 {
+        match self.traces.len() {
+            0 => 1.0,
+            count => self.traces.iter().map(|trace| trace.fitness()).sum::<f64>() / count as f64,
+        }
+    }
+}
+
+pub fn check_declare_conformance<TLog: EventLog>(log: &TLog, model: &DeclareModel) -> DeclareConformanceResult {
+    let traces = log
+        .to_raw_vector()
+        .iter()
+        .map(|trace| DeclareTraceConformance {
+            constraints_results: model
+                .constraints()
+                .iter()
+                .map(|constraint| check_constraint(constraint, trace))
+                .collect(),
+        })
+        .collect();
+
+    DeclareConformanceResult {
+        model: model.clone(),
+        traces,
+    }
+}
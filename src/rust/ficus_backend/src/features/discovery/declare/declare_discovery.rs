@@ -0,0 +1,91 @@
+use std::collections::BTreeSet;
+
+use crate::event_log::core::event_log::EventLog;
+
+use super::declare_model::{check_constraint, DeclareConstraint, DeclareConstraintMetrics, DeclareModel, DeclareTemplate};
+
+pub struct DeclareDiscoveryParams {
+    pub min_support: f64,
+    pub min_confidence: f64,
+    pub templates: Vec<DeclareTemplate>,
+}
+
+impl DeclareDiscoveryParams {
+    pub fn with_all_templates(min_support: f64, min_confidence: f64) -> Self {
+        Self {
+            min_support,
+            min_confidence,
+            templates: DeclareTemplate::all(),
+        }
+    }
+}
+
+//Support is the fraction of traces which satisfy the constraint, confidence is the fraction of traces
+//which satisfy the constraint among the traces where the constraint was activated
+pub fn discover_declare_model<TLog: EventLog>(log: &TLog, params: &DeclareDiscoveryParams) -> DeclareModel {
+    let traces = log.to_raw_vector();
+    let activities: BTreeSet<&String> = traces.iter().flat_map(|trace| trace.iter()).collect();
+
+    let mut constraints = vec![];
+    for template in &params.templates {
+        for first in &activities {
+            if template.arity() == 1 {
+                try_add_constraint(&traces, *template, vec![(*first).to_owned()], params, &mut constraints);
+                continue;
+            }
+
+            for second in activities.iter().filter(|second| **second != *first) {
+                let parameters = vec![(*first).to_owned(), (*second).to_owned()];
+                try_add_constraint(&traces, *template, parameters, params, &mut constraints);
+            }
+        }
+    }
+
+    DeclareModel::new(constraints)
+}
+
+fn try_add_constraint(
+    traces: &Vec<Vec<String>>,
+    template: DeclareTemplate,
+    parameters: Vec<String>,
+    params: &DeclareDiscoveryParams,
+    constraints: &mut Vec<DeclareConstraint>,
+) {
+    let constraint = DeclareConstraint::new(template, parameters, None).unwrap();
+    let metrics = calculate_metrics(&constraint, traces);
+
+    if metrics.support >= params.min_support && metrics.confidence >= params.min_confidence {
+        let constraint = DeclareConstraint::new(template, constraint.parameters().clone(), Some(metrics));
+        constraints.push(constraint.unwrap());
+    }
+}
+
+pub fn calculate_metrics(constraint: &DeclareConstraint, traces: &Vec<Vec<String>>) -> DeclareConstraintMetrics {
+    let mut satisfied_count = 0;
+    let mut activated_count = 0;
+    let mut activated_satisfied_count = 0;
+
+    for trace in traces {
+        let result = check_constraint(constraint, trace);
+        if result.is_satisfied() {
+            satisfied_count += 1;
+        }
+
+        if result.is_activated() {
+            activated_count += 1;
+            if result.is_satisfied() {
+                activated_satisfied_count += 1;
+            }
+        }
+    }
+
+    let ratio = |count: usize, total: usize| match total {
+        0 => 0.0,
+        _ => count as f64 / total as f64,
+    };
+
+    DeclareConstraintMetrics {
+        support: ratio(satisfied_count, traces.len()),
+        confidence: ratio(activated_satisfied_count, activated_count),
+    }
+}
@@ -0,0 +1,284 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DeclareTemplate {
+    Existence,
+    Absence,
+    AtMostOne,
+    ExactlyOne,
+    Init,
+    End,
+
+    RespondedExistence,
+    CoExistence,
+    Response,
+    Precedence,
+    Succession,
+    AlternateResponse,
+    AlternatePrecedence,
+    AlternateSuccession,
+    ChainResponse,
+    ChainPrecedence,
+    ChainSuccession,
+
+    NotCoExistence,
+    NotSuccession,
+    NotChainSuccession,
+}
+
+const ALL_TEMPLATES: [DeclareTemplate; 20] = [
+    DeclareTemplate::Existence,
+    DeclareTemplate::Absence,
+    DeclareTemplate::AtMostOne,
+    DeclareTemplate::ExactlyOne,
+    DeclareTemplate::Init,
+    DeclareTemplate::End,
+    DeclareTemplate::RespondedExistence,
+    DeclareTemplate::CoExistence,
+    DeclareTemplate::Response,
+    DeclareTemplate::Precedence,
+    DeclareTemplate::Succession,
+    DeclareTemplate::AlternateResponse,
+    DeclareTemplate::AlternatePrecedence,
+    DeclareTemplate::AlternateSuccession,
+    DeclareTemplate::ChainResponse,
+    DeclareTemplate::ChainPrecedence,
+    DeclareTemplate::ChainSuccession,
+    DeclareTemplate::NotCoExistence,
+    DeclareTemplate::NotSuccession,
+    DeclareTemplate::NotChainSuccession,
+];
+
+impl DeclareTemplate {
+    pub fn all() -> Vec<DeclareTemplate> {
+        ALL_TEMPLATES.to_vec()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeclareTemplate::Existence => "Existence",
+            DeclareTemplate::Absence => "Absence",
+            DeclareTemplate::AtMostOne => "AtMostOne",
+            DeclareTemplate::ExactlyOne => "ExactlyOne",
+            DeclareTemplate::Init => "Init",
+            DeclareTemplate::End => "End",
+            DeclareTemplate::RespondedExistence => "RespondedExistence",
+            DeclareTemplate::CoExistence => "CoExistence",
+            DeclareTemplate::Response => "Response",
+            DeclareTemplate::Precedence => "Precedence",
+            DeclareTemplate::Succession => "Succession",
+            DeclareTemplate::AlternateResponse => "AlternateResponse",
+            DeclareTemplate::AlternatePrecedence => "AlternatePrecedence",
+            DeclareTemplate::AlternateSuccession => "AlternateSuccession",
+            DeclareTemplate::ChainResponse => "ChainResponse",
+            DeclareTemplate::ChainPrecedence => "ChainPrecedence",
+            DeclareTemplate::ChainSuccession => "ChainSuccession",
+            DeclareTemplate::NotCoExistence => "NotCoExistence",
+            DeclareTemplate::NotSuccession => "NotSuccession",
+            DeclareTemplate::NotChainSuccession => "NotChainSuccession",
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        match self {
+            DeclareTemplate::Existence
+            | DeclareTemplate::Absence
+            | DeclareTemplate::AtMostOne
+            | DeclareTemplate::ExactlyOne
+            | DeclareTemplate::Init
+            | DeclareTemplate::End => 1,
+            _ => 2,
+        }
+    }
+}
+
+impl FromStr for DeclareTemplate {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match ALL_TEMPLATES.iter().find(|template| template.name() == s) {
+            Some(template) => Ok(*template),
+            None => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeclareConstraintMetrics {
+    pub support: f64,
+    pub confidence: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeclareConstraint {
+    template: DeclareTemplate,
+    parameters: Vec<String>,
+    metrics: Option<DeclareConstraintMetrics>,
+}
+
+impl DeclareConstraint {
+    pub fn new(template: DeclareTemplate, parameters: Vec<String>, metrics: Option<DeclareConstraintMetrics>) -> Option<Self> {
+        match parameters.len() == template.arity() {
+            true => Some(Self {
+                template,
+                parameters,
+                metrics,
+            }),
+            false => None,
+        }
+    }
+
+    pub fn template(&self) -> DeclareTemplate {
+        self.template
+    }
+
+    pub fn parameters(&self) -> &Vec<String> {
+        &self.parameters
+    }
+
+    pub fn metrics(&self) -> Option<&DeclareConstraintMetrics> {
+        self.metrics.as_ref()
+    }
+}
+
+impl Display for DeclareConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.template.name(), self.parameters.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeclareModel {
+    constraints: Vec<DeclareConstraint>,
+}
+
+impl DeclareModel {
+    pub fn new(constraints: Vec<DeclareConstraint>) -> Self {
+        Self { constraints }
+    }
+
+    pub fn constraints(&self) -> &Vec<DeclareConstraint> {
+        &self.constraints
+    }
+
+    pub fn find(&self, template: DeclareTemplate, parameters: &[&str]) -> Option<&DeclareConstraint> {
+        self.constraints
+            .iter()
+            .find(|constraint| constraint.template == template && constraint.parameters.iter().eq(parameters.iter()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DeclareConstraintCheckResult {
+    pub activations: usize,
+    pub fulfilments: usize,
+    pub violations: usize,
+}
+
+impl DeclareConstraintCheckResult {
+    pub fn is_satisfied(&self) -> bool {
+        self.violations == 0
+    }
+
+    pub fn is_activated(&self) -> bool {
+        self.activations > 0
+    }
+}
+
+//Unary templates are checked on the level of the whole trace (which is a single activation), for binary
+//templates each event of an activating class is an activation which is either fulfilled or violated
+pub fn check_constraint(constraint: &DeclareConstraint, trace: &[String]) -> DeclareConstraintCheckResult {
+    let first = constraint.parameters[0].as_str();
+    if constraint.template.arity() == 1 {
+        let count = trace.iter().filter(|event| *event == first).count();
+        let satisfied = match constraint.template {
+            DeclareTemplate::Existence => count > 0,
+            DeclareTemplate::Absence => count == 0,
+            DeclareTemplate::AtMostOne => count <= 1,
+            DeclareTemplate::ExactlyOne => count == 1,
+            DeclareTemplate::Init => trace.first().map(|event| event.as_str()) == Some(first),
+            DeclareTemplate::End => trace.last().map(|event| event.as_str()) == Some(first),
+            _ => unreachable!(),
+        };
+
+        return DeclareConstraintCheckResult {
+            activations: 1,
+            fulfilments: satisfied as usize,
+            violations: !satisfied as usize,
+        };
+    }
+
+    let second = constraint.parameters[1].as_str();
+    let mut result = DeclareConstraintCheckResult::default();
+
+    let mut check = |activation: &str, fulfilment_checker: &dyn Fn(usize) -> bool| {
+        for (index, event) in trace.iter().enumerate() {
+            if event != activation {
+                continue;
+            }
+
+            result.activations += 1;
+            match fulfilment_checker(index) {
+                true => result.fulfilments += 1,
+                false => result.violations += 1,
+            }
+        }
+    };
+
+    let contains = |name: &str| trace.iter().any(|event| event == name);
+    let occurs_after = |name: &str, index: usize| trace[(index + 1)..].iter().any(|event| event == name);
+    let occurs_before = |name: &str, index: usize| trace[..index].iter().any(|event| event == name);
+    let next_is = |name: &str, index: usize| trace.get(index + 1).map(|event| event.as_str()) == Some(name);
+    let previous_is = |name: &str, index: usize| index > 0 && trace[index - 1] == name;
+
+    let alternate_response = |index: usize| match trace[(index + 1)..].iter().position(|event| event == second) {
+        Some(position) => !trace[(index + 1)..(index + 1 + position)].iter().any(|event| event == first),
+        None => false,
+    };
+
+    let alternate_precedence = |index: usize| match trace[..index].iter().rposition(|event| event == first) {
+        Some(position) => !trace[(position + 1)..index].iter().any(|event| event == second),
+        None => false,
+    };
+
+    match constraint.template {
+        DeclareTemplate::RespondedExistence => check(first, &|_| contains(second)),
+        DeclareTemplate::CoExistence => {
+            check(first, &|_| contains(second));
+            check(second, &|_| contains(first));
+        }
+        DeclareTemplate::Response => check(first, &|index| occurs_after(second, index)),
+        DeclareTemplate::Precedence => check(second, &|index| occurs_before(first, index)),
+        DeclareTemplate::Succession => {
+            check(first, &|index| occurs_after(second, index));
+            check(second, &|index| occurs_before(first, index));
+        }
+        DeclareTemplate::AlternateResponse => check(first, &alternate_response),
+        DeclareTemplate::AlternatePrecedence => check(second, &alternate_precedence),
+        DeclareTemplate::AlternateSuccession => {
+            check(first, &alternate_response);
+            check(second, &alternate_precedence);
+        }
+        DeclareTemplate::ChainResponse => check(first, &|index| next_is(second, index)),
+        DeclareTemplate::ChainPrecedence => check(second, &|index| previous_is(first, index)),
+        DeclareTemplate::ChainSuccession => {
+            check(first, &|index| next_is(second, index));
+            check(second, &|index| previous_is(first, index));
+        }
+        DeclareTemplate::NotCoExistence => {
+            check(first, &|_| !contains(second));
+            check(second, &|_| !contains(first));
+        }
+        DeclareTemplate::NotSuccession => {
+            check(first, &|index| !occurs_after(second, index));
+            check(second, &|index| !occurs_before(first, index));
+        }
+        DeclareTemplate::NotChainSuccession => {
+            check(first, &|index| !next_is(second, index));
+            check(second, &|index| !previous_is(first, index));
+        }
+        _ => unreachable!(),
+    }
+
+    result
+}
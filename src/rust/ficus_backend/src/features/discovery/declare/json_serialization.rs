@@ -0,0 +1,141 @@
+use std::{collections::BTreeMap, fmt::Display, fs, str::FromStr};
+
+use rustc_serialize::json::{self, Json};
+
+use super::declare_model::{DeclareConstraint, DeclareConstraintMetrics, DeclareModel, DeclareTemplate};
+
+const CONSTRAINTS: &'static str = "constraints";
+const TEMPLATE: &'static str = "template";
+const PARAMETERS: &'static str = "parameters";
+const SUPPORT: &'static str = "support";
+const CONFIDENCE: &'static str = "confidence";
+
+#[derive(Debug)]
+pub enum DeclareJsonError {
+    IOError(std::io::Error),
+    ParseError(json::ParserError),
+    InvalidModel(String),
+}
+
+impl Display for DeclareJsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IOError(err) => Display::fmt(&err, f),
+            Self::ParseError(err) => Display::fmt(&err, f),
+            Self::InvalidModel(message) => write!(f, "Invalid Declare model: {}", message),
+        }
+    }
+}
+
+pub fn serialize_declare_model_to_json_file(model: &DeclareModel, save_path: &str) -> Result<(), DeclareJsonError> {
+    match fs::write(save_path, serialize_declare_model_to_json(model)) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(DeclareJsonError::IOError(error)),
+    }
+}
+
+pub fn serialize_declare_model_to_json(model: &DeclareModel) -> String {
+    let constraints = model.constraints().iter().map(serialize_constraint).collect();
+
+    let mut root = BTreeMap::new();
+    root.insert(CONSTRAINTS.to_owned(), Json::Array(constraints));
+
+    json::as_pretty_json(&Json::Object(root)).to_string()
+}
+
+fn serialize_constraint(constraint: &DeclareConstraint) -> Json {
+    let mut object = BTreeMap::new();
+    object.insert(TEMPLATE.to_owned(), Json::String(constraint.template().name().to_owned()));
+
+    let parameters = constraint
+        .parameters()
+        .iter()
+        .map(|parameter| Json::String(parameter.to_owned()))
+        .collect();
+    object.insert(PARAMETERS.to_owned(), Json::Array(parameters));
+
+    if let Some(metrics) = constraint.metrics() {
+        object.insert(SUPPORT.to_owned(), Json::F64(metrics.support));
+        object.insert(CONFIDENCE.to_owned(), Json::F64(metrics.confidence));
+    }
+
+    Json::Object(object)
+}
+
+pub fn read_declare_model_from_json_file(path: &str) -> Result<DeclareModel, DeclareJsonError> {
+    match fs::read_to_string(path) {
+        Ok(content) => read_declare_model_from_json(content.as_str()),
+        Err(error) => Err(DeclareJsonError::IOError(error)),
+    }
+}
+
+pub fn read_declare_model_from_json(content: &str) -> Result<DeclareModel, DeclareJsonError> {
+    let root = match Json::from_str(content) {
+        Ok(root) => root,
+        Err(error) => return Err(DeclareJsonError::ParseError(error)),
+    };
+
+    let constraints = match root.find(CONSTRAINTS).and_then(|constraints| constraints.as_array()) {
+        Some(constraints) => constraints,
+        None => return Err(invalid_model(format!("missing \"{}\" array", CONSTRAINTS))),
+    };
+
+    let mut result = vec![];
+    for constraint in constraints {
+        result.push(read_constraint(constraint)?);
+    }
+
+    Ok(DeclareModel::new(result))
+}
+
+fn read_constraint(constraint: &Json) -> Result<DeclareConstraint, DeclareJsonError> {
+    let template_name = match constraint.find(TEMPLATE).and_then(|template| template.as_string()) {
+        Some(template_name) => template_name,
+        None => return Err(invalid_model(format!("constraint without \"{}\"", TEMPLATE))),
+    };
+
+    let template = match DeclareTemplate::from_str(template_name) {
+        Ok(template) => template,
+        Err(_) => return Err(invalid_model(format!("unknown template {}", template_name))),
+    };
+
+    let parameters: Option<Vec<String>> = constraint
+        .find(PARAMETERS)
+        .and_then(|parameters| parameters.as_array())
+        .and_then(|parameters| {
+            parameters
+                .iter()
+                .map(|parameter| parameter.as_string().map(|parameter| parameter.to_owned()))
+                .collect()
+        });
+
+    let parameters = match parameters {
+        Some(parameters) => parameters,
+        None => {
+            return Err(invalid_model(format!(
+                "constraint {} without string \"{}\"",
+                template_name, PARAMETERS
+            )))
+        }
+    };
+
+    let support = constraint.find(SUPPORT).and_then(|support| support.as_f64());
+    let confidence = constraint.find(CONFIDENCE).and_then(|confidence| confidence.as_f64());
+    let metrics = match (support, confidence) {
+        (Some(support), Some(confidence)) => Some(DeclareConstraintMetrics { support, confidence }),
+        _ => None,
+    };
+
+    match DeclareConstraint::new(template, parameters, metrics) {
+        Some(constraint) => Ok(constraint),
+        None => Err(invalid_model(format!(
+            "template {} expects {} parameter(s)",
+            template_name,
+            template.arity()
+        ))),
+    }
+}
+
+fn invalid_model(message: String) -> DeclareJsonError {
+    DeclareJsonError::InvalidModel(message)
+}
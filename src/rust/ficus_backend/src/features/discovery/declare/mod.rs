@@ -0,0 +1,4 @@
+pub mod declare_conformance;
+pub mod declare_discovery;
+pub mod declare_model;
+pub mod json_serialization;
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+use crate::utils::graph::graph::Graph;
+use crate::utils::graph::graph_serialization::serialize_graph_to_dot_with_nodes_attributes;
+
+use super::fuzzy_miner::parse_cluster_data;
+
+//Cluster nodes created by the fuzzy miner are drawn as filled octagons labeled with the names of merged nodes
+pub fn serialize_fuzzy_graph_to_dot<TEdgeData>(graph: &Graph<String, TEdgeData>) -> String
+where
+    TEdgeData: ToString + Display,
+{
+    serialize_graph_to_dot_with_nodes_attributes(graph, |node| match node.data().and_then(|data| parse_cluster_data(data)) {
+        Some(cluster_nodes) => vec![
+            ("shape", "octagon".to_string()),
+            ("style", "filled".to_string()),
+            ("fillcolor", "lightgrey".to_string()),
+            ("label", cluster_nodes.join("\n")),
+        ],
+        None => vec![("shape", "box".to_string())],
+    })
+}
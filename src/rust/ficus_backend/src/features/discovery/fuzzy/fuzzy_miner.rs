@@ -10,6 +10,58 @@ use super::fuzzy_metrics_provider::FuzzyMetricsProvider;
 
 pub type FuzzyGraph = Graph<String, f64>;
 
+const CLUSTER_DATA_PREFIX: &'static str = "Cluster[";
+const CLUSTER_DATA_SUFFIX: &'static str = "]";
+const CLUSTER_DATA_SEPARATOR: char = ',';
+const CLUSTER_DATA_ESCAPE: char = '\\';
+
+//Merged nodes names are escaped, so that names containing separators are restored as is
+pub fn create_cluster_data<'a>(nodes_names: impl Iterator<Item = &'a str>) -> String {
+    let mut cluster_data = String::new();
+    cluster_data.push_str(CLUSTER_DATA_PREFIX);
+
+    for (index, name) in nodes_names.enumerate() {
+        if index > 0 {
+            cluster_data.push(CLUSTER_DATA_SEPARATOR);
+        }
+
+        for c in name.chars() {
+            if c == CLUSTER_DATA_SEPARATOR || c == CLUSTER_DATA_ESCAPE {
+                cluster_data.push(CLUSTER_DATA_ESCAPE);
+            }
+
+            cluster_data.push(c);
+        }
+    }
+
+    cluster_data.push_str(CLUSTER_DATA_SUFFIX);
+    cluster_data
+}
+
+pub fn parse_cluster_data(data: &str) -> Option<Vec<String>> {
+    if !data.starts_with(CLUSTER_DATA_PREFIX)
+        || !data.ends_with(CLUSTER_DATA_SUFFIX)
+        || data.len() < CLUSTER_DATA_PREFIX.len() + CLUSTER_DATA_SUFFIX.len()
+    {
+        return None;
+    }
+
+    let mut nodes_names = vec![];
+    let mut current_name = String::new();
+    let mut chars = data[CLUSTER_DATA_PREFIX.len()..(data.len() - CLUSTER_DATA_SUFFIX.len())].chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            CLUSTER_DATA_ESCAPE => current_name.push(chars.next()?),
+            CLUSTER_DATA_SEPARATOR => nodes_names.push(std::mem::take(&mut current_name)),
+            _ => current_name.push(c),
+        }
+    }
+
+    nodes_names.push(current_name);
+    Some(nodes_names)
+}
+
 pub fn discover_graph_fuzzy(
     log: &impl EventLog,
     unary_frequency_threshold: f64,
@@ -236,22 +288,9 @@ fn merge_nodes(graph: &mut FuzzyGraph, clusters: &ClustersMap) {
         graph.merge_nodes_into_one(
             &cluster.set().iter().map(|id| *id).collect(),
             |nodes_data| {
-                let mut cluster_data = String::new();
-                cluster_data.push_str("Cluster[");
-                for data in &nodes_data {
-                    if let Some(data) = data {
-                        cluster_data.push_str(data);
-                        cluster_data.push(',');
-                    }
-                }
-
-                if cluster_data.ends_with(',') {
-                    cluster_data.remove(cluster_data.len() - 1);
-                }
-
-                cluster_data.push_str("]");
-
-                Some(cluster_data)
+                Some(create_cluster_data(
+                    nodes_data.iter().filter_map(|data| data.map(|data| data.as_str())),
+                ))
             },
             |edges_data| {
                 edges_data
@@ -1,2 +1,3 @@
+pub mod fuzzy_graph_serialization;
 pub mod fuzzy_metrics_provider;
 pub mod fuzzy_miner;
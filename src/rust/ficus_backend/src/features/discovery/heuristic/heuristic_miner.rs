@@ -1,5 +1,6 @@
 use crate::event_log::core::event_log::EventLog;
 use crate::features::analysis::event_log_info::{EventLogInfo, EventLogInfoCreationDto};
+use crate::features::discovery::alpha::providers::alpha_plus_provider::calculate_triangle_relations;
 use crate::features::discovery::alpha::providers::alpha_provider::DefaultAlphaRelationsProvider;
 use crate::features::discovery::alpha::utils::maximize;
 use crate::features::discovery::heuristic::relations_provider::{AndOrXorRelation, HeuristicMinerRelationsProvider};
@@ -8,6 +9,7 @@ use crate::features::discovery::petri_net::place::Place;
 use crate::features::discovery::petri_net::transition::Transition;
 use crate::utils::sets::one_set::OneSet;
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 
 pub fn discover_petri_net_heuristic(
     log: &impl EventLog,
@@ -18,9 +20,30 @@ pub fn discover_petri_net_heuristic(
     loop_length_two_threshold: f64,
 ) -> DefaultPetriNet {
     let info = EventLogInfo::create_from(EventLogInfoCreationDto::default(log));
-    let provider = DefaultAlphaRelationsProvider::new(&info);
+    discover_petri_net_heuristic_from_relations(
+        &info,
+        calculate_triangle_relations(log),
+        dependency_threshold,
+        positive_observations_threshold,
+        relative_to_best_threshold,
+        and_threshold,
+        loop_length_two_threshold,
+    )
+}
+
+//Discovers heuristic net from already calculated relations, triangle relation (a, b) is the count of "a b a" occurrences
+pub fn discover_petri_net_heuristic_from_relations(
+    info: &EventLogInfo,
+    triangle_relations: HashMap<(String, String), usize>,
+    dependency_threshold: f64,
+    positive_observations_threshold: usize,
+    relative_to_best_threshold: f64,
+    and_threshold: f64,
+    loop_length_two_threshold: f64,
+) -> DefaultPetriNet {
+    let provider = DefaultAlphaRelationsProvider::new(info);
     let provider = HeuristicMinerRelationsProvider::new(
-        log,
+        triangle_relations,
         provider,
         dependency_threshold,
         positive_observations_threshold,
@@ -31,8 +54,8 @@ pub fn discover_petri_net_heuristic(
 
     let mut petri_net = DefaultPetriNet::empty();
 
-    construct_heuristic_petri_net(&info, &provider, &mut petri_net);
-    add_length_two_loops(&info, &provider, &mut petri_net);
+    construct_heuristic_petri_net(info, &provider, &mut petri_net);
+    add_length_two_loops(info, &provider, &mut petri_net);
 
     petri_net
 }
@@ -56,29 +79,8 @@ fn construct_heuristic_petri_net(log: &EventLogInfo, provider: &HeuristicMinerRe
             continue;
         }
 
-        let mut and_relations = HashSet::new();
-        for i in 0..followers.len() {
-            for j in (i + 1)..followers.len() {
-                let first = *followers.get(i).unwrap();
-                let second = *followers.get(j).unwrap();
-
-                if first != second && provider.and_or_xor_relation(first_class, first, second) == AndOrXorRelation::And {
-                    and_relations.insert(OneSet::new_two_elements(first, second));
-                }
-            }
-        }
-
-        let parallel_groups = maximize(and_relations, |first, second| {
-            let candidate = first.merge(second);
-            for first_el in candidate.set() {
-                for second_el in candidate.set() {
-                    if first_el != second_el && provider.and_or_xor_relation(first_class, first_el, second_el) != AndOrXorRelation::And {
-                        return None;
-                    }
-                }
-            }
-
-            Some(candidate)
+        let parallel_groups = find_parallel_groups(&followers, |first, second| {
+            provider.and_or_xor_relation(first_class, first, second) == AndOrXorRelation::And
         });
 
         let mut used = HashSet::new();
@@ -107,6 +109,31 @@ fn construct_heuristic_petri_net(log: &EventLogInfo, provider: &HeuristicMinerRe
     }
 }
 
+//Groups of elements which are pairwise in AND relation, the groups are maximal, but may intersect
+pub(crate) fn find_parallel_groups<T: Hash + Eq + Ord + Clone>(elements: &[T], is_and: impl Fn(&T, &T) -> bool) -> HashSet<OneSet<T>> {
+    let mut and_relations = HashSet::new();
+    for (index, first) in elements.iter().enumerate() {
+        for second in elements.iter().skip(index + 1) {
+            if first != second && is_and(first, second) {
+                and_relations.insert(OneSet::new_two_elements(first.clone(), second.clone()));
+            }
+        }
+    }
+
+    maximize(and_relations, |first, second| {
+        let candidate = first.merge(second);
+        for first_el in candidate.set() {
+            for second_el in candidate.set() {
+                if first_el != second_el && !is_and(first_el, second_el) {
+                    return None;
+                }
+            }
+        }
+
+        Some(candidate)
+    })
+}
+
 fn add_length_two_loops(info: &EventLogInfo, provider: &HeuristicMinerRelationsProvider, petri_net: &mut DefaultPetriNet) {
     let mut places_to_transitions = vec![];
     let mut transitions_to_places = vec![];
@@ -1,6 +1,4 @@
-use crate::event_log::core::event_log::EventLog;
 use crate::features::analysis::event_log_info::EventLogInfo;
-use crate::features::discovery::alpha::providers::alpha_plus_provider::calculate_triangle_relations;
 use crate::features::discovery::alpha::providers::alpha_provider::{AlphaRelationsProvider, DefaultAlphaRelationsProvider};
 use std::collections::HashMap;
 
@@ -25,7 +23,7 @@ pub enum AndOrXorRelation {
 
 impl<'a> HeuristicMinerRelationsProvider<'a> {
     pub fn new(
-        log: &impl EventLog,
+        triangle_relations: HashMap<(String, String), usize>,
         provider: DefaultAlphaRelationsProvider<'a>,
         dependency_threshold: f64,
         positive_observations_threshold: usize,
@@ -34,7 +32,7 @@ impl<'a> HeuristicMinerRelationsProvider<'a> {
         loop_length_two_threshold: f64,
     ) -> Self {
         let mut provider = Self {
-            triangle_relations: calculate_triangle_relations(log),
+            triangle_relations,
             dependency_threshold,
             positive_observations_threshold,
             relative_to_best_threshold,
@@ -116,6 +114,21 @@ impl<'a> HeuristicMinerRelationsProvider<'a> {
         }
     }
 
+    pub fn and_or_xor_join_relation(&self, a: &String, b: &String, c: &String) -> AndOrXorRelation {
+        let b_c = self.get_directly_follows_count(b, c) as f64;
+        let c_b = self.get_directly_follows_count(c, b) as f64;
+        let b_a = self.get_directly_follows_count(b, a) as f64;
+        let c_a = self.get_directly_follows_count(c, a) as f64;
+
+        let and_xor_measure = (b_c + c_b) / (b_a + c_a + 1.0);
+
+        if and_xor_measure > self.and_threshold {
+            AndOrXorRelation::And
+        } else {
+            AndOrXorRelation::Xor
+        }
+    }
+
     pub fn loop_length_two_relation(&self, first: &str, second: &str) -> bool {
         let a_b = self.triangle_occurrences_count(first, second) as f64;
         let b_a = self.triangle_occurrences_count(second, first) as f64;
@@ -0,0 +1,63 @@
+use crate::{
+    event_log::core::event_log::EventLog,
+    features::{
+        analysis::patterns::activity_instances::create_logs_for_activities_immediate_events,
+        discovery::petri_net::petri_net::DefaultPetriNet,
+    },
+};
+
+pub const ROOT_PROCESS_NAME: &'static str = "Root";
+
+#[derive(Debug)]
+pub struct HierarchicalPetriNet {
+    name: String,
+    petri_net: DefaultPetriNet,
+    subprocesses: Vec<HierarchicalPetriNet>,
+}
+
+impl HierarchicalPetriNet {
+    pub fn new(name: String, petri_net: DefaultPetriNet, subprocesses: Vec<HierarchicalPetriNet>) -> Self {
+        Self {
+            name,
+            petri_net,
+            subprocesses,
+        }
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn petri_net(&self) -> &DefaultPetriNet {
+        &self.petri_net
+    }
+
+    pub fn subprocesses(&self) -> &Vec<HierarchicalPetriNet> {
+        &self.subprocesses
+    }
+
+    pub fn subprocess(&self, name: &str) -> Option<&HierarchicalPetriNet> {
+        self.subprocesses.iter().find(|subprocess| subprocess.name == name)
+    }
+
+    pub fn depth(&self) -> usize {
+        1 + self.subprocesses.iter().map(|subprocess| subprocess.depth()).max().unwrap_or(0)
+    }
+}
+
+pub fn discover_hierarchical_petri_net<TLog: EventLog>(log: &TLog, miner: &dyn Fn(&TLog) -> DefaultPetriNet) -> HierarchicalPetriNet {
+    discover_subprocess(ROOT_PROCESS_NAME.to_owned(), log, miner)
+}
+
+fn discover_subprocess<TLog: EventLog>(name: String, log: &TLog, miner: &dyn Fn(&TLog) -> DefaultPetriNet) -> HierarchicalPetriNet {
+    let petri_net = miner(log);
+
+    let mut subprocesses: Vec<HierarchicalPetriNet> = create_logs_for_activities_immediate_events(log)
+        .into_iter()
+        .map(|(activity_name, activity_log)| discover_subprocess(activity_name, &*activity_log.borrow(), miner))
+        .collect();
+
+    subprocesses.sort_by(|first, second| first.name.cmp(&second.name));
+
+    HierarchicalPetriNet::new(name, petri_net, subprocesses)
+}
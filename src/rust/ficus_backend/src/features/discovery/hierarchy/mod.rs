@@ -0,0 +1 @@
+pub mod hierarchical_petri_net;
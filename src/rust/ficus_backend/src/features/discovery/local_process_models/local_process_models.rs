@@ -0,0 +1,240 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use super::process_tree::ProcessTreeNode;
+
+//Models whose language (up to the window size) is larger are not evaluated, as instances are matched against the whole language
+const MAX_LANGUAGE_WORDS_COUNT: usize = 10_000;
+
+pub struct LocalProcessModelsDiscoveryParams {
+    pub window_size: usize,
+    pub min_support: usize,
+    pub max_activities_count: usize,
+    pub top_models_count: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct LocalProcessModel {
+    tree: ProcessTreeNode,
+    support: usize,
+    confidence: f64,
+    language_fit: f64,
+    determinism: f64,
+    score: f64,
+}
+
+impl LocalProcessModel {
+    pub fn tree(&self) -> &ProcessTreeNode {
+        &self.tree
+    }
+
+    pub fn support(&self) -> usize {
+        self.support
+    }
+
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+
+    pub fn language_fit(&self) -> f64 {
+        self.language_fit
+    }
+
+    pub fn determinism(&self) -> f64 {
+        self.determinism
+    }
+
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+
+    fn activities_count(&self) -> usize {
+        self.tree.activities().len()
+    }
+}
+
+pub fn discover_local_process_models(log: &Vec<Vec<u64>>, params: &LocalProcessModelsDiscoveryParams) -> Vec<LocalProcessModel> {
+    let activities_counts = count_activities(log);
+
+    let mut activities: Vec<u64> = activities_counts
+        .iter()
+        .filter(|(_, count)| **count >= params.min_support)
+        .map(|(activity, _)| *activity)
+        .collect();
+
+    activities.sort();
+
+    let mut visited_trees = HashSet::new();
+    let mut current_models = vec![];
+    for activity in &activities {
+        let tree = ProcessTreeNode::Activity(*activity);
+        visited_trees.insert(tree.clone());
+
+        if let Some(model) = evaluate_model(tree, log, &activities_counts, params) {
+            current_models.push(model);
+        }
+    }
+
+    let mut result = vec![];
+    for _ in 1..params.max_activities_count {
+        current_models.sort_by(compare_models);
+        current_models.truncate(params.top_models_count);
+
+        let mut next_models = vec![];
+        for model in &current_models {
+            let model_activities = model.tree.activities();
+            for activity in activities.iter().filter(|activity| !model_activities.contains(*activity)) {
+                for expansion in model.tree.expansions(*activity) {
+                    if !visited_trees.insert(expansion.clone()) {
+                        continue;
+                    }
+
+                    if let Some(expanded_model) = evaluate_model(expansion, log, &activities_counts, params) {
+                        next_models.push(expanded_model);
+                    }
+                }
+            }
+        }
+
+        if next_models.is_empty() {
+            break;
+        }
+
+        result.extend(next_models.iter().cloned());
+        current_models = next_models;
+    }
+
+    result.sort_by(compare_models);
+    result.truncate(params.top_models_count);
+
+    result
+}
+
+fn compare_models(first: &LocalProcessModel, second: &LocalProcessModel) -> Ordering {
+    second
+        .score
+        .partial_cmp(&first.score)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| second.activities_count().cmp(&first.activities_count()))
+        .then_with(|| second.support.cmp(&first.support))
+        .then_with(|| first.tree.cmp(&second.tree))
+}
+
+fn count_activities(log: &Vec<Vec<u64>>) -> HashMap<u64, usize> {
+    let mut counts = HashMap::new();
+    for trace in log {
+        for activity in trace {
+            *counts.entry(*activity).or_insert(0) += 1;
+        }
+    }
+
+    counts
+}
+
+//Instances of a model are searched in traces projected onto the model's activities, an instance
+//is a word from the model's language whose events span at most `window_size` events of the trace,
+//models with too large languages are skipped
+fn evaluate_model(
+    tree: ProcessTreeNode,
+    log: &Vec<Vec<u64>>,
+    activities_counts: &HashMap<u64, usize>,
+    params: &LocalProcessModelsDiscoveryParams,
+) -> Option<LocalProcessModel> {
+    let language = tree.language(params.window_size, MAX_LANGUAGE_WORDS_COUNT)?;
+    let max_word_length = language.iter().map(|word| word.len()).max()?;
+    let alphabet = tree.activities();
+
+    let mut support = 0;
+    let mut covered_counts: HashMap<u64, usize> = HashMap::new();
+    let mut observed_words: HashMap<Vec<u64>, usize> = HashMap::new();
+
+    for trace in log {
+        let positions: Vec<usize> = (0..trace.len()).filter(|index| alphabet.contains(&trace[*index])).collect();
+
+        let mut start = 0;
+        while start < positions.len() {
+            let mut matched_word = None;
+            for length in (1..=max_word_length.min(positions.len() - start)).rev() {
+                if positions[start + length - 1] - positions[start] + 1 > params.window_size {
+                    continue;
+                }
+
+                let word: Vec<u64> = positions[start..(start + length)].iter().map(|index| trace[*index]).collect();
+                if language.contains(&word) {
+                    matched_word = Some(word);
+                    break;
+                }
+            }
+
+            match matched_word {
+                Some(word) => {
+                    support += 1;
+                    start += word.len();
+
+                    for activity in &word {
+                        *covered_counts.entry(*activity).or_insert(0) += 1;
+                    }
+
+                    *observed_words.entry(word).or_insert(0) += 1;
+                }
+                None => start += 1,
+            }
+        }
+    }
+
+    if support < params.min_support {
+        return None;
+    }
+
+    let mut inverse_confidences_sum = 0.0;
+    for activity in &alphabet {
+        let covered_count = *covered_counts.get(activity).unwrap_or(&0);
+        if covered_count == 0 {
+            inverse_confidences_sum = f64::INFINITY;
+            break;
+        }
+
+        inverse_confidences_sum += *activities_counts.get(activity).unwrap() as f64 / covered_count as f64;
+    }
+
+    let confidence = alphabet.len() as f64 / inverse_confidences_sum;
+    let language_fit = observed_words.len() as f64 / language.len() as f64;
+    let determinism = calculate_determinism(&language, &observed_words);
+    let scaled_support = support as f64 / (support as f64 + 1.0);
+
+    Some(LocalProcessModel {
+        tree,
+        support,
+        confidence,
+        language_fit,
+        determinism,
+        score: (scaled_support + confidence + language_fit + determinism) / 4.0,
+    })
+}
+
+//Determinism penalizes models which allow too much behaviour (i.e. choices of all activities), it is
+//the mean inverse number of possible continuations (including termination) at each step of the instances
+fn calculate_determinism(language: &HashSet<Vec<u64>>, observed_words: &HashMap<Vec<u64>, usize>) -> f64 {
+    let mut continuations: HashMap<&[u64], HashSet<Option<u64>>> = HashMap::new();
+    for word in language {
+        for i in 0..=word.len() {
+            continuations
+                .entry(&word[..i])
+                .or_insert_with(HashSet::new)
+                .insert(word.get(i).copied());
+        }
+    }
+
+    let mut determinism_sum = 0.0;
+    let mut steps_count = 0;
+    for (word, count) in observed_words {
+        for i in 0..=word.len() {
+            determinism_sum += *count as f64 / continuations.get(&word[..i]).unwrap().len() as f64;
+            steps_count += *count;
+        }
+    }
+
+    determinism_sum / steps_count as f64
+}
@@ -0,0 +1,2 @@
+pub mod local_process_models;
+pub mod process_tree;
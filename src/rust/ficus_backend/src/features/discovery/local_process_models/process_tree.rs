@@ -0,0 +1,300 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+const MAX_LOOP_REDO_COUNT: usize = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProcessTreeNode {
+    Activity(u64),
+    Sequence(Vec<ProcessTreeNode>),
+    Choice(Vec<ProcessTreeNode>),
+    Parallel(Vec<ProcessTreeNode>),
+    Loop(Box<ProcessTreeNode>, Box<ProcessTreeNode>),
+}
+
+impl ProcessTreeNode {
+    pub fn activities(&self) -> HashSet<u64> {
+        let mut activities = HashSet::new();
+        self.collect_activities(&mut activities);
+
+        activities
+    }
+
+    fn collect_activities(&self, activities: &mut HashSet<u64>) {
+        match self {
+            ProcessTreeNode::Activity(activity) => {
+                activities.insert(*activity);
+            }
+            ProcessTreeNode::Sequence(children) | ProcessTreeNode::Choice(children) | ProcessTreeNode::Parallel(children) => {
+                for child in children {
+                    child.collect_activities(activities);
+                }
+            }
+            ProcessTreeNode::Loop(do_node, redo_node) => {
+                do_node.collect_activities(activities);
+                redo_node.collect_activities(activities);
+            }
+        }
+    }
+
+    //Returns None if the language contains more than `max_words_count` words, the language of
+    //parallel and loop operators grows exponentially with the words length
+    pub fn language(&self, max_word_length: usize, max_words_count: usize) -> Option<HashSet<Vec<u64>>> {
+        let language = match self {
+            ProcessTreeNode::Activity(activity) => match max_word_length {
+                0 => HashSet::new(),
+                _ => HashSet::from_iter(vec![vec![*activity]]),
+            },
+            ProcessTreeNode::Sequence(children) => {
+                let mut language = HashSet::from_iter(vec![vec![]]);
+                for child in children {
+                    let child_language = child.language(max_word_length, max_words_count)?;
+                    language = concatenate(&language, &child_language, max_word_length, max_words_count)?;
+                }
+
+                language
+            }
+            ProcessTreeNode::Choice(children) => {
+                let mut language = HashSet::new();
+                for child in children {
+                    language.extend(child.language(max_word_length, max_words_count)?);
+                }
+
+                language
+            }
+            ProcessTreeNode::Parallel(children) => {
+                let mut language = HashSet::from_iter(vec![vec![]]);
+                for child in children {
+                    let child_language = child.language(max_word_length, max_words_count)?;
+                    language = shuffle(&language, &child_language, max_word_length, max_words_count)?;
+                }
+
+                language
+            }
+            ProcessTreeNode::Loop(do_node, redo_node) => {
+                let do_language = do_node.language(max_word_length, max_words_count)?;
+                let redo_language = redo_node.language(max_word_length, max_words_count)?;
+
+                let mut language = do_language.clone();
+                let mut current = do_language.clone();
+                for _ in 0..MAX_LOOP_REDO_COUNT {
+                    let with_redo = concatenate(&current, &redo_language, max_word_length, max_words_count)?;
+                    current = concatenate(&with_redo, &do_language, max_word_length, max_words_count)?;
+                    if current.is_empty() {
+                        break;
+                    }
+
+                    language.extend(current.iter().cloned());
+                }
+
+                language
+            }
+        };
+
+        match language.len() > max_words_count {
+            true => None,
+            false => Some(language),
+        }
+    }
+
+    pub fn expansions(&self, new_activity: u64) -> Vec<ProcessTreeNode> {
+        let mut expansions = vec![];
+        let new_leaf = ProcessTreeNode::Activity(new_activity);
+
+        let mut activities: Vec<u64> = self.activities().into_iter().collect();
+        activities.sort();
+
+        for activity in activities {
+            let leaf = ProcessTreeNode::Activity(activity);
+            let replacements = vec![
+                ProcessTreeNode::Sequence(vec![leaf.clone(), new_leaf.clone()]),
+                ProcessTreeNode::Sequence(vec![new_leaf.clone(), leaf.clone()]),
+                ProcessTreeNode::Choice(vec![leaf.clone(), new_leaf.clone()]),
+                ProcessTreeNode::Parallel(vec![leaf.clone(), new_leaf.clone()]),
+                ProcessTreeNode::Loop(Box::new(leaf.clone()), Box::new(new_leaf.clone())),
+                ProcessTreeNode::Loop(Box::new(new_leaf.clone()), Box::new(leaf.clone())),
+            ];
+
+            for replacement in replacements {
+                expansions.push(self.replace_activity(activity, &replacement).normalize());
+            }
+        }
+
+        expansions
+    }
+
+    fn replace_activity(&self, activity: u64, replacement: &ProcessTreeNode) -> ProcessTreeNode {
+        let replace_all = |children: &Vec<ProcessTreeNode>| -> Vec<ProcessTreeNode> {
+            children.iter().map(|child| child.replace_activity(activity, replacement)).collect()
+        };
+
+        match self {
+            ProcessTreeNode::Activity(current) => match *current == activity {
+                true => replacement.clone(),
+                false => self.clone(),
+            },
+            ProcessTreeNode::Sequence(children) => ProcessTreeNode::Sequence(replace_all(children)),
+            ProcessTreeNode::Choice(children) => ProcessTreeNode::Choice(replace_all(children)),
+            ProcessTreeNode::Parallel(children) => ProcessTreeNode::Parallel(replace_all(children)),
+            ProcessTreeNode::Loop(do_node, redo_node) => ProcessTreeNode::Loop(
+                Box::new(do_node.replace_activity(activity, replacement)),
+                Box::new(redo_node.replace_activity(activity, replacement)),
+            ),
+        }
+    }
+
+    pub fn normalize(&self) -> ProcessTreeNode {
+        match self {
+            ProcessTreeNode::Activity(_) => self.clone(),
+            ProcessTreeNode::Sequence(children) => ProcessTreeNode::Sequence(Self::flatten(children, |node| match node {
+                ProcessTreeNode::Sequence(children) => Some(children),
+                _ => None,
+            })),
+            ProcessTreeNode::Choice(children) => {
+                let mut children = Self::flatten(children, |node| match node {
+                    ProcessTreeNode::Choice(children) => Some(children),
+                    _ => None,
+                });
+
+                children.sort();
+                ProcessTreeNode::Choice(children)
+            }
+            ProcessTreeNode::Parallel(children) => {
+                let mut children = Self::flatten(children, |node| match node {
+                    ProcessTreeNode::Parallel(children) => Some(children),
+                    _ => None,
+                });
+
+                children.sort();
+                ProcessTreeNode::Parallel(children)
+            }
+            ProcessTreeNode::Loop(do_node, redo_node) => {
+                ProcessTreeNode::Loop(Box::new(do_node.normalize()), Box::new(redo_node.normalize()))
+            }
+        }
+    }
+
+    fn flatten(
+        children: &Vec<ProcessTreeNode>,
+        same_operator_children: impl Fn(&ProcessTreeNode) -> Option<&Vec<ProcessTreeNode>>,
+    ) -> Vec<ProcessTreeNode> {
+        let mut result = vec![];
+        for child in children {
+            let child = child.normalize();
+            match same_operator_children(&child) {
+                Some(grand_children) => result.extend(grand_children.iter().cloned()),
+                None => result.push(child),
+            }
+        }
+
+        result
+    }
+
+    pub fn to_string_with_names(&self, names: &HashMap<u64, String>) -> String {
+        self.format(&|activity| match names.get(&activity) {
+            Some(name) => name.to_owned(),
+            None => activity.to_string(),
+        })
+    }
+
+    fn format(&self, activity_formatter: &dyn Fn(u64) -> String) -> String {
+        let format_children = |operator: &str, children: &Vec<ProcessTreeNode>| {
+            let children: Vec<String> = children.iter().map(|child| child.format(activity_formatter)).collect();
+            format!("{}({})", operator, children.join(", "))
+        };
+
+        match self {
+            ProcessTreeNode::Activity(activity) => activity_formatter(*activity),
+            ProcessTreeNode::Sequence(children) => format_children("->", children),
+            ProcessTreeNode::Choice(children) => format_children("X", children),
+            ProcessTreeNode::Parallel(children) => format_children("+", children),
+            ProcessTreeNode::Loop(do_node, redo_node) => {
+                format!(
+                    "*({}, {})",
+                    do_node.format(activity_formatter),
+                    redo_node.format(activity_formatter)
+                )
+            }
+        }
+    }
+}
+
+impl Display for ProcessTreeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.format(&|activity| activity.to_string()).as_str())
+    }
+}
+
+fn concatenate(
+    first: &HashSet<Vec<u64>>,
+    second: &HashSet<Vec<u64>>,
+    max_word_length: usize,
+    max_words_count: usize,
+) -> Option<HashSet<Vec<u64>>> {
+    let mut result = HashSet::new();
+    for first_word in first {
+        for second_word in second {
+            if first_word.len() + second_word.len() > max_word_length {
+                continue;
+            }
+
+            let mut word = first_word.clone();
+            word.extend(second_word.iter());
+            result.insert(word);
+
+            if result.len() > max_words_count {
+                return None;
+            }
+        }
+    }
+
+    Some(result)
+}
+
+fn shuffle(
+    first: &HashSet<Vec<u64>>,
+    second: &HashSet<Vec<u64>>,
+    max_word_length: usize,
+    max_words_count: usize,
+) -> Option<HashSet<Vec<u64>>> {
+    let mut result = HashSet::new();
+    for first_word in first {
+        for second_word in second {
+            if first_word.len() + second_word.len() > max_word_length {
+                continue;
+            }
+
+            if !interleave(first_word, second_word, &mut vec![], &mut result, max_words_count) {
+                return None;
+            }
+        }
+    }
+
+    Some(result)
+}
+
+//Returns false as soon as the result contains more than `max_words_count` words
+fn interleave(first: &[u64], second: &[u64], prefix: &mut Vec<u64>, result: &mut HashSet<Vec<u64>>, max_words_count: usize) -> bool {
+    if first.is_empty() || second.is_empty() {
+        let mut word = prefix.clone();
+        word.extend(first.iter().chain(second.iter()));
+        result.insert(word);
+        return result.len() <= max_words_count;
+    }
+
+    prefix.push(first[0]);
+    let fits = interleave(&first[1..], second, prefix, result, max_words_count);
+    prefix.pop();
+
+    if !fits {
+        return false;
+    }
+
+    prefix.push(second[0]);
+    let fits = interleave(first, &second[1..], prefix, result, max_words_count);
+    prefix.pop();
+
+    fits
+}
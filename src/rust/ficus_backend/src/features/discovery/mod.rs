@@ -1,4 +1,9 @@
 pub mod alpha;
+pub mod bpmn;
+pub mod declare;
 pub mod fuzzy;
 pub mod heuristic;
+pub mod hierarchy;
+pub mod local_process_models;
 pub mod petri_net;
+pub mod transition_system;
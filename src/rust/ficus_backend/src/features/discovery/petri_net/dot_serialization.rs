@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::features::discovery::petri_net::marking::Marking;
+use crate::features::discovery::petri_net::petri_net::PetriNet;
+use crate::features::discovery::petri_net::place::Place;
+use crate::features::discovery::petri_net::transition::Transition;
+use crate::utils::dot_utils::{DotAttributes, DotWriter};
+
+const TOKEN: &'static str = "•";
+
+//Places are circles which contain tokens of the initial marking, places of the final marking are double circles,
+//silent transitions are drawn as narrow black boxes
+pub fn serialize_petri_net_to_dot<TTransitionData, TArcData>(net: &PetriNet<TTransitionData, TArcData>) -> String
+where
+    TTransitionData: ToString,
+{
+    let mut writer = DotWriter::new("petri_net", &vec![("rankdir", "LR".to_string())]);
+
+    let initial_tokens = marking_to_tokens(net.initial_marking());
+    let final_tokens = marking_to_tokens(net.final_marking());
+
+    let mut places = net.all_places();
+    places.sort_by(|first, second| first.id().cmp(&second.id()));
+
+    for place in places {
+        let shape = match final_tokens.contains_key(&place.id()) {
+            true => "doublecircle",
+            false => "circle",
+        };
+
+        let label = match initial_tokens.get(&place.id()) {
+            Some(1) => TOKEN.to_string(),
+            Some(count) => count.to_string(),
+            None => String::new(),
+        };
+
+        let attributes = vec![("shape", shape.to_string()), ("label", label), ("xlabel", place.name().to_owned())];
+
+        writer.write_node(create_place_id(place).as_str(), &attributes);
+    }
+
+    let mut transitions = net.all_transitions();
+    transitions.sort_by(|first, second| first.id().cmp(&second.id()));
+
+    for transition in &transitions {
+        writer.write_node(create_transition_id(transition).as_str(), &create_transition_attributes(transition));
+    }
+
+    for transition in &transitions {
+        for arc in transition.incoming_arcs() {
+            let place_id = create_place_id(net.place(&arc.place_id()));
+            writer.write_edge(
+                place_id.as_str(),
+                create_transition_id(transition).as_str(),
+                &create_arc_attributes(*arc.tokens_count()),
+            );
+        }
+
+        for arc in transition.outgoing_arcs() {
+            let place_id = create_place_id(net.place(&arc.place_id()));
+            writer.write_edge(
+                create_transition_id(transition).as_str(),
+                place_id.as_str(),
+                &create_arc_attributes(*arc.tokens_count()),
+            );
+        }
+    }
+
+    writer.finish()
+}
+
+fn marking_to_tokens(marking: Option<&Marking>) -> HashMap<u64, usize> {
+    match marking {
+        Some(marking) => marking
+            .active_places()
+            .iter()
+            .map(|single| (single.place_id(), single.tokens_count()))
+            .collect(),
+        None => HashMap::new(),
+    }
+}
+
+fn create_transition_attributes<TTransitionData, TArcData>(transition: &Transition<TTransitionData, TArcData>) -> DotAttributes
+where
+    TTransitionData: ToString,
+{
+    match *transition.is_silent() {
+        true => vec![
+            ("shape", "box".to_string()),
+            ("style", "filled".to_string()),
+            ("fillcolor", "black".to_string()),
+            ("width", "0.15".to_string()),
+            ("label", String::new()),
+        ],
+        false => {
+            let label = match transition.data() {
+                Some(data) => data.to_string(),
+                None => transition.name().to_owned(),
+            };
+
+            vec![("shape", "box".to_string()), ("label", label)]
+        }
+    }
+}
+
+fn create_arc_attributes(tokens_count: usize) -> DotAttributes {
+    match tokens_count > 1 {
+        true => vec![("label", tokens_count.to_string())],
+        false => vec![],
+    }
+}
+
+fn create_place_id(place: &Place) -> String {
+    format!("p{}", place.id())
+}
+
+fn create_transition_id<TTransitionData, TArcData>(transition: &Transition<TTransitionData, TArcData>) -> String
+where
+    TTransitionData: ToString,
+{
+    format!("t{}", transition.id())
+}
@@ -0,0 +1,50 @@
+use crate::features::discovery::petri_net::petri_net::PetriNet;
+use crate::utils::graph::layered_layout::{calculate_layered_layout, GraphLayout, LayeredLayoutParams, LayoutNodeSize};
+
+pub const PLACE_SIZE: f64 = 30.0;
+pub const TRANSITION_WIDTH: f64 = 60.0;
+pub const SILENT_TRANSITION_WIDTH: f64 = 10.0;
+pub const TRANSITION_HEIGHT: f64 = 40.0;
+
+//Places and transitions share the ids space, so arcs are laid out as edges between their ids
+pub fn calculate_petri_net_layout<TTransitionData, TArcData>(
+    net: &PetriNet<TTransitionData, TArcData>,
+    params: &LayeredLayoutParams,
+) -> GraphLayout
+where
+    TTransitionData: ToString,
+{
+    let mut nodes = vec![];
+    let mut edges = vec![];
+
+    for place in net.all_places() {
+        nodes.push(LayoutNodeSize {
+            id: place.id(),
+            width: PLACE_SIZE,
+            height: PLACE_SIZE,
+        });
+    }
+
+    for transition in net.all_transitions() {
+        let width = match *transition.is_silent() {
+            true => SILENT_TRANSITION_WIDTH,
+            false => TRANSITION_WIDTH,
+        };
+
+        nodes.push(LayoutNodeSize {
+            id: transition.id(),
+            width,
+            height: TRANSITION_HEIGHT,
+        });
+
+        for arc in transition.incoming_arcs() {
+            edges.push((arc.place_id(), transition.id()));
+        }
+
+        for arc in transition.outgoing_arcs() {
+            edges.push((transition.id(), arc.place_id()));
+        }
+    }
+
+    calculate_layered_layout(&nodes, &edges, params)
+}
@@ -1,9 +1,12 @@
 pub mod annotations;
 pub mod arc;
-mod ids;
+pub mod dot_serialization;
+pub(crate) mod ids;
+pub mod layout;
 pub mod marking;
 pub mod petri_net;
 pub mod place;
+pub mod pnml_deserialization;
 pub mod pnml_serialization;
 pub mod replay;
 pub mod transition;
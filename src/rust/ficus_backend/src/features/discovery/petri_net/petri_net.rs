@@ -7,7 +7,7 @@ use super::arc::Arc;
 
 pub type DefaultPetriNet = PetriNet<String, ()>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct PlaceTransitions {
     incoming_transitions: Vec<u64>,
     outgoing_transitions: Vec<u64>,
@@ -22,7 +22,7 @@ impl PlaceTransitions {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PetriNet<TTransitionData, TArcData>
 where
     TTransitionData: ToString,
@@ -3,7 +3,7 @@ use crate::utils::user_data::user_data::UserDataImpl;
 
 const EMPTY_PLACE_NAME: &'static str = "EmptyPlace";
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Place {
     id: u64,
     name: String,
@@ -0,0 +1,209 @@
+use crate::features::discovery::petri_net::marking::{Marking, SingleMarking};
+use crate::features::discovery::petri_net::petri_net::DefaultPetriNet;
+use crate::features::discovery::petri_net::place::Place;
+use crate::features::discovery::petri_net::transition::Transition;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fs;
+
+const PLACE_TAG_NAME: &'static [u8] = b"place";
+const TRANSITION_TAG_NAME: &'static [u8] = b"transition";
+const ARC_TAG_NAME: &'static [u8] = b"arc";
+const TEXT_TAG_NAME: &'static [u8] = b"text";
+const INITIAL_MARKING_TAG_NAME: &'static [u8] = b"initialMarking";
+const FINAL_MARKINGS_TAG_NAME: &'static [u8] = b"finalmarkings";
+const TOOL_SPECIFIC_TAG_NAME: &'static [u8] = b"toolspecific";
+
+const INVISIBLE_ACTIVITY: &'static str = "$invisible$";
+
+const ID_ATTR_NAME: &'static [u8] = b"id";
+const SOURCE_ATTR_NAME: &'static [u8] = b"source";
+const TARGET_ATTR_NAME: &'static [u8] = b"target";
+const IDREF_ATTR_NAME: &'static [u8] = b"idref";
+const ACTIVITY_ATTR_NAME: &'static [u8] = b"activity";
+
+pub enum PnmlDeserializationError {
+    IOError(std::io::Error),
+    XmlError(quick_xml::Error),
+    MissingAttribute(String),
+    UnknownNode(String),
+    InvalidTokensCount(String),
+}
+
+impl ToString for PnmlDeserializationError {
+    fn to_string(&self) -> String {
+        match self {
+            Self::IOError(err) => err.to_string(),
+            Self::XmlError(err) => err.to_string(),
+            Self::MissingAttribute(name) => format!("Missing attribute {}", name),
+            Self::UnknownNode(id) => format!("Arc references unknown node {}", id),
+            Self::InvalidTokensCount(text) => format!("Invalid tokens count {}", text),
+        }
+    }
+}
+
+pub fn deserialize_from_pnml_file(path: &str) -> Result<DefaultPetriNet, PnmlDeserializationError> {
+    match fs::read_to_string(path) {
+        Ok(content) => deserialize_from_pnml(content.as_str()),
+        Err(error) => Err(PnmlDeserializationError::IOError(error)),
+    }
+}
+
+//Initial marking, ProM final markings and ProM invisible transitions are read if they are present
+pub fn deserialize_from_pnml(content: &str) -> Result<DefaultPetriNet, PnmlDeserializationError> {
+    let mut reader = Reader::from_str(content);
+    reader.trim_text(true);
+
+    let mut net = DefaultPetriNet::empty();
+    let mut places_ids = HashMap::new();
+    let mut transitions_ids = HashMap::new();
+    let mut arcs = vec![];
+    let mut initial_marking = vec![];
+    let mut final_marking = vec![];
+
+    let mut current_place: Option<String> = None;
+    let mut current_transition: Option<String> = None;
+    let mut current_transition_data: Option<String> = None;
+    let mut current_transition_silent = false;
+    let mut inside_text = false;
+    let mut inside_initial_marking = false;
+    let mut inside_final_markings = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(tag)) => match tag.name().as_ref() {
+                PLACE_TAG_NAME if inside_final_markings => current_place = Some(read_attribute(&tag, IDREF_ATTR_NAME)?),
+                PLACE_TAG_NAME => {
+                    let id = read_attribute(&tag, ID_ATTR_NAME)?;
+                    places_ids.insert(id.clone(), net.add_place(Place::with_name(id.clone())));
+                    current_place = Some(id);
+                }
+                TRANSITION_TAG_NAME => current_transition = Some(read_attribute(&tag, ID_ATTR_NAME)?),
+                ARC_TAG_NAME => arcs.push(read_arc(&tag)?),
+                TEXT_TAG_NAME => inside_text = true,
+                INITIAL_MARKING_TAG_NAME => inside_initial_marking = true,
+                FINAL_MARKINGS_TAG_NAME => inside_final_markings = true,
+                _ => {}
+            },
+            Ok(Event::Empty(tag)) => match tag.name().as_ref() {
+                PLACE_TAG_NAME => {
+                    let id = read_attribute(&tag, ID_ATTR_NAME)?;
+                    places_ids.insert(id.clone(), net.add_place(Place::with_name(id)));
+                }
+                TRANSITION_TAG_NAME => {
+                    let id = read_attribute(&tag, ID_ATTR_NAME)?;
+                    transitions_ids.insert(id.clone(), net.add_transition(Transition::empty(id, false, None)));
+                }
+                ARC_TAG_NAME => arcs.push(read_arc(&tag)?),
+                TOOL_SPECIFIC_TAG_NAME if current_transition.is_some() => {
+                    current_transition_silent |=
+                        read_attribute(&tag, ACTIVITY_ATTR_NAME).is_ok_and(|activity| activity == INVISIBLE_ACTIVITY);
+                }
+                _ => {}
+            },
+            Ok(Event::Text(text)) => {
+                if inside_text {
+                    let text = match text.unescape() {
+                        Ok(text) => text.to_string(),
+                        Err(error) => return Err(PnmlDeserializationError::XmlError(error)),
+                    };
+
+                    if let Some(place) = current_place.as_ref().filter(|_| inside_initial_marking || inside_final_markings) {
+                        let tokens_count = parse_tokens_count(text)?;
+                        match inside_final_markings {
+                            true => final_marking.push((place.to_owned(), tokens_count)),
+                            false => initial_marking.push((place.to_owned(), tokens_count)),
+                        }
+                    } else if current_transition.is_some() {
+                        current_transition_data = Some(text);
+                    }
+                }
+            }
+            Ok(Event::End(tag)) => match tag.name().as_ref() {
+                TEXT_TAG_NAME => inside_text = false,
+                INITIAL_MARKING_TAG_NAME => inside_initial_marking = false,
+                FINAL_MARKINGS_TAG_NAME => inside_final_markings = false,
+                PLACE_TAG_NAME => current_place = None,
+                TRANSITION_TAG_NAME => {
+                    if let Some(id) = current_transition.take() {
+                        let data = current_transition_data.take();
+                        let silent = current_transition_silent;
+                        current_transition_silent = false;
+                        transitions_ids.insert(id.clone(), net.add_transition(Transition::empty(id, silent, data)));
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(error) => return Err(PnmlDeserializationError::XmlError(error)),
+            _ => {}
+        }
+    }
+
+    for (source, target) in arcs {
+        if let Some(place_id) = places_ids.get(&source) {
+            match transitions_ids.get(&target) {
+                Some(transition_id) => net.connect_place_to_transition(place_id, transition_id, None),
+                None => return Err(PnmlDeserializationError::UnknownNode(target)),
+            }
+        } else if let Some(transition_id) = transitions_ids.get(&source) {
+            match places_ids.get(&target) {
+                Some(place_id) => net.connect_transition_to_place(transition_id, place_id, None),
+                None => return Err(PnmlDeserializationError::UnknownNode(target)),
+            }
+        } else {
+            return Err(PnmlDeserializationError::UnknownNode(source));
+        }
+    }
+
+    if !initial_marking.is_empty() {
+        net.set_initial_marking(create_marking(initial_marking, &places_ids)?);
+    }
+
+    if !final_marking.is_empty() {
+        net.set_final_marking(create_marking(final_marking, &places_ids)?);
+    }
+
+    Ok(net)
+}
+
+fn parse_tokens_count(text: String) -> Result<usize, PnmlDeserializationError> {
+    match text.trim().parse::<usize>() {
+        Ok(tokens_count) => Ok(tokens_count),
+        Err(_) => Err(PnmlDeserializationError::InvalidTokensCount(text)),
+    }
+}
+
+fn create_marking(tokens: Vec<(String, usize)>, places_ids: &HashMap<String, u64>) -> Result<Marking, PnmlDeserializationError> {
+    let mut single_markings = vec![];
+    for (place, tokens_count) in tokens {
+        match places_ids.get(&place) {
+            Some(place_id) => single_markings.push(SingleMarking::new(*place_id, tokens_count)),
+            None => return Err(PnmlDeserializationError::UnknownNode(place)),
+        }
+    }
+
+    Ok(Marking::new(single_markings))
+}
+
+fn read_arc(tag: &BytesStart) -> Result<(String, String), PnmlDeserializationError> {
+    Ok((read_attribute(tag, SOURCE_ATTR_NAME)?, read_attribute(tag, TARGET_ATTR_NAME)?))
+}
+
+fn read_attribute(tag: &BytesStart, attr_name: &[u8]) -> Result<String, PnmlDeserializationError> {
+    for attr in tag.attributes() {
+        if let Ok(attr) = attr {
+            if attr.key.as_ref() == attr_name {
+                return match attr.unescape_value() {
+                    Ok(value) => Ok(value.to_string()),
+                    Err(error) => Err(PnmlDeserializationError::XmlError(error)),
+                };
+            }
+        }
+    }
+
+    Err(PnmlDeserializationError::MissingAttribute(
+        String::from_utf8_lossy(attr_name).to_string(),
+    ))
+}
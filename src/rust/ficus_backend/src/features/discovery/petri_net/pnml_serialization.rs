@@ -1,8 +1,10 @@
+use crate::features::discovery::hierarchy::hierarchical_petri_net::HierarchicalPetriNet;
 use crate::features::discovery::petri_net::arc::Arc;
+use crate::features::discovery::petri_net::marking::Marking;
 use crate::features::discovery::petri_net::petri_net::PetriNet;
 use crate::features::discovery::petri_net::place::Place;
 use crate::features::discovery::petri_net::transition::Transition;
-use crate::utils::xml_utils::{StartEndElementCookie, XmlWriteError};
+use crate::utils::xml_utils::{write_empty, StartEndElementCookie, XmlWriteError};
 use quick_xml::events::{BytesText, Event};
 use quick_xml::Writer;
 use std::cell::RefCell;
@@ -16,10 +18,23 @@ const PLACE_TAG_NAME: &'static str = "place";
 const NET_TAG_NAME: &'static str = "net";
 const TEXT_TAG_NAME: &'static str = "text";
 const NAME_TAG_NAME: &'static str = "name";
+const PAGE_TAG_NAME: &'static str = "page";
+const INITIAL_MARKING_TAG_NAME: &'static str = "initialMarking";
+const FINAL_MARKINGS_TAG_NAME: &'static str = "finalmarkings";
+const MARKING_TAG_NAME: &'static str = "marking";
+const TOOL_SPECIFIC_TAG_NAME: &'static str = "toolspecific";
+
+const PROM_TOOL_NAME: &'static str = "ProM";
+const INVISIBLE_ACTIVITY: &'static str = "$invisible$";
+
+const PAGE_ID_SEPARATOR: &'static str = "/";
 
 const ID_ATTR_NAME: &'static str = "id";
 const SOURCE_ATTR_NAME: &'static str = "source";
 const TARGET_ATTR_NAME: &'static str = "target";
+const IDREF_ATTR_NAME: &'static str = "idref";
+const TOOL_ATTR_NAME: &'static str = "tool";
+const ACTIVITY_ATTR_NAME: &'static str = "activity";
 
 pub fn serialize_to_pnml_file<TTransitionData, TArcData>(
     net: &PetriNet<TTransitionData, TArcData>,
@@ -29,7 +44,44 @@ pub fn serialize_to_pnml_file<TTransitionData, TArcData>(
 where
     TTransitionData: ToString,
 {
-    match serialize_to_pnml(net, use_names_as_ids) {
+    write_to_file(serialize_to_pnml(net, use_names_as_ids), save_path)
+}
+
+pub fn serialize_to_pnml<TTransitionData, TArcData>(
+    net: &PetriNet<TTransitionData, TArcData>,
+    use_names_as_ids: bool,
+) -> Result<String, XmlWriteError>
+where
+    TTransitionData: ToString,
+{
+    serialize_to_pnml_internal(net, use_names_as_ids, false)
+}
+
+pub fn serialize_to_executable_pnml_file<TTransitionData, TArcData>(
+    net: &PetriNet<TTransitionData, TArcData>,
+    save_path: &str,
+    use_names_as_ids: bool,
+) -> Result<(), XmlWriteError>
+where
+    TTransitionData: ToString,
+{
+    write_to_file(serialize_to_executable_pnml(net, use_names_as_ids), save_path)
+}
+
+//Besides the structure of the net writes initial marking of places, final marking (in the ProM "finalmarkings" format)
+//and marks silent transitions with the ProM tool specific "$invisible$" activity
+pub fn serialize_to_executable_pnml<TTransitionData, TArcData>(
+    net: &PetriNet<TTransitionData, TArcData>,
+    use_names_as_ids: bool,
+) -> Result<String, XmlWriteError>
+where
+    TTransitionData: ToString,
+{
+    serialize_to_pnml_internal(net, use_names_as_ids, true)
+}
+
+fn write_to_file(content: Result<String, XmlWriteError>, save_path: &str) -> Result<(), XmlWriteError> {
+    match content {
         Ok(content) => match fs::write(save_path, content) {
             Ok(_) => Ok(()),
             Err(error) => Err(XmlWriteError::IOError(error)),
@@ -38,9 +90,10 @@ where
     }
 }
 
-pub fn serialize_to_pnml<TTransitionData, TArcData>(
+fn serialize_to_pnml_internal<TTransitionData, TArcData>(
     net: &PetriNet<TTransitionData, TArcData>,
     use_names_as_ids: bool,
+    executable: bool,
 ) -> Result<String, XmlWriteError>
 where
     TTransitionData: ToString,
@@ -50,13 +103,115 @@ where
     let pnml_cookie = StartEndElementCookie::new(&writer, PNML_TAG_NAME)?;
     let net_cookie = StartEndElementCookie::new(&writer, NET_TAG_NAME)?;
 
-    write_places(net, &writer, use_names_as_ids)?;
-    write_transitions(net, &writer, use_names_as_ids)?;
-    write_arcs(net, &writer, use_names_as_ids)?;
+    write_net_elements(net, &writer, use_names_as_ids, "", executable)?;
 
     drop(net_cookie);
     drop(pnml_cookie);
 
+    create_pnml_string(&writer)
+}
+
+pub fn serialize_hierarchical_to_pnml_file(
+    net: &HierarchicalPetriNet,
+    save_path: &str,
+    use_names_as_ids: bool,
+) -> Result<(), XmlWriteError> {
+    match serialize_hierarchical_to_pnml(net, use_names_as_ids) {
+        Ok(content) => match fs::write(save_path, content) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(XmlWriteError::IOError(error)),
+        },
+        Err(error) => Err(error),
+    }
+}
+
+pub fn serialize_hierarchical_to_pnml(net: &HierarchicalPetriNet, use_names_as_ids: bool) -> Result<String, XmlWriteError> {
+    let writer = RefCell::new(Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2));
+
+    let pnml_cookie = StartEndElementCookie::new(&writer, PNML_TAG_NAME)?;
+    let net_cookie = StartEndElementCookie::new(&writer, NET_TAG_NAME)?;
+
+    write_page(net, &writer, use_names_as_ids, net.name())?;
+
+    drop(net_cookie);
+    drop(pnml_cookie);
+
+    create_pnml_string(&writer)
+}
+
+fn write_page(
+    net: &HierarchicalPetriNet,
+    writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
+    use_names_as_ids: bool,
+    page_id: &str,
+) -> Result<(), XmlWriteError> {
+    let page_cookie = StartEndElementCookie::new_with_attrs(writer, PAGE_TAG_NAME, &vec![(ID_ATTR_NAME, page_id)])?;
+
+    write_name(writer, net.name())?;
+    write_net_elements(
+        net.petri_net(),
+        writer,
+        use_names_as_ids,
+        format!("{}{}", page_id, PAGE_ID_SEPARATOR).as_str(),
+        false,
+    )?;
+
+    for subprocess in net.subprocesses() {
+        let subprocess_page_id = format!("{}{}{}", page_id, PAGE_ID_SEPARATOR, subprocess.name());
+        write_page(subprocess, writer, use_names_as_ids, subprocess_page_id.as_str())?;
+    }
+
+    drop(page_cookie);
+    Ok(())
+}
+
+fn write_net_elements<TTransitionData, TArcData>(
+    net: &PetriNet<TTransitionData, TArcData>,
+    writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
+    use_names_as_ids: bool,
+    id_prefix: &str,
+    executable: bool,
+) -> Result<(), XmlWriteError>
+where
+    TTransitionData: ToString,
+{
+    write_places(net, writer, use_names_as_ids, id_prefix, executable)?;
+    write_transitions(net, writer, use_names_as_ids, id_prefix, executable)?;
+    write_arcs(net, writer, use_names_as_ids, id_prefix)?;
+
+    match net.final_marking() {
+        Some(final_marking) if executable => write_final_marking(net, final_marking, writer, use_names_as_ids, id_prefix),
+        _ => Ok(()),
+    }
+}
+
+fn write_final_marking<TTransitionData, TArcData>(
+    net: &PetriNet<TTransitionData, TArcData>,
+    final_marking: &Marking,
+    writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
+    use_names_as_ids: bool,
+    id_prefix: &str,
+) -> Result<(), XmlWriteError>
+where
+    TTransitionData: ToString,
+{
+    let final_markings_cookie = StartEndElementCookie::new(writer, FINAL_MARKINGS_TAG_NAME)?;
+    let marking_cookie = StartEndElementCookie::new(writer, MARKING_TAG_NAME)?;
+
+    for single_marking in final_marking.active_places() {
+        let place_id = create_place_id(net.place(&single_marking.place_id()), use_names_as_ids, id_prefix);
+        let place_cookie = StartEndElementCookie::new_with_attrs(writer, PLACE_TAG_NAME, &vec![(IDREF_ATTR_NAME, place_id.as_str())])?;
+        write_text(writer, single_marking.tokens_count().to_string().as_str())?;
+        drop(place_cookie);
+    }
+
+    drop(marking_cookie);
+    drop(final_markings_cookie);
+
+    Ok(())
+}
+
+fn create_pnml_string(writer: &RefCell<Writer<Cursor<Vec<u8>>>>) -> Result<String, XmlWriteError> {
     let content = writer.borrow().get_ref().get_ref().clone();
     match String::from_utf8(content) {
         Ok(string) => Ok(string),
@@ -68,6 +223,8 @@ fn write_places<TTransitionData, TArcData>(
     net: &PetriNet<TTransitionData, TArcData>,
     writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
     use_names_as_ids: bool,
+    id_prefix: &str,
+    executable: bool,
 ) -> Result<(), XmlWriteError>
 where
     TTransitionData: ToString,
@@ -76,11 +233,26 @@ where
     places.sort_by(|left, right| left.name().cmp(right.name()));
 
     for place in places {
-        let _ = StartEndElementCookie::new_with_attrs(
+        let cookie = StartEndElementCookie::new_with_attrs(
             writer,
             PLACE_TAG_NAME,
-            &vec![(ID_ATTR_NAME, create_place_id(place, use_names_as_ids).as_str())],
+            &vec![(ID_ATTR_NAME, create_place_id(place, use_names_as_ids, id_prefix).as_str())],
         )?;
+
+        let initial_tokens = net.initial_marking().and_then(|marking| {
+            marking
+                .active_places()
+                .iter()
+                .find(|single_marking| single_marking.place_id() == place.id())
+        });
+
+        if let Some(single_marking) = initial_tokens.filter(|_| executable) {
+            let marking_cookie = StartEndElementCookie::new(writer, INITIAL_MARKING_TAG_NAME)?;
+            write_text(writer, single_marking.tokens_count().to_string().as_str())?;
+            drop(marking_cookie);
+        }
+
+        drop(cookie);
     }
 
     Ok(())
@@ -90,6 +262,8 @@ fn write_transitions<TTransitionData, TArcData>(
     net: &PetriNet<TTransitionData, TArcData>,
     writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
     use_names_as_ids: bool,
+    id_prefix: &str,
+    executable: bool,
 ) -> Result<(), XmlWriteError>
 where
     TTransitionData: ToString,
@@ -98,23 +272,19 @@ where
         let cookie = StartEndElementCookie::new_with_attrs(
             &writer,
             TRANSITION_TAG_NAME,
-            &vec![(ID_ATTR_NAME, create_transition_id(transition, use_names_as_ids).as_str())],
+            &vec![(ID_ATTR_NAME, create_transition_id(transition, use_names_as_ids, id_prefix).as_str())],
         );
 
         if let Some(data) = transition.data() {
-            let name = StartEndElementCookie::new(&writer, NAME_TAG_NAME);
-            let text = StartEndElementCookie::new(&writer, TEXT_TAG_NAME);
-
-            match writer
-                .borrow_mut()
-                .write_event(Event::Text(BytesText::new(data.to_string().as_str())))
-            {
-                Ok(()) => {}
-                Err(error) => return Err(XmlWriteError::WriterError(error)),
-            };
-
-            drop(text);
-            drop(name);
+            write_name(writer, data.to_string().as_str())?;
+        }
+
+        if executable && *transition.is_silent() {
+            write_empty(
+                &mut writer.borrow_mut(),
+                TOOL_SPECIFIC_TAG_NAME,
+                &vec![(TOOL_ATTR_NAME, PROM_TOOL_NAME), (ACTIVITY_ATTR_NAME, INVISIBLE_ACTIVITY)],
+            )?;
         }
 
         drop(cookie)
@@ -123,6 +293,27 @@ where
     Ok(())
 }
 
+fn write_name(writer: &RefCell<Writer<Cursor<Vec<u8>>>>, name: &str) -> Result<(), XmlWriteError> {
+    let name_cookie = StartEndElementCookie::new(writer, NAME_TAG_NAME);
+    write_text(writer, name)?;
+    drop(name_cookie);
+
+    Ok(())
+}
+
+fn write_text(writer: &RefCell<Writer<Cursor<Vec<u8>>>>, text: &str) -> Result<(), XmlWriteError> {
+    let text_cookie = StartEndElementCookie::new(writer, TEXT_TAG_NAME);
+
+    match writer.borrow_mut().write_event(Event::Text(BytesText::new(text))) {
+        Ok(()) => {}
+        Err(error) => return Err(XmlWriteError::WriterError(error)),
+    };
+
+    drop(text_cookie);
+
+    Ok(())
+}
+
 fn created_ordered_transitions_list<TTransitionData, TArcData>(
     net: &PetriNet<TTransitionData, TArcData>,
 ) -> Vec<&Transition<TTransitionData, TArcData>>
@@ -139,13 +330,14 @@ fn write_arcs<TTransitionData, TArcData>(
     net: &PetriNet<TTransitionData, TArcData>,
     writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
     use_names_as_ids: bool,
+    id_prefix: &str,
 ) -> Result<(), XmlWriteError>
 where
     TTransitionData: ToString,
 {
     for transition in created_ordered_transitions_list(net) {
-        write_incoming_arcs(net, transition, writer, use_names_as_ids)?;
-        write_outgoing_arcs(net, transition, writer, use_names_as_ids)?;
+        write_incoming_arcs(net, transition, writer, use_names_as_ids, id_prefix)?;
+        write_outgoing_arcs(net, transition, writer, use_names_as_ids, id_prefix)?;
     }
 
     Ok(())
@@ -156,14 +348,15 @@ fn write_incoming_arcs<TTransitionData, TArcData>(
     transition: &Transition<TTransitionData, TArcData>,
     writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
     use_names_as_ids: bool,
+    id_prefix: &str,
 ) -> Result<(), XmlWriteError>
 where
     TTransitionData: ToString,
 {
-    let incoming_arcs = patch_arcs_list(transition.incoming_arcs(), use_names_as_ids, |arc| {
+    let incoming_arcs = patch_arcs_list(transition.incoming_arcs(), use_names_as_ids, id_prefix, |arc| {
         create_arc_name::<TArcData>(
-            create_place_id(net.place(&arc.place_id()), use_names_as_ids),
-            create_transition_id(transition, use_names_as_ids),
+            create_place_id(net.place(&arc.place_id()), use_names_as_ids, id_prefix),
+            create_transition_id(transition, use_names_as_ids, id_prefix),
         )
     });
 
@@ -175,9 +368,12 @@ where
                 (ID_ATTR_NAME, arc.1.as_str()),
                 (
                     SOURCE_ATTR_NAME,
-                    create_place_id(net.place(&arc.0.place_id()), use_names_as_ids).as_str(),
+                    create_place_id(net.place(&arc.0.place_id()), use_names_as_ids, id_prefix).as_str(),
+                ),
+                (
+                    TARGET_ATTR_NAME,
+                    create_transition_id(transition, use_names_as_ids, id_prefix).as_str(),
                 ),
-                (TARGET_ATTR_NAME, create_transition_id(transition, use_names_as_ids).as_str()),
             ],
         )?;
     }
@@ -185,19 +381,20 @@ where
     Ok(())
 }
 
-fn patch_arcs_list<TArcData>(
-    arcs: &Vec<Arc<TArcData>>,
+fn patch_arcs_list<'a, TArcData>(
+    arcs: &'a Vec<Arc<TArcData>>,
     use_names_as_ids: bool,
+    id_prefix: &str,
     names_creator: impl Fn(&Arc<TArcData>) -> String,
-) -> Vec<(&Arc<TArcData>, String)> {
-    let mut arcs: Vec<(&Arc<TArcData>, String)> = arcs
+) -> Vec<(&'a Arc<TArcData>, String)> {
+    let mut arcs: Vec<(&'a Arc<TArcData>, String)> = arcs
         .iter()
         .map(|arc| {
             (
                 arc,
                 match use_names_as_ids {
                     true => names_creator(arc),
-                    false => arc.id().to_string(),
+                    false => format!("{}{}", id_prefix, arc.id()),
                 },
             )
         })
@@ -212,14 +409,15 @@ fn write_outgoing_arcs<TTransitionData, TArcData>(
     transition: &Transition<TTransitionData, TArcData>,
     writer: &RefCell<Writer<Cursor<Vec<u8>>>>,
     use_names_as_ids: bool,
+    id_prefix: &str,
 ) -> Result<(), XmlWriteError>
 where
     TTransitionData: ToString,
 {
-    let outgoing_arcs = patch_arcs_list(transition.outgoing_arcs(), use_names_as_ids, |arc| {
+    let outgoing_arcs = patch_arcs_list(transition.outgoing_arcs(), use_names_as_ids, id_prefix, |arc| {
         create_arc_name::<TArcData>(
-            create_transition_id(transition, use_names_as_ids),
-            create_place_id(net.place(&arc.place_id()), use_names_as_ids),
+            create_transition_id(transition, use_names_as_ids, id_prefix),
+            create_place_id(net.place(&arc.place_id()), use_names_as_ids, id_prefix),
         )
     });
 
@@ -231,9 +429,12 @@ where
                 (ID_ATTR_NAME, arc.1.as_str()),
                 (
                     TARGET_ATTR_NAME,
-                    create_place_id(net.place(&arc.0.place_id()), use_names_as_ids).as_str(),
+                    create_place_id(net.place(&arc.0.place_id()), use_names_as_ids, id_prefix).as_str(),
+                ),
+                (
+                    SOURCE_ATTR_NAME,
+                    create_transition_id(transition, use_names_as_ids, id_prefix).as_str(),
                 ),
-                (SOURCE_ATTR_NAME, create_transition_id(transition, use_names_as_ids).as_str()),
             ],
         )?;
     }
@@ -241,20 +442,24 @@ where
     Ok(())
 }
 
-fn create_place_id(place: &Place, use_names_as_ids: bool) -> String {
+fn create_place_id(place: &Place, use_names_as_ids: bool, id_prefix: &str) -> String {
     match use_names_as_ids {
-        true => place.name().to_owned(),
-        false => place.id().to_string(),
+        true => format!("{}{}", id_prefix, place.name()),
+        false => format!("{}{}", id_prefix, place.id()),
     }
 }
 
-fn create_transition_id<TTransitionData, TArcData>(transition: &Transition<TTransitionData, TArcData>, use_names_as_ids: bool) -> String
+fn create_transition_id<TTransitionData, TArcData>(
+    transition: &Transition<TTransitionData, TArcData>,
+    use_names_as_ids: bool,
+    id_prefix: &str,
+) -> String
 where
     TTransitionData: ToString,
 {
     match use_names_as_ids {
-        true => transition.name().to_string(),
-        false => transition.id().to_string(),
+        true => format!("{}{}", id_prefix, transition.name()),
+        false => format!("{}{}", id_prefix, transition.id()),
     }
 }
 
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::event_log::core::{event::event::Event, event_log::EventLog, trace::trace::Trace};
 
@@ -139,3 +139,240 @@ pub fn replay_petri_net(log: &impl EventLog, net: &DefaultPetriNet) -> Option<Ve
 
     Some(result)
 }
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TokenReplayCounts {
+    produced: usize,
+    consumed: usize,
+    missing: usize,
+    remaining: usize,
+}
+
+impl TokenReplayCounts {
+    pub fn new(produced: usize, consumed: usize, missing: usize, remaining: usize) -> Self {
+        Self {
+            produced,
+            consumed,
+            missing,
+            remaining,
+        }
+    }
+
+    pub fn produced(&self) -> usize {
+        self.produced
+    }
+
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    pub fn missing(&self) -> usize {
+        self.missing
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    pub fn add(&mut self, other: &TokenReplayCounts, times: usize) {
+        self.produced += other.produced * times;
+        self.consumed += other.consumed * times;
+        self.missing += other.missing * times;
+        self.remaining += other.remaining * times;
+    }
+
+    //Fitness is 0.5 * (1 - missing / consumed) + 0.5 * (1 - remaining / produced)
+    pub fn fitness(&self) -> f64 {
+        let consumed_part = match self.consumed {
+            0 => 1.0,
+            consumed => 1.0 - self.missing as f64 / consumed as f64,
+        };
+
+        let produced_part = match self.produced {
+            0 => 1.0,
+            produced => 1.0 - self.remaining as f64 / produced as f64,
+        };
+
+        0.5 * consumed_part + 0.5 * produced_part
+    }
+}
+
+//Token-based replay: when a transition is not enabled, the shortest sequence of silent transitions (not longer than
+//MAX_SILENT_PATH_LENGTH) which enables it is fired first, events without transitions are counted as one missing
+//and one remaining token.
+//Tokens of the final marking are consumed at the end, if there is no final marking then all tokens in places
+//without outgoing arcs are consumed
+pub fn replay_trace_tokens(trace: &[String], net: &DefaultPetriNet) -> TokenReplayCounts {
+    let mut counts = TokenReplayCounts::default();
+    let mut markings: HashMap<u64, usize> = HashMap::new();
+    if let Some(initial_marking) = net.initial_marking() {
+        for single_marking in initial_marking.active_places() {
+            *markings.entry(single_marking.place_id()).or_default() += single_marking.tokens_count();
+            counts.produced += single_marking.tokens_count();
+        }
+    }
+
+    for event in trace {
+        let candidates = net
+            .all_transitions()
+            .into_iter()
+            .filter(|transition| !*transition.is_silent() && transition.name() == event)
+            .collect::<Vec<&Transition<String, ()>>>();
+
+        if candidates.is_empty() {
+            counts.consumed += 1;
+            counts.missing += 1;
+            counts.produced += 1;
+            counts.remaining += 1;
+            continue;
+        }
+
+        let transition = match candidates.iter().find(|transition| is_enabled(transition, &markings)) {
+            Some(transition) => *transition,
+            None => {
+                let enabled_transition = candidates
+                    .iter()
+                    .find(|candidate| try_enable_with_silent_transitions(candidate, net, &mut markings, &mut counts));
+
+                match enabled_transition {
+                    Some(transition) => *transition,
+                    None => candidates[0],
+                }
+            }
+        };
+
+        fire_transition(transition, &mut markings, &mut counts);
+    }
+
+    match net.final_marking() {
+        Some(final_marking) => {
+            for single_marking in final_marking.active_places() {
+                consume_tokens(single_marking.place_id(), single_marking.tokens_count(), &mut markings, &mut counts);
+            }
+        }
+        None => {
+            let consumed_places = net
+                .all_transitions()
+                .iter()
+                .flat_map(|transition| transition.incoming_arcs().iter().map(|arc| arc.place_id()))
+                .collect::<HashSet<u64>>();
+
+            let sink_places = markings
+                .keys()
+                .filter(|place_id| !consumed_places.contains(place_id))
+                .copied()
+                .collect::<Vec<u64>>();
+
+            for place_id in sink_places {
+                let tokens_count = markings[&place_id];
+                consume_tokens(place_id, tokens_count, &mut markings, &mut counts);
+            }
+        }
+    }
+
+    counts.remaining += markings.values().sum::<usize>();
+    counts
+}
+
+pub fn calculate_token_replay_fitness(log: &impl EventLog, net: &DefaultPetriNet) -> f64 {
+    let mut counts = TokenReplayCounts::default();
+    for trace in log.traces() {
+        let trace = trace.borrow();
+        let names = trace
+            .events()
+            .iter()
+            .map(|event| event.borrow().name().to_owned())
+            .collect::<Vec<String>>();
+
+        counts.add(&replay_trace_tokens(&names, net), 1);
+    }
+
+    counts.fitness()
+}
+
+fn is_enabled(transition: &Transition<String, ()>, markings: &HashMap<u64, usize>) -> bool {
+    transition
+        .incoming_arcs()
+        .iter()
+        .all(|arc| markings.get(&arc.place_id()).copied().unwrap_or_default() >= *arc.tokens_count())
+}
+
+const MAX_SILENT_PATH_LENGTH: usize = 5;
+
+//Breadth-first search over markings reachable by firing silent transitions, markings and counts are
+//changed only if the transition becomes enabled
+fn try_enable_with_silent_transitions(
+    transition: &Transition<String, ()>,
+    net: &DefaultPetriNet,
+    markings: &mut HashMap<u64, usize>,
+    counts: &mut TokenReplayCounts,
+) -> bool {
+    let silent_transitions = net
+        .all_transitions()
+        .into_iter()
+        .filter(|candidate| *candidate.is_silent())
+        .collect::<Vec<&Transition<String, ()>>>();
+
+    let mut visited = HashSet::from([to_sorted_markings(markings)]);
+    let mut queue = VecDeque::from([(markings.clone(), *counts, 0)]);
+
+    while let Some((current_markings, current_counts, path_length)) = queue.pop_front() {
+        if path_length == MAX_SILENT_PATH_LENGTH {
+            continue;
+        }
+
+        for silent_transition in &silent_transitions {
+            if !is_enabled(silent_transition, &current_markings) {
+                continue;
+            }
+
+            let mut new_markings = current_markings.clone();
+            let mut new_counts = current_counts;
+            fire_transition(silent_transition, &mut new_markings, &mut new_counts);
+
+            if is_enabled(transition, &new_markings) {
+                *markings = new_markings;
+                *counts = new_counts;
+                return true;
+            }
+
+            if visited.insert(to_sorted_markings(&new_markings)) {
+                queue.push_back((new_markings, new_counts, path_length + 1));
+            }
+        }
+    }
+
+    false
+}
+
+fn to_sorted_markings(markings: &HashMap<u64, usize>) -> Vec<(u64, usize)> {
+    let mut sorted_markings = markings
+        .iter()
+        .map(|(place_id, count)| (*place_id, *count))
+        .collect::<Vec<(u64, usize)>>();
+    sorted_markings.sort();
+    sorted_markings
+}
+
+fn fire_transition(transition: &Transition<String, ()>, markings: &mut HashMap<u64, usize>, counts: &mut TokenReplayCounts) {
+    for arc in transition.incoming_arcs() {
+        consume_tokens(arc.place_id(), *arc.tokens_count(), markings, counts);
+    }
+
+    for arc in transition.outgoing_arcs() {
+        *markings.entry(arc.place_id()).or_default() += *arc.tokens_count();
+        counts.produced += *arc.tokens_count();
+    }
+}
+
+fn consume_tokens(place_id: u64, tokens_count: usize, markings: &mut HashMap<u64, usize>, counts: &mut TokenReplayCounts) {
+    let available = markings.get(&place_id).copied().unwrap_or_default();
+    counts.consumed += tokens_count;
+    counts.missing += tokens_count.saturating_sub(available);
+
+    if available > tokens_count {
+        markings.insert(place_id, available - tokens_count);
+    } else {
+        markings.remove(&place_id);
+    }
+}
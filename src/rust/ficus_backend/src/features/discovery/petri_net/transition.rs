@@ -2,7 +2,7 @@ use crate::features::discovery::petri_net::arc::Arc;
 use crate::features::discovery::petri_net::ids::next_id;
 use std::hash::{Hash, Hasher};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Transition<TTransitionData, TArcData>
 where
     TTransitionData: ToString,
@@ -0,0 +1,22 @@
+use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
+
+pub enum RegionsSynthesisError {
+    SearchStepsLimitExceeded(usize),
+}
+
+impl Into<PipelinePartExecutionError> for RegionsSynthesisError {
+    fn into(self) -> PipelinePartExecutionError {
+        PipelinePartExecutionError::Raw(RawPartExecutionError::new(self.to_string()))
+    }
+}
+
+impl ToString for RegionsSynthesisError {
+    fn to_string(&self) -> String {
+        match self {
+            Self::SearchStepsLimitExceeded(limit) => format!(
+                "Search of regions exceeded the limit of {} steps, the horizon of states should be decreased",
+                limit
+            ),
+        }
+    }
+}
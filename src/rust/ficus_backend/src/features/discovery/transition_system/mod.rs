@@ -0,0 +1,3 @@
+pub mod error;
+pub mod regions;
+pub mod transition_system;
@@ -0,0 +1,405 @@
+use std::{cell::Cell, collections::HashSet};
+
+use crate::features::{
+    analysis::constants::FAKE_EVENT_START_NAME,
+    discovery::{
+        petri_net::{
+            marking::{Marking, SingleMarking},
+            petri_net::DefaultPetriNet,
+            place::Place,
+            transition::Transition,
+        },
+        transition_system::{error::RegionsSynthesisError, transition_system::TransitionSystem},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct StatesSet {
+    bits: Vec<u64>,
+}
+
+impl StatesSet {
+    fn empty(states_count: usize) -> Self {
+        Self {
+            bits: vec![0; states_count.div_ceil(64)],
+        }
+    }
+
+    fn contains(&self, state: usize) -> bool {
+        self.bits[state / 64] & (1 << (state % 64)) != 0
+    }
+
+    fn insert(&mut self, state: usize) {
+        self.bits[state / 64] |= 1 << (state % 64);
+    }
+
+    fn len(&self) -> usize {
+        self.bits.iter().map(|bits| bits.count_ones() as usize).sum()
+    }
+
+    fn is_subset(&self, other: &StatesSet) -> bool {
+        self.bits.iter().zip(other.bits.iter()).all(|(first, second)| first & !second == 0)
+    }
+
+    fn intersect(&self, other: &StatesSet) -> StatesSet {
+        StatesSet {
+            bits: self
+                .bits
+                .iter()
+                .zip(other.bits.iter())
+                .map(|(first, second)| first & second)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Crossing {
+    Enter,
+    Exit,
+    NoCross,
+    Inside,
+}
+
+struct LabeledTransition {
+    from: usize,
+    to: usize,
+}
+
+struct Label {
+    name: String,
+    silent: bool,
+    transitions: Vec<usize>,
+}
+
+struct RegionsSearch<'a> {
+    states_count: usize,
+    transitions: &'a Vec<LabeledTransition>,
+    labels: &'a Vec<Label>,
+    steps: &'a Cell<usize>,
+    max_steps: usize,
+}
+
+impl<'a> RegionsSearch<'a> {
+    fn crossing(&self, region: &StatesSet, label: usize) -> Option<Crossing> {
+        let mut result = None;
+        for transition in &self.labels[label].transitions {
+            let transition = &self.transitions[*transition];
+            let crossing = match (region.contains(transition.from), region.contains(transition.to)) {
+                (false, true) => Crossing::Enter,
+                (true, false) => Crossing::Exit,
+                (true, true) => Crossing::Inside,
+                (false, false) => Crossing::NoCross,
+            };
+
+            let crossing = match (result, crossing) {
+                (Some(Crossing::Inside), Crossing::NoCross) | (Some(Crossing::NoCross), Crossing::Inside) => Crossing::NoCross,
+                (Some(previous), crossing) if previous != crossing => return None,
+                _ => crossing,
+            };
+
+            result = Some(crossing);
+        }
+
+        result
+    }
+
+    fn expand(&self, region: &StatesSet, label: usize, crossing: Crossing) -> Option<StatesSet> {
+        let mut expanded = region.clone();
+        for transition in &self.labels[label].transitions {
+            let transition = &self.transitions[*transition];
+            let (from, to) = (region.contains(transition.from), region.contains(transition.to));
+            match crossing {
+                Crossing::Enter if from => return None,
+                Crossing::Exit | Crossing::Inside if !from => expanded.insert(transition.from),
+                _ => {}
+            }
+
+            match crossing {
+                Crossing::Exit if to => return None,
+                Crossing::Enter | Crossing::Inside if !to => expanded.insert(transition.to),
+                Crossing::NoCross if from != to => {
+                    expanded.insert(transition.from);
+                    expanded.insert(transition.to);
+                }
+                _ => {}
+            }
+        }
+
+        Some(expanded)
+    }
+
+    //Regions only grow, an inconsistent label is made entering, exiting or not crossing in separate branches
+    fn find_minimal_regions(&self, label: usize, crossing: Crossing) -> Result<Vec<StatesSet>, RegionsSynthesisError> {
+        let start = match self.expand(&StatesSet::empty(self.states_count), label, crossing) {
+            Some(start) => start,
+            None => return Ok(vec![]),
+        };
+
+        let mut visited = HashSet::new();
+        let mut regions = vec![];
+        self.search(start, label, crossing, &mut visited, &mut regions)?;
+
+        Ok(minimize(regions))
+    }
+
+    fn search(
+        &self,
+        region: StatesSet,
+        label: usize,
+        crossing: Crossing,
+        visited: &mut HashSet<StatesSet>,
+        regions: &mut Vec<StatesSet>,
+    ) -> Result<(), RegionsSynthesisError> {
+        if region.len() == self.states_count || !visited.insert(region.clone()) {
+            return Ok(());
+        }
+
+        self.steps.set(self.steps.get() + 1);
+        if self.steps.get() > self.max_steps {
+            return Err(RegionsSynthesisError::SearchStepsLimitExceeded(self.max_steps));
+        }
+
+        match self.expand(&region, label, crossing) {
+            Some(expanded) if expanded == region => {}
+            Some(expanded) => return self.search(expanded, label, crossing, visited, regions),
+            None => return Ok(()),
+        }
+
+        for other_label in 0..self.labels.len() {
+            if other_label == label || self.crossing(&region, other_label).is_some() {
+                continue;
+            }
+
+            for other_crossing in [Crossing::Enter, Crossing::Exit, Crossing::NoCross] {
+                if let Some(expanded) = self.expand(&region, other_label, other_crossing) {
+                    self.search(expanded, label, crossing, visited, regions)?;
+                }
+            }
+
+            return Ok(());
+        }
+
+        regions.push(region);
+        Ok(())
+    }
+
+    //Minimal regions which contain all states where the label is enabled: either the label exits them or stays inside them
+    fn find_pre_regions(&self, label: usize) -> Result<Vec<StatesSet>, RegionsSynthesisError> {
+        let mut regions = self.find_minimal_regions(label, Crossing::Exit)?;
+        regions.extend(self.find_minimal_regions(label, Crossing::Inside)?);
+
+        Ok(minimize(regions))
+    }
+
+    fn is_excitation_closed(&self, label: usize, pre_regions: &Vec<StatesSet>) -> bool {
+        let mut excitation_region = StatesSet::empty(self.states_count);
+        for transition in &self.labels[label].transitions {
+            excitation_region.insert(self.transitions[*transition].from);
+        }
+
+        match pre_regions.split_first() {
+            None => false,
+            Some((first, rest)) => rest.iter().fold(first.clone(), |result, region| result.intersect(region)) == excitation_region,
+        }
+    }
+}
+
+fn minimize(regions: Vec<StatesSet>) -> Vec<StatesSet> {
+    let mut result: Vec<StatesSet> = vec![];
+    for region in &regions {
+        let is_minimal = regions.iter().all(|other| other == region || !other.is_subset(region));
+        if is_minimal && !result.contains(region) {
+            result.push(region.clone());
+        }
+    }
+
+    result
+}
+
+pub const DEFAULT_MAX_REGIONS_SEARCH_STEPS: usize = 1_000_000;
+
+pub struct RegionsPetriNet {
+    petri_net: DefaultPetriNet,
+    not_excitation_closed_labels: Vec<String>,
+}
+
+impl RegionsPetriNet {
+    pub fn petri_net(&self) -> &DefaultPetriNet {
+        &self.petri_net
+    }
+
+    pub fn into_petri_net(self) -> DefaultPetriNet {
+        self.petri_net
+    }
+
+    //Labels with one transition which are still not excitation closed
+    pub fn not_excitation_closed_labels(&self) -> &Vec<String> {
+        &self.not_excitation_closed_labels
+    }
+}
+
+//State-based regions synthesis (Cortadella et al.), labels which are not excitation closed are split per transition
+pub fn synthesize_petri_net_from_regions(
+    system: &TransitionSystem,
+    max_search_steps: usize,
+) -> Result<RegionsPetriNet, RegionsSynthesisError> {
+    let mut petri_net = DefaultPetriNet::empty();
+    if system.states().is_empty() {
+        return Ok(RegionsPetriNet {
+            petri_net,
+            not_excitation_closed_labels: vec![],
+        });
+    }
+
+    let mut states_count = system.states().len();
+    let mut transitions = vec![];
+    let mut labels: Vec<Label> = vec![];
+
+    for transition in system.transitions() {
+        transitions.push(LabeledTransition {
+            from: transition.from(),
+            to: transition.to(),
+        });
+
+        add_to_label(&mut labels, transition.label(), false, transitions.len() - 1);
+    }
+
+    let initial_state = match system.initial_states().as_slice() {
+        [initial_state] => *initial_state,
+        initial_states => {
+            for state in initial_states {
+                transitions.push(LabeledTransition {
+                    from: states_count,
+                    to: *state,
+                });
+
+                add_to_label(&mut labels, FAKE_EVENT_START_NAME, true, transitions.len() - 1);
+            }
+
+            states_count += 1;
+            states_count - 1
+        }
+    };
+
+    let steps = Cell::new(0);
+    let (pre_regions, not_excitation_closed_labels) = loop {
+        let search = RegionsSearch {
+            states_count,
+            transitions: &transitions,
+            labels: &labels,
+            steps: &steps,
+            max_steps: max_search_steps,
+        };
+
+        let pre_regions = (0..labels.len())
+            .map(|label| search.find_pre_regions(label))
+            .collect::<Result<Vec<Vec<StatesSet>>, RegionsSynthesisError>>()?;
+        let (not_closed, not_closed_single): (HashSet<usize>, HashSet<usize>) = (0..labels.len())
+            .filter(|label| !search.is_excitation_closed(*label, &pre_regions[*label]))
+            .partition(|label| labels[*label].transitions.len() > 1);
+
+        if not_closed.is_empty() {
+            let mut not_closed_labels = not_closed_single
+                .iter()
+                .map(|label| labels[*label].name.to_owned())
+                .collect::<Vec<String>>();
+            not_closed_labels.sort();
+            not_closed_labels.dedup();
+
+            break (pre_regions, not_closed_labels);
+        }
+
+        let mut split_labels = vec![];
+        for (index, label) in labels.into_iter().enumerate() {
+            if !not_closed.contains(&index) {
+                split_labels.push(label);
+                continue;
+            }
+
+            for transition in label.transitions {
+                split_labels.push(Label {
+                    name: label.name.to_owned(),
+                    silent: label.silent,
+                    transitions: vec![transition],
+                });
+            }
+        }
+
+        labels = split_labels;
+    };
+
+    let search = RegionsSearch {
+        states_count,
+        transitions: &transitions,
+        labels: &labels,
+        steps: &steps,
+        max_steps: max_search_steps,
+    };
+
+    let mut places: Vec<StatesSet> = vec![];
+    for (label, label_pre_regions) in pre_regions.iter().enumerate() {
+        for region in label_pre_regions
+            .iter()
+            .chain(search.find_minimal_regions(label, Crossing::Enter)?.iter())
+        {
+            if !places.contains(region) {
+                places.push(region.clone());
+            }
+        }
+    }
+
+    let places_ids = (0..places.len())
+        .map(|index| petri_net.add_place(Place::with_name(format!("region_{}", index))))
+        .collect::<Vec<u64>>();
+
+    for (index, label) in labels.iter().enumerate() {
+        let transition = Transition::empty(label.name.to_owned(), label.silent, Some(label.name.to_owned()));
+        let transition_id = petri_net.add_transition(transition);
+
+        for (place, region) in places.iter().enumerate() {
+            let crossing = search.crossing(region, index);
+            let is_self_loop = crossing == Some(Crossing::Inside) && pre_regions[index].contains(region);
+
+            if crossing == Some(Crossing::Exit) || is_self_loop {
+                petri_net.connect_place_to_transition(&places_ids[place], &transition_id, None);
+            }
+
+            if crossing == Some(Crossing::Enter) || is_self_loop {
+                petri_net.connect_transition_to_place(&transition_id, &places_ids[place], None);
+            }
+        }
+    }
+
+    petri_net.set_initial_marking(create_marking(&places, &places_ids, initial_state));
+    if let [final_state] = system.final_states().as_slice() {
+        petri_net.set_final_marking(create_marking(&places, &places_ids, *final_state));
+    }
+
+    Ok(RegionsPetriNet {
+        petri_net,
+        not_excitation_closed_labels,
+    })
+}
+
+fn add_to_label(labels: &mut Vec<Label>, name: &str, silent: bool, transition: usize) {
+    match labels.iter_mut().find(|label| label.name == name) {
+        Some(label) => label.transitions.push(transition),
+        None => labels.push(Label {
+            name: name.to_owned(),
+            silent,
+            transitions: vec![transition],
+        }),
+    }
+}
+
+fn create_marking(places: &Vec<StatesSet>, places_ids: &Vec<u64>, state: usize) -> Marking {
+    let single_markings = places
+        .iter()
+        .enumerate()
+        .filter(|(_, region)| region.contains(state))
+        .map(|(index, _)| SingleMarking::new(places_ids[index], 1))
+        .collect();
+
+    Marking::new(single_markings)
+}
@@ -0,0 +1,241 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{event_log::core::event_log::EventLog, utils::graph::graph::DefaultGraph};
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum StateAbstraction {
+    Sequence,
+    Set,
+    Multiset,
+}
+
+impl FromStr for StateAbstraction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Sequence" => Ok(Self::Sequence),
+            "Set" => Ok(Self::Set),
+            "Multiset" => Ok(Self::Multiset),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum StateDirection {
+    Past,
+    Future,
+}
+
+impl FromStr for StateDirection {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Past" => Ok(Self::Past),
+            "Future" => Ok(Self::Future),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TransitionSystemParams {
+    pub abstraction: StateAbstraction,
+    pub direction: StateDirection,
+    pub horizon: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransitionSystemTransition {
+    from: usize,
+    to: usize,
+    label: String,
+    count: usize,
+}
+
+impl TransitionSystemTransition {
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    pub fn label(&self) -> &String {
+        &self.label
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+//States are abstractions of the events before (past) or after (future) the position in the trace, if the horizon is set
+//only the nearest horizon events are considered. States and transitions are numbered in the order of their first occurrence
+#[derive(Clone, Debug)]
+pub struct TransitionSystem {
+    params: TransitionSystemParams,
+    states: Vec<Vec<String>>,
+    states_ids: HashMap<Vec<String>, usize>,
+    transitions: Vec<TransitionSystemTransition>,
+    initial_states: Vec<usize>,
+    final_states: Vec<usize>,
+}
+
+impl TransitionSystem {
+    pub fn build(log: &impl EventLog, params: &TransitionSystemParams) -> Self {
+        let mut system = Self {
+            params: *params,
+            states: vec![],
+            states_ids: HashMap::new(),
+            transitions: vec![],
+            initial_states: vec![],
+            final_states: vec![],
+        };
+
+        let mut transitions_ids: HashMap<(usize, String, usize), usize> = HashMap::new();
+
+        for names in log.to_raw_vector() {
+            let mut previous_state = None;
+            for position in 0..=names.len() {
+                let key = abstract_state(&names, position, params);
+                let state = match system.states_ids.get(&key) {
+                    Some(state) => *state,
+                    None => {
+                        system.states_ids.insert(key.clone(), system.states.len());
+                        system.states.push(key);
+                        system.states.len() - 1
+                    }
+                };
+
+                match previous_state {
+                    None => {
+                        if !system.initial_states.contains(&state) {
+                            system.initial_states.push(state);
+                        }
+                    }
+                    Some(previous_state) => {
+                        let transition_key = (previous_state, names[position - 1].to_owned(), state);
+                        match transitions_ids.get(&transition_key) {
+                            Some(index) => system.transitions[*index].count += 1,
+                            None => {
+                                transitions_ids.insert(transition_key, system.transitions.len());
+                                system.transitions.push(TransitionSystemTransition {
+                                    from: previous_state,
+                                    to: state,
+                                    label: names[position - 1].to_owned(),
+                                    count: 1,
+                                });
+                            }
+                        }
+                    }
+                }
+
+                previous_state = Some(state);
+            }
+
+            if let Some(state) = previous_state {
+                if !system.final_states.contains(&state) {
+                    system.final_states.push(state);
+                }
+            }
+        }
+
+        system
+    }
+
+    pub fn params(&self) -> &TransitionSystemParams {
+        &self.params
+    }
+
+    pub fn states(&self) -> &Vec<Vec<String>> {
+        &self.states
+    }
+
+    pub fn transitions(&self) -> &Vec<TransitionSystemTransition> {
+        &self.transitions
+    }
+
+    pub fn initial_states(&self) -> &Vec<usize> {
+        &self.initial_states
+    }
+
+    pub fn final_states(&self) -> &Vec<usize> {
+        &self.final_states
+    }
+
+    pub fn find_state(&self, key: &[String]) -> Option<usize> {
+        self.states_ids.get(key).copied()
+    }
+
+    //Abstraction of the events before or after the position in the trace with the params of this transition system
+    pub fn abstract_state(&self, names: &[String], position: usize) -> Vec<String> {
+        abstract_state(names, position, &self.params)
+    }
+
+    pub fn to_graph(&self) -> DefaultGraph {
+        self.to_annotated_graph(|_| None)
+    }
+
+    //Nodes are labeled with states events followed by the annotation of the state if there is one, edges are labeled with
+    //activities and counts, transitions between the same states by different activities share the edge
+    pub fn to_annotated_graph(&self, annotation: impl Fn(usize) -> Option<String>) -> DefaultGraph {
+        let mut graph = DefaultGraph::empty();
+        let ids = self
+            .states
+            .iter()
+            .enumerate()
+            .map(|(index, state)| {
+                let label = match annotation(index) {
+                    Some(annotation) => format!("[{}] {}", state.join(", "), annotation),
+                    None => format!("[{}]", state.join(", ")),
+                };
+
+                graph.add_node(Some(label))
+            })
+            .collect::<Vec<u64>>();
+
+        let mut edges_labels: HashMap<(usize, usize), Vec<String>> = HashMap::new();
+        for transition in &self.transitions {
+            let label = format!("{} ({})", transition.label, transition.count);
+            edges_labels.entry((transition.from, transition.to)).or_default().push(label);
+        }
+
+        let mut edges_labels = edges_labels.into_iter().collect::<Vec<((usize, usize), Vec<String>)>>();
+        edges_labels.sort_by(|first, second| first.0.cmp(&second.0));
+
+        for ((from, to), labels) in edges_labels {
+            graph.connect_nodes(&ids[from], &ids[to], Some(labels.join(", ")));
+        }
+
+        graph
+    }
+}
+
+fn abstract_state(names: &[String], position: usize, params: &TransitionSystemParams) -> Vec<String> {
+    let (start, end) = match params.direction {
+        StateDirection::Past => match params.horizon {
+            Some(horizon) => (position.saturating_sub(horizon), position),
+            None => (0, position),
+        },
+        StateDirection::Future => match params.horizon {
+            Some(horizon) => (position, (position + horizon).min(names.len())),
+            None => (position, names.len()),
+        },
+    };
+
+    let mut state = names[start..end].to_vec();
+    match params.abstraction {
+        StateAbstraction::Sequence => {}
+        StateAbstraction::Multiset => state.sort(),
+        StateAbstraction::Set => {
+            state.sort();
+            state.dedup();
+        }
+    }
+
+    state
+}
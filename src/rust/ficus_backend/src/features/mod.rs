@@ -2,3 +2,5 @@ pub mod analysis;
 pub mod clustering;
 pub mod discovery;
 pub mod mutations;
+pub mod prediction;
+pub mod streaming;
@@ -0,0 +1,14 @@
+use crate::event_log::core::event_log::EventLog;
+use crate::features::analysis::concept_drift::{create_window_log, order_traces_by_start_time};
+
+//Traces are ordered by the timestamp of their first event, the first train_fraction of them form the train log,
+//the rest form the test log, so that models are evaluated on the cases which started after the training ones
+pub fn split_log_temporal<TLog: EventLog>(log: &TLog, train_fraction: f64) -> (TLog, TLog) {
+    let ordered_traces = order_traces_by_start_time(log);
+    let train_count = ((ordered_traces.len() as f64 * train_fraction).round() as usize).min(ordered_traces.len());
+
+    (
+        create_window_log(log, &ordered_traces[..train_count]),
+        create_window_log(log, &ordered_traces[train_count..]),
+    )
+}
@@ -0,0 +1,3 @@
+pub mod holdout;
+pub mod next_activity;
+pub mod remaining_time;
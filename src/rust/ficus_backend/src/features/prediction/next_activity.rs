@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use crate::{
+    event_log::core::event_log::EventLog,
+    features::analysis::constants::{FAKE_EVENT_END_NAME, FAKE_EVENT_START_NAME},
+};
+
+pub struct NextActivityPrediction {
+    activity: String,
+    probability: f64,
+    context_length: usize,
+}
+
+impl NextActivityPrediction {
+    pub fn activity(&self) -> &String {
+        &self.activity
+    }
+
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    pub fn context_length(&self) -> usize {
+        self.context_length
+    }
+}
+
+//Variable-order n-gram model over event classes. For each context of length from 0 to order the counts of the following
+//event classes are stored, traces are preceded by FAKE_EVENT_START_NAME and followed by FAKE_EVENT_END_NAME. Prediction backs
+//off from the longest context of the prefix to the shorter ones until the context which was observed in the train log is found
+#[derive(Clone, Debug)]
+pub struct NextActivityModel {
+    order: usize,
+    followers: HashMap<Vec<String>, HashMap<String, usize>>,
+}
+
+impl NextActivityModel {
+    pub fn train(log: &impl EventLog, order: usize) -> Self {
+        let mut followers: HashMap<Vec<String>, HashMap<String, usize>> = HashMap::new();
+        for names in log.to_raw_vector() {
+            let names = add_start_event(&names);
+            for index in 1..=names.len() {
+                let next = match names.get(index) {
+                    Some(name) => name.to_owned(),
+                    None => FAKE_EVENT_END_NAME.to_owned(),
+                };
+
+                for context_length in 0..=order.min(index) {
+                    let context = names[(index - context_length)..index].to_vec();
+                    *followers.entry(context).or_default().entry(next.to_owned()).or_default() += 1;
+                }
+            }
+        }
+
+        Self { order, followers }
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    //All event classes which can be predicted, including FAKE_EVENT_END_NAME, sorted by name
+    pub fn event_classes(&self) -> Vec<String> {
+        let mut classes = match self.followers.get(&vec![]) {
+            Some(followers) => followers.keys().map(|class| class.to_owned()).collect::<Vec<String>>(),
+            None => vec![],
+        };
+
+        classes.sort();
+        classes
+    }
+
+    //Returns the length of the used context and the probabilities of the next event classes
+    pub fn distribution(&self, prefix: &[String]) -> Option<(usize, HashMap<String, f64>)> {
+        let prefix = add_start_event(prefix);
+        for context_length in (0..=self.order.min(prefix.len())).rev() {
+            if let Some(followers) = self.followers.get(&prefix[(prefix.len() - context_length)..]) {
+                let total = followers.values().sum::<usize>() as f64;
+                let probabilities = followers
+                    .iter()
+                    .map(|(class, count)| (class.to_owned(), *count as f64 / total))
+                    .collect();
+
+                return Some((context_length, probabilities));
+            }
+        }
+
+        None
+    }
+
+    //The most probable event class is predicted, ties are resolved by the name of the event class
+    pub fn predict(&self, prefix: &[String]) -> Option<NextActivityPrediction> {
+        let (context_length, probabilities) = self.distribution(prefix)?;
+        let (activity, probability) = probabilities
+            .into_iter()
+            .max_by(|first, second| first.1.total_cmp(&second.1).then(second.0.cmp(&first.0)))?;
+
+        Some(NextActivityPrediction {
+            activity,
+            probability,
+            context_length,
+        })
+    }
+
+    //Accuracy of the predictions for all prefixes of all traces, including the prediction of the end of the trace
+    pub fn evaluate(&self, log: &impl EventLog) -> f64 {
+        let mut predictions_count = 0;
+        let mut correct_predictions_count = 0;
+
+        for names in log.to_raw_vector() {
+            for index in 0..=names.len() {
+                let expected = match names.get(index) {
+                    Some(name) => name.as_str(),
+                    None => FAKE_EVENT_END_NAME,
+                };
+
+                predictions_count += 1;
+                if self
+                    .predict(&names[..index])
+                    .is_some_and(|prediction| prediction.activity == expected)
+                {
+                    correct_predictions_count += 1;
+                }
+            }
+        }
+
+        match predictions_count {
+            0 => 0.0,
+            _ => correct_predictions_count as f64 / predictions_count as f64,
+        }
+    }
+}
+
+fn add_start_event(names: &[String]) -> Vec<String> {
+    let mut result = vec![FAKE_EVENT_START_NAME.to_owned()];
+    result.extend(names.iter().cloned());
+    result
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    event_log::core::{event::event::Event, event_log::EventLog, trace::trace::Trace},
+    features::discovery::transition_system::transition_system::{
+        StateAbstraction, StateDirection, TransitionSystem, TransitionSystemParams,
+    },
+    utils::graph::graph::DefaultGraph,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct StateTimeAnnotation {
+    visits: usize,
+    elapsed_sum: f64,
+    remaining_sum: f64,
+}
+
+impl StateTimeAnnotation {
+    pub fn visits(&self) -> usize {
+        self.visits
+    }
+
+    pub fn mean_elapsed(&self) -> f64 {
+        self.elapsed_sum / self.visits as f64
+    }
+
+    pub fn mean_remaining(&self) -> f64 {
+        self.remaining_sum / self.visits as f64
+    }
+}
+
+//Transition system with past states (only the last horizon events are considered if the horizon is set), each state is
+//annotated with the elapsed and remaining times (in seconds) of the prefixes which visited it. Times are measured from
+//the first event of the trace. Remaining time of the prefix is the mean remaining time of its state, or the mean remaining
+//time of all visits of all states if the state was not observed in the train log
+#[derive(Clone, Debug)]
+pub struct RemainingTimeModel {
+    system: TransitionSystem,
+    annotations: Vec<StateTimeAnnotation>,
+    fallback: StateTimeAnnotation,
+}
+
+impl RemainingTimeModel {
+    pub fn train(log: &impl EventLog, abstraction: StateAbstraction, horizon: Option<usize>) -> Self {
+        let params = TransitionSystemParams {
+            abstraction,
+            direction: StateDirection::Past,
+            horizon,
+        };
+
+        let system = TransitionSystem::build(log, &params);
+        let mut annotations = vec![StateTimeAnnotation::default(); system.states().len()];
+        let mut fallback = StateTimeAnnotation::default();
+
+        for trace in log.traces() {
+            let (names, stamps) = get_names_and_stamps(&*trace.borrow());
+            if names.is_empty() {
+                continue;
+            }
+
+            for length in 0..=names.len() {
+                let state = system.find_state(&system.abstract_state(&names, length)).unwrap();
+                let reference = stamps[length.max(1) - 1];
+                let elapsed = seconds_between(&stamps[0], &reference);
+                let remaining = seconds_between(&reference, &stamps[stamps.len() - 1]);
+
+                for annotation in [&mut annotations[state], &mut fallback] {
+                    annotation.visits += 1;
+                    annotation.elapsed_sum += elapsed;
+                    annotation.remaining_sum += remaining;
+                }
+            }
+        }
+
+        Self {
+            system,
+            annotations,
+            fallback,
+        }
+    }
+
+    pub fn system(&self) -> &TransitionSystem {
+        &self.system
+    }
+
+    pub fn state(&self, prefix: &[String]) -> Option<&StateTimeAnnotation> {
+        match self.system.find_state(&self.system.abstract_state(prefix, prefix.len())) {
+            Some(state) if self.annotations[state].visits > 0 => Some(&self.annotations[state]),
+            _ => None,
+        }
+    }
+
+    pub fn predict(&self, prefix: &[String]) -> Option<f64> {
+        match self.state(prefix) {
+            Some(state) => Some(state.mean_remaining()),
+            None if self.fallback.visits > 0 => Some(self.fallback.mean_remaining()),
+            None => None,
+        }
+    }
+
+    //Mean absolute error (in seconds) of the predictions for all non-empty prefixes of all traces
+    pub fn evaluate(&self, log: &impl EventLog) -> f64 {
+        let mut errors_sum = 0.0;
+        let mut predictions_count = 0;
+
+        for trace in log.traces() {
+            let (names, stamps) = get_names_and_stamps(&*trace.borrow());
+            for length in 1..=names.len() {
+                let remaining = seconds_between(&stamps[length - 1], &stamps[stamps.len() - 1]);
+                errors_sum += (self.predict(&names[..length]).unwrap_or(0.0) - remaining).abs();
+                predictions_count += 1;
+            }
+        }
+
+        match predictions_count {
+            0 => 0.0,
+            _ => errors_sum / predictions_count as f64,
+        }
+    }
+
+    //Nodes are states labeled with their events and the mean remaining time
+    pub fn to_graph(&self) -> DefaultGraph {
+        self.system
+            .to_annotated_graph(|state| Some(format!("{:.2}", self.annotations[state].mean_remaining())))
+    }
+}
+
+fn get_names_and_stamps(trace: &impl Trace) -> (Vec<String>, Vec<DateTime<Utc>>) {
+    trace
+        .events()
+        .iter()
+        .map(|event| (event.borrow().name().to_owned(), *event.borrow().timestamp()))
+        .unzip()
+}
+
+fn seconds_between(first: &DateTime<Utc>, second: &DateTime<Utc>) -> f64 {
+    (*second - *first).num_milliseconds() as f64 / 1000.0
+}
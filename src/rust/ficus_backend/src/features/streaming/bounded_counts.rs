@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+//Counts of items observed over a stream. Each item stores its frequency and the maximum error of this
+//frequency, which is the index of the lossy counting bucket preceding the one where the item was inserted
+#[derive(Clone)]
+pub struct BoundedCounts<TKey>
+where
+    TKey: Hash + Eq + Clone,
+{
+    counts: HashMap<TKey, (usize, usize)>,
+}
+
+impl<TKey> BoundedCounts<TKey>
+where
+    TKey: Hash + Eq + Clone,
+{
+    pub fn empty() -> Self {
+        Self { counts: HashMap::new() }
+    }
+
+    pub fn increment(&mut self, key: &TKey, current_bucket: usize) {
+        if let Some((frequency, _)) = self.counts.get_mut(key) {
+            *frequency += 1;
+        } else {
+            self.counts.insert(key.clone(), (1, current_bucket.saturating_sub(1)));
+        }
+    }
+
+    //Item is removed when its frequency becomes zero
+    pub fn decrement(&mut self, key: &TKey) {
+        if let Some((frequency, _)) = self.counts.get_mut(key) {
+            *frequency -= 1;
+            if *frequency == 0 {
+                self.counts.remove(key);
+            }
+        }
+    }
+
+    //Removes items whose frequency plus maximum error does not exceed the current bucket, returns removed items
+    pub fn prune(&mut self, current_bucket: usize) -> Vec<TKey> {
+        let pruned = self
+            .counts
+            .iter()
+            .filter(|(_, (frequency, error))| frequency + error <= current_bucket)
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<TKey>>();
+
+        for key in &pruned {
+            self.counts.remove(key);
+        }
+
+        pruned
+    }
+
+    pub fn frequency(&self, key: &TKey) -> usize {
+        match self.counts.get(key) {
+            Some((frequency, _)) => *frequency,
+            None => 0,
+        }
+    }
+
+    pub fn contains(&self, key: &TKey) -> bool {
+        self.counts.contains_key(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&TKey, usize)> {
+        self.counts.iter().map(|(key, (frequency, _))| (key, *frequency))
+    }
+}
@@ -0,0 +1,21 @@
+use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
+
+pub enum StreamingError {
+    FailedToParseEvent(String),
+    IOError(String),
+}
+
+impl Into<PipelinePartExecutionError> for StreamingError {
+    fn into(self) -> PipelinePartExecutionError {
+        PipelinePartExecutionError::Raw(RawPartExecutionError::new(self.to_string()))
+    }
+}
+
+impl ToString for StreamingError {
+    fn to_string(&self) -> String {
+        match self {
+            Self::FailedToParseEvent(line) => format!("Failed to parse event from line: {}", line),
+            Self::IOError(message) => message.clone(),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::str::FromStr;
+
+use crate::features::streaming::{
+    error::StreamingError,
+    stream_event::StreamEvent,
+    streaming_discovery::{StreamingDiscovery, StreamingDiscoveryParams},
+};
+
+//Follows the file to which events are appended, one event per line
+#[derive(Clone)]
+pub struct EventsFileTail {
+    path: String,
+    offset: u64,
+}
+
+impl EventsFileTail {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+            offset: 0,
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    //Feeds the events from complete lines appended since the previous call, the trailing line without line break
+    //is left for the next call. Lines are fed one by one, if the line can not be parsed the error is returned and
+    //the offset stays at this line. Returns the count of fed events
+    pub fn feed(&mut self, discovery: &mut StreamingDiscovery) -> Result<usize, StreamingError> {
+        let mut reader = match File::open(&self.path).and_then(|mut file| {
+            file.seek(SeekFrom::Start(self.offset))?;
+            Ok(BufReader::new(file))
+        }) {
+            Ok(reader) => reader,
+            Err(error) => return Err(StreamingError::IOError(error.to_string())),
+        };
+
+        let mut fed_count = 0;
+        let mut line = vec![];
+        loop {
+            line.clear();
+            let read_count = match reader.read_until(b'\n', &mut line) {
+                Ok(read_count) => read_count,
+                Err(error) => return Err(StreamingError::IOError(error.to_string())),
+            };
+
+            if read_count == 0 || line.last() != Some(&b'\n') {
+                return Ok(fed_count);
+            }
+
+            let content = match std::str::from_utf8(&line) {
+                Ok(content) => content.trim(),
+                Err(error) => return Err(StreamingError::IOError(error.to_string())),
+            };
+
+            if !content.is_empty() {
+                discovery.observe(&StreamEvent::from_str(content)?);
+                fed_count += 1;
+            }
+
+            self.offset += read_count as u64;
+        }
+    }
+}
+
+//Discovery over the followed file, it is kept between executions, so that only appended events are read
+#[derive(Clone)]
+pub struct EventsFileDiscovery {
+    tail: EventsFileTail,
+    discovery: StreamingDiscovery,
+}
+
+impl EventsFileDiscovery {
+    pub fn new(path: &str, params: &StreamingDiscoveryParams) -> Self {
+        Self {
+            tail: EventsFileTail::new(path),
+            discovery: StreamingDiscovery::new(params),
+        }
+    }
+
+    pub fn tail(&self) -> &EventsFileTail {
+        &self.tail
+    }
+
+    pub fn discovery(&self) -> &StreamingDiscovery {
+        &self.discovery
+    }
+
+    pub fn feed(&mut self) -> Result<usize, StreamingError> {
+        self.tail.feed(&mut self.discovery)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod bounded_counts;
+pub mod error;
+pub mod events_file_tail;
+pub mod stream_event;
+pub mod streaming_discovery;
@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::features::streaming::error::StreamingError;
+
+pub struct StreamEvent {
+    case_id: String,
+    activity: String,
+    timestamp: DateTime<Utc>,
+}
+
+impl StreamEvent {
+    pub fn new(case_id: String, activity: String, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            case_id,
+            activity,
+            timestamp,
+        }
+    }
+
+    pub fn case_id(&self) -> &String {
+        &self.case_id
+    }
+
+    pub fn activity(&self) -> &String {
+        &self.activity
+    }
+
+    pub fn timestamp(&self) -> &DateTime<Utc> {
+        &self.timestamp
+    }
+}
+
+//Event is parsed from the line "case_id,activity,timestamp", timestamp is in RFC 3339 format
+impl FromStr for StreamEvent {
+    type Err = StreamingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(',').map(|part| part.trim()).collect::<Vec<&str>>();
+        if parts.len() != 3 || parts[0].is_empty() || parts[1].is_empty() {
+            return Err(StreamingError::FailedToParseEvent(s.to_owned()));
+        }
+
+        match DateTime::parse_from_rfc3339(parts[2]) {
+            Ok(timestamp) => Ok(Self::new(parts[0].to_owned(), parts[1].to_owned(), timestamp.with_timezone(&Utc))),
+            Err(_) => Err(StreamingError::FailedToParseEvent(s.to_owned())),
+        }
+    }
+}
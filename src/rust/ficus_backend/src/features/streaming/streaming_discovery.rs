@@ -0,0 +1,364 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
+    rc::Rc,
+    str::FromStr,
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    event_log::{
+        core::{event::event::Event, event_log::EventLog, trace::trace::Trace},
+        xes::{xes_event::XesEventImpl, xes_event_log::XesEventLogImpl, xes_trace::XesTraceImpl},
+    },
+    features::{
+        analysis::{directly_follows_graph::construct_dfg, event_log_info::EventLogInfo},
+        discovery::{
+            fuzzy::fuzzy_miner::{discover_graph_fuzzy, FuzzyGraph},
+            heuristic::heuristic_miner::discover_petri_net_heuristic_from_relations,
+            petri_net::petri_net::DefaultPetriNet,
+        },
+        streaming::{bounded_counts::BoundedCounts, stream_event::StreamEvent},
+    },
+    utils::graph::graph::DefaultGraph,
+};
+
+pub const DEFAULT_MAX_CASE_EVENTS: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StreamingCountsMode {
+    //Counts are approximate, underestimated by at most error * observed events count (Manku & Motwani)
+    LossyCounting { error: f64 },
+    //Counts are exact for the last size events
+    SlidingWindow { size: usize },
+}
+
+#[derive(Clone, Copy, Debug, Hash)]
+pub enum StreamingModel {
+    Dfg,
+    HeuristicNet,
+    FuzzyGraph,
+}
+
+impl FromStr for StreamingModel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Dfg" => Ok(Self::Dfg),
+            "HeuristicNet" => Ok(Self::HeuristicNet),
+            "FuzzyGraph" => Ok(Self::FuzzyGraph),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct StreamingDiscoveryParams {
+    pub mode: StreamingCountsMode,
+    pub max_case_events: usize,
+}
+
+impl StreamingDiscoveryParams {
+    pub fn new(mode: StreamingCountsMode) -> Self {
+        Self {
+            mode,
+            max_case_events: DEFAULT_MAX_CASE_EVENTS,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct CaseState {
+    events: VecDeque<(String, DateTime<Utc>)>,
+    window_events: usize,
+}
+
+#[derive(Clone)]
+struct WindowEntry {
+    case_id: String,
+    activity: String,
+    relation: Option<(String, String)>,
+    triangle: Option<(String, String)>,
+    is_start: bool,
+}
+
+//Maintains directly-follows relations over the stream of events in bounded memory. Each case keeps at most
+//max_case_events last events, which are used to continue relations and to build the fuzzy graph. With lossy counting
+//activities, relations, start activities and cases which are infrequent are pruned at the buckets boundaries,
+//with sliding window all counts are decremented when the event leaves the window and the case is forgotten
+//when all its events left the window
+#[derive(Clone)]
+pub struct StreamingDiscovery {
+    mode: StreamingCountsMode,
+    max_case_events: usize,
+    observed_events_count: usize,
+    activities: BoundedCounts<String>,
+    relations: BoundedCounts<(String, String)>,
+    triangles: BoundedCounts<(String, String)>,
+    start_activities: BoundedCounts<String>,
+    cases_counts: BoundedCounts<String>,
+    cases: HashMap<String, CaseState>,
+    window: VecDeque<WindowEntry>,
+}
+
+impl StreamingDiscovery {
+    pub fn new(params: &StreamingDiscoveryParams) -> Self {
+        Self {
+            mode: params.mode,
+            max_case_events: params.max_case_events.max(2),
+            observed_events_count: 0,
+            activities: BoundedCounts::empty(),
+            relations: BoundedCounts::empty(),
+            triangles: BoundedCounts::empty(),
+            start_activities: BoundedCounts::empty(),
+            cases_counts: BoundedCounts::empty(),
+            cases: HashMap::new(),
+            window: VecDeque::new(),
+        }
+    }
+
+    pub fn mode(&self) -> StreamingCountsMode {
+        self.mode
+    }
+
+    pub fn observed_events_count(&self) -> usize {
+        self.observed_events_count
+    }
+
+    pub fn retained_cases_count(&self) -> usize {
+        self.cases.len()
+    }
+
+    pub fn observe(&mut self, event: &StreamEvent) {
+        self.observed_events_count += 1;
+        let bucket = self.current_bucket();
+
+        let case = self.cases.entry(event.case_id().to_owned()).or_insert_with(|| CaseState {
+            events: VecDeque::new(),
+            window_events: 0,
+        });
+
+        let is_start = case.events.is_empty();
+        let relation = case.events.back().map(|(last, _)| (last.to_owned(), event.activity().to_owned()));
+        let triangle = match case.events.len() {
+            len if len >= 2 && &case.events[len - 2].0 == event.activity() => {
+                Some((event.activity().to_owned(), case.events[len - 1].0.to_owned()))
+            }
+            _ => None,
+        };
+
+        case.events.push_back((event.activity().to_owned(), *event.timestamp()));
+        case.window_events += 1;
+        if case.events.len() > self.max_case_events {
+            case.events.pop_front();
+        }
+
+        self.activities.increment(event.activity(), bucket);
+        self.cases_counts.increment(event.case_id(), bucket);
+
+        if is_start {
+            self.start_activities.increment(event.activity(), bucket);
+        }
+
+        if let Some(relation) = relation.as_ref() {
+            self.relations.increment(relation, bucket);
+        }
+
+        if let Some(triangle) = triangle.as_ref() {
+            self.triangles.increment(triangle, bucket);
+        }
+
+        match self.mode {
+            StreamingCountsMode::LossyCounting { error } => {
+                if self.observed_events_count % Self::bucket_width(error) == 0 {
+                    self.prune(bucket);
+                }
+            }
+            StreamingCountsMode::SlidingWindow { size } => {
+                self.window.push_back(WindowEntry {
+                    case_id: event.case_id().to_owned(),
+                    activity: event.activity().to_owned(),
+                    relation,
+                    triangle,
+                    is_start,
+                });
+
+                while self.window.len() > size {
+                    self.evict_oldest_event();
+                }
+            }
+        }
+    }
+
+    fn bucket_width(error: f64) -> usize {
+        ((1.0 / error).ceil() as usize).max(1)
+    }
+
+    fn current_bucket(&self) -> usize {
+        match self.mode {
+            StreamingCountsMode::LossyCounting { error } => (self.observed_events_count - 1) / Self::bucket_width(error) + 1,
+            StreamingCountsMode::SlidingWindow { .. } => 0,
+        }
+    }
+
+    fn prune(&mut self, bucket: usize) {
+        self.activities.prune(bucket);
+        self.relations.prune(bucket);
+        self.triangles.prune(bucket);
+        self.start_activities.prune(bucket);
+
+        for case_id in self.cases_counts.prune(bucket) {
+            self.cases.remove(&case_id);
+        }
+    }
+
+    fn evict_oldest_event(&mut self) {
+        let entry = match self.window.pop_front() {
+            Some(entry) => entry,
+            None => return,
+        };
+
+        self.activities.decrement(&entry.activity);
+        self.cases_counts.decrement(&entry.case_id);
+
+        if entry.is_start {
+            self.start_activities.decrement(&entry.activity);
+        }
+
+        if let Some(relation) = entry.relation.as_ref() {
+            self.relations.decrement(relation);
+        }
+
+        if let Some(triangle) = entry.triangle.as_ref() {
+            self.triangles.decrement(triangle);
+        }
+
+        if let Some(case) = self.cases.get_mut(&entry.case_id) {
+            case.window_events -= 1;
+            while case.events.len() > case.window_events {
+                case.events.pop_front();
+            }
+
+            if case.window_events == 0 {
+                self.cases.remove(&entry.case_id);
+            }
+        }
+    }
+
+    //Relations whose activities were pruned are skipped, end activities are the last activities of retained cases
+    pub fn event_log_info(&self) -> EventLogInfo {
+        let event_classes_counts = self
+            .activities
+            .iter()
+            .map(|(activity, count)| (activity.to_owned(), count))
+            .collect::<HashMap<String, usize>>();
+
+        let mut dfg_pairs: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        for ((first, second), count) in self.relations.iter() {
+            if !self.activities.contains(first) || !self.activities.contains(second) {
+                continue;
+            }
+
+            if let Some(followers) = dfg_pairs.get_mut(first) {
+                followers.insert(second.to_owned(), count);
+            } else {
+                dfg_pairs.insert(first.to_owned(), HashMap::from_iter(vec![(second.to_owned(), count)]));
+            }
+        }
+
+        let start_event_classes = self
+            .start_activities
+            .iter()
+            .filter(|(activity, _)| self.activities.contains(activity))
+            .map(|(activity, _)| activity.to_owned())
+            .collect::<HashSet<String>>();
+
+        let end_event_classes = self
+            .cases
+            .values()
+            .filter_map(|case| case.events.back())
+            .filter(|(activity, _)| self.activities.contains(activity))
+            .map(|(activity, _)| activity.to_owned())
+            .collect::<HashSet<String>>();
+
+        EventLogInfo::from_counts(
+            event_classes_counts.values().sum(),
+            event_classes_counts,
+            dfg_pairs,
+            self.cases.len(),
+            start_event_classes,
+            end_event_classes,
+        )
+    }
+
+    pub fn dfg(&self) -> DefaultGraph {
+        construct_dfg(&self.event_log_info())
+    }
+
+    pub fn heuristic_petri_net(
+        &self,
+        dependency_threshold: f64,
+        positive_observations_threshold: usize,
+        relative_to_best_threshold: f64,
+        and_threshold: f64,
+        loop_length_two_threshold: f64,
+    ) -> DefaultPetriNet {
+        let triangle_relations = self
+            .triangles
+            .iter()
+            .map(|(triangle, count)| (triangle.to_owned(), count))
+            .collect::<HashMap<(String, String), usize>>();
+
+        discover_petri_net_heuristic_from_relations(
+            &self.event_log_info(),
+            triangle_relations,
+            dependency_threshold,
+            positive_observations_threshold,
+            relative_to_best_threshold,
+            and_threshold,
+            loop_length_two_threshold,
+        )
+    }
+
+    //Fuzzy miner needs the whole traces, so the graph is discovered from the retained events of the retained cases
+    pub fn fuzzy_graph(
+        &self,
+        unary_frequency_threshold: f64,
+        binary_frequency_significance_threshold: f64,
+        preserve_threshold: f64,
+        ratio_threshold: f64,
+        utility_rate: f64,
+        edge_cutoff_threshold: f64,
+        node_cutoff_threshold: f64,
+    ) -> FuzzyGraph {
+        discover_graph_fuzzy(
+            &self.retained_cases_log(),
+            unary_frequency_threshold,
+            binary_frequency_significance_threshold,
+            preserve_threshold,
+            ratio_threshold,
+            utility_rate,
+            edge_cutoff_threshold,
+            node_cutoff_threshold,
+        )
+    }
+
+    //Traces are ordered by case id
+    pub fn retained_cases_log(&self) -> XesEventLogImpl {
+        let mut case_ids = self.cases.keys().collect::<Vec<&String>>();
+        case_ids.sort();
+
+        let mut log = XesEventLogImpl::empty();
+        for case_id in case_ids {
+            let mut trace = XesTraceImpl::empty();
+            for (activity, timestamp) in &self.cases.get(case_id).unwrap().events {
+                trace.push(Rc::new(RefCell::new(XesEventImpl::new(activity.to_owned(), *timestamp))));
+            }
+
+            log.push(Rc::new(RefCell::new(trace)));
+        }
+
+        log
+    }
+}
@@ -1,7 +1,6 @@
 use std::str::FromStr;
 use std::{
     any::Any,
-    collections::HashMap,
     pin::Pin,
     sync::{Arc, Mutex},
 };
@@ -9,26 +8,37 @@ use std::{
 use futures::Stream;
 use tokio::sync::mpsc::{self, Sender};
 use tokio_stream::wrappers::ReceiverStream;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 use uuid::Uuid;
 
 use super::{
+    context_store::{ContextStore, ContextStoreSettings},
     converters::{convert_to_grpc_context_value, create_initial_context, put_into_user_data},
+    event_log_upload::{read_uploaded_event_log, UploadedEventLogFormat, UPLOADED_CHUNKS_BUFFER_SIZE},
     get_context_pipeline::GetContextValuePipelinePart,
     logs_handler::LogMessageHandlerImpl,
 };
-use crate::pipelines::context::PipelineInfrastructure;
+use crate::event_log::xes::xes_event_log::XesEventLogImpl;
+use crate::features::streaming::events_file_tail::EventsFileDiscovery;
+use crate::pipelines::context::{PipelineContext, PipelineInfrastructure};
 use crate::{
     ficus_proto::{
         grpc_backend_service_server::GrpcBackendService, grpc_get_context_value_result::ContextValueResult,
-        grpc_pipeline_final_result::ExecutionResult, grpc_pipeline_part_base::Part, GrpcContextKeyValue, GrpcGetContextValueRequest,
-        GrpcGetContextValueResult, GrpcGuid, GrpcPipeline, GrpcPipelineExecutionRequest, GrpcPipelineFinalResult, GrpcPipelinePart,
-        GrpcPipelinePartExecutionResult,
+        grpc_pipeline_final_result::ExecutionResult, grpc_pipeline_part_base::Part, grpc_pipeline_predicate::Operand, GrpcContextKeyValue,
+        GrpcEventLogChunk, GrpcExecutionContextInfo, GrpcExecutionContextsList, GrpcGetContextValueRequest, GrpcGetContextValueResult,
+        GrpcGuid, GrpcInvalidatePipelineCacheRequest, GrpcPipeline, GrpcPipelineCacheStatistics, GrpcPipelineExecutionRequest,
+        GrpcPipelineFinalResult, GrpcPipelinePart, GrpcPipelinePartExecutionResult, GrpcPipelinePredicate, GrpcUploadEventLogResult,
+        GrpcUuid,
     },
     pipelines::{
         context::LogMessageHandler,
+        control_flow::{
+            ComparisonKind, ForEachTraceClusterPipelinePart, IfPipelinePart, LogMetric, PipelinePredicate, PredicateOperand,
+            WhilePipelinePart,
+        },
         errors::pipeline_errors::PipelinePartExecutionError,
         keys::{context_key::ContextKey, context_keys::ContextKeys},
+        memoization::PipelinePartsCache,
         pipeline_parts::PipelineParts,
         pipelines::{DefaultPipelinePart, Pipeline, PipelinePart},
     },
@@ -37,23 +47,33 @@ use crate::{
 
 pub(super) type GrpcResult = crate::ficus_proto::grpc_pipeline_part_execution_result::Result;
 pub(super) type GrpcSender = Sender<Result<GrpcPipelinePartExecutionResult, Status>>;
+type GrpcUploadResult = crate::ficus_proto::grpc_upload_event_log_result::Result;
 
 pub struct FicusService {
     pipeline_parts: Arc<Box<PipelineParts>>,
     context_keys: Arc<Box<ContextKeys>>,
-    contexts: Arc<Box<Mutex<HashMap<String, UserDataImpl>>>>,
+    contexts: Arc<Box<Mutex<ContextStore>>>,
+    parts_cache: Arc<Mutex<PipelinePartsCache>>,
 }
 
+const PIPELINE_PARTS_CACHE_MAX_ENTRIES: usize = 1024;
+
 impl FicusService {
-    pub fn new(types: Arc<Box<ContextKeys>>) -> Self {
+    pub fn new(types: Arc<Box<ContextKeys>>, store_settings: ContextStoreSettings) -> Self {
         Self {
             pipeline_parts: Arc::new(Box::new(PipelineParts::new())),
+            contexts: Arc::new(Box::new(Mutex::new(ContextStore::new(store_settings, types.clone())))),
             context_keys: types,
-            contexts: Arc::new(Box::new(Mutex::new(HashMap::new()))),
+            parts_cache: Arc::new(Mutex::new(PipelinePartsCache::new(PIPELINE_PARTS_CACHE_MAX_ENTRIES))),
         }
     }
 }
 
+struct StoredContextValues {
+    log: Option<XesEventLogImpl>,
+    events_file_discovery: Option<EventsFileDiscovery>,
+}
+
 pub(super) struct ServicePipelineExecutionContext<'a> {
     grpc_pipeline: &'a GrpcPipeline,
     context_values: &'a Vec<GrpcContextKeyValue>,
@@ -137,16 +157,51 @@ impl GrpcBackendService for FicusService {
         let context_keys = self.context_keys.clone();
         let pipeline_parts = self.pipeline_parts.clone();
         let contexts = self.contexts.clone();
+        let parts_cache = match request.get_ref().use_cache {
+            true => Some(self.parts_cache.clone()),
+            false => None,
+        };
+
         let (sender, receiver) = mpsc::channel(4);
 
         tokio::task::spawn_blocking(move || {
             let grpc_pipeline = request.get_ref().pipeline.as_ref().unwrap();
             let context_values = &request.get_ref().initial_context;
+            let initial_values = match request.get_ref().initial_context_id.as_ref() {
+                None => None,
+                Some(id) => {
+                    if let Err(status) = Self::validate_context_id(&id.guid) {
+                        sender.blocking_send(Err(status)).ok();
+                        return;
+                    }
+
+                    match Self::get_stored_values(&contexts, &context_keys, &id.guid) {
+                        Ok(values) => Some(values),
+                        Err(message) => {
+                            sender
+                                .blocking_send(Ok(Self::create_final_result(ExecutionResult::Error(message))))
+                                .ok();
+                            return;
+                        }
+                    }
+                }
+            };
+
             let context = ServicePipelineExecutionContext::new(grpc_pipeline, context_values, context_keys, pipeline_parts, sender);
+            let (pipeline, pipeline_context) =
+                match Self::to_pipeline(&context).and_then(|pipeline| Ok((pipeline, create_initial_context(&context)?))) {
+                    Ok(prepared) => prepared,
+                    Err(message) => {
+                        context.sender().blocking_send(Err(Status::invalid_argument(message))).ok();
+                        return;
+                    }
+                };
 
-            match Self::execute_grpc_pipeline(&context) {
+            match Self::execute_grpc_pipeline(&context, pipeline, pipeline_context, initial_values, parts_cache) {
                 Ok((guid, created_context)) => {
-                    contexts.lock().as_mut().unwrap().insert(guid.guid.to_owned(), created_context);
+                    if let Err(message) = contexts.lock().as_mut().unwrap().insert(guid.guid.to_owned(), created_context) {
+                        context.log_message_handler().handle(message).ok();
+                    }
 
                     context
                         .sender()
@@ -171,7 +226,8 @@ impl GrpcBackendService for FicusService {
             None => Self::create_get_context_value_error("Failed to find key for key name".to_string()),
             Some(key) => {
                 let id = request.get_ref().execution_id.as_ref().unwrap();
-                match self.contexts.lock().as_mut().unwrap().get_mut(&id.guid) {
+                Self::validate_context_id(&id.guid)?;
+                match self.contexts.lock().as_mut().unwrap().get(&id.guid) {
                     None => Self::create_get_context_value_error("Failed to get context for guid".to_string()),
                     Some(value) => match value.any(key.key()) {
                         None => {
@@ -194,22 +250,145 @@ impl GrpcBackendService for FicusService {
         let mut contexts = self.contexts.lock();
         let contexts = contexts.as_mut().ok().unwrap();
         let guid_str = &request.get_ref().guid;
+        Self::validate_context_id(guid_str)?;
 
-        match contexts.remove(guid_str) {
-            None => Err(Status::not_found(format!("The session for {} does not exist", guid_str))),
-            Some(_) => Ok(Response::new(())),
+        if contexts.remove(guid_str) {
+            Ok(Response::new(()))
+        } else {
+            Err(Status::not_found(format!("The session for {} does not exist", guid_str)))
         }
     }
+
+    async fn list_execution_contexts(&self, _: Request<()>) -> Result<Response<GrpcExecutionContextsList>, Status> {
+        let mut contexts = self.contexts.lock();
+        let contexts = contexts.as_mut().ok().unwrap();
+
+        let infos = contexts
+            .list()
+            .into_iter()
+            .map(|info| GrpcExecutionContextInfo {
+                execution_id: Some(GrpcGuid { guid: info.id }),
+                size_bytes: info.size as u64,
+                age_seconds: info.age.as_secs(),
+                in_memory: info.in_memory,
+            })
+            .collect();
+
+        Ok(Response::new(GrpcExecutionContextsList { contexts: infos }))
+    }
+
+    async fn upload_event_log(&self, request: Request<Streaming<GrpcEventLogChunk>>) -> Result<Response<GrpcUploadEventLogResult>, Status> {
+        let mut stream = request.into_inner();
+        let first_chunk = match stream.message().await? {
+            Some(chunk) => chunk,
+            None => {
+                return Ok(Self::create_upload_result(GrpcUploadResult::Error(
+                    "Event log stream is empty".to_string(),
+                )))
+            }
+        };
+
+        let format = match UploadedEventLogFormat::from_str(&first_chunk.format) {
+            Ok(format) => format,
+            Err(_) => {
+                let message = format!("Unsupported event log format {}", first_chunk.format);
+                return Ok(Self::create_upload_result(GrpcUploadResult::Error(message)));
+            }
+        };
+
+        let (sender, receiver) = mpsc::channel(UPLOADED_CHUNKS_BUFFER_SIZE);
+        let context_keys = self.context_keys.clone();
+        let reading_task = tokio::task::spawn_blocking(move || match read_uploaded_event_log(format, receiver) {
+            Ok(log) => {
+                let mut user_data = UserDataImpl::new();
+                user_data.put_concrete(context_keys.event_log().key(), log);
+                Ok(user_data)
+            }
+            Err(message) => Err(message),
+        });
+
+        let mut stream_error = None;
+        if sender.send(Ok(first_chunk.data)).await.is_ok() {
+            loop {
+                match stream.message().await {
+                    Ok(Some(chunk)) => {
+                        if sender.send(Ok(chunk.data)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(status) => {
+                        sender.send(Err(status.message().to_owned())).await.ok();
+                        stream_error = Some(status);
+                        break;
+                    }
+                }
+            }
+        }
+
+        drop(sender);
+        let reading_result = reading_task.await;
+
+        if let Some(status) = stream_error {
+            return Err(status);
+        }
+
+        let result = match reading_result {
+            Ok(Ok(user_data)) => {
+                let guid = Uuid::new_v4().to_string();
+                let mut contexts = self.contexts.lock();
+                let contexts = contexts.as_mut().unwrap();
+                match contexts.insert(guid.to_owned(), user_data) {
+                    Ok(()) => GrpcUploadResult::Success(GrpcGuid { guid }),
+                    Err(message) => {
+                        contexts.remove(&guid);
+                        GrpcUploadResult::Error(message)
+                    }
+                }
+            }
+            Ok(Err(message)) => GrpcUploadResult::Error(message),
+            Err(error) => GrpcUploadResult::Error(error.to_string()),
+        };
+
+        Ok(Self::create_upload_result(result))
+    }
+
+    async fn get_pipeline_cache_statistics(&self, _: Request<()>) -> Result<Response<GrpcPipelineCacheStatistics>, Status> {
+        let statistics = self.parts_cache.lock().unwrap().statistics();
+
+        Ok(Response::new(GrpcPipelineCacheStatistics {
+            hits: statistics.hits as u64,
+            misses: statistics.misses as u64,
+            entries_count: statistics.entries_count as u64,
+        }))
+    }
+
+    async fn invalidate_pipeline_cache(&self, request: Request<GrpcInvalidatePipelineCacheRequest>) -> Result<Response<()>, Status> {
+        self.parts_cache.lock().unwrap().invalidate(&request.get_ref().parts_names);
+        Ok(Response::new(()))
+    }
 }
 
 impl FicusService {
     fn execute_grpc_pipeline<'a>(
         context: &ServicePipelineExecutionContext,
+        pipeline: Pipeline,
+        mut pipeline_context: PipelineContext<'a>,
+        initial_values: Option<StoredContextValues>,
+        parts_cache: Option<Arc<Mutex<PipelinePartsCache>>>,
     ) -> Result<(GrpcGuid, UserDataImpl), PipelinePartExecutionError> {
         let id = Uuid::new_v4();
-        let pipeline = Self::to_pipeline(context);
-        let mut pipeline_context = create_initial_context(context);
-        let infra = PipelineInfrastructure::new(Some(context.log_message_handler()));
+        if let Some(initial_values) = initial_values {
+            if let Some(log) = initial_values.log {
+                pipeline_context.put_concrete(context.keys().event_log().key(), log);
+            }
+
+            if let Some(file_discovery) = initial_values.events_file_discovery {
+                pipeline_context.put_concrete(context.keys().events_file_discovery().key(), file_discovery);
+            }
+        }
+
+        let infra = PipelineInfrastructure::new_with_cache(Some(context.log_message_handler()), parts_cache);
 
         match pipeline.execute(&mut pipeline_context, &infra, context.keys()) {
             Ok(()) => Ok((GrpcGuid { guid: id.to_string() }, pipeline_context.devastate_user_data())),
@@ -217,39 +396,93 @@ impl FicusService {
         }
     }
 
-    pub(super) fn to_pipeline(context: &ServicePipelineExecutionContext) -> Pipeline {
+    //Returns an error if the pipeline sent by the client is malformed
+    pub(super) fn to_pipeline(context: &ServicePipelineExecutionContext) -> Result<Pipeline, String> {
         let mut pipeline = Pipeline::empty();
         for grpc_part in &context.grpc_pipeline().parts {
-            match grpc_part.part.as_ref().unwrap() {
-                Part::DefaultPart(grpc_default_part) => match Self::find_default_part(grpc_default_part, context) {
-                    Some(found_part) => {
-                        pipeline.push(found_part);
+            match grpc_part.part.as_ref().ok_or("Pipeline part is not set".to_string())? {
+                Part::DefaultPart(grpc_default_part) => pipeline.push(Self::find_default_part(grpc_default_part, context)?),
+                Part::ParallelPart(_) => return Err("Parallel pipeline parts are not supported".to_string()),
+                Part::IfPart(part) => {
+                    let predicate = Self::create_predicate(part.predicate.as_ref().ok_or("If part has no predicate".to_string())?)?;
+                    let then_pipeline = part.then_pipeline.as_ref().ok_or("If part has no then pipeline".to_string())?;
+                    let then_pipeline = Self::to_pipeline(&context.with_pipeline(then_pipeline))?;
+                    let else_pipeline = match part.else_pipeline.as_ref() {
+                        Some(else_pipeline) => Some(Self::to_pipeline(&context.with_pipeline(else_pipeline))?),
+                        None => None,
+                    };
+
+                    pipeline.push(Box::new(IfPipelinePart::new(predicate, then_pipeline, else_pipeline)));
+                }
+                Part::WhilePart(part) => {
+                    let predicate = match part.predicate.as_ref() {
+                        Some(predicate) => Some(Self::create_predicate(predicate)?),
+                        None => None,
+                    };
+
+                    //Zero iterations is the proto default, such loop would never execute its body
+                    if part.max_iterations == 0 {
+                        return Err("Max iterations of the while part must be greater than zero".to_string());
                     }
-                    None => todo!(),
-                },
-                Part::ParallelPart(_) => todo!(),
+
+                    let body = Self::to_pipeline(&context.with_pipeline(part.body.as_ref().ok_or("While part has no body".to_string())?))?;
+
+                    pipeline.push(Box::new(WhilePipelinePart::new(predicate, body, part.max_iterations as usize)));
+                }
+                Part::ForEachTraceClusterPart(part) => {
+                    let cluster_pipeline = part
+                        .pipeline
+                        .as_ref()
+                        .ok_or("For each trace cluster part has no pipeline".to_string())?;
+                    let cluster_pipeline = Self::to_pipeline(&context.with_pipeline(cluster_pipeline))?;
+                    pipeline.push(Box::new(ForEachTraceClusterPipelinePart::new(cluster_pipeline)));
+                }
                 Part::SimpleContextRequestPart(part) => {
-                    let key_name = part.key.as_ref().unwrap().name.clone();
-                    let uuid = Uuid::from_str(&part.frontend_part_uuid.as_ref().unwrap().uuid).ok().unwrap();
+                    let key_name = part.key.as_ref().ok_or("Context request part has no key".to_string())?.name.clone();
+                    let uuid = Self::parse_frontend_part_uuid(part.frontend_part_uuid.as_ref())?;
 
                     pipeline.push(Self::create_get_context_part(vec![key_name], uuid, &context.sender(), None));
                 }
                 Part::ComplexContextRequestPart(part) => {
-                    let grpc_default_part = part.before_pipeline_part.as_ref().unwrap();
-                    let uuid = Uuid::from_str(&part.frontend_part_uuid.as_ref().unwrap().uuid).ok().unwrap();
-
-                    match Self::find_default_part(grpc_default_part, context) {
-                        Some(found_part) => {
-                            let key_names = part.keys.iter().map(|x| x.name.to_owned()).collect();
-                            pipeline.push(Self::create_get_context_part(key_names, uuid, &context.sender(), Some(found_part)));
-                        }
-                        None => todo!(),
-                    }
+                    let grpc_default_part = part
+                        .before_pipeline_part
+                        .as_ref()
+                        .ok_or("Context request part has no before pipeline part".to_string())?;
+                    let uuid = Self::parse_frontend_part_uuid(part.frontend_part_uuid.as_ref())?;
+
+                    let found_part = Self::find_default_part(grpc_default_part, context)?;
+                    let key_names = part.keys.iter().map(|x| x.name.to_owned()).collect();
+                    pipeline.push(Self::create_get_context_part(key_names, uuid, &context.sender(), Some(found_part)));
                 }
             }
         }
 
-        pipeline
+        Ok(pipeline)
+    }
+
+    fn create_predicate(grpc_predicate: &GrpcPipelinePredicate) -> Result<PipelinePredicate, String> {
+        let operand = match grpc_predicate.operand.as_ref().ok_or("Predicate has no operand".to_string())? {
+            Operand::LogMetric(metric) => match LogMetric::from_str(metric) {
+                Ok(metric) => PredicateOperand::LogMetric(metric),
+                Err(_) => return Err(format!("Unknown log metric {}", metric)),
+            },
+            Operand::ContextKey(key) => PredicateOperand::ContextValue(key.name.to_owned()),
+        };
+
+        let comparison = match ComparisonKind::from_str(&grpc_predicate.comparison) {
+            Ok(comparison) => comparison,
+            Err(_) => return Err(format!("Unknown comparison {}", grpc_predicate.comparison)),
+        };
+
+        Ok(PipelinePredicate::new(operand, comparison, grpc_predicate.threshold))
+    }
+
+    fn parse_frontend_part_uuid(grpc_uuid: Option<&GrpcUuid>) -> Result<Uuid, String> {
+        let grpc_uuid = grpc_uuid.ok_or("Context request part has no frontend part uuid".to_string())?;
+        match Uuid::from_str(&grpc_uuid.uuid) {
+            Ok(uuid) => Ok(uuid),
+            Err(_) => Err(format!("Invalid frontend part uuid {}", grpc_uuid.uuid)),
+        }
     }
 
     fn create_get_context_part(
@@ -265,24 +498,59 @@ impl FicusService {
     fn find_default_part(
         grpc_default_part: &GrpcPipelinePart,
         context: &ServicePipelineExecutionContext,
-    ) -> Option<Box<DefaultPipelinePart>> {
+    ) -> Result<Box<DefaultPipelinePart>, String> {
         let mut part_config = UserDataImpl::new();
         let grpc_config = &grpc_default_part.configuration.as_ref().unwrap();
 
         for conf_value in &grpc_config.configuration_parameters {
             let key_name = conf_value.key.as_ref().unwrap().name.as_ref();
             if let Some(key) = context.keys().find_key(key_name) {
-                let value = conf_value.value.as_ref().unwrap().context_value.as_ref().unwrap();
-                put_into_user_data(key.key(), value, &mut part_config, context);
+                match conf_value.value.as_ref().and_then(|value| value.context_value.as_ref()) {
+                    Some(value) => put_into_user_data(key.key(), value, &mut part_config, context)?,
+                    None => return Err(format!("Configuration value for key {} is not set", key_name)),
+                }
             }
         }
 
         match context.parts().find_part(&grpc_default_part.name) {
-            Some(default_part) => Some(Box::new(default_part(Box::new(part_config)))),
-            None => None,
+            Some(default_part) => Ok(Box::new(default_part(Box::new(part_config)))),
+            None => Err(format!("Unknown pipeline part {}", grpc_default_part.name)),
         }
     }
 
+    //The streaming discovery state is continued too, so that the followed file is not read from the beginning
+    fn validate_context_id(guid: &str) -> Result<(), Status> {
+        match ContextStore::is_valid_id(guid) {
+            true => Ok(()),
+            false => Err(Status::invalid_argument(format!("Invalid context id {}", guid))),
+        }
+    }
+
+    fn get_stored_values(contexts: &Mutex<ContextStore>, keys: &ContextKeys, guid: &str) -> Result<StoredContextValues, String> {
+        let mut contexts = contexts.lock();
+        let user_data = match contexts.as_mut().unwrap().get(guid) {
+            None => return Err(format!("The context for {} does not exist", guid)),
+            Some(user_data) => user_data,
+        };
+
+        let values = StoredContextValues {
+            log: user_data.concrete(keys.event_log().key()).cloned(),
+            events_file_discovery: user_data.concrete(keys.events_file_discovery().key()).cloned(),
+        };
+
+        match values.log.is_none() && values.events_file_discovery.is_none() {
+            true => Err(format!(
+                "The context for {} does not contain event log or streaming discovery",
+                guid
+            )),
+            false => Ok(values),
+        }
+    }
+
+    fn create_upload_result(result: GrpcUploadResult) -> Response<GrpcUploadEventLogResult> {
+        Response::new(GrpcUploadEventLogResult { result: Some(result) })
+    }
+
     fn create_get_context_value_error(message: String) -> GrpcGetContextValueResult {
         GrpcGetContextValueResult {
             context_value_result: Some(ContextValueResult::Error(message)),
@@ -0,0 +1,563 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    mem::size_of,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    event_log::{
+        bxes::{bxes_to_xes_converter::read_bxes_into_xes_log, xes_to_bxes_converter::write_event_log_to_bxes},
+        core::{
+            event::event::{Event, EventPayloadValue},
+            event_log::EventLog,
+            trace::trace::Trace,
+        },
+        xes::{xes_event::XesEventImpl, xes_event_log::XesEventLogImpl},
+    },
+    features::discovery::petri_net::{
+        pnml_deserialization::deserialize_from_pnml_file, pnml_serialization::serialize_to_executable_pnml_file,
+    },
+    pipelines::keys::{context_key::DefaultContextKey, context_keys::ContextKeys},
+    utils::{
+        dataset::{
+            dataset::{FicusDataset, LabeledDataset},
+            dataset_serialization::{
+                deserialize_dataset_from_file, deserialize_labeled_dataset_from_file, serialize_dataset_to_file,
+                serialize_labeled_dataset_to_file,
+            },
+        },
+        user_data::{
+            keys::Key,
+            user_data::{UserData, UserDataImpl},
+        },
+    },
+};
+use uuid::Uuid;
+
+const MAX_SIZE_ENV_VAR: &'static str = "FICUS_CONTEXTS_MAX_SIZE_MB";
+const TTL_ENV_VAR: &'static str = "FICUS_CONTEXTS_TTL_SECONDS";
+const STORAGE_DIR_ENV_VAR: &'static str = "FICUS_CONTEXTS_STORAGE_DIR";
+
+const META_FILE_NAME: &'static str = "context.meta";
+const BXES_EXTENSION: &'static str = "bxes";
+const PNML_EXTENSION: &'static str = "pnml";
+const DATASET_EXTENSION: &'static str = "dataset.tsv";
+const LABELED_DATASET_EXTENSION: &'static str = "labeled_dataset.tsv";
+
+const ENTRY_OVERHEAD: usize = 64;
+
+pub struct ContextStoreSettings {
+    pub max_size_bytes: Option<usize>,
+    pub time_to_live: Option<Duration>,
+    pub storage_directory: Option<PathBuf>,
+}
+
+impl ContextStoreSettings {
+    pub fn unbounded() -> Self {
+        Self {
+            max_size_bytes: None,
+            time_to_live: None,
+            storage_directory: None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let read_number = |name: &str| env::var(name).ok().and_then(|value| value.parse::<u64>().ok());
+
+        Self {
+            max_size_bytes: read_number(MAX_SIZE_ENV_VAR).map(|mb| mb as usize * 1024 * 1024),
+            time_to_live: read_number(TTL_ENV_VAR).map(|seconds| Duration::from_secs(seconds)),
+            storage_directory: env::var(STORAGE_DIR_ENV_VAR).ok().map(|dir| PathBuf::from(dir)),
+        }
+    }
+}
+
+pub struct StoredContextInfo {
+    pub id: String,
+    pub size: usize,
+    pub age: Duration,
+    pub in_memory: bool,
+}
+
+struct StoredContext {
+    user_data: UserDataImpl,
+    size: usize,
+    created_at: SystemTime,
+    last_access: SystemTime,
+    persisted: bool,
+}
+
+pub struct ContextStore {
+    settings: ContextStoreSettings,
+    context_keys: Arc<Box<ContextKeys>>,
+    contexts: HashMap<String, StoredContext>,
+    total_size: usize,
+}
+
+impl ContextStore {
+    pub fn new(settings: ContextStoreSettings, context_keys: Arc<Box<ContextKeys>>) -> Self {
+        if let Some(dir) = settings.storage_directory.as_ref() {
+            fs::create_dir_all(dir).ok();
+        }
+
+        Self {
+            settings,
+            context_keys,
+            contexts: HashMap::new(),
+            total_size: 0,
+        }
+    }
+
+    //Ids are used as names of storage directories, so only canonical uuids are accepted
+    pub fn is_valid_id(id: &str) -> bool {
+        match Uuid::parse_str(id) {
+            Ok(uuid) => uuid.to_string() == id,
+            Err(_) => false,
+        }
+    }
+
+    //If persistence fails or the context has values which can not be persisted the context is kept in memory and is never
+    //evicted by the size limit, the persistence error is returned
+    pub fn insert(&mut self, id: String, user_data: UserDataImpl) -> Result<(), String> {
+        if !Self::is_valid_id(&id) {
+            return Err(format!("Invalid context id {}", id));
+        }
+
+        self.remove(&id);
+
+        let now = SystemTime::now();
+        let mut stored_context = StoredContext {
+            size: self.estimate_size(&user_data),
+            user_data,
+            created_at: now,
+            last_access: now,
+            persisted: false,
+        };
+
+        let persist_result = self.persist(&id, &stored_context);
+        stored_context.persisted =
+            persist_result.is_ok() && self.settings.storage_directory.is_some() && self.is_persistable(&stored_context.user_data);
+
+        self.total_size += stored_context.size;
+        self.contexts.insert(id.to_owned(), stored_context);
+
+        self.evict(Some(&id));
+
+        persist_result
+    }
+
+    pub fn get(&mut self, id: &str) -> Option<&UserDataImpl> {
+        self.evict_expired(None);
+
+        if !self.contexts.contains_key(id) {
+            let restored = self.restore(id)?;
+            self.total_size += restored.size;
+            self.contexts.insert(id.to_owned(), restored);
+            self.evict(Some(id));
+        }
+
+        let context = self.contexts.get_mut(id)?;
+        context.last_access = SystemTime::now();
+
+        Some(&context.user_data)
+    }
+
+    pub fn remove(&mut self, id: &str) -> bool {
+        let removed_from_memory = match self.contexts.remove(id) {
+            Some(context) => {
+                self.total_size -= context.size;
+                true
+            }
+            None => false,
+        };
+
+        let removed_from_disk = match self.context_dir(id) {
+            Some(dir) if dir.exists() => fs::remove_dir_all(dir).is_ok(),
+            _ => false,
+        };
+
+        removed_from_memory || removed_from_disk
+    }
+
+    pub fn list(&mut self) -> Vec<StoredContextInfo> {
+        self.evict(None);
+
+        let now = SystemTime::now();
+        let mut infos = vec![];
+
+        for (id, context) in &self.contexts {
+            infos.push(StoredContextInfo {
+                id: id.to_owned(),
+                size: context.size,
+                age: now.duration_since(context.created_at).unwrap_or_default(),
+                in_memory: true,
+            });
+        }
+
+        for id in self.persisted_ids() {
+            if self.contexts.contains_key(&id) {
+                continue;
+            }
+
+            let dir = self.context_dir(&id).unwrap();
+            let created_at = Self::read_meta(&dir).map(|meta| meta.0).unwrap_or(now);
+
+            infos.push(StoredContextInfo {
+                size: Self::directory_size(&dir),
+                age: now.duration_since(created_at).unwrap_or_default(),
+                in_memory: false,
+                id,
+            });
+        }
+
+        infos.sort_by(|first, second| first.id.cmp(&second.id));
+        infos
+    }
+
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    fn evict(&mut self, protected_id: Option<&str>) {
+        self.evict_expired(protected_id);
+        self.evict_least_recently_used(protected_id);
+    }
+
+    fn evict_expired(&mut self, protected_id: Option<&str>) {
+        let ttl = match self.settings.time_to_live {
+            Some(ttl) => ttl,
+            None => return,
+        };
+
+        let now = SystemTime::now();
+        let is_expired = |last_access: SystemTime| now.duration_since(last_access).unwrap_or_default() > ttl;
+
+        let mut expired = vec![];
+        for (id, context) in &self.contexts {
+            if is_expired(context.last_access) {
+                expired.push(id.to_owned());
+            }
+        }
+
+        for id in self.persisted_ids() {
+            if self.contexts.contains_key(&id) {
+                continue;
+            }
+
+            if let Some((_, last_access)) = Self::read_meta(&self.context_dir(&id).unwrap()) {
+                if is_expired(last_access) {
+                    expired.push(id);
+                }
+            }
+        }
+
+        for id in expired {
+            if Some(id.as_str()) != protected_id {
+                self.remove(&id);
+            }
+        }
+    }
+
+    fn evict_least_recently_used(&mut self, protected_id: Option<&str>) {
+        let max_size = match self.settings.max_size_bytes {
+            Some(max_size) => max_size,
+            None => return,
+        };
+
+        //Without storage directory contexts are removed, otherwise only persisted contexts are unloaded from memory,
+        //last access time is written to disk at this moment, so the TTL of the unloaded context is preserved, if it can not
+        //be written the context stays in memory
+        let has_storage = self.settings.storage_directory.is_some();
+        while self.total_size > max_size && self.contexts.len() > 1 {
+            let candidate = self
+                .contexts
+                .iter()
+                .filter(|(id, context)| Some(id.as_str()) != protected_id && (context.persisted || !has_storage))
+                .min_by_key(|(_, context)| context.last_access)
+                .map(|(id, _)| id.to_owned());
+
+            let candidate = match candidate {
+                Some(candidate) => candidate,
+                None => return,
+            };
+
+            if !has_storage {
+                self.remove(&candidate);
+                continue;
+            }
+
+            let dir = self.settings.storage_directory.as_ref().unwrap().join(&candidate);
+            let context = self.contexts.get_mut(&candidate).unwrap();
+            if Self::write_meta(&dir, context.created_at, context.last_access).is_err() {
+                context.persisted = false;
+                continue;
+            }
+
+            let context = self.contexts.remove(&candidate).unwrap();
+            self.total_size -= context.size;
+        }
+    }
+
+    fn context_dir(&self, id: &str) -> Option<PathBuf> {
+        match Self::is_valid_id(id) {
+            true => self.settings.storage_directory.as_ref().map(|dir| dir.join(id)),
+            false => None,
+        }
+    }
+
+    //Only values of these keys are restored, so contexts with other values are never unloaded from memory
+    fn is_persistable(&self, user_data: &UserDataImpl) -> bool {
+        let keys = &self.context_keys;
+        let mut persistable_ids = HashSet::from([keys.event_log().key().id(), keys.petri_net().key().id()]);
+        persistable_ids.extend(self.dataset_keys().iter().map(|(_, key)| key.key().id()));
+        persistable_ids.extend(self.labeled_dataset_keys().iter().map(|(_, key)| key.key().id()));
+
+        user_data.entries().iter().all(|(id, _)| persistable_ids.contains(id))
+    }
+
+    fn persisted_ids(&self) -> Vec<String> {
+        let mut ids = vec![];
+        if let Some(dir) = self.settings.storage_directory.as_ref() {
+            if let Ok(entries) = fs::read_dir(dir) {
+                for entry in entries.flatten() {
+                    if entry.path().is_dir() {
+                        if let Some(name) = entry.file_name().to_str().filter(|name| Self::is_valid_id(name)) {
+                            ids.push(name.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+
+        ids
+    }
+
+    //Meta file is written last, so the context whose persistence failed is not restored
+    fn persist(&self, id: &str, context: &StoredContext) -> Result<(), String> {
+        let dir = match self.context_dir(id) {
+            Some(dir) => dir,
+            None => return Ok(()),
+        };
+
+        let result = self.persist_values(&dir, context);
+        if result.is_err() {
+            fs::remove_dir_all(&dir).ok();
+        }
+
+        result.map_err(|error| format!("Failed to persist context {}: {}", id, error))
+    }
+
+    fn persist_values(&self, dir: &Path, context: &StoredContext) -> Result<(), String> {
+        fs::create_dir_all(dir).map_err(|error| error.to_string())?;
+
+        let keys = &self.context_keys;
+        let user_data = &context.user_data;
+
+        if let Some(log) = user_data.concrete(keys.event_log().key()) {
+            let path = Self::value_path(dir, ContextKeys::EVENT_LOG, BXES_EXTENSION);
+            write_event_log_to_bxes(log, path.as_str()).map_err(|error| error.to_string())?;
+        }
+
+        if let Some(net) = user_data.concrete(keys.petri_net().key()) {
+            let path = Self::value_path(dir, ContextKeys::PETRI_NET, PNML_EXTENSION);
+            serialize_to_executable_pnml_file(net, path.as_str(), true).map_err(|error| error.to_string())?;
+        }
+
+        for (name, key) in self.dataset_keys() {
+            if let Some(dataset) = user_data.concrete(key.key()) {
+                let path = Self::value_path(dir, name, DATASET_EXTENSION);
+                serialize_dataset_to_file(dataset, path.as_str()).map_err(|error| error.to_string())?;
+            }
+        }
+
+        for (name, key) in self.labeled_dataset_keys() {
+            if let Some(dataset) = user_data.concrete(key.key()) {
+                let path = Self::value_path(dir, name, LABELED_DATASET_EXTENSION);
+                serialize_labeled_dataset_to_file(dataset, path.as_str()).map_err(|error| error.to_string())?;
+            }
+        }
+
+        Self::write_meta(dir, context.created_at, context.last_access).map_err(|error| error.to_string())
+    }
+
+    fn restore(&self, id: &str) -> Option<StoredContext> {
+        let dir = self.context_dir(id)?;
+        let (created_at, _) = Self::read_meta(&dir)?;
+
+        let keys = &self.context_keys;
+        let mut user_data = UserDataImpl::new();
+
+        let log_path = Self::value_path(&dir, ContextKeys::EVENT_LOG, BXES_EXTENSION);
+        if Path::new(&log_path).exists() {
+            if let Ok(log) = read_bxes_into_xes_log(log_path.as_str()) {
+                user_data.put_concrete(keys.event_log().key(), log);
+            }
+        }
+
+        let net_path = Self::value_path(&dir, ContextKeys::PETRI_NET, PNML_EXTENSION);
+        if Path::new(&net_path).exists() {
+            if let Ok(net) = deserialize_from_pnml_file(net_path.as_str()) {
+                user_data.put_concrete(keys.petri_net().key(), net);
+            }
+        }
+
+        for (name, key) in self.dataset_keys() {
+            if let Ok(dataset) = deserialize_dataset_from_file(Self::value_path(&dir, name, DATASET_EXTENSION).as_str()) {
+                user_data.put_concrete(key.key(), dataset);
+            }
+        }
+
+        for (name, key) in self.labeled_dataset_keys() {
+            if let Ok(dataset) = deserialize_labeled_dataset_from_file(Self::value_path(&dir, name, LABELED_DATASET_EXTENSION).as_str()) {
+                user_data.put_concrete(key.key(), dataset);
+            }
+        }
+
+        Some(StoredContext {
+            size: self.estimate_size(&user_data),
+            user_data,
+            created_at,
+            last_access: SystemTime::now(),
+            persisted: true,
+        })
+    }
+
+    fn dataset_keys(&self) -> Vec<(&'static str, &DefaultContextKey<FicusDataset>)> {
+        vec![
+            (
+                ContextKeys::TRACES_ACTIVITIES_DATASET,
+                self.context_keys.traces_activities_dataset(),
+            ),
+            (ContextKeys::LOG_TRACES_DATASET, self.context_keys.log_traces_dataset()),
+        ]
+    }
+
+    fn labeled_dataset_keys(&self) -> Vec<(&'static str, &DefaultContextKey<LabeledDataset>)> {
+        vec![
+            (
+                ContextKeys::LABELED_TRACES_ACTIVITIES_DATASET,
+                self.context_keys.labeled_traces_activities_dataset(),
+            ),
+            (
+                ContextKeys::LABELED_LOG_TRACES_DATASET,
+                self.context_keys.labeled_log_traces_dataset(),
+            ),
+        ]
+    }
+
+    fn value_path(dir: &Path, key_name: &str, extension: &str) -> String {
+        dir.join(format!("{}.{}", key_name, extension)).to_str().unwrap().to_owned()
+    }
+
+    fn write_meta(dir: &Path, created_at: SystemTime, last_access: SystemTime) -> std::io::Result<()> {
+        let to_millis = |time: SystemTime| time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        let content = format!("{}\n{}\n", to_millis(created_at), to_millis(last_access));
+        fs::write(dir.join(META_FILE_NAME), content)
+    }
+
+    fn read_meta(dir: &Path) -> Option<(SystemTime, SystemTime)> {
+        let content = fs::read_to_string(dir.join(META_FILE_NAME)).ok()?;
+        let mut lines = content.lines();
+
+        let mut read_time = || -> Option<SystemTime> {
+            let millis = lines.next()?.parse::<u64>().ok()?;
+            Some(UNIX_EPOCH + Duration::from_millis(millis))
+        };
+
+        Some((read_time()?, read_time()?))
+    }
+
+    fn directory_size(dir: &Path) -> usize {
+        let mut size = 0;
+        if let Ok(entries) = fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                if let Ok(metadata) = entry.metadata() {
+                    size += metadata.len() as usize;
+                }
+            }
+        }
+
+        size
+    }
+
+    fn estimate_size(&self, user_data: &UserDataImpl) -> usize {
+        let keys = &self.context_keys;
+        let mut size = user_data.len() * ENTRY_OVERHEAD;
+
+        if let Some(log) = user_data.concrete(keys.event_log().key()) {
+            size += Self::estimate_log_size(log);
+        }
+
+        if let Some(net) = user_data.concrete(keys.petri_net().key()) {
+            let arcs_count: usize = net
+                .all_transitions()
+                .iter()
+                .map(|t| t.incoming_arcs().len() + t.outgoing_arcs().len())
+                .sum();
+
+            size += (net.all_places().len() + net.all_transitions().len() + arcs_count) * ENTRY_OVERHEAD;
+        }
+
+        if let Some(graph) = user_data.concrete(keys.graph().key()) {
+            size += (graph.all_nodes().len() + graph.all_edges().len()) * ENTRY_OVERHEAD;
+        }
+
+        if let Some(log) = user_data.concrete(keys.hashes_event_log().key()) {
+            size += log.iter().map(|trace| trace.len() * size_of::<u64>()).sum::<usize>();
+        }
+
+        if let Some(log) = user_data.concrete(keys.names_event_log().key()) {
+            size += log
+                .iter()
+                .map(|trace| trace.iter().map(|name| name.len() + size_of::<String>()).sum::<usize>())
+                .sum::<usize>();
+        }
+
+        for (_, key) in self.dataset_keys() {
+            if let Some(dataset) = user_data.concrete(key.key()) {
+                size += Self::estimate_dataset_size(dataset);
+            }
+        }
+
+        for (_, key) in self.labeled_dataset_keys() {
+            if let Some(dataset) = user_data.concrete(key.key()) {
+                size += Self::estimate_dataset_size(dataset.dataset()) + dataset.labels().len() * 2 * size_of::<usize>();
+            }
+        }
+
+        size
+    }
+
+    fn estimate_log_size(log: &XesEventLogImpl) -> usize {
+        let mut size = 0;
+        for trace in log.traces() {
+            for event in trace.borrow().events() {
+                let event = event.borrow();
+                size += size_of::<XesEventImpl>() + event.name().len();
+
+                if let Some(payload) = event.payload_map() {
+                    for (key, _) in payload {
+                        size += key.len() + size_of::<String>() + size_of::<EventPayloadValue>();
+                    }
+                }
+            }
+        }
+
+        size
+    }
+
+    fn estimate_dataset_size(dataset: &FicusDataset) -> usize {
+        let values_size = dataset.values().iter().map(|row| row.len() * size_of::<f64>()).sum::<usize>();
+        let names_size = dataset
+            .row_names()
+            .iter()
+            .chain(dataset.columns_names().iter())
+            .map(|name| name.len() + size_of::<String>())
+            .sum::<usize>();
+
+        values_size + names_size
+    }
+}
@@ -1,33 +1,63 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::rc::Rc;
 use std::{any::Any, str::FromStr};
 
 use super::backend_service::{FicusService, ServicePipelineExecutionContext};
+use crate::event_log::core::event::event::{Event, EventPayloadValue};
+use crate::event_log::core::event::lifecycle::xes_lifecycle::Lifecycle;
+use crate::event_log::core::event_log::EventLog;
+use crate::event_log::core::trace::trace::Trace;
+use crate::event_log::xes::xes_event::XesEventImpl;
+use crate::event_log::xes::xes_event_log::XesEventLogImpl;
+use crate::event_log::xes::xes_trace::XesTraceImpl;
+use crate::features::analysis::concept_drift::DriftFeature;
 use crate::features::analysis::patterns::activity_instances::{ActivityInTraceFilterKind, ActivityNarrowingKind};
+use crate::features::analysis::patterns::sequential_patterns::SequentialPattern;
 use crate::features::clustering::activities::activities_params::ActivityRepresentationSource;
+use crate::features::clustering::agglomerative::{AgglomerativeClusteringLinkage, Dendrogram};
+use crate::features::clustering::distance_matrix::DistanceMatrixClusteringMethod;
+use crate::features::clustering::quality::{ClusteringQualityIndex, ClusteringSearchParameter};
 use crate::features::clustering::traces::traces_params::TracesRepresentationSource;
+use crate::features::discovery::declare::declare_conformance::DeclareConformanceResult;
+use crate::features::discovery::declare::declare_model::{DeclareConstraint, DeclareConstraintMetrics, DeclareModel, DeclareTemplate};
+use crate::features::discovery::hierarchy::hierarchical_petri_net::HierarchicalPetriNet;
 use crate::features::discovery::petri_net::arc::Arc;
+use crate::features::discovery::petri_net::layout::calculate_petri_net_layout;
 use crate::features::discovery::petri_net::marking::{Marking, SingleMarking};
 use crate::features::discovery::petri_net::petri_net::DefaultPetriNet;
 use crate::features::discovery::petri_net::place::Place;
 use crate::features::discovery::petri_net::transition::Transition;
+use crate::features::discovery::transition_system::transition_system::{StateAbstraction, StateDirection};
+use crate::features::streaming::streaming_discovery::StreamingModel;
 use crate::ficus_proto::{
-    GrpcCountAnnotation, GrpcDataset, GrpcEntityCountAnnotation, GrpcEntityFrequencyAnnotation, GrpcFrequenciesAnnotation, GrpcGraph,
-    GrpcGraphEdge, GrpcGraphNode, GrpcLabeledDataset, GrpcMatixRow, GrpcMatrix, GrpcPetriNet, GrpcPetriNetArc, GrpcPetriNetMarking,
-    GrpcPetriNetPlace, GrpcPetriNetSinglePlaceMarking, GrpcPetriNetTransition,
+    grpc_event_attribute_value, GrpcCountAnnotation, GrpcDataset, GrpcDeclareConformance, GrpcDeclareConstraint,
+    GrpcDeclareConstraintCheckResult, GrpcDeclareConstraintMetrics, GrpcDeclareModel, GrpcDeclareTraceConformance, GrpcDendrogram,
+    GrpcDendrogramMerge, GrpcEntityCountAnnotation, GrpcEntityFrequencyAnnotation, GrpcEventAttribute, GrpcEventAttributeValue,
+    GrpcEventLogGlobal, GrpcFrequenciesAnnotation, GrpcFullEvent, GrpcFullEventLog, GrpcFullTrace, GrpcGraph, GrpcGraphEdge, GrpcGraphNode,
+    GrpcHierarchicalPetriNet, GrpcLabeledDataset, GrpcMatixRow, GrpcMatrix, GrpcNodeLayout, GrpcPetriNet, GrpcPetriNetArc,
+    GrpcPetriNetMarking, GrpcPetriNetPlace, GrpcPetriNetSinglePlaceMarking, GrpcPetriNetTransition, GrpcPoint, GrpcSequentialPattern,
+    GrpcSequentialPatterns,
 };
-use crate::pipelines::activities_parts::{ActivitiesLogsSourceDto, UndefActivityHandlingStrategyDto};
+use crate::pipelines::activities_parts::{ActivitiesLogsSourceDto, DatasetProjectionSourceDto, UndefActivityHandlingStrategyDto};
+use crate::pipelines::discovery_parts::PetriNetMinerDto;
 use crate::pipelines::patterns_parts::PatternsKindDto;
 use crate::utils::dataset::dataset::{FicusDataset, LabeledDataset};
+use crate::utils::dataset::projection::DatasetProjectionMethod;
 use crate::utils::distance::distance::FicusDistance;
 use crate::utils::graph::graph::{DefaultGraph, Graph};
 use crate::utils::graph::graph_edge::GraphEdge;
 use crate::utils::graph::graph_node::GraphNode;
+use crate::utils::graph::layered_layout::{calculate_graph_layout, GraphLayout, LayeredLayoutParams, LayoutNode, LayoutPoint};
+use crate::utils::vec_utils;
 use crate::{
     features::analysis::{
         event_log_info::EventLogInfo,
         patterns::{
-            activity_instances::AdjustingMode, contexts::PatternsDiscoveryStrategy, repeat_sets::SubArrayWithTraceIndex,
+            activity_instances::AdjustingMode,
+            contexts::{EpisodeKind, PatternsDiscoveryStrategy, PatternsSearchBackend},
+            repeat_sets::SubArrayWithTraceIndex,
             tandem_arrays::SubArrayInTraceInfo,
         },
     },
@@ -48,33 +78,46 @@ use crate::{
         user_data::{keys::Key, user_data::UserData},
     },
 };
+use chrono::{DateTime, TimeZone, Utc};
 use nameof::name_of_type;
 
-pub(super) fn create_initial_context<'a>(context: &'a ServicePipelineExecutionContext) -> PipelineContext<'a> {
+pub(super) fn create_initial_context<'a>(context: &'a ServicePipelineExecutionContext) -> Result<PipelineContext<'a>, String> {
     let mut pipeline_context = PipelineContext::new_with_logging(context.parts());
 
     for value in context.context_values() {
-        let key = context.keys().find_key(&value.key.as_ref().unwrap().name).unwrap();
-        let value = value.value.as_ref().unwrap().context_value.as_ref().unwrap();
-        put_into_user_data(key.key(), value, &mut pipeline_context, context);
+        let key_name = match value.key.as_ref() {
+            Some(key) => &key.name,
+            None => return Err("Context value without key".to_string()),
+        };
+
+        let key = match context.keys().find_key(key_name) {
+            Some(key) => key,
+            None => return Err(format!("Unknown context key {}", key_name)),
+        };
+
+        match value.value.as_ref().and_then(|value| value.context_value.as_ref()) {
+            Some(value) => put_into_user_data(key.key(), value, &mut pipeline_context, context)?,
+            None => return Err(format!("Context value for key {} is not set", key_name)),
+        }
     }
 
-    pipeline_context
+    Ok(pipeline_context)
 }
 
+//Returns an error if the value can not be converted, the message is returned to the client
 pub(super) fn put_into_user_data(
     key: &dyn Key,
     value: &ContextValue,
     user_data: &mut impl UserData,
     context: &ServicePipelineExecutionContext,
-) {
+) -> Result<(), String> {
     match value {
         ContextValue::String(string) => user_data.put_any::<String>(key, string.clone()),
-        ContextValue::HashesLog(_) => todo!(),
+        ContextValue::HashesLog(_) => return Err(create_unsupported_input_value_error("Hashes log")),
         ContextValue::NamesLog(grpc_log) => put_names_log_to_context(key, grpc_log, user_data),
         ContextValue::Uint32(number) => user_data.put_any::<u32>(key, number.clone()),
-        ContextValue::TracesSubArrays(_) => todo!(),
-        ContextValue::TraceIndexSubArrays(_) => todo!(),
+        ContextValue::TracesSubArrays(_) => return Err(create_unsupported_input_value_error("Traces sub arrays")),
+        ContextValue::TraceIndexSubArrays(_) => return Err(create_unsupported_input_value_error("Trace index sub arrays")),
         ContextValue::Bool(bool) => user_data.put_any::<bool>(key, bool.clone()),
         ContextValue::XesEventLog(grpc_log) => put_names_log_to_context(key, grpc_log, user_data),
         ContextValue::ColorsLog(_) => {}
@@ -100,22 +143,60 @@ pub(super) fn put_into_user_data(
                 parse_grpc_enum::<FicusDistance>(user_data, key, &grpc_enum.value);
             } else if enum_name == name_of_type!(TracesRepresentationSource) {
                 parse_grpc_enum::<TracesRepresentationSource>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(PatternsSearchBackend) {
+                parse_grpc_enum::<PatternsSearchBackend>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(EpisodeKind) {
+                parse_grpc_enum::<EpisodeKind>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(PetriNetMinerDto) {
+                parse_grpc_enum::<PetriNetMinerDto>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(AgglomerativeClusteringLinkage) {
+                parse_grpc_enum::<AgglomerativeClusteringLinkage>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(DistanceMatrixClusteringMethod) {
+                parse_grpc_enum::<DistanceMatrixClusteringMethod>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(ClusteringQualityIndex) {
+                parse_grpc_enum::<ClusteringQualityIndex>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(ClusteringSearchParameter) {
+                parse_grpc_enum::<ClusteringSearchParameter>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(DatasetProjectionMethod) {
+                parse_grpc_enum::<DatasetProjectionMethod>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(DriftFeature) {
+                parse_grpc_enum::<DriftFeature>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(StreamingModel) {
+                parse_grpc_enum::<StreamingModel>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(StateAbstraction) {
+                parse_grpc_enum::<StateAbstraction>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(StateDirection) {
+                parse_grpc_enum::<StateDirection>(user_data, key, &grpc_enum.value);
+            } else if enum_name == name_of_type!(DatasetProjectionSourceDto) {
+                parse_grpc_enum::<DatasetProjectionSourceDto>(user_data, key, &grpc_enum.value);
             }
         }
-        ContextValue::EventLogInfo(_) => todo!(),
+        ContextValue::EventLogInfo(_) => return Err(create_unsupported_input_value_error("Event log info")),
         ContextValue::Strings(strings) => user_data.put_any::<Vec<String>>(key, strings.strings.clone()),
         ContextValue::Pipeline(pipeline) => {
-            let pipeline = FicusService::to_pipeline(&context.with_pipeline(pipeline));
+            let pipeline = FicusService::to_pipeline(&context.with_pipeline(pipeline))?;
             user_data.put_any::<Pipeline>(key, pipeline);
         }
-        ContextValue::PetriNet(_) => todo!(),
-        ContextValue::Graph(_) => todo!(),
+        ContextValue::PetriNet(_) => return Err(create_unsupported_input_value_error("Petri net")),
+        ContextValue::HierarchicalPetriNet(_) => return Err(create_unsupported_input_value_error("Hierarchical Petri net")),
+        ContextValue::SequentialPatterns(_) => return Err(create_unsupported_input_value_error("Sequential patterns")),
+        ContextValue::DeclareModel(grpc_model) => user_data.put_any::<DeclareModel>(key, convert_from_grpc_declare_model(grpc_model)?),
+        ContextValue::DeclareConformance(_) => return Err(create_unsupported_input_value_error("Declare conformance")),
+        ContextValue::Graph(_) => return Err(create_unsupported_input_value_error("Graph")),
         ContextValue::Float(value) => user_data.put_any::<f64>(key, *value as f64),
-        ContextValue::CountAnnotation(_) => todo!(),
-        ContextValue::FrequencyAnnotation(_) => todo!(),
-        ContextValue::Dataset(_) => todo!(),
-        ContextValue::LabeledDataset(_) => todo!(),
+        ContextValue::CountAnnotation(_) => return Err(create_unsupported_input_value_error("Count annotation")),
+        ContextValue::FrequencyAnnotation(_) => return Err(create_unsupported_input_value_error("Frequency annotation")),
+        ContextValue::Dataset(_) => return Err(create_unsupported_input_value_error("Dataset")),
+        ContextValue::LabeledDataset(_) => return Err(create_unsupported_input_value_error("Labeled dataset")),
+        ContextValue::Dendrogram(_) => return Err(create_unsupported_input_value_error("Dendrogram")),
+        ContextValue::FullEventLog(grpc_log) => user_data.put_any::<XesEventLogImpl>(key, convert_from_grpc_full_event_log(grpc_log)?),
     }
+
+    Ok(())
+}
+
+fn create_unsupported_input_value_error(value_name: &str) -> String {
+    format!("{} can not be passed as an input context value", value_name)
 }
 
 fn parse_grpc_enum<TEnum: FromStr + 'static>(user_data: &mut impl UserData, key: &dyn Key, raw_enum: &str) {
@@ -142,6 +223,10 @@ fn put_names_log_to_context(key: &dyn Key, grpc_log: &GrpcNamesEventLogContextVa
 pub fn convert_to_grpc_context_value(key: &dyn ContextKey, value: &dyn Any, keys: &ContextKeys) -> Option<GrpcContextValue> {
     if keys.is_path(key) {
         try_convert_to_string_context_value(value)
+    } else if keys.is_event_log(key) {
+        try_convert_to_grpc_full_event_log(value)
+    } else if keys.is_residual_traces_cluster(key) {
+        try_convert_to_grpc_full_event_log(value)
     } else if keys.is_hashes_event_log(key) {
         try_convert_to_hashes_event_log(value)
     } else if keys.is_names_event_log(key) {
@@ -150,12 +235,22 @@ pub fn convert_to_grpc_context_value(key: &dyn ContextKey, value: &dyn Any, keys
         try_convert_to_grpc_traces_sub_arrays(value)
     } else if keys.is_repeat_sets(key) {
         try_convert_to_grpc_sub_arrays_with_index(value)
+    } else if keys.is_patterns_occurrences(key) {
+        try_convert_to_grpc_sub_arrays_with_index(value)
+    } else if keys.is_sequential_patterns(key) {
+        try_convert_to_grpc_sequential_patterns(value)
     } else if keys.is_colors_event_log(key) {
         try_convert_to_grpc_colors_event_log(value)
     } else if keys.is_event_log_info(key) {
         try_convert_to_grpc_event_log_info(value)
     } else if keys.is_petri_net(key) {
         try_convert_to_grpc_petri_net(value)
+    } else if keys.is_hierarchical_petri_net(key) {
+        try_convert_to_grpc_hierarchical_petri_net(value)
+    } else if keys.is_declare_model(key) {
+        try_convert_to_grpc_declare_model(value)
+    } else if keys.is_declare_conformance(key) {
+        try_convert_to_grpc_declare_conformance(value)
     } else if keys.is_graph(key) {
         try_convert_to_grpc_graph(value)
     } else if keys.is_petri_net_count_annotation(key) {
@@ -172,6 +267,24 @@ pub fn convert_to_grpc_context_value(key: &dyn ContextKey, value: &dyn Any, keys
         try_convert_to_grpc_labeled_dataset(value)
     } else if keys.is_log_traces_dataset(key) {
         try_convert_to_grpc_dataset(value)
+    } else if keys.is_graph_analysis_dataset(key) {
+        try_convert_to_grpc_dataset(value)
+    } else if keys.is_dendrogram(key) {
+        try_convert_to_grpc_dendrogram(value)
+    } else if keys.is_clustering_scores(key) {
+        try_convert_to_grpc_dataset(value)
+    } else if keys.is_projected_dataset(key) {
+        try_convert_to_grpc_dataset(value)
+    } else if keys.is_labeled_projected_dataset(key) {
+        try_convert_to_grpc_labeled_dataset(value)
+    } else if keys.is_concept_drift_dataset(key) {
+        try_convert_to_grpc_dataset(value)
+    } else if keys.is_next_activity_dataset(key) {
+        try_convert_to_grpc_dataset(value)
+    } else if keys.is_remaining_time_dataset(key) {
+        try_convert_to_grpc_dataset(value)
+    } else if keys.is_prediction_evaluation_dataset(key) {
+        try_convert_to_grpc_dataset(value)
     } else {
         None
     }
@@ -297,17 +410,7 @@ fn try_convert_to_grpc_sub_arrays_with_index(value: &dyn Any) -> Option<GrpcCont
         None
     } else {
         let vec = value.downcast_ref::<Vec<SubArrayWithTraceIndex>>().unwrap();
-        let mut sub_arrays = vec![];
-
-        for array in vec {
-            sub_arrays.push(GrpcSubArrayWithTraceIndex {
-                sub_array: Some(GrpcTraceSubArray {
-                    start: array.sub_array.start_index as u32,
-                    end: (array.sub_array.start_index + array.sub_array.length) as u32,
-                }),
-                trace_index: array.trace_index as u32,
-            })
-        }
+        let sub_arrays = vec.iter().map(convert_to_grpc_sub_array_with_index).collect();
 
         Some(GrpcContextValue {
             context_value: Some(ContextValue::TraceIndexSubArrays(GrpcSubArraysWithTraceIndexContextValue {
@@ -317,6 +420,36 @@ fn try_convert_to_grpc_sub_arrays_with_index(value: &dyn Any) -> Option<GrpcCont
     }
 }
 
+fn convert_to_grpc_sub_array_with_index(array: &SubArrayWithTraceIndex) -> GrpcSubArrayWithTraceIndex {
+    GrpcSubArrayWithTraceIndex {
+        sub_array: Some(GrpcTraceSubArray {
+            start: array.sub_array.start_index as u32,
+            end: (array.sub_array.start_index + array.sub_array.length) as u32,
+        }),
+        trace_index: array.trace_index as u32,
+    }
+}
+
+fn try_convert_to_grpc_sequential_patterns(value: &dyn Any) -> Option<GrpcContextValue> {
+    if !value.is::<Vec<SequentialPattern>>() {
+        None
+    } else {
+        let patterns = value.downcast_ref::<Vec<SequentialPattern>>().unwrap();
+        let patterns = patterns
+            .iter()
+            .map(|pattern| GrpcSequentialPattern {
+                elements: pattern.elements().clone(),
+                support: pattern.support() as u32,
+                occurrences: pattern.occurrences().iter().map(convert_to_grpc_sub_array_with_index).collect(),
+            })
+            .collect();
+
+        Some(GrpcContextValue {
+            context_value: Some(ContextValue::SequentialPatterns(GrpcSequentialPatterns { patterns })),
+        })
+    }
+}
+
 fn try_convert_to_grpc_colors_event_log(value: &dyn Any) -> Option<GrpcContextValue> {
     if !value.is::<ColorsEventLog>() {
         None
@@ -376,46 +509,179 @@ fn try_convert_to_grpc_petri_net(value: &dyn Any) -> Option<GrpcContextValue> {
         None
     } else {
         let petri_net = value.downcast_ref::<DefaultPetriNet>().unwrap();
-        let grpc_places: Vec<GrpcPetriNetPlace> = petri_net.all_places().iter().map(|place| convert_to_grpc_place(place)).collect();
+        Some(GrpcContextValue {
+            context_value: Some(ContextValue::PetriNet(convert_to_grpc_petri_net(petri_net))),
+        })
+    }
+}
+
+fn convert_to_grpc_petri_net(petri_net: &DefaultPetriNet) -> GrpcPetriNet {
+    let layout = calculate_petri_net_layout(petri_net, &LayeredLayoutParams::default());
+
+    let grpc_places: Vec<GrpcPetriNetPlace> = petri_net
+        .all_places()
+        .iter()
+        .map(|place| convert_to_grpc_place(place, &layout))
+        .collect();
+
+    let grpc_transitions: Vec<GrpcPetriNetTransition> = petri_net
+        .all_transitions()
+        .iter()
+        .map(|transition| convert_to_grpc_transition(transition, &layout))
+        .collect();
+
+    GrpcPetriNet {
+        places: grpc_places,
+        transitions: grpc_transitions,
+        initial_marking: try_convert_to_grpc_marking(petri_net.initial_marking()),
+        final_marking: try_convert_to_grpc_marking(petri_net.final_marking()),
+    }
+}
+
+fn try_convert_to_grpc_declare_model(value: &dyn Any) -> Option<GrpcContextValue> {
+    if !value.is::<DeclareModel>() {
+        None
+    } else {
+        let model = value.downcast_ref::<DeclareModel>().unwrap();
+        Some(GrpcContextValue {
+            context_value: Some(ContextValue::DeclareModel(convert_to_grpc_declare_model(model))),
+        })
+    }
+}
+
+fn convert_to_grpc_declare_model(model: &DeclareModel) -> GrpcDeclareModel {
+    GrpcDeclareModel {
+        constraints: model
+            .constraints()
+            .iter()
+            .map(|constraint| GrpcDeclareConstraint {
+                template: constraint.template().name().to_owned(),
+                parameters: constraint.parameters().clone(),
+                metrics: constraint.metrics().map(|metrics| GrpcDeclareConstraintMetrics {
+                    support: metrics.support,
+                    confidence: metrics.confidence,
+                }),
+            })
+            .collect(),
+    }
+}
+
+pub fn convert_from_grpc_declare_model(grpc_model: &GrpcDeclareModel) -> Result<DeclareModel, String> {
+    let mut constraints = vec![];
+    for (index, grpc_constraint) in grpc_model.constraints.iter().enumerate() {
+        let template = match DeclareTemplate::from_str(grpc_constraint.template.as_str()) {
+            Ok(template) => template,
+            Err(_) => {
+                return Err(format!(
+                    "Unknown template {} of Declare constraint {}",
+                    grpc_constraint.template, index
+                ))
+            }
+        };
+
+        let metrics = grpc_constraint.metrics.as_ref().map(|metrics| DeclareConstraintMetrics {
+            support: metrics.support,
+            confidence: metrics.confidence,
+        });
+
+        match DeclareConstraint::new(template, grpc_constraint.parameters.clone(), metrics) {
+            Some(constraint) => constraints.push(constraint),
+            None => {
+                return Err(format!(
+                    "Invalid parameters [{}] of Declare constraint {} ({})",
+                    grpc_constraint.parameters.join(", "),
+                    index,
+                    grpc_constraint.template
+                ))
+            }
+        }
+    }
+
+    Ok(DeclareModel::new(constraints))
+}
 
-        let grpc_transitions: Vec<GrpcPetriNetTransition> = petri_net
-            .all_transitions()
+fn try_convert_to_grpc_declare_conformance(value: &dyn Any) -> Option<GrpcContextValue> {
+    if !value.is::<DeclareConformanceResult>() {
+        None
+    } else {
+        let conformance = value.downcast_ref::<DeclareConformanceResult>().unwrap();
+        let traces = conformance
+            .traces()
             .iter()
-            .map(|transition| convert_to_grpc_transition(transition))
+            .map(|trace| GrpcDeclareTraceConformance {
+                constraints_results: trace
+                    .constraints_results()
+                    .iter()
+                    .map(|result| GrpcDeclareConstraintCheckResult {
+                        activations: result.activations as u32,
+                        fulfilments: result.fulfilments as u32,
+                        violations: result.violations as u32,
+                    })
+                    .collect(),
+                fitness: trace.fitness(),
+            })
             .collect();
 
         Some(GrpcContextValue {
-            context_value: Some(ContextValue::PetriNet(GrpcPetriNet {
-                places: grpc_places,
-                transitions: grpc_transitions,
-                initial_marking: try_convert_to_grpc_marking(petri_net.initial_marking()),
-                final_marking: try_convert_to_grpc_marking(petri_net.final_marking()),
+            context_value: Some(ContextValue::DeclareConformance(GrpcDeclareConformance {
+                model: Some(convert_to_grpc_declare_model(conformance.model())),
+                traces,
+                fitness: conformance.fitness(),
             })),
         })
     }
 }
 
-fn convert_to_grpc_place(place: &Place) -> GrpcPetriNetPlace {
+fn try_convert_to_grpc_hierarchical_petri_net(value: &dyn Any) -> Option<GrpcContextValue> {
+    if !value.is::<HierarchicalPetriNet>() {
+        None
+    } else {
+        let hierarchical_net = value.downcast_ref::<HierarchicalPetriNet>().unwrap();
+        Some(GrpcContextValue {
+            context_value: Some(ContextValue::HierarchicalPetriNet(convert_to_grpc_hierarchical_petri_net(
+                hierarchical_net,
+            ))),
+        })
+    }
+}
+
+fn convert_to_grpc_hierarchical_petri_net(hierarchical_net: &HierarchicalPetriNet) -> GrpcHierarchicalPetriNet {
+    GrpcHierarchicalPetriNet {
+        name: hierarchical_net.name().to_owned(),
+        petri_net: Some(convert_to_grpc_petri_net(hierarchical_net.petri_net())),
+        subprocesses: hierarchical_net
+            .subprocesses()
+            .iter()
+            .map(|subprocess| convert_to_grpc_hierarchical_petri_net(subprocess))
+            .collect(),
+    }
+}
+
+fn convert_to_grpc_place(place: &Place, layout: &GraphLayout) -> GrpcPetriNetPlace {
     GrpcPetriNetPlace {
         id: place.id() as i64,
         name: place.name().to_owned(),
+        layout: convert_to_grpc_node_layout(layout.node(&place.id())),
     }
 }
 
-fn convert_to_grpc_transition<TTransitionData, TArcData>(transition: &Transition<TTransitionData, TArcData>) -> GrpcPetriNetTransition
+fn convert_to_grpc_transition<TTransitionData, TArcData>(
+    transition: &Transition<TTransitionData, TArcData>,
+    layout: &GraphLayout,
+) -> GrpcPetriNetTransition
 where
     TTransitionData: ToString,
 {
     let incoming_arcs = transition
         .incoming_arcs()
         .iter()
-        .map(|arc| convert_to_grpc_arc(arc))
+        .map(|arc| convert_to_grpc_arc(arc, layout.bend_points(&arc.place_id(), &transition.id())))
         .collect::<Vec<GrpcPetriNetArc>>();
 
     let outgoing_arcs = transition
         .outgoing_arcs()
         .iter()
-        .map(|arc| convert_to_grpc_arc(arc))
+        .map(|arc| convert_to_grpc_arc(arc, layout.bend_points(&transition.id(), &arc.place_id())))
         .collect::<Vec<GrpcPetriNetArc>>();
 
     GrpcPetriNetTransition {
@@ -426,14 +692,32 @@ where
             None => "".to_string(),
             Some(data) => data.to_string(),
         },
+        layout: convert_to_grpc_node_layout(layout.node(&transition.id())),
     }
 }
 
-fn convert_to_grpc_arc<TArcData>(arc: &Arc<TArcData>) -> GrpcPetriNetArc {
+fn convert_to_grpc_arc<TArcData>(arc: &Arc<TArcData>, bend_points: Option<&Vec<LayoutPoint>>) -> GrpcPetriNetArc {
     GrpcPetriNetArc {
         id: arc.id() as i64,
         place_id: arc.place_id() as i64,
         tokens_count: *arc.tokens_count() as i64,
+        bend_points: convert_to_grpc_points(bend_points),
+    }
+}
+
+fn convert_to_grpc_node_layout(node: Option<&LayoutNode>) -> Option<GrpcNodeLayout> {
+    node.map(|node| GrpcNodeLayout {
+        x: node.x,
+        y: node.y,
+        width: node.width,
+        height: node.height,
+    })
+}
+
+fn convert_to_grpc_points(points: Option<&Vec<LayoutPoint>>) -> Vec<GrpcPoint> {
+    match points {
+        Some(points) => points.iter().map(|point| GrpcPoint { x: point.x, y: point.y }).collect(),
+        None => vec![],
     }
 }
 
@@ -473,13 +757,23 @@ where
     TNodeData: ToString,
     TEdgeData: ToString + Display,
 {
-    let nodes: Vec<GrpcGraphNode> = graph.all_nodes().iter().map(|node| convert_to_grpc_graph_node(*node)).collect();
-    let edges: Vec<GrpcGraphEdge> = graph.all_edges().iter().map(|edge| convert_to_grpc_graph_edge(edge)).collect();
+    let layout = calculate_graph_layout(graph, &LayeredLayoutParams::default());
+
+    let nodes: Vec<GrpcGraphNode> = graph
+        .all_nodes()
+        .iter()
+        .map(|node| convert_to_grpc_graph_node(*node, &layout))
+        .collect();
+    let edges: Vec<GrpcGraphEdge> = graph
+        .all_edges()
+        .iter()
+        .map(|edge| convert_to_grpc_graph_edge(edge, &layout))
+        .collect();
 
     GrpcGraph { edges, nodes }
 }
 
-fn convert_to_grpc_graph_node<TNodeData>(node: &GraphNode<TNodeData>) -> GrpcGraphNode
+fn convert_to_grpc_graph_node<TNodeData>(node: &GraphNode<TNodeData>, layout: &GraphLayout) -> GrpcGraphNode
 where
     TNodeData: ToString,
 {
@@ -489,10 +783,11 @@ where
             None => "".to_string(),
             Some(data) => data.to_string(),
         },
+        layout: convert_to_grpc_node_layout(layout.node(node.id())),
     }
 }
 
-fn convert_to_grpc_graph_edge<TEdgeData>(edge: &GraphEdge<TEdgeData>) -> GrpcGraphEdge
+fn convert_to_grpc_graph_edge<TEdgeData>(edge: &GraphEdge<TEdgeData>, layout: &GraphLayout) -> GrpcGraphEdge
 where
     TEdgeData: ToString,
 {
@@ -503,6 +798,7 @@ where
             None => "".to_string(),
             Some(data) => data.to_string(),
         },
+        bend_points: convert_to_grpc_points(layout.bend_points(edge.from_node(), edge.to_node())),
     }
 }
 
@@ -571,3 +867,194 @@ fn convert_to_labeled_grpc_dataset(dataset: &LabeledDataset) -> GrpcLabeledDatas
         labels_colors,
     }
 }
+
+fn try_convert_to_grpc_dendrogram(value: &dyn Any) -> Option<GrpcContextValue> {
+    if !value.is::<Dendrogram>() {
+        None
+    } else {
+        Some(GrpcContextValue {
+            context_value: Some(ContextValue::Dendrogram(convert_to_grpc_dendrogram(
+                value.downcast_ref::<Dendrogram>().unwrap(),
+            ))),
+        })
+    }
+}
+
+fn convert_to_grpc_dendrogram(dendrogram: &Dendrogram) -> GrpcDendrogram {
+    GrpcDendrogram {
+        leaves_names: dendrogram.leaves_names().clone(),
+        merges: dendrogram
+            .merges()
+            .iter()
+            .map(|merge| GrpcDendrogramMerge {
+                first: merge.first() as u32,
+                second: merge.second() as u32,
+                distance: merge.distance(),
+                size: merge.size() as u32,
+            })
+            .collect(),
+    }
+}
+
+fn try_convert_to_grpc_full_event_log(value: &dyn Any) -> Option<GrpcContextValue> {
+    if !value.is::<XesEventLogImpl>() {
+        None
+    } else {
+        let log = value.downcast_ref::<XesEventLogImpl>().unwrap();
+        Some(GrpcContextValue {
+            context_value: Some(ContextValue::FullEventLog(convert_to_grpc_full_event_log(log))),
+        })
+    }
+}
+
+pub fn convert_to_grpc_full_event_log(log: &XesEventLogImpl) -> GrpcFullEventLog {
+    let mut traces = vec![];
+    for trace in log.traces() {
+        let trace = trace.borrow();
+        let mut events = vec![];
+        for event in trace.events() {
+            let event = event.borrow();
+            events.push(GrpcFullEvent {
+                name: event.name().to_owned(),
+                timestamp: Some(convert_to_grpc_timestamp(event.timestamp())),
+                attributes: convert_to_grpc_attributes(event.ordered_payload()),
+            });
+        }
+
+        let mut metadata = trace.metadata().iter().collect::<Vec<(&String, &EventPayloadValue)>>();
+        vec_utils::sort_by_first(&mut metadata);
+
+        traces.push(GrpcFullTrace {
+            events,
+            attributes: convert_to_grpc_attributes(metadata),
+        });
+    }
+
+    let globals = log
+        .ordered_globals()
+        .into_iter()
+        .map(|(scope, defaults)| GrpcEventLogGlobal {
+            scope: scope.to_owned(),
+            defaults: convert_to_grpc_attributes(defaults),
+        })
+        .collect();
+
+    GrpcFullEventLog { traces, globals }
+}
+
+fn convert_to_grpc_attributes(attributes: Vec<(&String, &EventPayloadValue)>) -> Vec<GrpcEventAttribute> {
+    attributes
+        .into_iter()
+        .map(|(key, value)| GrpcEventAttribute {
+            key: key.to_owned(),
+            value: Some(convert_to_grpc_attribute_value(value)),
+        })
+        .collect()
+}
+
+fn convert_to_grpc_attribute_value(value: &EventPayloadValue) -> GrpcEventAttributeValue {
+    let value = match value {
+        EventPayloadValue::Date(date) => grpc_event_attribute_value::Value::Date(convert_to_grpc_timestamp(date)),
+        EventPayloadValue::String(string) => grpc_event_attribute_value::Value::String(string.as_ref().as_ref().to_owned()),
+        EventPayloadValue::Boolean(bool) => grpc_event_attribute_value::Value::Bool(*bool),
+        EventPayloadValue::Int32(value) => grpc_event_attribute_value::Value::Int32(*value),
+        EventPayloadValue::Int64(value) => grpc_event_attribute_value::Value::Int64(*value),
+        EventPayloadValue::Float32(value) => grpc_event_attribute_value::Value::Float32(*value),
+        EventPayloadValue::Float64(value) => grpc_event_attribute_value::Value::Float64(*value),
+        EventPayloadValue::Uint32(value) => grpc_event_attribute_value::Value::Uint32(*value),
+        EventPayloadValue::Uint64(value) => grpc_event_attribute_value::Value::Uint64(*value),
+        EventPayloadValue::Guid(guid) => grpc_event_attribute_value::Value::Guid(guid.to_string()),
+        EventPayloadValue::Timestamp(value) => grpc_event_attribute_value::Value::Timestamp(*value),
+        EventPayloadValue::Lifecycle(Lifecycle::XesStandardLifecycle(lifecycle)) => {
+            grpc_event_attribute_value::Value::StandardLifecycle(lifecycle.to_string())
+        }
+        EventPayloadValue::Lifecycle(Lifecycle::BrafLifecycle(lifecycle)) => {
+            grpc_event_attribute_value::Value::BrafLifecycle(lifecycle.to_string())
+        }
+    };
+
+    GrpcEventAttributeValue { value: Some(value) }
+}
+
+fn convert_to_grpc_timestamp(date: &DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: date.timestamp(),
+        nanos: date.timestamp_subsec_nanos() as i32,
+    }
+}
+
+//Events without timestamps or with timestamps out of the supported range are rejected, as all time-based features rely on them
+pub fn convert_from_grpc_full_event_log(grpc_log: &GrpcFullEventLog) -> Result<XesEventLogImpl, String> {
+    let mut log = XesEventLogImpl::empty();
+    for grpc_global in &grpc_log.globals {
+        let defaults = convert_from_grpc_attributes(&grpc_global.defaults);
+        log.globals_mut().insert(grpc_global.scope.to_owned(), defaults);
+    }
+
+    for (trace_index, grpc_trace) in grpc_log.traces.iter().enumerate() {
+        let mut trace = XesTraceImpl::empty();
+        *trace.metadata_mut() = convert_from_grpc_attributes(&grpc_trace.attributes);
+
+        for (event_index, grpc_event) in grpc_trace.events.iter().enumerate() {
+            let timestamp = match grpc_event.timestamp.as_ref().and_then(convert_from_grpc_timestamp) {
+                Some(timestamp) => timestamp,
+                None => {
+                    let message = format!(
+                        "Event {} ({}) of trace {} has no valid timestamp",
+                        event_index, grpc_event.name, trace_index
+                    );
+                    return Err(message);
+                }
+            };
+
+            let name = Rc::new(Box::new(grpc_event.name.to_owned()));
+            let payload = convert_from_grpc_attributes(&grpc_event.attributes);
+            let event = XesEventImpl::new_all_fields(name, timestamp, Some(payload));
+
+            trace.push(Rc::new(RefCell::new(event)));
+        }
+
+        log.push(Rc::new(RefCell::new(trace)));
+    }
+
+    Ok(log)
+}
+
+fn convert_from_grpc_attributes(attributes: &Vec<GrpcEventAttribute>) -> HashMap<String, EventPayloadValue> {
+    let mut result = HashMap::new();
+    for attribute in attributes {
+        if let Some(value) = attribute.value.as_ref().and_then(convert_from_grpc_attribute_value) {
+            result.insert(attribute.key.to_owned(), value);
+        }
+    }
+
+    result
+}
+
+fn convert_from_grpc_attribute_value(value: &GrpcEventAttributeValue) -> Option<EventPayloadValue> {
+    match value.value.as_ref()? {
+        grpc_event_attribute_value::Value::Date(timestamp) => convert_from_grpc_timestamp(timestamp).map(EventPayloadValue::Date),
+        grpc_event_attribute_value::Value::String(string) => Some(EventPayloadValue::String(Rc::new(Box::new(string.to_owned())))),
+        grpc_event_attribute_value::Value::Bool(bool) => Some(EventPayloadValue::Boolean(*bool)),
+        grpc_event_attribute_value::Value::Int32(value) => Some(EventPayloadValue::Int32(*value)),
+        grpc_event_attribute_value::Value::Int64(value) => Some(EventPayloadValue::Int64(*value)),
+        grpc_event_attribute_value::Value::Float32(value) => Some(EventPayloadValue::Float32(*value)),
+        grpc_event_attribute_value::Value::Float64(value) => Some(EventPayloadValue::Float64(*value)),
+        grpc_event_attribute_value::Value::Uint32(value) => Some(EventPayloadValue::Uint32(*value)),
+        grpc_event_attribute_value::Value::Uint64(value) => Some(EventPayloadValue::Uint64(*value)),
+        grpc_event_attribute_value::Value::Guid(guid) => uuid::Uuid::parse_str(guid).ok().map(EventPayloadValue::Guid),
+        grpc_event_attribute_value::Value::Timestamp(value) => Some(EventPayloadValue::Timestamp(*value)),
+        grpc_event_attribute_value::Value::StandardLifecycle(lifecycle) => match lifecycle.parse() {
+            Ok(lifecycle) => Some(EventPayloadValue::Lifecycle(Lifecycle::XesStandardLifecycle(lifecycle))),
+            Err(_) => None,
+        },
+        grpc_event_attribute_value::Value::BrafLifecycle(lifecycle) => match lifecycle.parse() {
+            Ok(lifecycle) => Some(EventPayloadValue::Lifecycle(Lifecycle::BrafLifecycle(lifecycle))),
+            Err(_) => None,
+        },
+    }
+}
+
+fn convert_from_grpc_timestamp(timestamp: &prost_types::Timestamp) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(timestamp.seconds, timestamp.nanos.max(0) as u32).single()
+}
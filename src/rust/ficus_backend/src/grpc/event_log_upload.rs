@@ -0,0 +1,110 @@
+use std::{
+    env, fs,
+    io::{BufReader, Read, Write},
+    str::FromStr,
+};
+
+use tokio::sync::mpsc::Receiver;
+use uuid::Uuid;
+
+use crate::event_log::{
+    bxes::bxes_to_xes_converter::read_bxes_into_xes_log,
+    xes::{reader::file_xes_log_reader::read_event_log_from_reader, xes_event_log::XesEventLogImpl},
+};
+
+pub(super) type UploadedChunk = Result<Vec<u8>, String>;
+
+//Chunks which are received but not yet consumed by the reader, the stream is not polled when the buffer is full
+pub(super) const UPLOADED_CHUNKS_BUFFER_SIZE: usize = 16;
+
+pub(super) enum UploadedEventLogFormat {
+    Xes,
+    Bxes,
+}
+
+impl FromStr for UploadedEventLogFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Xes" => Ok(Self::Xes),
+            "Bxes" => Ok(Self::Bxes),
+            _ => Err(()),
+        }
+    }
+}
+
+struct ChunksReader {
+    receiver: Receiver<UploadedChunk>,
+    current_chunk: Vec<u8>,
+    position: usize,
+}
+
+impl ChunksReader {
+    fn new(receiver: Receiver<UploadedChunk>) -> Self {
+        Self {
+            receiver,
+            current_chunk: vec![],
+            position: 0,
+        }
+    }
+}
+
+impl Read for ChunksReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.position >= self.current_chunk.len() {
+            match self.receiver.blocking_recv() {
+                Some(Ok(chunk)) => {
+                    self.current_chunk = chunk;
+                    self.position = 0;
+                }
+                Some(Err(message)) => return Err(std::io::Error::new(std::io::ErrorKind::Other, message)),
+                None => return Ok(0),
+            }
+        }
+
+        let count = buf.len().min(self.current_chunk.len() - self.position);
+        buf[..count].copy_from_slice(&self.current_chunk[self.position..(self.position + count)]);
+        self.position += count;
+
+        Ok(count)
+    }
+}
+
+pub(super) fn read_uploaded_event_log(
+    format: UploadedEventLogFormat,
+    receiver: Receiver<UploadedChunk>,
+) -> Result<XesEventLogImpl, String> {
+    match format {
+        UploadedEventLogFormat::Xes => {
+            let reader = BufReader::new(ChunksReader::new(receiver));
+            match read_event_log_from_reader(Box::new(reader)) {
+                Some(log) => Ok(log),
+                None => Err("Failed to read uploaded XES event log".to_string()),
+            }
+        }
+        UploadedEventLogFormat::Bxes => read_uploaded_bxes_event_log(receiver),
+    }
+}
+
+fn read_uploaded_bxes_event_log(receiver: Receiver<UploadedChunk>) -> Result<XesEventLogImpl, String> {
+    let path = env::temp_dir().join(format!("{}.bxes", Uuid::new_v4()));
+    let result = match write_chunks_to_file(receiver, path.to_str().unwrap()) {
+        Ok(()) => match read_bxes_into_xes_log(path.to_str().unwrap()) {
+            Ok(log) => Ok(log),
+            Err(error) => Err(error.to_string()),
+        },
+        Err(error) => Err(error.to_string()),
+    };
+
+    fs::remove_file(path).ok();
+    result
+}
+
+fn write_chunks_to_file(receiver: Receiver<UploadedChunk>, path: &str) -> std::io::Result<()> {
+    let mut file = fs::File::create(path)?;
+    let mut reader = ChunksReader::new(receiver);
+
+    std::io::copy(&mut reader, &mut file)?;
+    file.flush()
+}
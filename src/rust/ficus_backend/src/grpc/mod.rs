@@ -1,4 +1,6 @@
 pub mod backend_service;
+pub mod context_store;
 pub mod converters;
+pub mod event_log_upload;
 pub mod get_context_pipeline;
 pub mod logs_handler;
@@ -1,5 +1,6 @@
 use ficus_backend::{
-    ficus_proto::grpc_backend_service_server::GrpcBackendServiceServer, grpc::backend_service::FicusService,
+    ficus_proto::grpc_backend_service_server::GrpcBackendServiceServer,
+    grpc::{backend_service::FicusService, context_store::ContextStoreSettings},
     pipelines::keys::context_keys::ContextKeys,
 };
 use std::sync::Arc;
@@ -11,7 +12,7 @@ mod utils;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let ficus_service = FicusService::new(Arc::new(Box::new(ContextKeys::new())));
+    let ficus_service = FicusService::new(Arc::new(Box::new(ContextKeys::new())), ContextStoreSettings::from_env());
     let service = GrpcBackendServiceServer::new(ficus_service);
     Server::builder().add_service(service).serve("[::]:8080".parse()?).await?;
 
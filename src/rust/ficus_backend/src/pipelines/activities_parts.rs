@@ -4,15 +4,30 @@ use crate::event_log::xes::xes_trace::XesTraceImpl;
 use crate::features::analysis::event_log_info::count_events;
 use crate::features::analysis::patterns::activity_instances;
 use crate::features::analysis::patterns::activity_instances::{substitute_underlying_events, ActivitiesLogSource, UNDEF_ACTIVITY_NAME};
-use crate::features::clustering::activities::activities_common::create_dataset;
+use crate::features::clustering::activities::activities_common::{create_activities_features_dataset, create_dataset};
 use crate::features::clustering::activities::activities_params::{ActivitiesClusteringParams, ActivitiesVisualizationParams};
-use crate::features::clustering::activities::dbscan::clusterize_activities_dbscan;
+use crate::features::clustering::activities::dbscan::{clusterize_activities_dbscan, search_activities_dbscan_clustering};
+use crate::features::clustering::activities::distance_matrix::{
+    clusterize_activities_distance_matrix, search_activities_distance_matrix_clustering,
+};
 use crate::features::clustering::activities::k_means::{clusterize_activities_k_means, clusterize_activities_k_means_grid_search};
+use crate::features::clustering::agglomerative::DendrogramCut;
 use crate::features::clustering::common::{transform_to_ficus_dataset, CommonVisualizationParams};
-use crate::features::clustering::traces::dbscan::clusterize_log_by_traces_dbscan;
+use crate::features::clustering::distance_matrix::{DistanceMatrixClustering, DistanceMatrixClusteringMethod};
+use crate::features::clustering::featurisation::TracesFeaturisation;
+use crate::features::clustering::quality::{calculate_labeled_dataset_quality, ClusteringQualityIndex, ClusteringScores};
+use crate::features::clustering::traces::agglomerative::clusterize_log_by_traces_agglomerative;
+use crate::features::clustering::traces::dbscan::{clusterize_log_by_traces_dbscan, search_log_traces_dbscan_clustering};
+use crate::features::clustering::traces::distance_matrix::{
+    clusterize_log_by_traces_distance_matrix, search_log_traces_distance_matrix_clustering,
+};
+use crate::features::clustering::traces::model_driven::{clusterize_log_by_traces_models, ModelDrivenClusteringParams};
 use crate::features::clustering::traces::traces_params::TracesClusteringParams;
 use crate::pipelines::context::PipelineInfrastructure;
 use crate::pipelines::pipeline_parts::PipelineParts;
+use crate::utils::dataset::dataset::LabeledDataset;
+use crate::utils::dataset::projection::{project_dataset, project_labeled_dataset, DatasetProjection, DatasetProjectionMethod};
+use crate::utils::distance::distance::FicusDistance;
 use crate::{
     event_log::{
         core::event_log::EventLog,
@@ -40,6 +55,7 @@ use super::{
     pipelines::{DefaultPipelinePart, PipelinePart, PipelinePartFactory},
 };
 
+#[derive(Hash)]
 pub enum UndefActivityHandlingStrategyDto {
     DontInsert,
     InsertAsSingleEvent,
@@ -59,6 +75,7 @@ impl FromStr for UndefActivityHandlingStrategyDto {
     }
 }
 
+#[derive(Hash)]
 pub enum ActivitiesLogsSourceDto {
     Log,
     TracesActivities,
@@ -76,6 +93,28 @@ impl FromStr for ActivitiesLogsSourceDto {
     }
 }
 
+#[derive(Hash)]
+pub enum DatasetProjectionSourceDto {
+    TracesActivitiesDataset,
+    LabeledTracesActivitiesDataset,
+    LogTracesDataset,
+    LabeledLogTracesDataset,
+}
+
+impl FromStr for DatasetProjectionSourceDto {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TracesActivitiesDataset" => Ok(Self::TracesActivitiesDataset),
+            "LabeledTracesActivitiesDataset" => Ok(Self::LabeledTracesActivitiesDataset),
+            "LogTracesDataset" => Ok(Self::LogTracesDataset),
+            "LabeledLogTracesDataset" => Ok(Self::LabeledLogTracesDataset),
+            _ => Err(()),
+        }
+    }
+}
+
 impl PipelineParts {
     pub(super) fn discover_activities() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::DISCOVER_ACTIVITIES, &|context, _, keys, config| {
@@ -493,12 +532,16 @@ impl PipelineParts {
             let mut params = Self::create_activities_clustering_params(context, config, keys)?;
             let clusters_count = *Self::get_user_data(config, keys.clusters_count())? as usize;
             let learning_iterations_count = *Self::get_user_data(config, keys.learning_iterations_count())? as usize;
+            let distance = *Self::get_user_data(config, keys.distance())?;
 
             let labeled_dataset = match clusterize_activities_k_means(&mut params, clusters_count, learning_iterations_count) {
                 Ok(labeled_dataset) => labeled_dataset,
                 Err(error) => return Err(error.into()),
             };
 
+            let configuration = format!("KMeans(clusters_count={})", clusters_count);
+            Self::put_clustering_quality(context, keys, config, configuration, &labeled_dataset, Some(distance), false)?;
+
             context.put_concrete(keys.labeled_traces_activities_dataset().key(), labeled_dataset);
             Ok(())
         })
@@ -553,7 +596,7 @@ impl PipelineParts {
         if let Some(params) = ActivitiesClusteringParams::new(vis_params, tolerance, distance) {
             Ok(params)
         } else {
-            let message = "Failed to create activities clustering params".to_owned();
+            let message = "Failed to create activities clustering params, sequence distances require distance matrix clustering".to_owned();
             Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(message)))
         }
     }
@@ -563,13 +606,16 @@ impl PipelineParts {
             Self::CLUSTERIZE_ACTIVITIES_FROM_TRACES_KMEANS_GRID_SEARCH,
             &|context, _, keys, config| {
                 let learning_iterations_count = *Self::get_user_data(config, keys.learning_iterations_count())? as usize;
+                let index = Self::get_clustering_quality_index(config, keys);
                 let mut params = Self::create_activities_clustering_params(context, config, keys)?;
 
-                let labeled_dataset = match clusterize_activities_k_means_grid_search(&mut params, learning_iterations_count) {
-                    Ok(labeled_dataset) => labeled_dataset,
-                    Err(error) => return Err(error.into()),
-                };
+                let (labeled_dataset, scores) =
+                    match clusterize_activities_k_means_grid_search(&mut params, learning_iterations_count, index) {
+                        Ok(result) => result,
+                        Err(error) => return Err(error.into()),
+                    };
 
+                context.put_concrete(keys.clustering_scores().key(), scores.to_dataset());
                 context.put_concrete(keys.labeled_traces_activities_dataset().key(), labeled_dataset);
                 Ok(())
             },
@@ -579,11 +625,42 @@ impl PipelineParts {
     pub(super) fn clusterize_activities_from_traces_dbscan() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::CLUSTERIZE_ACTIVITIES_FROM_TRACES_DBSCAN, &|context, _, keys, config| {
             let min_points_in_cluster = *Self::get_user_data(config, keys.min_events_in_clusters_count())? as usize;
+            let tolerance = *Self::get_user_data(config, keys.tolerance())?;
+            let distance = *Self::get_user_data(config, keys.distance())?;
+            let index = Self::get_clustering_quality_index(config, keys);
+            let search_parameter = Self::get_user_data(config, keys.clustering_search_parameter()).ok().copied();
             let mut params = Self::create_activities_clustering_params(context, config, keys)?;
 
-            let labeled_dataset = match clusterize_activities_dbscan(&mut params, min_points_in_cluster) {
-                Ok(labeled_dataset) => labeled_dataset,
-                Err(error) => return Err(error.into()),
+            let labeled_dataset = if let Some(parameter) = search_parameter {
+                let (labeled_dataset, scores) =
+                    match search_activities_dbscan_clustering(&mut params, min_points_in_cluster, parameter, index) {
+                        Ok(result) => result,
+                        Err(error) => return Err(error.into()),
+                    };
+
+                context.put_concrete(keys.clustering_scores().key(), scores.to_dataset());
+                labeled_dataset
+            } else {
+                let labeled_dataset = match clusterize_activities_dbscan(&mut params, min_points_in_cluster) {
+                    Ok(labeled_dataset) => labeled_dataset,
+                    Err(error) => return Err(error.into()),
+                };
+
+                let configuration = DistanceMatrixClustering::Dbscan {
+                    tolerance,
+                    min_points: min_points_in_cluster,
+                };
+
+                Self::put_clustering_quality(
+                    context,
+                    keys,
+                    config,
+                    configuration.to_string(),
+                    &labeled_dataset,
+                    Some(distance),
+                    true,
+                )?;
+                labeled_dataset
             };
 
             context.put_concrete(keys.labeled_traces_activities_dataset().key(), labeled_dataset);
@@ -593,8 +670,19 @@ impl PipelineParts {
 
     pub(super) fn create_traces_activities_dataset() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::CREATE_TRACES_ACTIVITIES_DATASET, &|context, _, keys, config| {
+            let featurisation = Self::get_traces_featurisation(config, keys)?;
             let params = Self::create_activities_visualization_params(context, config, keys)?;
 
+            if let Some(featurisation) = featurisation {
+                let ficus_dataset = match create_activities_features_dataset(&params, &featurisation) {
+                    Ok(dataset) => dataset,
+                    Err(error) => return Err(error.into()),
+                };
+
+                context.put_concrete(keys.traces_activities_dataset().key(), ficus_dataset);
+                return Ok(());
+            }
+
             let (dataset, processed, classes) = match create_dataset(&params) {
                 Ok((dataset, processed, classes)) => (dataset, processed, classes),
                 Err(error) => return Err(error.into()),
@@ -608,43 +696,398 @@ impl PipelineParts {
         })
     }
 
+    pub(super) fn project_dataset() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::PROJECT_DATASET, &|context, _, keys, config| {
+            let projection = Self::create_dataset_projection(config, keys)?;
+            let dimensions = match Self::get_user_data(config, keys.projection_dimensions()) {
+                Ok(dimensions) => *dimensions as usize,
+                Err(_) => 2,
+            };
+
+            if dimensions != 2 && dimensions != 3 {
+                let message = format!("Dataset can be projected only into 2 or 3 dimensions, got {}", dimensions);
+                return Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(message)));
+            }
+
+            match Self::get_user_data(config, keys.dataset_projection_source())? {
+                DatasetProjectionSourceDto::TracesActivitiesDataset => {
+                    let dataset = Self::get_user_data(context, keys.traces_activities_dataset())?;
+                    let projected_dataset = project_dataset(dataset, &projection, dimensions);
+                    context.put_concrete(keys.projected_dataset().key(), projected_dataset);
+                }
+                DatasetProjectionSourceDto::LogTracesDataset => {
+                    let dataset = Self::get_user_data(context, keys.log_traces_dataset())?;
+                    let projected_dataset = project_dataset(dataset, &projection, dimensions);
+                    context.put_concrete(keys.projected_dataset().key(), projected_dataset);
+                }
+                DatasetProjectionSourceDto::LabeledTracesActivitiesDataset => {
+                    let dataset = Self::get_user_data(context, keys.labeled_traces_activities_dataset())?;
+                    let projected_dataset = project_labeled_dataset(dataset, &projection, dimensions);
+                    context.put_concrete(keys.labeled_projected_dataset().key(), projected_dataset);
+                }
+                DatasetProjectionSourceDto::LabeledLogTracesDataset => {
+                    let dataset = Self::get_user_data(context, keys.labeled_log_traces_dataset())?;
+                    let projected_dataset = project_labeled_dataset(dataset, &projection, dimensions);
+                    context.put_concrete(keys.labeled_projected_dataset().key(), projected_dataset);
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn create_dataset_projection(config: &UserDataImpl, keys: &ContextKeys) -> Result<DatasetProjection, PipelinePartExecutionError> {
+        Ok(match Self::get_user_data(config, keys.dataset_projection_method())? {
+            DatasetProjectionMethod::Pca => DatasetProjection::Pca,
+            DatasetProjectionMethod::Tsne => DatasetProjection::Tsne {
+                perplexity: match Self::get_user_data(config, keys.tsne_perplexity()) {
+                    Ok(perplexity) => *perplexity,
+                    Err(_) => 30.0,
+                },
+                iterations_count: match Self::get_user_data(config, keys.learning_iterations_count()) {
+                    Ok(iterations_count) => *iterations_count as usize,
+                    Err(_) => 1000,
+                },
+            },
+        })
+    }
+
     fn create_traces_clustering_params<'a>(
         context: &'a mut PipelineContext,
         config: &'a UserDataImpl,
         keys: &ContextKeys,
     ) -> Result<TracesClusteringParams<'a, XesEventLogImpl>, PipelinePartExecutionError> {
         let tolerance = *Self::get_user_data(config, keys.tolerance())?;
+        Self::create_traces_clustering_params_with_tolerance(context, config, keys, tolerance)
+    }
+
+    fn create_traces_clustering_params_with_tolerance<'a>(
+        context: &'a mut PipelineContext,
+        config: &'a UserDataImpl,
+        keys: &ContextKeys,
+        tolerance: f64,
+    ) -> Result<TracesClusteringParams<'a, XesEventLogImpl>, PipelinePartExecutionError> {
         let distance = *Self::get_user_data(config, keys.distance())?;
         let repr_source = *Self::get_user_data(config, keys.traces_representation_source())?;
+        let featurisation = Self::get_traces_featurisation(config, keys)?;
 
         Ok(TracesClusteringParams {
             vis_params: Self::create_common_vis_params(context, config, keys)?,
             distance,
             tolerance,
             repr_source,
+            featurisation,
         })
     }
 
+    fn get_traces_featurisation(
+        config: &UserDataImpl,
+        keys: &ContextKeys,
+    ) -> Result<Option<TracesFeaturisation>, PipelinePartExecutionError> {
+        let feature_sets = match Self::get_user_data(config, keys.traces_feature_sets()) {
+            Ok(feature_sets) => feature_sets,
+            Err(_) => return Ok(None),
+        };
+
+        match TracesFeaturisation::parse(feature_sets) {
+            Ok(featurisation) => Ok(Some(featurisation)),
+            Err(feature_set) => Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(format!(
+                "Failed to parse traces feature set {}",
+                feature_set
+            )))),
+        }
+    }
+
     pub(super) fn clusterize_log_traces() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::CLUSTERIZE_LOG_TRACES, &|context, infra, keys, config| {
             let mut params = Self::create_traces_clustering_params(context, config, keys)?;
-            let after_clusterization_pipeline = Self::get_user_data(config, keys.pipeline())?;
             let min_points_in_cluster = *Self::get_user_data(config, keys.min_events_in_clusters_count())? as usize;
+            let index = Self::get_clustering_quality_index(config, keys);
+
+            if let Ok(parameter) = Self::get_user_data(config, keys.clustering_search_parameter()) {
+                let (new_logs, labeled_dataset, scores) =
+                    match search_log_traces_dbscan_clustering(&mut params, min_points_in_cluster, *parameter, index) {
+                        Ok(result) => result,
+                        Err(error) => return Err(error.into()),
+                    };
+
+                context.put_concrete(keys.clustering_scores().key(), scores.to_dataset());
+                return Self::put_traces_clusters(context, infra, keys, config, new_logs, labeled_dataset);
+            }
 
             let new_logs = match clusterize_log_by_traces_dbscan(&mut params, min_points_in_cluster) {
                 Ok(new_logs) => new_logs,
                 Err(error) => return Err(error.into()),
             };
 
-            context.put_concrete(keys.labeled_log_traces_dataset().key(), new_logs.1);
-            for log in new_logs.0 {
+            let configuration = DistanceMatrixClustering::Dbscan {
+                tolerance: params.tolerance,
+                min_points: min_points_in_cluster,
+            };
+
+            let distance = Self::get_quality_features_distance(params.distance);
+            Self::put_clustering_quality(context, keys, config, configuration.to_string(), &new_logs.1, distance, true)?;
+            Self::put_traces_clusters(context, infra, keys, config, new_logs.0, new_logs.1)
+        })
+    }
+
+    fn put_traces_clusters(
+        context: &mut PipelineContext,
+        infra: &PipelineInfrastructure,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+        new_logs: Vec<XesEventLogImpl>,
+        labeled_dataset: LabeledDataset,
+    ) -> Result<(), PipelinePartExecutionError> {
+        context.put_concrete(keys.labeled_log_traces_dataset().key(), labeled_dataset);
+        if let Ok(after_clusterization_pipeline) = Self::get_user_data(config, keys.pipeline()) {
+            for log in &new_logs {
                 let mut new_context = context.clone();
-                new_context.put_concrete(keys.event_log().key(), log);
+                new_context.put_concrete(keys.event_log().key(), log.clone());
 
                 after_clusterization_pipeline.execute(&mut new_context, infra, keys)?;
             }
+        }
+
+        context.put_concrete(keys.traces_clusters().key(), new_logs);
+
+        Ok(())
+    }
+
+    //Clusters are cut at `clusters_count` if it is present, otherwise at the `tolerance` distance
+    fn get_dendrogram_cut(config: &UserDataImpl, keys: &ContextKeys) -> Result<DendrogramCut, PipelinePartExecutionError> {
+        match Self::get_user_data(config, keys.clusters_count()) {
+            Ok(clusters_count) => Ok(DendrogramCut::ClustersCount(*clusters_count as usize)),
+            Err(_) => Ok(DendrogramCut::Distance(*Self::get_user_data(config, keys.tolerance())?)),
+        }
+    }
+
+    pub(super) fn clusterize_log_traces_agglomerative() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::CLUSTERIZE_LOG_TRACES_AGGLOMERATIVE, &|context, infra, keys, config| {
+            let linkage = *Self::get_user_data(config, keys.linkage())?;
+            let cut = Self::get_dendrogram_cut(config, keys)?;
+
+            let tolerance = match cut {
+                DendrogramCut::Distance(threshold) => threshold,
+                DendrogramCut::ClustersCount(_) => 0.0,
+            };
+
+            let mut params = Self::create_traces_clustering_params_with_tolerance(context, config, keys, tolerance)?;
+            let (new_logs, labeled_dataset, dendrogram) = match clusterize_log_by_traces_agglomerative(&mut params, linkage, cut) {
+                Ok(result) => result,
+                Err(error) => return Err(error.into()),
+            };
+
+            let configuration = DistanceMatrixClustering::Agglomerative { linkage, cut };
+            let distance = Self::get_quality_features_distance(params.distance);
+            Self::put_clustering_quality(context, keys, config, configuration.to_string(), &labeled_dataset, distance, false)?;
+
+            context.put_concrete(keys.dendrogram().key(), dendrogram);
+            Self::put_traces_clusters(context, infra, keys, config, new_logs, labeled_dataset)
+        })
+    }
+
+    fn create_distance_matrix_clustering(
+        config: &UserDataImpl,
+        keys: &ContextKeys,
+    ) -> Result<DistanceMatrixClustering, PipelinePartExecutionError> {
+        Ok(match *Self::get_user_data(config, keys.distance_matrix_clustering_method())? {
+            DistanceMatrixClusteringMethod::Dbscan => DistanceMatrixClustering::Dbscan {
+                tolerance: *Self::get_user_data(config, keys.tolerance())?,
+                min_points: *Self::get_user_data(config, keys.min_events_in_clusters_count())? as usize,
+            },
+            DistanceMatrixClusteringMethod::KMedoids => DistanceMatrixClustering::KMedoids {
+                clusters_count: *Self::get_user_data(config, keys.clusters_count())? as usize,
+                iterations_count: *Self::get_user_data(config, keys.learning_iterations_count())? as usize,
+            },
+            DistanceMatrixClusteringMethod::Agglomerative => DistanceMatrixClustering::Agglomerative {
+                linkage: *Self::get_user_data(config, keys.linkage())?,
+                cut: Self::get_dendrogram_cut(config, keys)?,
+            },
+        })
+    }
+
+    pub(super) fn clusterize_log_traces_distance_matrix() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::CLUSTERIZE_LOG_TRACES_DISTANCE_MATRIX, &|context, infra, keys, config| {
+            let clustering = Self::create_distance_matrix_clustering(config, keys)?;
+            let tolerance = match clustering {
+                DistanceMatrixClustering::Dbscan { tolerance, .. } => tolerance,
+                _ => 0.0,
+            };
+
+            let index = Self::get_clustering_quality_index(config, keys);
+            let search_parameter = Self::get_user_data(config, keys.clustering_search_parameter()).ok().copied();
+
+            let mut params = Self::create_traces_clustering_params_with_tolerance(context, config, keys, tolerance)?;
+            let (new_logs, labeled_dataset, dendrogram) = if let Some(parameter) = search_parameter {
+                let (new_logs, labeled_dataset, dendrogram, scores) =
+                    match search_log_traces_distance_matrix_clustering(&mut params, &clustering, parameter, index) {
+                        Ok(result) => result,
+                        Err(error) => return Err(error.into()),
+                    };
+
+                context.put_concrete(keys.clustering_scores().key(), scores.to_dataset());
+                (new_logs, labeled_dataset, dendrogram)
+            } else {
+                let (new_logs, labeled_dataset, dendrogram) = match clusterize_log_by_traces_distance_matrix(&mut params, &clustering) {
+                    Ok(result) => result,
+                    Err(error) => return Err(error.into()),
+                };
+
+                Self::put_clustering_quality(context, keys, config, clustering.to_string(), &labeled_dataset, None, true)?;
+                (new_logs, labeled_dataset, dendrogram)
+            };
+
+            if let Some(dendrogram) = dendrogram {
+                context.put_concrete(keys.dendrogram().key(), dendrogram);
+            }
+
+            Self::put_traces_clusters(context, infra, keys, config, new_logs, labeled_dataset)
+        })
+    }
+
+    //Clusters count is optional and limits the count of discovered models, the after-clusterization pipeline
+    //receives both the sub-log and its model
+    pub(super) fn clusterize_log_traces_model_driven() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::CLUSTERIZE_LOG_TRACES_MODEL_DRIVEN, &|context, infra, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let miner = Self::create_petri_net_miner(config, keys)?;
+            let target_fitness = *Self::get_user_data(config, keys.target_fitness())?;
+            let max_clusters_count = match Self::get_user_data(config, keys.clusters_count()) {
+                Ok(clusters_count) => Some(*clusters_count as usize),
+                Err(_) => None,
+            };
+
+            //Each rejected candidate costs the model re-discovery, so the count of such candidates is limited
+            let look_ahead_count = match Self::get_user_data(config, keys.look_ahead_count()) {
+                Ok(look_ahead_count) => *look_ahead_count as usize,
+                Err(_) => 5,
+            };
+
+            let colors_holder = Self::get_user_data_mut(context, keys.colors_holder())?;
+            let mut params = ModelDrivenClusteringParams {
+                log,
+                colors_holder,
+                miner: miner.as_ref(),
+                target_fitness,
+                max_clusters_count,
+                look_ahead_count,
+            };
+
+            let clusters = match clusterize_log_by_traces_models(&mut params) {
+                Ok(clusters) => clusters,
+                Err(error) => return Err(error.into()),
+            };
+
+            if let Ok(after_clusterization_pipeline) = Self::get_user_data(config, keys.pipeline()) {
+                for (log, petri_net) in clusters.logs().iter().zip(clusters.petri_nets().iter()) {
+                    let mut new_context = context.clone();
+                    new_context.put_concrete(keys.event_log().key(), log.clone());
+                    new_context.put_concrete(keys.petri_net().key(), petri_net.clone());
+
+                    after_clusterization_pipeline.execute(&mut new_context, infra, keys)?;
+                }
+            }
+
+            context.put_concrete(keys.labeled_log_traces_dataset().key(), clusters.dataset().clone());
+            context.put_concrete(keys.traces_clusters_petri_nets().key(), clusters.petri_nets().clone());
+            context.put_concrete(keys.residual_traces_cluster().key(), clusters.residual_log().clone());
+            context.put_concrete(keys.traces_clusters().key(), clusters.logs().clone());
 
             Ok(())
         })
     }
+
+    pub(super) fn clusterize_activities_from_traces_distance_matrix() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(
+            Self::CLUSTERIZE_ACTIVITIES_FROM_TRACES_DISTANCE_MATRIX,
+            &|context, _, keys, config| {
+                let clustering = Self::create_distance_matrix_clustering(config, keys)?;
+                let distance = *Self::get_user_data(config, keys.distance())?;
+                let index = Self::get_clustering_quality_index(config, keys);
+                let search_parameter = Self::get_user_data(config, keys.clustering_search_parameter()).ok().copied();
+                let mut params = Self::create_activities_visualization_params(context, config, keys)?;
+
+                let (labeled_dataset, dendrogram) = if let Some(parameter) = search_parameter {
+                    let (labeled_dataset, dendrogram, scores) =
+                        match search_activities_distance_matrix_clustering(&mut params, distance, &clustering, parameter, index) {
+                            Ok(result) => result,
+                            Err(error) => return Err(error.into()),
+                        };
+
+                    context.put_concrete(keys.clustering_scores().key(), scores.to_dataset());
+                    (labeled_dataset, dendrogram)
+                } else {
+                    let (labeled_dataset, dendrogram) = match clusterize_activities_distance_matrix(&mut params, distance, &clustering) {
+                        Ok(result) => result,
+                        Err(error) => return Err(error.into()),
+                    };
+
+                    Self::put_clustering_quality(context, keys, config, clustering.to_string(), &labeled_dataset, None, true)?;
+                    (labeled_dataset, dendrogram)
+                };
+
+                if let Some(dendrogram) = dendrogram {
+                    context.put_concrete(keys.dendrogram().key(), dendrogram);
+                }
+
+                context.put_concrete(keys.labeled_traces_activities_dataset().key(), labeled_dataset);
+                Ok(())
+            },
+        )
+    }
+
+    //Silhouette index is used by default
+    fn get_clustering_quality_index(config: &UserDataImpl, keys: &ContextKeys) -> ClusteringQualityIndex {
+        match Self::get_user_data(config, keys.clustering_quality_index()) {
+            Ok(index) => *index,
+            Err(_) => ClusteringQualityIndex::Silhouette,
+        }
+    }
+
+    fn create_single_clustering_scores(
+        configuration: String,
+        labeled_dataset: &LabeledDataset,
+        distance: Option<FicusDistance>,
+        has_noise: bool,
+    ) -> Result<ClusteringScores, PipelinePartExecutionError> {
+        let quality = match calculate_labeled_dataset_quality(labeled_dataset, distance, has_noise) {
+            Ok(quality) => quality,
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut scores = ClusteringScores::default();
+        scores.push(configuration, quality);
+
+        Ok(scores)
+    }
+
+    //Sequence distances are clusterized over the distance matrix, so the labeled dataset already contains distances
+    fn get_quality_features_distance(distance: FicusDistance) -> Option<FicusDistance> {
+        match distance.is_features_distance() {
+            true => Some(distance),
+            false => None,
+        }
+    }
+
+    //Quality indices require all pairwise distances, so they are calculated only when the evaluation is requested
+    fn put_clustering_quality(
+        context: &mut PipelineContext,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+        configuration: String,
+        labeled_dataset: &LabeledDataset,
+        distance: Option<FicusDistance>,
+        has_noise: bool,
+    ) -> Result<(), PipelinePartExecutionError> {
+        match Self::get_user_data(config, keys.evaluate_clustering()) {
+            Ok(evaluate) if *evaluate => {}
+            _ => return Ok(()),
+        }
+
+        let scores = Self::create_single_clustering_scores(configuration, labeled_dataset, distance, has_noise)?;
+        context.put_concrete(keys.clustering_scores().key(), scores.to_dataset());
+
+        Ok(())
+    }
 }
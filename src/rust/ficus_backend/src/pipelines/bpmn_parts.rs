@@ -0,0 +1,56 @@
+use crate::features::discovery::bpmn::bpmn_serialization::serialize_bpmn_to_file;
+use crate::features::discovery::bpmn::heuristic_to_bpmn::discover_bpmn_heuristic;
+use crate::features::discovery::bpmn::petri_net_to_bpmn::convert_petri_net_to_bpmn;
+use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
+use crate::pipelines::pipeline_parts::PipelineParts;
+use crate::pipelines::pipelines::PipelinePartFactory;
+use crate::utils::user_data::user_data::UserData;
+
+impl PipelineParts {
+    pub(super) fn convert_petri_net_to_bpmn() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::CONVERT_PETRI_NET_TO_BPMN, &|context, _, keys, _| {
+            let petri_net = Self::get_user_data(context, keys.petri_net())?;
+            let bpmn_model = convert_petri_net_to_bpmn(petri_net);
+
+            context.put_concrete(keys.bpmn_model().key(), bpmn_model);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn discover_bpmn_heuristic() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::DISCOVER_BPMN_HEURISTIC, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let dependency_threshold = *Self::get_user_data(config, keys.dependency_threshold())?;
+            let positive_observations_threshold = *Self::get_user_data(config, keys.positive_observations_threshold())? as usize;
+            let relative_to_best_threshold = *Self::get_user_data(config, keys.relative_to_best_threshold())?;
+            let and_threshold = *Self::get_user_data(config, keys.and_threshold())?;
+            let loop_length_two_threshold = *Self::get_user_data(config, keys.loop_length_two_threshold())?;
+
+            let bpmn_model = discover_bpmn_heuristic(
+                log,
+                dependency_threshold,
+                positive_observations_threshold,
+                relative_to_best_threshold,
+                and_threshold,
+                loop_length_two_threshold,
+            );
+
+            context.put_concrete(keys.bpmn_model().key(), bpmn_model);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn serialize_bpmn() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::SERIALIZE_BPMN, &|context, _, keys, config| {
+            let bpmn_model = Self::get_user_data(context, keys.bpmn_model())?;
+            let save_path = Self::get_user_data(config, keys.path())?;
+
+            match serialize_bpmn_to_file(bpmn_model, save_path) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(error.to_string()))),
+            }
+        })
+    }
+}
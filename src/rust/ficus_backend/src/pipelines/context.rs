@@ -1,6 +1,12 @@
+use crate::pipelines::memoization::{ContextAccesses, PipelinePartsCache};
 use crate::pipelines::pipeline_parts::PipelineParts;
 use crate::utils::performance::performance_cookie::PerformanceLogger;
-use std::{any::Any, sync::Arc};
+use std::{
+    any::Any,
+    cell::{Ref, RefCell},
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 use crate::utils::user_data::{
     keys::{DefaultKey, Key},
@@ -15,6 +21,7 @@ pub trait LogMessageHandler: Send + Sync {
 
 pub struct PipelineInfrastructure {
     log_message_handler: Option<Arc<Box<dyn LogMessageHandler>>>,
+    parts_cache: Option<Arc<Mutex<PipelinePartsCache>>>,
 }
 
 impl PerformanceLogger<PipelinePartExecutionError> for PipelineInfrastructure {
@@ -26,7 +33,24 @@ impl PerformanceLogger<PipelinePartExecutionError> for PipelineInfrastructure {
 
 impl PipelineInfrastructure {
     pub fn new(log_message_handler: Option<Arc<Box<dyn LogMessageHandler>>>) -> Self {
-        Self { log_message_handler }
+        Self {
+            log_message_handler,
+            parts_cache: None,
+        }
+    }
+
+    pub fn new_with_cache(
+        log_message_handler: Option<Arc<Box<dyn LogMessageHandler>>>,
+        parts_cache: Option<Arc<Mutex<PipelinePartsCache>>>,
+    ) -> Self {
+        Self {
+            log_message_handler,
+            parts_cache,
+        }
+    }
+
+    pub fn parts_cache(&self) -> Option<&Arc<Mutex<PipelinePartsCache>>> {
+        self.parts_cache.as_ref()
     }
 
     pub fn log(&self, message: String) -> Result<(), PipelinePartExecutionError> {
@@ -42,27 +66,29 @@ impl PipelineInfrastructure {
 pub struct PipelineContext<'a> {
     user_data: UserDataImpl,
     pipeline_parts: Option<&'a PipelineParts>,
+    fingerprints: RefCell<HashMap<u64, u64>>,
+    accesses: RefCell<Option<ContextAccesses>>,
 }
 
 impl<'a> PipelineContext<'a> {
     pub fn new_with_logging(parts: &'a PipelineParts) -> Self {
-        Self {
-            user_data: UserDataImpl::new(),
-            pipeline_parts: Some(parts),
-        }
+        Self::new_internal(Some(parts))
     }
 
     pub fn empty() -> Self {
-        Self {
-            user_data: UserDataImpl::new(),
-            pipeline_parts: None,
-        }
+        Self::new_internal(None)
     }
 
     pub fn empty_from(other: &'a PipelineContext) -> Self {
+        Self::new_internal(other.pipeline_parts.clone())
+    }
+
+    fn new_internal(pipeline_parts: Option<&'a PipelineParts>) -> Self {
         Self {
             user_data: UserDataImpl::new(),
-            pipeline_parts: other.pipeline_parts.clone(),
+            pipeline_parts,
+            fingerprints: RefCell::new(HashMap::new()),
+            accesses: RefCell::new(None),
         }
     }
 }
@@ -73,30 +99,38 @@ impl<'a> UserData for PipelineContext<'a> {
     }
 
     fn put_concrete<T: 'static>(&mut self, key: &DefaultKey<T>, value: T) {
+        self.on_write(key);
         self.user_data.put_concrete(key, value)
     }
 
     fn put_any<T: 'static>(&mut self, key: &dyn Key, value: T) {
+        self.on_write(key);
         self.user_data.put_any(key, value)
     }
 
     fn concrete<T: 'static>(&self, key: &DefaultKey<T>) -> Option<&T> {
+        self.on_read(key);
         self.user_data.concrete(key)
     }
 
     fn any(&self, key: &dyn Key) -> Option<&dyn Any> {
+        self.on_read(key);
         self.user_data.any(key)
     }
 
     fn concrete_mut<T: 'static>(&self, key: &DefaultKey<T>) -> Option<&mut T> {
+        self.on_read(key);
+        self.on_write(key);
         self.user_data.concrete_mut(key)
     }
 
     fn remove_concrete<T: 'static>(&mut self, key: &DefaultKey<T>) {
+        self.on_remove(key);
         self.user_data.remove_concrete(key)
     }
 
     fn remove_any<T: 'static>(&mut self, key: &dyn Key) {
+        self.on_remove(key);
         self.user_data.remove_any::<T>(key)
     }
 }
@@ -109,4 +143,50 @@ impl<'a> PipelineContext<'a> {
     pub fn devastate_user_data(self) -> UserDataImpl {
         self.user_data
     }
+
+    pub(super) fn entries(&self) -> Vec<(u64, Ref<dyn Any>)> {
+        self.user_data.entries()
+    }
+
+    pub(super) fn fingerprint(&self, key_id: u64) -> Option<u64> {
+        self.fingerprints.borrow().get(&key_id).cloned()
+    }
+
+    pub(super) fn set_fingerprint(&self, key_id: u64, fingerprint: u64) {
+        self.fingerprints.borrow_mut().insert(key_id, fingerprint);
+    }
+
+    pub(super) fn start_accesses_tracking(&self) -> bool {
+        let mut accesses = self.accesses.borrow_mut();
+        if accesses.is_some() {
+            return false;
+        }
+
+        *accesses = Some(ContextAccesses::empty());
+        true
+    }
+
+    pub(super) fn finish_accesses_tracking(&self) -> ContextAccesses {
+        self.accesses.borrow_mut().take().unwrap_or(ContextAccesses::empty())
+    }
+
+    fn on_read(&self, key: &dyn Key) {
+        if let Some(accesses) = self.accesses.borrow_mut().as_mut() {
+            accesses.read.insert(key.id(), key.name().to_owned());
+        }
+    }
+
+    fn on_write(&self, key: &dyn Key) {
+        self.fingerprints.borrow_mut().remove(&key.id());
+        if let Some(accesses) = self.accesses.borrow_mut().as_mut() {
+            accesses.written.insert(key.id(), key.name().to_owned());
+        }
+    }
+
+    fn on_remove(&self, key: &dyn Key) {
+        self.fingerprints.borrow_mut().remove(&key.id());
+        if let Some(accesses) = self.accesses.borrow_mut().as_mut() {
+            accesses.removed.insert(key.id(), key.name().to_owned());
+        }
+    }
 }
@@ -0,0 +1,245 @@
+use std::str::FromStr;
+
+use crate::event_log::core::event_log::EventLog;
+use crate::event_log::xes::xes_event_log::XesEventLogImpl;
+use crate::features::analysis::event_log_info::count_events;
+use crate::utils::user_data::user_data::UserData;
+
+use super::{
+    context::{PipelineContext, PipelineInfrastructure},
+    errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError},
+    keys::context_keys::ContextKeys,
+    pipeline_parts::PipelineParts,
+    pipelines::{Pipeline, PipelinePart},
+};
+
+#[derive(Clone, Copy)]
+pub enum LogMetric {
+    EventsCount,
+    TracesCount,
+}
+
+impl FromStr for LogMetric {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "EventsCount" => Ok(Self::EventsCount),
+            "TracesCount" => Ok(Self::TracesCount),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum ComparisonKind {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl FromStr for ComparisonKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Less" => Ok(Self::Less),
+            "LessOrEqual" => Ok(Self::LessOrEqual),
+            "Greater" => Ok(Self::Greater),
+            "GreaterOrEqual" => Ok(Self::GreaterOrEqual),
+            "Equal" => Ok(Self::Equal),
+            "NotEqual" => Ok(Self::NotEqual),
+            _ => Err(()),
+        }
+    }
+}
+
+pub enum PredicateOperand {
+    LogMetric(LogMetric),
+    ContextValue(String),
+}
+
+pub struct PipelinePredicate {
+    operand: PredicateOperand,
+    comparison: ComparisonKind,
+    threshold: f64,
+}
+
+impl PipelinePredicate {
+    pub fn new(operand: PredicateOperand, comparison: ComparisonKind, threshold: f64) -> Self {
+        Self {
+            operand,
+            comparison,
+            threshold,
+        }
+    }
+
+    pub fn evaluate(&self, context: &PipelineContext, keys: &ContextKeys) -> Result<bool, PipelinePartExecutionError> {
+        let value = self.evaluate_operand(context, keys)?;
+
+        Ok(match self.comparison {
+            ComparisonKind::Less => value < self.threshold,
+            ComparisonKind::LessOrEqual => value <= self.threshold,
+            ComparisonKind::Greater => value > self.threshold,
+            ComparisonKind::GreaterOrEqual => value >= self.threshold,
+            ComparisonKind::Equal => value == self.threshold,
+            ComparisonKind::NotEqual => value != self.threshold,
+        })
+    }
+
+    fn evaluate_operand(&self, context: &PipelineContext, keys: &ContextKeys) -> Result<f64, PipelinePartExecutionError> {
+        match &self.operand {
+            PredicateOperand::LogMetric(metric) => {
+                let log = PipelineParts::get_user_data(context, keys.event_log())?;
+                Ok(Self::evaluate_log_metric(log, metric))
+            }
+            PredicateOperand::ContextValue(key_name) => {
+                let key = match keys.find_key(key_name) {
+                    Some(key) => key,
+                    None => return Err(Self::create_error(format!("Failed to find key {}", key_name))),
+                };
+
+                let value = match context.any(key.key()) {
+                    Some(value) => value,
+                    None => return Err(Self::create_error(format!("Context value for key {} is missing", key_name))),
+                };
+
+                if let Some(value) = value.downcast_ref::<u32>() {
+                    Ok(*value as f64)
+                } else if let Some(value) = value.downcast_ref::<usize>() {
+                    Ok(*value as f64)
+                } else if let Some(value) = value.downcast_ref::<f64>() {
+                    Ok(*value)
+                } else if let Some(value) = value.downcast_ref::<f32>() {
+                    Ok(*value as f64)
+                } else if let Some(value) = value.downcast_ref::<bool>() {
+                    Ok(if *value { 1.0 } else { 0.0 })
+                } else if let Some(log) = value.downcast_ref::<XesEventLogImpl>() {
+                    Ok(Self::evaluate_log_metric(log, &LogMetric::EventsCount))
+                } else {
+                    Err(Self::create_error(format!("Context value for key {} is not a number", key_name)))
+                }
+            }
+        }
+    }
+
+    fn evaluate_log_metric(log: &XesEventLogImpl, metric: &LogMetric) -> f64 {
+        match metric {
+            LogMetric::EventsCount => count_events(log) as f64,
+            LogMetric::TracesCount => log.traces().len() as f64,
+        }
+    }
+
+    fn create_error(message: String) -> PipelinePartExecutionError {
+        PipelinePartExecutionError::Raw(RawPartExecutionError::new(message))
+    }
+}
+
+pub struct IfPipelinePart {
+    predicate: PipelinePredicate,
+    then_pipeline: Pipeline,
+    else_pipeline: Option<Pipeline>,
+}
+
+impl IfPipelinePart {
+    pub fn new(predicate: PipelinePredicate, then_pipeline: Pipeline, else_pipeline: Option<Pipeline>) -> Self {
+        Self {
+            predicate,
+            then_pipeline,
+            else_pipeline,
+        }
+    }
+}
+
+impl PipelinePart for IfPipelinePart {
+    fn execute(
+        &self,
+        context: &mut PipelineContext,
+        infra: &PipelineInfrastructure,
+        keys: &ContextKeys,
+    ) -> Result<(), PipelinePartExecutionError> {
+        if self.predicate.evaluate(context, keys)? {
+            self.then_pipeline.execute(context, infra, keys)
+        } else if let Some(else_pipeline) = self.else_pipeline.as_ref() {
+            else_pipeline.execute(context, infra, keys)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+pub struct WhilePipelinePart {
+    predicate: Option<PipelinePredicate>,
+    body: Pipeline,
+    max_iterations: usize,
+}
+
+impl WhilePipelinePart {
+    pub fn new(predicate: Option<PipelinePredicate>, body: Pipeline, max_iterations: usize) -> Self {
+        Self {
+            predicate,
+            body,
+            max_iterations,
+        }
+    }
+}
+
+impl PipelinePart for WhilePipelinePart {
+    fn execute(
+        &self,
+        context: &mut PipelineContext,
+        infra: &PipelineInfrastructure,
+        keys: &ContextKeys,
+    ) -> Result<(), PipelinePartExecutionError> {
+        for _ in 0..self.max_iterations {
+            if let Some(predicate) = self.predicate.as_ref() {
+                if !predicate.evaluate(context, keys)? {
+                    return Ok(());
+                }
+            }
+
+            self.body.execute(context, infra, keys)?;
+        }
+
+        if let Some(predicate) = self.predicate.as_ref() {
+            if predicate.evaluate(context, keys)? {
+                infra.log(format!("Loop was stopped after reaching {} iterations", self.max_iterations))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct ForEachTraceClusterPipelinePart {
+    pipeline: Pipeline,
+}
+
+impl ForEachTraceClusterPipelinePart {
+    pub fn new(pipeline: Pipeline) -> Self {
+        Self { pipeline }
+    }
+}
+
+impl PipelinePart for ForEachTraceClusterPipelinePart {
+    fn execute(
+        &self,
+        context: &mut PipelineContext,
+        infra: &PipelineInfrastructure,
+        keys: &ContextKeys,
+    ) -> Result<(), PipelinePartExecutionError> {
+        let clusters = PipelineParts::get_user_data(context, keys.traces_clusters())?;
+
+        for log in clusters {
+            let mut new_context = context.clone();
+            new_context.put_concrete(keys.event_log().key(), log.clone());
+
+            self.pipeline.execute(&mut new_context, infra, keys)?;
+        }
+
+        Ok(())
+    }
+}
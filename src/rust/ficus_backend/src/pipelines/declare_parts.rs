@@ -0,0 +1,62 @@
+use crate::features::discovery::declare::declare_conformance::check_declare_conformance;
+use crate::features::discovery::declare::declare_discovery::{discover_declare_model, DeclareDiscoveryParams};
+use crate::features::discovery::declare::json_serialization::{read_declare_model_from_json_file, serialize_declare_model_to_json_file};
+use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
+use crate::pipelines::pipeline_parts::PipelineParts;
+use crate::pipelines::pipelines::PipelinePartFactory;
+use crate::utils::user_data::user_data::UserData;
+
+impl PipelineParts {
+    pub(super) fn discover_declare_model() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::DISCOVER_DECLARE_MODEL, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let min_support = *Self::get_user_data(config, keys.min_relative_support())?;
+            let min_confidence = *Self::get_user_data(config, keys.min_confidence())?;
+
+            let params = DeclareDiscoveryParams::with_all_templates(min_support, min_confidence);
+            let model = discover_declare_model(log, &params);
+
+            context.put_concrete(keys.declare_model().key(), model);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn check_declare_conformance() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::CHECK_DECLARE_CONFORMANCE, &|context, _, keys, _| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let model = Self::get_user_data(context, keys.declare_model())?;
+
+            let conformance = check_declare_conformance(log, model);
+            context.put_concrete(keys.declare_conformance().key(), conformance);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn serialize_declare_model() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::SERIALIZE_DECLARE_MODEL, &|context, _, keys, config| {
+            let model = Self::get_user_data(context, keys.declare_model())?;
+            let save_path = Self::get_user_data(config, keys.path())?;
+
+            match serialize_declare_model_to_json_file(model, save_path) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(error.to_string()))),
+            }
+        })
+    }
+
+    pub(super) fn read_declare_model() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::READ_DECLARE_MODEL, &|context, _, keys, config| {
+            let path = Self::get_user_data(config, keys.path())?;
+
+            match read_declare_model_from_json_file(path) {
+                Ok(model) => {
+                    context.put_concrete(keys.declare_model().key(), model);
+                    Ok(())
+                }
+                Err(error) => Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(error.to_string()))),
+            }
+        })
+    }
+}
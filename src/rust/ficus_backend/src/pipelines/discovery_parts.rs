@@ -1,27 +1,64 @@
+use std::fs;
+use std::str::FromStr;
+
+use crate::event_log::xes::xes_event_log::XesEventLogImpl;
 use crate::features::analysis::directly_follows_graph::construct_dfg;
 use crate::features::analysis::event_log_info::{EventLogInfo, EventLogInfoCreationDto};
+use crate::features::analysis::graph_analysis::analyze_graph;
 use crate::features::discovery::alpha::alpha::{discover_petri_net_alpha, discover_petri_net_alpha_plus, find_transitions_one_length_loop};
 use crate::features::discovery::alpha::alpha_plus_plus_nfc::alpha_plus_plus_nfc::discover_petri_net_alpha_plus_plus_nfc;
 use crate::features::discovery::alpha::providers::alpha_plus_provider::AlphaPlusRelationsProviderImpl;
 use crate::features::discovery::alpha::providers::alpha_provider::DefaultAlphaRelationsProvider;
+use crate::features::discovery::fuzzy::fuzzy_graph_serialization::serialize_fuzzy_graph_to_dot;
 use crate::features::discovery::fuzzy::fuzzy_miner::discover_graph_fuzzy;
 use crate::features::discovery::heuristic::heuristic_miner::discover_petri_net_heuristic;
+use crate::features::discovery::hierarchy::hierarchical_petri_net::discover_hierarchical_petri_net;
+use crate::features::discovery::local_process_models::local_process_models::{
+    discover_local_process_models, LocalProcessModelsDiscoveryParams,
+};
+use crate::features::discovery::petri_net::dot_serialization::serialize_petri_net_to_dot;
 use crate::features::discovery::petri_net::marking::ensure_initial_marking;
-use crate::features::discovery::petri_net::pnml_serialization::serialize_to_pnml_file;
+use crate::features::discovery::petri_net::petri_net::DefaultPetriNet;
+use crate::features::discovery::petri_net::pnml_serialization::{serialize_hierarchical_to_pnml_file, serialize_to_pnml_file};
+use crate::features::discovery::transition_system::regions::{synthesize_petri_net_from_regions, DEFAULT_MAX_REGIONS_SEARCH_STEPS};
+use crate::features::discovery::transition_system::transition_system::{TransitionSystem, TransitionSystemParams};
 use crate::pipelines::context::PipelineContext;
 use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
 use crate::pipelines::keys::context_keys::ContextKeys;
 use crate::pipelines::pipeline_parts::PipelineParts;
 use crate::pipelines::pipelines::PipelinePartFactory;
-use crate::utils::user_data::user_data::UserData;
+use crate::utils::graph::graph_serialization::serialize_graph_to_json;
+use crate::utils::user_data::user_data::{UserData, UserDataImpl};
+
+#[derive(Clone, Copy, Hash)]
+pub enum PetriNetMinerDto {
+    Alpha,
+    AlphaPlus,
+    AlphaPlusPlus,
+    AlphaPlusPlusNfc,
+    Heuristic,
+}
+
+impl FromStr for PetriNetMinerDto {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Alpha" => Ok(Self::Alpha),
+            "AlphaPlus" => Ok(Self::AlphaPlus),
+            "AlphaPlusPlus" => Ok(Self::AlphaPlusPlus),
+            "AlphaPlusPlusNfc" => Ok(Self::AlphaPlusPlusNfc),
+            "Heuristic" => Ok(Self::Heuristic),
+            _ => Err(()),
+        }
+    }
+}
 
 impl PipelineParts {
     pub(super) fn discover_petri_net_alpha() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::DISCOVER_PETRI_NET_ALPHA, &|context, _, keys, _| {
             let log = Self::get_user_data(context, keys.event_log())?;
-            let event_log_info = EventLogInfo::create_from(EventLogInfoCreationDto::default(log));
-            let provider = DefaultAlphaRelationsProvider::new(&event_log_info);
-            let discovered_net = discover_petri_net_alpha(&provider);
+            let discovered_net = Self::discover_petri_net_alpha_from_log(log);
 
             context.put_concrete(keys.petri_net().key(), discovered_net);
 
@@ -29,6 +66,13 @@ impl PipelineParts {
         })
     }
 
+    fn discover_petri_net_alpha_from_log(log: &XesEventLogImpl) -> DefaultPetriNet {
+        let event_log_info = EventLogInfo::create_from(EventLogInfoCreationDto::default(log));
+        let provider = DefaultAlphaRelationsProvider::new(&event_log_info);
+
+        discover_petri_net_alpha(&provider)
+    }
+
     pub(super) fn serialize_petri_net() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::SERIALIZE_PETRI_NET, &|context, _, keys, config| {
             let petri_net = Self::get_user_data(context, keys.petri_net())?;
@@ -42,6 +86,49 @@ impl PipelineParts {
         })
     }
 
+    pub(super) fn serialize_petri_net_to_dot() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::SERIALIZE_PETRI_NET_TO_DOT, &|context, _, keys, config| {
+            let petri_net = Self::get_user_data(context, keys.petri_net())?;
+            let save_path = Self::get_user_data(config, keys.path())?;
+
+            Self::write_to_file(save_path, serialize_petri_net_to_dot(petri_net))
+        })
+    }
+
+    pub(super) fn serialize_graph() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::SERIALIZE_GRAPH, &|context, _, keys, config| {
+            let graph = Self::get_user_data(context, keys.graph())?;
+            let save_path = Self::get_user_data(config, keys.path())?;
+
+            Self::write_to_file(save_path, serialize_graph_to_json(graph))
+        })
+    }
+
+    pub(super) fn serialize_to_dot() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::SERIALIZE_TO_DOT, &|context, _, keys, config| {
+            let graph = Self::get_user_data(context, keys.graph())?;
+            let save_path = Self::get_user_data(config, keys.path())?;
+
+            Self::write_to_file(save_path, serialize_fuzzy_graph_to_dot(graph))
+        })
+    }
+
+    pub(super) fn analyze_graph() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::ANALYZE_GRAPH, &|context, _, keys, _| {
+            let dataset = analyze_graph(Self::get_user_data(context, keys.graph())?);
+            context.put_concrete(keys.graph_analysis_dataset().key(), dataset);
+
+            Ok(())
+        })
+    }
+
+    fn write_to_file(save_path: &str, content: String) -> Result<(), PipelinePartExecutionError> {
+        match fs::write(save_path, content) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(error.to_string()))),
+        }
+    }
+
     pub(super) fn discover_petri_net_alpha_plus() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::DISCOVER_PETRI_NET_ALPHA_PLUS, &|context, _, keys, _| {
             Self::do_discover_petri_net_alpha_plus(context, keys, false)
@@ -54,17 +141,20 @@ impl PipelineParts {
         alpha_plus_plus: bool,
     ) -> Result<(), PipelinePartExecutionError> {
         let log = Self::get_user_data(context, keys.event_log())?;
+        let discovered_net = Self::discover_petri_net_alpha_plus_from_log(log, alpha_plus_plus);
 
+        context.put_concrete(keys.petri_net().key(), discovered_net);
+
+        Ok(())
+    }
+
+    fn discover_petri_net_alpha_plus_from_log(log: &XesEventLogImpl, alpha_plus_plus: bool) -> DefaultPetriNet {
         let one_length_loop_transitions = find_transitions_one_length_loop(log);
         let event_log_info = EventLogInfo::create_from(EventLogInfoCreationDto::default_ignore(log, &one_length_loop_transitions));
 
         let provider = AlphaPlusRelationsProviderImpl::new(&event_log_info, log, &one_length_loop_transitions);
 
-        let discovered_net = discover_petri_net_alpha_plus(log, &provider, alpha_plus_plus);
-
-        context.put_concrete(keys.petri_net().key(), discovered_net);
-
-        Ok(())
+        discover_petri_net_alpha_plus(log, &provider, alpha_plus_plus)
     }
 
     pub(super) fn discover_petri_net_alpha_plus_plus() -> (String, PipelinePartFactory) {
@@ -154,4 +244,136 @@ impl PipelineParts {
             Ok(())
         })
     }
+
+    pub(super) fn discover_hierarchical_petri_net() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::DISCOVER_HIERARCHICAL_PETRI_NET, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let miner = Self::create_petri_net_miner(config, keys)?;
+
+            let hierarchical_net = discover_hierarchical_petri_net(log, miner.as_ref());
+            context.put_concrete(keys.hierarchical_petri_net().key(), hierarchical_net);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn create_petri_net_miner(
+        config: &UserDataImpl,
+        keys: &ContextKeys,
+    ) -> Result<Box<dyn Fn(&XesEventLogImpl) -> DefaultPetriNet>, PipelinePartExecutionError> {
+        let miner: Box<dyn Fn(&XesEventLogImpl) -> DefaultPetriNet> = match *Self::get_user_data(config, keys.petri_net_miner())? {
+            PetriNetMinerDto::Alpha => Box::new(|log: &XesEventLogImpl| Self::discover_petri_net_alpha_from_log(log)),
+            PetriNetMinerDto::AlphaPlus => Box::new(|log: &XesEventLogImpl| Self::discover_petri_net_alpha_plus_from_log(log, false)),
+            PetriNetMinerDto::AlphaPlusPlus => Box::new(|log: &XesEventLogImpl| Self::discover_petri_net_alpha_plus_from_log(log, true)),
+            PetriNetMinerDto::AlphaPlusPlusNfc => Box::new(|log: &XesEventLogImpl| discover_petri_net_alpha_plus_plus_nfc(log)),
+            PetriNetMinerDto::Heuristic => {
+                let dependency_threshold = *Self::get_user_data(config, keys.dependency_threshold())?;
+                let positive_observations_threshold = *Self::get_user_data(config, keys.positive_observations_threshold())? as usize;
+                let relative_to_best_threshold = *Self::get_user_data(config, keys.relative_to_best_threshold())?;
+                let and_threshold = *Self::get_user_data(config, keys.and_threshold())?;
+                let loop_length_two_threshold = *Self::get_user_data(config, keys.loop_length_two_threshold())?;
+
+                Box::new(move |log: &XesEventLogImpl| {
+                    discover_petri_net_heuristic(
+                        log,
+                        dependency_threshold,
+                        positive_observations_threshold,
+                        relative_to_best_threshold,
+                        and_threshold,
+                        loop_length_two_threshold,
+                    )
+                })
+            }
+        };
+
+        Ok(miner)
+    }
+
+    pub(super) fn serialize_hierarchical_petri_net() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::SERIALIZE_HIERARCHICAL_PETRI_NET, &|context, _, keys, config| {
+            let hierarchical_net = Self::get_user_data(context, keys.hierarchical_petri_net())?;
+            let save_path = Self::get_user_data(config, keys.path())?;
+            let use_names_as_ids = *Self::get_user_data(config, keys.pnml_use_names_as_ids())?;
+
+            match serialize_hierarchical_to_pnml_file(hierarchical_net, save_path, use_names_as_ids) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(error.to_string()))),
+            }
+        })
+    }
+
+    pub(super) fn discover_local_process_models() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::DISCOVER_LOCAL_PROCESS_MODELS, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let params = LocalProcessModelsDiscoveryParams {
+                window_size: *Self::get_user_data(config, keys.lpm_window_size())? as usize,
+                min_support: *Self::get_user_data(config, keys.lpm_min_support())? as usize,
+                max_activities_count: *Self::get_user_data(config, keys.max_activities_count())? as usize,
+                top_models_count: *Self::get_user_data(config, keys.top_models_count())? as usize,
+            };
+
+            let hashed_log = Self::create_hashed_event_log(config, keys, log);
+            let models = discover_local_process_models(&hashed_log, &params);
+
+            context.put_concrete(keys.hashes_event_log().key(), hashed_log);
+            context.put_concrete(keys.local_process_models().key(), models);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn build_transition_system() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::BUILD_TRANSITION_SYSTEM, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let params = Self::create_transition_system_params(keys, config)?;
+            let system = TransitionSystem::build(log, &params);
+
+            context.put_concrete(keys.graph().key(), system.to_graph());
+            context.put_concrete(keys.transition_system().key(), system);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn discover_petri_net_regions() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::DISCOVER_PETRI_NET_REGIONS, &|context, infra, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let params = Self::create_transition_system_params(keys, config)?;
+            let system = TransitionSystem::build(log, &params);
+
+            let result = match synthesize_petri_net_from_regions(&system, DEFAULT_MAX_REGIONS_SEARCH_STEPS) {
+                Ok(result) => result,
+                Err(error) => return Err(error.into()),
+            };
+
+            if !result.not_excitation_closed_labels().is_empty() {
+                infra.log(format!(
+                    "Labels are not excitation closed: {}",
+                    result.not_excitation_closed_labels().join(", ")
+                ))?;
+            }
+
+            context.put_concrete(keys.petri_net().key(), result.into_petri_net());
+            context.put_concrete(keys.transition_system().key(), system);
+
+            Ok(())
+        })
+    }
+
+    //State horizon is optional, all events before or after the position are abstracted if it is not set
+    fn create_transition_system_params(
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+    ) -> Result<TransitionSystemParams, PipelinePartExecutionError> {
+        let horizon = match Self::get_user_data(config, keys.state_horizon()) {
+            Ok(horizon) => Some(*horizon as usize),
+            Err(_) => None,
+        };
+
+        Ok(TransitionSystemParams {
+            abstraction: *Self::get_user_data(config, keys.state_abstraction())?,
+            direction: *Self::get_user_data(config, keys.state_direction())?,
+            horizon,
+        })
+    }
 }
@@ -0,0 +1,74 @@
+use crate::features::analysis::concept_drift::{detect_concept_drift, split_log_by_change_points, ConceptDriftParams, ConceptDriftResult};
+use crate::pipelines::context::PipelineContext;
+use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
+use crate::pipelines::keys::context_keys::ContextKeys;
+use crate::pipelines::pipeline_parts::PipelineParts;
+use crate::pipelines::pipelines::{PipelinePart, PipelinePartFactory};
+use crate::utils::user_data::user_data::{UserData, UserDataImpl};
+
+impl PipelineParts {
+    pub(super) fn detect_concept_drift() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::DETECT_CONCEPT_DRIFT, &|context, _, keys, config| {
+            let result = Self::detect_log_concept_drift(context, keys, config)?;
+            context.put_concrete(keys.concept_drift_dataset().key(), result.to_dataset());
+
+            Ok(())
+        })
+    }
+
+    //Sub-logs are ordered by time, the after-split pipeline is executed for each of them
+    pub(super) fn split_log_by_concept_drift() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::SPLIT_LOG_BY_CONCEPT_DRIFT, &|context, infra, keys, config| {
+            let result = Self::detect_log_concept_drift(context, keys, config)?;
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let sub_logs = split_log_by_change_points(log, &result);
+
+            if let Ok(after_split_pipeline) = Self::get_user_data(config, keys.pipeline()) {
+                for sub_log in &sub_logs {
+                    let mut new_context = context.clone();
+                    new_context.put_concrete(keys.event_log().key(), sub_log.clone());
+
+                    after_split_pipeline.execute(&mut new_context, infra, keys)?;
+                }
+            }
+
+            context.put_concrete(keys.concept_drift_dataset().key(), result.to_dataset());
+            context.put_concrete(keys.drift_sub_logs().key(), sub_logs);
+
+            Ok(())
+        })
+    }
+
+    //Significance level is optional and equals 0.05 by default
+    fn detect_log_concept_drift(
+        context: &PipelineContext,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+    ) -> Result<ConceptDriftResult, PipelinePartExecutionError> {
+        let log = Self::get_user_data(context, keys.event_log())?;
+        let feature = *Self::get_user_data(config, keys.drift_feature())?;
+        let window_size = *Self::get_user_data(config, keys.drift_window_size())? as usize;
+        let significance_level = match Self::get_user_data(config, keys.drift_significance_level()) {
+            Ok(significance_level) => *significance_level,
+            Err(_) => 0.05,
+        };
+
+        if window_size == 0 {
+            let message = "Drift window size should be greater than zero".to_string();
+            return Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(message)));
+        }
+
+        if !(significance_level > 0.0 && significance_level < 1.0) {
+            let message = format!("Drift significance level should be in (0, 1), got {}", significance_level);
+            return Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(message)));
+        }
+
+        let params = ConceptDriftParams {
+            feature,
+            window_size,
+            significance_level,
+        };
+
+        Ok(detect_concept_drift(log, &params))
+    }
+}
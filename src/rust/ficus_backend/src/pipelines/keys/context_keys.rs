@@ -1,12 +1,29 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
+use crate::features::analysis::concept_drift::DriftFeature;
 use crate::features::analysis::patterns::activity_instances::{ActivityInTraceFilterKind, ActivityNarrowingKind};
+use crate::features::analysis::patterns::sequential_patterns::SequentialPattern;
 use crate::features::clustering::activities::activities_params::ActivityRepresentationSource;
+use crate::features::clustering::agglomerative::{AgglomerativeClusteringLinkage, Dendrogram};
+use crate::features::clustering::distance_matrix::DistanceMatrixClusteringMethod;
+use crate::features::clustering::quality::{ClusteringQualityIndex, ClusteringSearchParameter};
 use crate::features::clustering::traces::traces_params::TracesRepresentationSource;
+use crate::features::discovery::bpmn::bpmn_model::BpmnModel;
+use crate::features::discovery::declare::declare_conformance::DeclareConformanceResult;
+use crate::features::discovery::declare::declare_model::DeclareModel;
+use crate::features::discovery::hierarchy::hierarchical_petri_net::HierarchicalPetriNet;
+use crate::features::discovery::local_process_models::local_process_models::LocalProcessModel;
 use crate::features::discovery::petri_net::petri_net::DefaultPetriNet;
-use crate::pipelines::activities_parts::{ActivitiesLogsSourceDto, UndefActivityHandlingStrategyDto};
+use crate::features::discovery::transition_system::transition_system::{StateAbstraction, StateDirection, TransitionSystem};
+use crate::features::prediction::next_activity::NextActivityModel;
+use crate::features::prediction::remaining_time::RemainingTimeModel;
+use crate::features::streaming::events_file_tail::EventsFileDiscovery;
+use crate::features::streaming::streaming_discovery::StreamingModel;
+use crate::pipelines::activities_parts::{ActivitiesLogsSourceDto, DatasetProjectionSourceDto, UndefActivityHandlingStrategyDto};
+use crate::pipelines::discovery_parts::PetriNetMinerDto;
 use crate::pipelines::patterns_parts::PatternsKindDto;
 use crate::utils::dataset::dataset::{FicusDataset, LabeledDataset};
+use crate::utils::dataset::projection::DatasetProjectionMethod;
 use crate::utils::distance::distance::FicusDistance;
 use crate::utils::graph::graph::DefaultGraph;
 use crate::{
@@ -15,7 +32,7 @@ use crate::{
         event_log_info::EventLogInfo,
         patterns::{
             activity_instances::{ActivityInTraceInfo, AdjustingMode},
-            contexts::PatternsDiscoveryStrategy,
+            contexts::{EpisodeKind, PatternsDiscoveryStrategy, PatternsSearchBackend},
             repeat_sets::{ActivityNode, SubArrayWithTraceIndex},
             tandem_arrays::SubArrayInTraceInfo,
         },
@@ -623,4 +640,553 @@ impl ContextKeys {
     pub fn is_traces_representation_source(&self, key: &dyn ContextKey) -> bool {
         Self::are_keys_equal(self.traces_representation_source(), key)
     }
+
+    pub fn traces_clusters(&self) -> &DefaultContextKey<Vec<XesEventLogImpl>> {
+        self.find_concrete_key::<Vec<XesEventLogImpl>>(Self::TRACES_CLUSTERS)
+            .expect("TRACES_CLUSTERS should be present in keys")
+    }
+
+    pub fn is_traces_clusters(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.traces_clusters(), key)
+    }
+
+    pub fn max_mismatches(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::MAX_MISMATCHES)
+            .expect("MAX_MISMATCHES should be present in keys")
+    }
+
+    pub fn is_max_mismatches(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.max_mismatches(), key)
+    }
+
+    pub fn max_gap(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::MAX_GAP)
+            .expect("MAX_GAP should be present in keys")
+    }
+
+    pub fn is_max_gap(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.max_gap(), key)
+    }
+
+    pub fn patterns_search_backend(&self) -> &DefaultContextKey<PatternsSearchBackend> {
+        self.find_concrete_key::<PatternsSearchBackend>(Self::PATTERNS_SEARCH_BACKEND)
+            .expect("PATTERNS_SEARCH_BACKEND should be present in keys")
+    }
+
+    pub fn is_patterns_search_backend(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.patterns_search_backend(), key)
+    }
+
+    pub fn hierarchical_petri_net(&self) -> &DefaultContextKey<HierarchicalPetriNet> {
+        self.find_concrete_key::<HierarchicalPetriNet>(Self::HIERARCHICAL_PETRI_NET)
+            .expect("HIERARCHICAL_PETRI_NET should be present in keys")
+    }
+
+    pub fn is_hierarchical_petri_net(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.hierarchical_petri_net(), key)
+    }
+
+    pub fn petri_net_miner(&self) -> &DefaultContextKey<PetriNetMinerDto> {
+        self.find_concrete_key::<PetriNetMinerDto>(Self::PETRI_NET_MINER)
+            .expect("PETRI_NET_MINER should be present in keys")
+    }
+
+    pub fn is_petri_net_miner(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.petri_net_miner(), key)
+    }
+
+    pub fn lpm_window_size(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::LPM_WINDOW_SIZE)
+            .expect("LPM_WINDOW_SIZE should be present in keys")
+    }
+
+    pub fn is_lpm_window_size(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.lpm_window_size(), key)
+    }
+
+    pub fn lpm_min_support(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::LPM_MIN_SUPPORT)
+            .expect("LPM_MIN_SUPPORT should be present in keys")
+    }
+
+    pub fn is_lpm_min_support(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.lpm_min_support(), key)
+    }
+
+    pub fn min_support(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::MIN_SUPPORT)
+            .expect("MIN_SUPPORT should be present in keys")
+    }
+
+    pub fn is_min_support(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.min_support(), key)
+    }
+
+    pub fn max_activities_count(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::MAX_ACTIVITIES_COUNT)
+            .expect("MAX_ACTIVITIES_COUNT should be present in keys")
+    }
+
+    pub fn is_max_activities_count(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.max_activities_count(), key)
+    }
+
+    pub fn top_models_count(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::TOP_MODELS_COUNT)
+            .expect("TOP_MODELS_COUNT should be present in keys")
+    }
+
+    pub fn is_top_models_count(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.top_models_count(), key)
+    }
+
+    pub fn local_process_models(&self) -> &DefaultContextKey<Vec<LocalProcessModel>> {
+        self.find_concrete_key::<Vec<LocalProcessModel>>(Self::LOCAL_PROCESS_MODELS)
+            .expect("LOCAL_PROCESS_MODELS should be present in keys")
+    }
+
+    pub fn is_local_process_models(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.local_process_models(), key)
+    }
+
+    pub fn max_pattern_length(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::MAX_PATTERN_LENGTH)
+            .expect("MAX_PATTERN_LENGTH should be present in keys")
+    }
+
+    pub fn is_max_pattern_length(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.max_pattern_length(), key)
+    }
+
+    pub fn time_window_seconds(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::TIME_WINDOW_SECONDS)
+            .expect("TIME_WINDOW_SECONDS should be present in keys")
+    }
+
+    pub fn is_time_window_seconds(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.time_window_seconds(), key)
+    }
+
+    pub fn episode_kind(&self) -> &DefaultContextKey<EpisodeKind> {
+        self.find_concrete_key::<EpisodeKind>(Self::EPISODE_KIND)
+            .expect("EPISODE_KIND should be present in keys")
+    }
+
+    pub fn is_episode_kind(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.episode_kind(), key)
+    }
+
+    pub fn sequential_patterns(&self) -> &DefaultContextKey<Vec<SequentialPattern>> {
+        self.find_concrete_key::<Vec<SequentialPattern>>(Self::SEQUENTIAL_PATTERNS)
+            .expect("SEQUENTIAL_PATTERNS should be present in keys")
+    }
+
+    pub fn is_sequential_patterns(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.sequential_patterns(), key)
+    }
+
+    pub fn patterns_occurrences(&self) -> &DefaultContextKey<RepeatSets> {
+        self.find_concrete_key::<RepeatSets>(Self::PATTERNS_OCCURRENCES)
+            .expect("PATTERNS_OCCURRENCES should be present in keys")
+    }
+
+    pub fn is_patterns_occurrences(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.patterns_occurrences(), key)
+    }
+
+    pub fn min_relative_support(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::MIN_RELATIVE_SUPPORT)
+            .expect("MIN_RELATIVE_SUPPORT should be present in keys")
+    }
+
+    pub fn is_min_relative_support(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.min_relative_support(), key)
+    }
+
+    pub fn min_confidence(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::MIN_CONFIDENCE)
+            .expect("MIN_CONFIDENCE should be present in keys")
+    }
+
+    pub fn is_min_confidence(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.min_confidence(), key)
+    }
+
+    pub fn declare_model(&self) -> &DefaultContextKey<DeclareModel> {
+        self.find_concrete_key::<DeclareModel>(Self::DECLARE_MODEL)
+            .expect("DECLARE_MODEL should be present in keys")
+    }
+
+    pub fn is_declare_model(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.declare_model(), key)
+    }
+
+    pub fn declare_conformance(&self) -> &DefaultContextKey<DeclareConformanceResult> {
+        self.find_concrete_key::<DeclareConformanceResult>(Self::DECLARE_CONFORMANCE)
+            .expect("DECLARE_CONFORMANCE should be present in keys")
+    }
+
+    pub fn is_declare_conformance(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.declare_conformance(), key)
+    }
+
+    pub fn bpmn_model(&self) -> &DefaultContextKey<BpmnModel> {
+        self.find_concrete_key::<BpmnModel>(Self::BPMN_MODEL)
+            .expect("BPMN_MODEL should be present in keys")
+    }
+
+    pub fn is_bpmn_model(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.bpmn_model(), key)
+    }
+
+    pub fn graph_analysis_dataset(&self) -> &DefaultContextKey<FicusDataset> {
+        self.find_concrete_key::<FicusDataset>(Self::GRAPH_ANALYSIS_DATASET)
+            .expect("GRAPH_ANALYSIS_DATASET should be present in keys")
+    }
+
+    pub fn is_graph_analysis_dataset(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.graph_analysis_dataset(), key)
+    }
+
+    pub fn linkage(&self) -> &DefaultContextKey<AgglomerativeClusteringLinkage> {
+        self.find_concrete_key::<AgglomerativeClusteringLinkage>(Self::LINKAGE)
+            .expect("LINKAGE should be present in keys")
+    }
+
+    pub fn is_linkage(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.linkage(), key)
+    }
+
+    pub fn dendrogram(&self) -> &DefaultContextKey<Dendrogram> {
+        self.find_concrete_key::<Dendrogram>(Self::DENDROGRAM)
+            .expect("DENDROGRAM should be present in keys")
+    }
+
+    pub fn is_dendrogram(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.dendrogram(), key)
+    }
+
+    pub fn distance_matrix_clustering_method(&self) -> &DefaultContextKey<DistanceMatrixClusteringMethod> {
+        self.find_concrete_key::<DistanceMatrixClusteringMethod>(Self::DISTANCE_MATRIX_CLUSTERING_METHOD)
+            .expect("DISTANCE_MATRIX_CLUSTERING_METHOD should be present in keys")
+    }
+
+    pub fn is_distance_matrix_clustering_method(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.distance_matrix_clustering_method(), key)
+    }
+
+    pub fn clustering_quality_index(&self) -> &DefaultContextKey<ClusteringQualityIndex> {
+        self.find_concrete_key::<ClusteringQualityIndex>(Self::CLUSTERING_QUALITY_INDEX)
+            .expect("CLUSTERING_QUALITY_INDEX should be present in keys")
+    }
+
+    pub fn is_clustering_quality_index(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.clustering_quality_index(), key)
+    }
+
+    pub fn clustering_search_parameter(&self) -> &DefaultContextKey<ClusteringSearchParameter> {
+        self.find_concrete_key::<ClusteringSearchParameter>(Self::CLUSTERING_SEARCH_PARAMETER)
+            .expect("CLUSTERING_SEARCH_PARAMETER should be present in keys")
+    }
+
+    pub fn is_clustering_search_parameter(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.clustering_search_parameter(), key)
+    }
+
+    pub fn clustering_scores(&self) -> &DefaultContextKey<FicusDataset> {
+        self.find_concrete_key::<FicusDataset>(Self::CLUSTERING_SCORES)
+            .expect("CLUSTERING_SCORES should be present in keys")
+    }
+
+    pub fn is_clustering_scores(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.clustering_scores(), key)
+    }
+
+    pub fn traces_feature_sets(&self) -> &DefaultContextKey<Vec<String>> {
+        self.find_concrete_key::<Vec<String>>(Self::TRACES_FEATURE_SETS)
+            .expect("TRACES_FEATURE_SETS should be present in keys")
+    }
+
+    pub fn is_traces_feature_sets(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.traces_feature_sets(), key)
+    }
+
+    pub fn dataset_projection_method(&self) -> &DefaultContextKey<DatasetProjectionMethod> {
+        self.find_concrete_key::<DatasetProjectionMethod>(Self::DATASET_PROJECTION_METHOD)
+            .expect("DATASET_PROJECTION_METHOD should be present in keys")
+    }
+
+    pub fn is_dataset_projection_method(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.dataset_projection_method(), key)
+    }
+
+    pub fn dataset_projection_source(&self) -> &DefaultContextKey<DatasetProjectionSourceDto> {
+        self.find_concrete_key::<DatasetProjectionSourceDto>(Self::DATASET_PROJECTION_SOURCE)
+            .expect("DATASET_PROJECTION_SOURCE should be present in keys")
+    }
+
+    pub fn is_dataset_projection_source(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.dataset_projection_source(), key)
+    }
+
+    pub fn projection_dimensions(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::PROJECTION_DIMENSIONS)
+            .expect("PROJECTION_DIMENSIONS should be present in keys")
+    }
+
+    pub fn is_projection_dimensions(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.projection_dimensions(), key)
+    }
+
+    pub fn tsne_perplexity(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::TSNE_PERPLEXITY)
+            .expect("TSNE_PERPLEXITY should be present in keys")
+    }
+
+    pub fn is_tsne_perplexity(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.tsne_perplexity(), key)
+    }
+
+    pub fn projected_dataset(&self) -> &DefaultContextKey<FicusDataset> {
+        self.find_concrete_key::<FicusDataset>(Self::PROJECTED_DATASET)
+            .expect("PROJECTED_DATASET should be present in keys")
+    }
+
+    pub fn is_projected_dataset(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.projected_dataset(), key)
+    }
+
+    pub fn labeled_projected_dataset(&self) -> &DefaultContextKey<LabeledDataset> {
+        self.find_concrete_key::<LabeledDataset>(Self::LABELED_PROJECTED_DATASET)
+            .expect("LABELED_PROJECTED_DATASET should be present in keys")
+    }
+
+    pub fn is_labeled_projected_dataset(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.labeled_projected_dataset(), key)
+    }
+
+    pub fn target_fitness(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::TARGET_FITNESS)
+            .expect("TARGET_FITNESS should be present in keys")
+    }
+
+    pub fn is_target_fitness(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.target_fitness(), key)
+    }
+
+    pub fn traces_clusters_petri_nets(&self) -> &DefaultContextKey<Vec<DefaultPetriNet>> {
+        self.find_concrete_key::<Vec<DefaultPetriNet>>(Self::TRACES_CLUSTERS_PETRI_NETS)
+            .expect("TRACES_CLUSTERS_PETRI_NETS should be present in keys")
+    }
+
+    pub fn is_traces_clusters_petri_nets(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.traces_clusters_petri_nets(), key)
+    }
+
+    pub fn residual_traces_cluster(&self) -> &DefaultContextKey<XesEventLogImpl> {
+        self.find_concrete_key::<XesEventLogImpl>(Self::RESIDUAL_TRACES_CLUSTER)
+            .expect("RESIDUAL_TRACES_CLUSTER should be present in keys")
+    }
+
+    pub fn is_residual_traces_cluster(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.residual_traces_cluster(), key)
+    }
+
+    pub fn drift_feature(&self) -> &DefaultContextKey<DriftFeature> {
+        self.find_concrete_key::<DriftFeature>(Self::DRIFT_FEATURE)
+            .expect("DRIFT_FEATURE should be present in keys")
+    }
+
+    pub fn is_drift_feature(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.drift_feature(), key)
+    }
+
+    pub fn drift_window_size(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::DRIFT_WINDOW_SIZE)
+            .expect("DRIFT_WINDOW_SIZE should be present in keys")
+    }
+
+    pub fn is_drift_window_size(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.drift_window_size(), key)
+    }
+
+    pub fn drift_significance_level(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::DRIFT_SIGNIFICANCE_LEVEL)
+            .expect("DRIFT_SIGNIFICANCE_LEVEL should be present in keys")
+    }
+
+    pub fn is_drift_significance_level(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.drift_significance_level(), key)
+    }
+
+    pub fn concept_drift_dataset(&self) -> &DefaultContextKey<FicusDataset> {
+        self.find_concrete_key::<FicusDataset>(Self::CONCEPT_DRIFT_DATASET)
+            .expect("CONCEPT_DRIFT_DATASET should be present in keys")
+    }
+
+    pub fn is_concept_drift_dataset(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.concept_drift_dataset(), key)
+    }
+
+    pub fn drift_sub_logs(&self) -> &DefaultContextKey<Vec<XesEventLogImpl>> {
+        self.find_concrete_key::<Vec<XesEventLogImpl>>(Self::DRIFT_SUB_LOGS)
+            .expect("DRIFT_SUB_LOGS should be present in keys")
+    }
+
+    pub fn is_drift_sub_logs(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.drift_sub_logs(), key)
+    }
+
+    pub fn streaming_model(&self) -> &DefaultContextKey<StreamingModel> {
+        self.find_concrete_key::<StreamingModel>(Self::STREAMING_MODEL)
+            .expect("STREAMING_MODEL should be present in keys")
+    }
+
+    pub fn is_streaming_model(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.streaming_model(), key)
+    }
+
+    pub fn streaming_window_size(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::STREAMING_WINDOW_SIZE)
+            .expect("STREAMING_WINDOW_SIZE should be present in keys")
+    }
+
+    pub fn is_streaming_window_size(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.streaming_window_size(), key)
+    }
+
+    pub fn lossy_counting_error(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::LOSSY_COUNTING_ERROR)
+            .expect("LOSSY_COUNTING_ERROR should be present in keys")
+    }
+
+    pub fn is_lossy_counting_error(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.lossy_counting_error(), key)
+    }
+
+    pub fn ngram_order(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::NGRAM_ORDER)
+            .expect("NGRAM_ORDER should be present in keys")
+    }
+
+    pub fn is_ngram_order(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.ngram_order(), key)
+    }
+
+    pub fn train_fraction(&self) -> &DefaultContextKey<f64> {
+        self.find_concrete_key::<f64>(Self::TRAIN_FRACTION)
+            .expect("TRAIN_FRACTION should be present in keys")
+    }
+
+    pub fn is_train_fraction(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.train_fraction(), key)
+    }
+
+    pub fn next_activity_model(&self) -> &DefaultContextKey<NextActivityModel> {
+        self.find_concrete_key::<NextActivityModel>(Self::NEXT_ACTIVITY_MODEL)
+            .expect("NEXT_ACTIVITY_MODEL should be present in keys")
+    }
+
+    pub fn is_next_activity_model(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.next_activity_model(), key)
+    }
+
+    pub fn remaining_time_model(&self) -> &DefaultContextKey<RemainingTimeModel> {
+        self.find_concrete_key::<RemainingTimeModel>(Self::REMAINING_TIME_MODEL)
+            .expect("REMAINING_TIME_MODEL should be present in keys")
+    }
+
+    pub fn is_remaining_time_model(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.remaining_time_model(), key)
+    }
+
+    pub fn next_activity_dataset(&self) -> &DefaultContextKey<FicusDataset> {
+        self.find_concrete_key::<FicusDataset>(Self::NEXT_ACTIVITY_DATASET)
+            .expect("NEXT_ACTIVITY_DATASET should be present in keys")
+    }
+
+    pub fn is_next_activity_dataset(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.next_activity_dataset(), key)
+    }
+
+    pub fn remaining_time_dataset(&self) -> &DefaultContextKey<FicusDataset> {
+        self.find_concrete_key::<FicusDataset>(Self::REMAINING_TIME_DATASET)
+            .expect("REMAINING_TIME_DATASET should be present in keys")
+    }
+
+    pub fn is_remaining_time_dataset(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.remaining_time_dataset(), key)
+    }
+
+    pub fn prediction_evaluation_dataset(&self) -> &DefaultContextKey<FicusDataset> {
+        self.find_concrete_key::<FicusDataset>(Self::PREDICTION_EVALUATION_DATASET)
+            .expect("PREDICTION_EVALUATION_DATASET should be present in keys")
+    }
+
+    pub fn is_prediction_evaluation_dataset(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.prediction_evaluation_dataset(), key)
+    }
+
+    pub fn state_abstraction(&self) -> &DefaultContextKey<StateAbstraction> {
+        self.find_concrete_key::<StateAbstraction>(Self::STATE_ABSTRACTION)
+            .expect("STATE_ABSTRACTION should be present in keys")
+    }
+
+    pub fn is_state_abstraction(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.state_abstraction(), key)
+    }
+
+    pub fn state_direction(&self) -> &DefaultContextKey<StateDirection> {
+        self.find_concrete_key::<StateDirection>(Self::STATE_DIRECTION)
+            .expect("STATE_DIRECTION should be present in keys")
+    }
+
+    pub fn is_state_direction(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.state_direction(), key)
+    }
+
+    pub fn state_horizon(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::STATE_HORIZON)
+            .expect("STATE_HORIZON should be present in keys")
+    }
+
+    pub fn is_state_horizon(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.state_horizon(), key)
+    }
+
+    pub fn transition_system(&self) -> &DefaultContextKey<TransitionSystem> {
+        self.find_concrete_key::<TransitionSystem>(Self::TRANSITION_SYSTEM)
+            .expect("TRANSITION_SYSTEM should be present in keys")
+    }
+
+    pub fn is_transition_system(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.transition_system(), key)
+    }
+
+    pub fn evaluate_clustering(&self) -> &DefaultContextKey<bool> {
+        self.find_concrete_key::<bool>(Self::EVALUATE_CLUSTERING)
+            .expect("EVALUATE_CLUSTERING should be present in keys")
+    }
+
+    pub fn is_evaluate_clustering(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.evaluate_clustering(), key)
+    }
+
+    pub fn look_ahead_count(&self) -> &DefaultContextKey<u32> {
+        self.find_concrete_key::<u32>(Self::LOOK_AHEAD_COUNT)
+            .expect("LOOK_AHEAD_COUNT should be present in keys")
+    }
+
+    pub fn is_look_ahead_count(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.look_ahead_count(), key)
+    }
+
+    pub fn events_file_discovery(&self) -> &DefaultContextKey<EventsFileDiscovery> {
+        self.find_concrete_key::<EventsFileDiscovery>(Self::EVENTS_FILE_DISCOVERY)
+            .expect("EVENTS_FILE_DISCOVERY should be present in keys")
+    }
+
+    pub fn is_events_file_discovery(&self, key: &dyn ContextKey) -> bool {
+        Self::are_keys_equal(self.events_file_discovery(), key)
+    }
 }
@@ -1,19 +1,39 @@
 use std::{any::Any, borrow::Cow, collections::HashMap};
 
+use crate::features::analysis::concept_drift::DriftFeature;
 use crate::features::analysis::patterns::activity_instances::{ActivityInTraceFilterKind, ActivityNarrowingKind};
+use crate::features::analysis::patterns::sequential_patterns::SequentialPattern;
 use crate::features::clustering::activities::activities_params::ActivityRepresentationSource;
+use crate::features::clustering::agglomerative::{AgglomerativeClusteringLinkage, Dendrogram};
+use crate::features::clustering::distance_matrix::DistanceMatrixClusteringMethod;
+use crate::features::clustering::quality::{ClusteringQualityIndex, ClusteringSearchParameter};
 use crate::features::clustering::traces::traces_params::TracesRepresentationSource;
+use crate::features::discovery::bpmn::bpmn_model::BpmnModel;
+use crate::features::discovery::declare::declare_conformance::DeclareConformanceResult;
+use crate::features::discovery::declare::declare_model::DeclareModel;
+use crate::features::discovery::hierarchy::hierarchical_petri_net::HierarchicalPetriNet;
+use crate::features::discovery::local_process_models::local_process_models::LocalProcessModel;
 use crate::features::discovery::petri_net::petri_net::DefaultPetriNet;
-use crate::pipelines::activities_parts::{ActivitiesLogsSourceDto, UndefActivityHandlingStrategyDto};
+use crate::features::discovery::transition_system::transition_system::{StateAbstraction, StateDirection, TransitionSystem};
+use crate::features::prediction::next_activity::NextActivityModel;
+use crate::features::prediction::remaining_time::RemainingTimeModel;
+use crate::features::streaming::events_file_tail::EventsFileDiscovery;
+use crate::features::streaming::streaming_discovery::StreamingModel;
+use crate::pipelines::activities_parts::{ActivitiesLogsSourceDto, DatasetProjectionSourceDto, UndefActivityHandlingStrategyDto};
+use crate::pipelines::discovery_parts::PetriNetMinerDto;
 use crate::pipelines::patterns_parts::PatternsKindDto;
 use crate::utils::dataset::dataset::{FicusDataset, LabeledDataset};
+use crate::utils::dataset::projection::DatasetProjectionMethod;
 use crate::utils::distance::distance::FicusDistance;
 use crate::utils::graph::graph::DefaultGraph;
 use crate::{
     event_log::xes::xes_event_log::XesEventLogImpl,
     features::analysis::{
         event_log_info::EventLogInfo,
-        patterns::{activity_instances::AdjustingMode, contexts::PatternsDiscoveryStrategy},
+        patterns::{
+            activity_instances::AdjustingMode,
+            contexts::{EpisodeKind, PatternsDiscoveryStrategy, PatternsSearchBackend},
+        },
     },
     pipelines::{aliases::*, pipelines::Pipeline},
     utils::colors::ColorsHolder,
@@ -125,6 +145,67 @@ impl ContextKeys {
     pub const LABELED_LOG_TRACES_DATASET: &'static str = "labeled_log_traces_dataset";
     pub const LOG_TRACES_DATASET: &'static str = "log_traces_dataset";
     pub const TRACES_REPR_SOURCE: &'static str = "traces_repr_source";
+    pub const TRACES_CLUSTERS: &'static str = "traces_clusters";
+    pub const MAX_MISMATCHES: &'static str = "max_mismatches";
+    pub const MAX_GAP: &'static str = "max_gap";
+    pub const PATTERNS_SEARCH_BACKEND: &'static str = "patterns_search_backend";
+    pub const HIERARCHICAL_PETRI_NET: &'static str = "hierarchical_petri_net";
+    pub const PETRI_NET_MINER: &'static str = "petri_net_miner";
+    pub const LPM_WINDOW_SIZE: &'static str = "lpm_window_size";
+    pub const LPM_MIN_SUPPORT: &'static str = "lpm_min_support";
+    pub const MIN_SUPPORT: &'static str = "min_support";
+    pub const MAX_ACTIVITIES_COUNT: &'static str = "max_activities_count";
+    pub const TOP_MODELS_COUNT: &'static str = "top_models_count";
+    pub const LOCAL_PROCESS_MODELS: &'static str = "local_process_models";
+    pub const MAX_PATTERN_LENGTH: &'static str = "max_pattern_length";
+    pub const TIME_WINDOW_SECONDS: &'static str = "time_window_seconds";
+    pub const EPISODE_KIND: &'static str = "episode_kind";
+    pub const SEQUENTIAL_PATTERNS: &'static str = "sequential_patterns";
+    pub const PATTERNS_OCCURRENCES: &'static str = "patterns_occurrences";
+    pub const MIN_RELATIVE_SUPPORT: &'static str = "min_relative_support";
+    pub const MIN_CONFIDENCE: &'static str = "min_confidence";
+    pub const DECLARE_MODEL: &'static str = "declare_model";
+    pub const DECLARE_CONFORMANCE: &'static str = "declare_conformance";
+    pub const BPMN_MODEL: &'static str = "bpmn_model";
+    pub const GRAPH_ANALYSIS_DATASET: &'static str = "graph_analysis_dataset";
+    pub const LINKAGE: &'static str = "linkage";
+    pub const DENDROGRAM: &'static str = "dendrogram";
+    pub const DISTANCE_MATRIX_CLUSTERING_METHOD: &'static str = "distance_matrix_clustering_method";
+    pub const CLUSTERING_QUALITY_INDEX: &'static str = "clustering_quality_index";
+    pub const CLUSTERING_SEARCH_PARAMETER: &'static str = "clustering_search_parameter";
+    pub const CLUSTERING_SCORES: &'static str = "clustering_scores";
+    pub const TRACES_FEATURE_SETS: &'static str = "traces_feature_sets";
+    pub const DATASET_PROJECTION_METHOD: &'static str = "dataset_projection_method";
+    pub const DATASET_PROJECTION_SOURCE: &'static str = "dataset_projection_source";
+    pub const PROJECTION_DIMENSIONS: &'static str = "projection_dimensions";
+    pub const TSNE_PERPLEXITY: &'static str = "tsne_perplexity";
+    pub const PROJECTED_DATASET: &'static str = "projected_dataset";
+    pub const LABELED_PROJECTED_DATASET: &'static str = "labeled_projected_dataset";
+    pub const TARGET_FITNESS: &'static str = "target_fitness";
+    pub const TRACES_CLUSTERS_PETRI_NETS: &'static str = "traces_clusters_petri_nets";
+    pub const RESIDUAL_TRACES_CLUSTER: &'static str = "residual_traces_cluster";
+    pub const DRIFT_FEATURE: &'static str = "drift_feature";
+    pub const DRIFT_WINDOW_SIZE: &'static str = "drift_window_size";
+    pub const DRIFT_SIGNIFICANCE_LEVEL: &'static str = "drift_significance_level";
+    pub const CONCEPT_DRIFT_DATASET: &'static str = "concept_drift_dataset";
+    pub const DRIFT_SUB_LOGS: &'static str = "drift_sub_logs";
+    pub const STREAMING_MODEL: &'static str = "streaming_model";
+    pub const STREAMING_WINDOW_SIZE: &'static str = "streaming_window_size";
+    pub const LOSSY_COUNTING_ERROR: &'static str = "lossy_counting_error";
+    pub const NGRAM_ORDER: &'static str = "ngram_order";
+    pub const TRAIN_FRACTION: &'static str = "train_fraction";
+    pub const NEXT_ACTIVITY_MODEL: &'static str = "next_activity_model";
+    pub const REMAINING_TIME_MODEL: &'static str = "remaining_time_model";
+    pub const NEXT_ACTIVITY_DATASET: &'static str = "next_activity_dataset";
+    pub const REMAINING_TIME_DATASET: &'static str = "remaining_time_dataset";
+    pub const PREDICTION_EVALUATION_DATASET: &'static str = "prediction_evaluation_dataset";
+    pub const STATE_ABSTRACTION: &'static str = "state_abstraction";
+    pub const STATE_DIRECTION: &'static str = "state_direction";
+    pub const STATE_HORIZON: &'static str = "state_horizon";
+    pub const TRANSITION_SYSTEM: &'static str = "transition_system";
+    pub const EVALUATE_CLUSTERING: &'static str = "evaluate_clustering";
+    pub const LOOK_AHEAD_COUNT: &'static str = "look_ahead_count";
+    pub const EVENTS_FILE_DISCOVERY: &'static str = "events_file_discovery";
 
     pub fn new() -> Self {
         let mut context = ContextKeysInitContext::empty();
@@ -195,6 +276,67 @@ impl ContextKeys {
         Self::insert_log_traces_dataset(&mut context);
         Self::insert_labeled_log_traces_dataset(&mut context);
         Self::insert_traces_repr_source(&mut context);
+        Self::insert_traces_clusters(&mut context);
+        Self::insert_max_mismatches(&mut context);
+        Self::insert_max_gap(&mut context);
+        Self::insert_patterns_search_backend(&mut context);
+        Self::insert_hierarchical_petri_net(&mut context);
+        Self::insert_petri_net_miner(&mut context);
+        Self::insert_lpm_window_size(&mut context);
+        Self::insert_lpm_min_support(&mut context);
+        Self::insert_min_support(&mut context);
+        Self::insert_max_activities_count(&mut context);
+        Self::insert_top_models_count(&mut context);
+        Self::insert_local_process_models(&mut context);
+        Self::insert_max_pattern_length(&mut context);
+        Self::insert_time_window_seconds(&mut context);
+        Self::insert_episode_kind(&mut context);
+        Self::insert_sequential_patterns(&mut context);
+        Self::insert_patterns_occurrences(&mut context);
+        Self::insert_min_relative_support(&mut context);
+        Self::insert_min_confidence(&mut context);
+        Self::insert_declare_model(&mut context);
+        Self::insert_declare_conformance(&mut context);
+        Self::insert_bpmn_model(&mut context);
+        Self::insert_graph_analysis_dataset(&mut context);
+        Self::insert_linkage(&mut context);
+        Self::insert_dendrogram(&mut context);
+        Self::insert_distance_matrix_clustering_method(&mut context);
+        Self::insert_clustering_quality_index(&mut context);
+        Self::insert_clustering_search_parameter(&mut context);
+        Self::insert_clustering_scores(&mut context);
+        Self::insert_traces_feature_sets(&mut context);
+        Self::insert_dataset_projection_method(&mut context);
+        Self::insert_dataset_projection_source(&mut context);
+        Self::insert_projection_dimensions(&mut context);
+        Self::insert_tsne_perplexity(&mut context);
+        Self::insert_projected_dataset(&mut context);
+        Self::insert_labeled_projected_dataset(&mut context);
+        Self::insert_target_fitness(&mut context);
+        Self::insert_traces_clusters_petri_nets(&mut context);
+        Self::insert_residual_traces_cluster(&mut context);
+        Self::insert_drift_feature(&mut context);
+        Self::insert_drift_window_size(&mut context);
+        Self::insert_drift_significance_level(&mut context);
+        Self::insert_concept_drift_dataset(&mut context);
+        Self::insert_drift_sub_logs(&mut context);
+        Self::insert_streaming_model(&mut context);
+        Self::insert_streaming_window_size(&mut context);
+        Self::insert_lossy_counting_error(&mut context);
+        Self::insert_ngram_order(&mut context);
+        Self::insert_train_fraction(&mut context);
+        Self::insert_next_activity_model(&mut context);
+        Self::insert_remaining_time_model(&mut context);
+        Self::insert_next_activity_dataset(&mut context);
+        Self::insert_remaining_time_dataset(&mut context);
+        Self::insert_prediction_evaluation_dataset(&mut context);
+        Self::insert_state_abstraction(&mut context);
+        Self::insert_state_direction(&mut context);
+        Self::insert_state_horizon(&mut context);
+        Self::insert_transition_system(&mut context);
+        Self::insert_evaluate_clustering(&mut context);
+        Self::insert_look_ahead_count(&mut context);
+        Self::insert_events_file_discovery(&mut context);
 
         let (concrete_keys, context_keys) = context.deconstruct();
 
@@ -464,4 +606,248 @@ impl ContextKeys {
     fn insert_traces_repr_source(context: &mut ContextKeysInitContext) {
         Self::insert_key::<TracesRepresentationSource>(context, Self::TRACES_REPR_SOURCE)
     }
+
+    fn insert_traces_clusters(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<Vec<XesEventLogImpl>>(context, Self::TRACES_CLUSTERS)
+    }
+
+    fn insert_max_mismatches(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::MAX_MISMATCHES)
+    }
+
+    fn insert_max_gap(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::MAX_GAP)
+    }
+
+    fn insert_patterns_search_backend(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<PatternsSearchBackend>(context, Self::PATTERNS_SEARCH_BACKEND)
+    }
+
+    fn insert_hierarchical_petri_net(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<HierarchicalPetriNet>(context, Self::HIERARCHICAL_PETRI_NET)
+    }
+
+    fn insert_petri_net_miner(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<PetriNetMinerDto>(context, Self::PETRI_NET_MINER)
+    }
+
+    fn insert_lpm_window_size(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::LPM_WINDOW_SIZE)
+    }
+
+    fn insert_lpm_min_support(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::LPM_MIN_SUPPORT)
+    }
+
+    fn insert_min_support(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::MIN_SUPPORT)
+    }
+
+    fn insert_max_activities_count(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::MAX_ACTIVITIES_COUNT)
+    }
+
+    fn insert_top_models_count(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::TOP_MODELS_COUNT)
+    }
+
+    fn insert_local_process_models(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<Vec<LocalProcessModel>>(context, Self::LOCAL_PROCESS_MODELS)
+    }
+
+    fn insert_max_pattern_length(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::MAX_PATTERN_LENGTH)
+    }
+
+    fn insert_time_window_seconds(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::TIME_WINDOW_SECONDS)
+    }
+
+    fn insert_episode_kind(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<EpisodeKind>(context, Self::EPISODE_KIND)
+    }
+
+    fn insert_sequential_patterns(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<Vec<SequentialPattern>>(context, Self::SEQUENTIAL_PATTERNS)
+    }
+
+    fn insert_patterns_occurrences(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<RepeatSets>(context, Self::PATTERNS_OCCURRENCES)
+    }
+
+    fn insert_min_relative_support(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::MIN_RELATIVE_SUPPORT)
+    }
+
+    fn insert_min_confidence(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::MIN_CONFIDENCE)
+    }
+
+    fn insert_declare_model(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<DeclareModel>(context, Self::DECLARE_MODEL)
+    }
+
+    fn insert_declare_conformance(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<DeclareConformanceResult>(context, Self::DECLARE_CONFORMANCE)
+    }
+
+    fn insert_bpmn_model(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<BpmnModel>(context, Self::BPMN_MODEL)
+    }
+
+    fn insert_graph_analysis_dataset(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<FicusDataset>(context, Self::GRAPH_ANALYSIS_DATASET)
+    }
+
+    fn insert_linkage(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<AgglomerativeClusteringLinkage>(context, Self::LINKAGE)
+    }
+
+    fn insert_dendrogram(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<Dendrogram>(context, Self::DENDROGRAM)
+    }
+
+    fn insert_distance_matrix_clustering_method(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<DistanceMatrixClusteringMethod>(context, Self::DISTANCE_MATRIX_CLUSTERING_METHOD)
+    }
+
+    fn insert_clustering_quality_index(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<ClusteringQualityIndex>(context, Self::CLUSTERING_QUALITY_INDEX)
+    }
+
+    fn insert_clustering_search_parameter(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<ClusteringSearchParameter>(context, Self::CLUSTERING_SEARCH_PARAMETER)
+    }
+
+    fn insert_clustering_scores(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<FicusDataset>(context, Self::CLUSTERING_SCORES)
+    }
+
+    fn insert_traces_feature_sets(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<Vec<String>>(context, Self::TRACES_FEATURE_SETS)
+    }
+
+    fn insert_dataset_projection_method(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<DatasetProjectionMethod>(context, Self::DATASET_PROJECTION_METHOD)
+    }
+
+    fn insert_dataset_projection_source(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<DatasetProjectionSourceDto>(context, Self::DATASET_PROJECTION_SOURCE)
+    }
+
+    fn insert_projection_dimensions(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::PROJECTION_DIMENSIONS)
+    }
+
+    fn insert_tsne_perplexity(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::TSNE_PERPLEXITY)
+    }
+
+    fn insert_projected_dataset(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<FicusDataset>(context, Self::PROJECTED_DATASET)
+    }
+
+    fn insert_labeled_projected_dataset(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<LabeledDataset>(context, Self::LABELED_PROJECTED_DATASET)
+    }
+
+    fn insert_target_fitness(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::TARGET_FITNESS)
+    }
+
+    fn insert_traces_clusters_petri_nets(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<Vec<DefaultPetriNet>>(context, Self::TRACES_CLUSTERS_PETRI_NETS)
+    }
+
+    fn insert_residual_traces_cluster(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<XesEventLogImpl>(context, Self::RESIDUAL_TRACES_CLUSTER)
+    }
+
+    fn insert_drift_feature(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<DriftFeature>(context, Self::DRIFT_FEATURE)
+    }
+
+    fn insert_drift_window_size(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::DRIFT_WINDOW_SIZE)
+    }
+
+    fn insert_drift_significance_level(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::DRIFT_SIGNIFICANCE_LEVEL)
+    }
+
+    fn insert_concept_drift_dataset(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<FicusDataset>(context, Self::CONCEPT_DRIFT_DATASET)
+    }
+
+    fn insert_drift_sub_logs(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<Vec<XesEventLogImpl>>(context, Self::DRIFT_SUB_LOGS)
+    }
+
+    fn insert_streaming_model(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<StreamingModel>(context, Self::STREAMING_MODEL)
+    }
+
+    fn insert_streaming_window_size(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::STREAMING_WINDOW_SIZE)
+    }
+
+    fn insert_lossy_counting_error(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::LOSSY_COUNTING_ERROR)
+    }
+
+    fn insert_ngram_order(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::NGRAM_ORDER)
+    }
+
+    fn insert_train_fraction(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<f64>(context, Self::TRAIN_FRACTION)
+    }
+
+    fn insert_next_activity_model(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<NextActivityModel>(context, Self::NEXT_ACTIVITY_MODEL)
+    }
+
+    fn insert_remaining_time_model(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<RemainingTimeModel>(context, Self::REMAINING_TIME_MODEL)
+    }
+
+    fn insert_next_activity_dataset(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<FicusDataset>(context, Self::NEXT_ACTIVITY_DATASET)
+    }
+
+    fn insert_remaining_time_dataset(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<FicusDataset>(context, Self::REMAINING_TIME_DATASET)
+    }
+
+    fn insert_prediction_evaluation_dataset(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<FicusDataset>(context, Self::PREDICTION_EVALUATION_DATASET)
+    }
+
+    fn insert_state_abstraction(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<StateAbstraction>(context, Self::STATE_ABSTRACTION)
+    }
+
+    fn insert_state_direction(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<StateDirection>(context, Self::STATE_DIRECTION)
+    }
+
+    fn insert_state_horizon(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::STATE_HORIZON)
+    }
+
+    fn insert_transition_system(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<TransitionSystem>(context, Self::TRANSITION_SYSTEM)
+    }
+
+    fn insert_evaluate_clustering(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<bool>(context, Self::EVALUATE_CLUSTERING)
+    }
+
+    fn insert_look_ahead_count(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<u32>(context, Self::LOOK_AHEAD_COUNT)
+    }
+
+    fn insert_events_file_discovery(context: &mut ContextKeysInitContext) {
+        Self::insert_key::<EventsFileDiscovery>(context, Self::EVENTS_FILE_DISCOVERY)
+    }
 }
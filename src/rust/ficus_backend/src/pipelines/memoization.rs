@@ -0,0 +1,501 @@
+use std::{
+    any::{Any, TypeId},
+    cell::Ref,
+    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
+    fs,
+    hash::{Hash, Hasher},
+    io::Cursor,
+    sync::Mutex,
+};
+
+use crate::event_log::xes::reader::file_xes_log_reader::read_event_log_from_reader;
+use crate::event_log::xes::writer::xes_event_log_writer::serialize_event_log;
+use crate::event_log::xes::xes_event_log::XesEventLogImpl;
+use crate::features::analysis::concept_drift::DriftFeature;
+use crate::features::analysis::patterns::activity_instances::{ActivityInTraceFilterKind, ActivityNarrowingKind, AdjustingMode};
+use crate::features::analysis::patterns::contexts::{EpisodeKind, PatternsDiscoveryStrategy, PatternsSearchBackend};
+use crate::features::analysis::patterns::sequential_patterns::SequentialPattern;
+use crate::features::clustering::activities::activities_params::ActivityRepresentationSource;
+use crate::features::clustering::agglomerative::{AgglomerativeClusteringLinkage, Dendrogram};
+use crate::features::clustering::distance_matrix::DistanceMatrixClusteringMethod;
+use crate::features::clustering::quality::{ClusteringQualityIndex, ClusteringSearchParameter};
+use crate::features::clustering::traces::traces_params::TracesRepresentationSource;
+use crate::features::discovery::bpmn::bpmn_model::BpmnModel;
+use crate::features::discovery::declare::declare_conformance::DeclareConformanceResult;
+use crate::features::discovery::declare::declare_model::DeclareModel;
+use crate::features::discovery::local_process_models::local_process_models::LocalProcessModel;
+use crate::features::discovery::transition_system::transition_system::{StateAbstraction, StateDirection};
+use crate::features::streaming::streaming_discovery::StreamingModel;
+use crate::pipelines::activities_parts::{ActivitiesLogsSourceDto, DatasetProjectionSourceDto, UndefActivityHandlingStrategyDto};
+use crate::pipelines::aliases::{Patterns, RepeatSets};
+use crate::pipelines::discovery_parts::PetriNetMinerDto;
+use crate::pipelines::patterns_parts::PatternsKindDto;
+use crate::pipelines::pipeline_parts::PipelineParts;
+use crate::utils::dataset::dataset::{FicusDataset, LabeledDataset};
+use crate::utils::dataset::projection::DatasetProjectionMethod;
+use crate::utils::distance::distance::FicusDistance;
+use crate::utils::user_data::{
+    keys::Key,
+    user_data::{UserData, UserDataImpl},
+};
+
+use super::{
+    context::{PipelineContext, PipelineInfrastructure},
+    errors::pipeline_errors::PipelinePartExecutionError,
+    keys::context_keys::ContextKeys,
+    pipelines::PipelinePart,
+};
+
+#[derive(Clone)]
+pub(super) struct ContextAccesses {
+    pub(super) read: HashMap<u64, String>,
+    pub(super) written: HashMap<u64, String>,
+    pub(super) removed: HashMap<u64, String>,
+}
+
+impl ContextAccesses {
+    pub(super) fn empty() -> Self {
+        Self {
+            read: HashMap::new(),
+            written: HashMap::new(),
+            removed: HashMap::new(),
+        }
+    }
+}
+
+//Values in the context may share Rc-based state, so the cache, which is shared between gRPC requests,
+//stores owned snapshots of them which are converted back to the context values on restore
+type ValueSnapshot = Box<dyn Any + Send>;
+type ValueHasher = Box<dyn Fn(&dyn Any, &mut DefaultHasher) + Send>;
+type ValueSnapshotter = Box<dyn Fn(&dyn Any) -> Option<ValueSnapshot> + Send>;
+type ValueRestorer = Box<dyn Fn(&ValueSnapshot, &dyn Key, &mut PipelineContext) -> bool + Send>;
+
+struct CachedOutput {
+    key_id: u64,
+    key_name: String,
+    value: Option<(TypeId, ValueSnapshot)>,
+}
+
+struct CachedPartExecution {
+    fingerprint: u64,
+    inputs: Vec<(u64, Option<u64>)>,
+    outputs: Vec<CachedOutput>,
+}
+
+pub struct PipelinePartsCacheStatistics {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries_count: usize,
+}
+
+pub struct PipelinePartsCache {
+    max_entries_count: usize,
+    executions: HashMap<u64, Vec<CachedPartExecution>>,
+    parts_names: HashMap<u64, String>,
+    insertion_order: VecDeque<(u64, u64)>,
+    hashers: HashMap<TypeId, ValueHasher>,
+    snapshotters: HashMap<TypeId, (ValueSnapshotter, ValueRestorer)>,
+    hits: usize,
+    misses: usize,
+}
+
+impl PipelinePartsCache {
+    const FILE_READER_PARTS: [&'static str; 4] = [
+        PipelineParts::READ_LOG_FROM_XES,
+        PipelineParts::READ_LOG_FROM_BXES,
+        PipelineParts::READ_DECLARE_MODEL,
+        PipelineParts::DISCOVER_STREAMING_MODEL,
+    ];
+
+    pub fn new(max_entries_count: usize) -> Self {
+        let mut cache = Self {
+            max_entries_count,
+            executions: HashMap::new(),
+            parts_names: HashMap::new(),
+            insertion_order: VecDeque::new(),
+            hashers: HashMap::new(),
+            snapshotters: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        };
+
+        cache.register_hashable::<String>();
+        cache.register_hashable::<u32>();
+        cache.register_hashable::<u64>();
+        cache.register_hashable::<usize>();
+        cache.register_hashable::<i64>();
+        cache.register_hashable::<bool>();
+        cache.register_hashable::<Vec<String>>();
+        cache.register_hashable::<Vec<Vec<String>>>();
+        cache.register_hashable::<Vec<Vec<u64>>>();
+        cache.register_hasher::<f64>(|value, state| value.to_bits().hash(state));
+        cache.register_hasher::<f32>(|value, state| value.to_bits().hash(state));
+
+        cache.register_hashable::<PatternsDiscoveryStrategy>();
+        cache.register_hashable::<PatternsSearchBackend>();
+        cache.register_hashable::<EpisodeKind>();
+        cache.register_hashable::<AdjustingMode>();
+        cache.register_hashable::<PatternsKindDto>();
+        cache.register_hashable::<UndefActivityHandlingStrategyDto>();
+        cache.register_hashable::<ActivityNarrowingKind>();
+        cache.register_hashable::<ActivityInTraceFilterKind>();
+        cache.register_hashable::<ActivitiesLogsSourceDto>();
+        cache.register_hashable::<ActivityRepresentationSource>();
+        cache.register_hashable::<TracesRepresentationSource>();
+        cache.register_hashable::<FicusDistance>();
+        cache.register_hashable::<PetriNetMinerDto>();
+        cache.register_hashable::<AgglomerativeClusteringLinkage>();
+        cache.register_hashable::<DistanceMatrixClusteringMethod>();
+        cache.register_hashable::<ClusteringQualityIndex>();
+        cache.register_hashable::<ClusteringSearchParameter>();
+        cache.register_hashable::<DatasetProjectionMethod>();
+        cache.register_hashable::<DriftFeature>();
+        cache.register_hashable::<StreamingModel>();
+        cache.register_hashable::<StateAbstraction>();
+        cache.register_hashable::<StateDirection>();
+        cache.register_hashable::<DatasetProjectionSourceDto>();
+
+        cache.register_cloneable::<String>();
+        cache.register_cloneable::<u32>();
+        cache.register_cloneable::<u64>();
+        cache.register_cloneable::<usize>();
+        cache.register_cloneable::<f64>();
+        cache.register_cloneable::<bool>();
+        cache.register_cloneable::<Vec<String>>();
+        cache.register_cloneable::<Vec<Vec<String>>>();
+        cache.register_cloneable::<Vec<Vec<u64>>>();
+        cache.register_snapshot::<XesEventLogImpl, Vec<u8>>(Self::serialize_log, Self::deserialize_log);
+        cache.register_snapshot::<Vec<XesEventLogImpl>, Vec<Vec<u8>>>(
+            |logs| logs.iter().map(Self::serialize_log).collect(),
+            |logs| logs.iter().map(Self::deserialize_log).collect(),
+        );
+        cache.register_cloneable::<Patterns>();
+        cache.register_cloneable::<RepeatSets>();
+        cache.register_cloneable::<HashMap<u64, usize>>();
+        cache.register_cloneable::<HashMap<u64, f64>>();
+        cache.register_cloneable::<FicusDataset>();
+        cache.register_cloneable::<LabeledDataset>();
+        cache.register_cloneable::<Vec<LocalProcessModel>>();
+        cache.register_cloneable::<Vec<SequentialPattern>>();
+        cache.register_cloneable::<DeclareModel>();
+        cache.register_cloneable::<BpmnModel>();
+        cache.register_cloneable::<DeclareConformanceResult>();
+        cache.register_cloneable::<Dendrogram>();
+
+        cache
+    }
+
+    fn register_hasher<T: 'static>(&mut self, hasher: fn(&T, &mut DefaultHasher)) {
+        let hasher: ValueHasher = Box::new(move |value, state| hasher(value.downcast_ref::<T>().unwrap(), state));
+        self.hashers.insert(TypeId::of::<T>(), hasher);
+    }
+
+    fn register_hashable<T: Hash + 'static>(&mut self) {
+        self.register_hasher::<T>(|value, state| value.hash(state));
+    }
+
+    fn register_cloneable<T: Clone + Send + 'static>(&mut self) {
+        self.register_snapshot::<T, T>(|value| Some(value.clone()), |snapshot| Some(snapshot.clone()));
+    }
+
+    fn register_snapshot<T: 'static, TSnapshot: Send + 'static>(
+        &mut self,
+        to_snapshot: fn(&T) -> Option<TSnapshot>,
+        from_snapshot: fn(&TSnapshot) -> Option<T>,
+    ) {
+        let snapshotter: ValueSnapshotter = Box::new(move |value| {
+            let snapshot: ValueSnapshot = Box::new(to_snapshot(value.downcast_ref::<T>().unwrap())?);
+            Some(snapshot)
+        });
+
+        let restorer: ValueRestorer =
+            Box::new(
+                move |snapshot, key, context| match from_snapshot(snapshot.downcast_ref::<TSnapshot>().unwrap()) {
+                    Some(value) => {
+                        context.put_any::<T>(key, value);
+                        true
+                    }
+                    None => false,
+                },
+            );
+
+        self.snapshotters.insert(TypeId::of::<T>(), (snapshotter, restorer));
+    }
+
+    fn serialize_log(log: &XesEventLogImpl) -> Option<Vec<u8>> {
+        serialize_event_log(log).ok().map(|log| log.into_bytes())
+    }
+
+    fn deserialize_log(bytes: &Vec<u8>) -> Option<XesEventLogImpl> {
+        read_event_log_from_reader(Box::new(Cursor::new(bytes.clone())))
+    }
+
+    pub fn statistics(&self) -> PipelinePartsCacheStatistics {
+        PipelinePartsCacheStatistics {
+            hits: self.hits,
+            misses: self.misses,
+            entries_count: self.insertion_order.len(),
+        }
+    }
+
+    pub fn invalidate(&mut self, parts_names: &Vec<String>) {
+        if parts_names.is_empty() {
+            self.executions.clear();
+            self.parts_names.clear();
+            self.insertion_order.clear();
+            return;
+        }
+
+        let parts_names = parts_names.iter().collect::<HashSet<&String>>();
+        let invalidated_keys = self
+            .parts_names
+            .iter()
+            .filter(|(_, name)| parts_names.contains(name))
+            .map(|(part_key, _)| *part_key)
+            .collect::<HashSet<u64>>();
+
+        for part_key in &invalidated_keys {
+            self.executions.remove(part_key);
+            self.parts_names.remove(part_key);
+        }
+
+        self.insertion_order.retain(|(part_key, _)| !invalidated_keys.contains(part_key));
+    }
+
+    pub fn execute_part(
+        cache: &Mutex<PipelinePartsCache>,
+        part: &dyn PipelinePart,
+        context: &mut PipelineContext,
+        infra: &PipelineInfrastructure,
+        keys: &ContextKeys,
+    ) -> Result<(), PipelinePartExecutionError> {
+        let part_key = match part.memoization_info() {
+            Some((name, config)) => match cache.lock().unwrap().fingerprint_part(name, config) {
+                Some(part_key) if Self::FILE_READER_PARTS.contains(&name.as_str()) => {
+                    Self::fingerprint_read_file(part_key, config, context, keys)
+                }
+                part_key => part_key,
+            },
+            None => None,
+        };
+
+        let part_key = match part_key {
+            Some(part_key) => part_key,
+            None => return part.execute(context, infra, keys),
+        };
+
+        let snapshot = {
+            let mut cache = cache.lock().unwrap();
+            let snapshot = cache.create_snapshot(context);
+            if cache.try_restore(part_key, &snapshot, context, keys) {
+                cache.hits += 1;
+                return Ok(());
+            }
+
+            cache.misses += 1;
+            snapshot
+        };
+
+        if !context.start_accesses_tracking() {
+            return part.execute(context, infra, keys);
+        }
+
+        let result = part.execute(context, infra, keys);
+        let accesses = context.finish_accesses_tracking();
+        result?;
+
+        let mut cache = cache.lock().unwrap();
+        if let Some(execution) = cache.create_execution(part_key, &snapshot, &accesses, context) {
+            let name = part.memoization_info().unwrap().0.to_owned();
+            cache.put_execution(part_key, name, execution);
+        }
+
+        Ok(())
+    }
+
+    fn fingerprint_part(&self, name: &String, config: &UserDataImpl) -> Option<u64> {
+        let mut state = DefaultHasher::new();
+        name.hash(&mut state);
+
+        let mut entries = config.entries();
+        entries.sort_by(|first, second| first.0.cmp(&second.0));
+
+        for (id, value) in entries {
+            let value: &dyn Any = &*value;
+            id.hash(&mut state);
+            (self.hashers.get(&value.type_id())?)(value, &mut state);
+        }
+
+        Some(state.finish())
+    }
+
+    //The file at the path may change between executions while the path stays the same
+    fn fingerprint_read_file(part_key: u64, config: &UserDataImpl, context: &PipelineContext, keys: &ContextKeys) -> Option<u64> {
+        let path = match config.concrete(keys.path().key()) {
+            Some(path) => path,
+            None => context.concrete(keys.path().key())?,
+        };
+
+        let metadata = fs::metadata(path).ok()?;
+
+        let mut state = DefaultHasher::new();
+        part_key.hash(&mut state);
+        metadata.modified().ok()?.hash(&mut state);
+        metadata.len().hash(&mut state);
+
+        Some(state.finish())
+    }
+
+    fn create_snapshot(&self, context: &PipelineContext) -> HashMap<u64, Option<u64>> {
+        let mut snapshot = HashMap::new();
+        for (id, value) in context.entries() {
+            let value: &dyn Any = &*value;
+            let fingerprint = match context.fingerprint(id) {
+                Some(fingerprint) => Some(fingerprint),
+                None => match self.hashers.get(&value.type_id()) {
+                    Some(hasher) => {
+                        let mut state = DefaultHasher::new();
+                        hasher(value, &mut state);
+                        Some(state.finish())
+                    }
+                    None => None,
+                },
+            };
+
+            snapshot.insert(id, fingerprint);
+        }
+
+        snapshot
+    }
+
+    fn try_restore(&self, part_key: u64, snapshot: &HashMap<u64, Option<u64>>, context: &mut PipelineContext, keys: &ContextKeys) -> bool {
+        let executions = match self.executions.get(&part_key) {
+            Some(executions) => executions,
+            None => return false,
+        };
+
+        let execution = executions.iter().find(|execution| {
+            execution.inputs.iter().all(|(id, fingerprint)| match snapshot.get(id) {
+                Some(Some(current)) => Some(*current) == *fingerprint,
+                Some(None) => false,
+                None => fingerprint.is_none(),
+            })
+        });
+
+        let execution = match execution {
+            Some(execution) => execution,
+            None => return false,
+        };
+
+        for output in &execution.outputs {
+            let key = match keys.find_key(&output.key_name) {
+                Some(key) => key.key(),
+                None => return false,
+            };
+
+            match output.value.as_ref() {
+                Some((type_id, snapshot)) => {
+                    if !(self.snapshotters.get(type_id).unwrap().1)(snapshot, key, context) {
+                        return false;
+                    }
+                }
+                None => context.remove_any::<()>(key),
+            }
+
+            context.set_fingerprint(output.key_id, Self::fingerprint_output(execution.fingerprint, output.key_id));
+        }
+
+        true
+    }
+
+    fn create_execution(
+        &self,
+        part_key: u64,
+        snapshot: &HashMap<u64, Option<u64>>,
+        accesses: &ContextAccesses,
+        context: &PipelineContext,
+    ) -> Option<CachedPartExecution> {
+        let mut inputs = vec![];
+        for id in accesses.read.keys() {
+            let fingerprint = match snapshot.get(id) {
+                Some(fingerprint) => Some((*fingerprint)?),
+                None => None,
+            };
+
+            inputs.push((*id, fingerprint));
+        }
+
+        inputs.sort();
+
+        let mut state = DefaultHasher::new();
+        part_key.hash(&mut state);
+        inputs.hash(&mut state);
+        let fingerprint = state.finish();
+
+        let current_values = context.entries().into_iter().collect::<HashMap<u64, Ref<dyn Any>>>();
+        let mut outputs = vec![];
+        for (id, name) in accesses.written.iter().chain(accesses.removed.iter()) {
+            if outputs.iter().any(|output: &CachedOutput| output.key_id == *id) {
+                continue;
+            }
+
+            let value = match current_values.get(id) {
+                Some(value) => {
+                    let value: &dyn Any = &**value;
+                    let type_id = value.type_id();
+                    let snapshotter = &self.snapshotters.get(&type_id)?.0;
+                    Some((type_id, snapshotter(value)?))
+                }
+                None => None,
+            };
+
+            outputs.push(CachedOutput {
+                key_id: *id,
+                key_name: name.to_owned(),
+                value,
+            });
+        }
+
+        if outputs.is_empty() {
+            return None;
+        }
+
+        for output in &outputs {
+            context.set_fingerprint(output.key_id, Self::fingerprint_output(fingerprint, output.key_id));
+        }
+
+        Some(CachedPartExecution {
+            fingerprint,
+            inputs,
+            outputs,
+        })
+    }
+
+    fn fingerprint_output(execution_fingerprint: u64, key_id: u64) -> u64 {
+        let mut state = DefaultHasher::new();
+        execution_fingerprint.hash(&mut state);
+        key_id.hash(&mut state);
+        state.finish()
+    }
+
+    fn put_execution(&mut self, part_key: u64, name: String, execution: CachedPartExecution) {
+        self.insertion_order.retain(|entry| *entry != (part_key, execution.fingerprint));
+        self.insertion_order.push_back((part_key, execution.fingerprint));
+        self.parts_names.insert(part_key, name);
+
+        let executions = self.executions.entry(part_key).or_insert_with(|| vec![]);
+        executions.retain(|existing| existing.fingerprint != execution.fingerprint);
+        executions.push(execution);
+
+        while self.insertion_order.len() > self.max_entries_count {
+            let (part_key, fingerprint) = self.insertion_order.pop_front().unwrap();
+            if let Some(executions) = self.executions.get_mut(&part_key) {
+                executions.retain(|existing| existing.fingerprint != fingerprint);
+                if executions.is_empty() {
+                    self.executions.remove(&part_key);
+                    self.parts_names.remove(&part_key);
+                }
+            }
+        }
+    }
+}
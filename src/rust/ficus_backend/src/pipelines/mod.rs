@@ -1,16 +1,23 @@
 pub mod activities_parts;
 pub mod aliases;
 pub mod annotations_parts;
+pub mod bpmn_parts;
 pub mod context;
+pub mod control_flow;
+pub mod declare_parts;
 pub mod discovery_parts;
 pub mod drawing_parts;
+pub mod drift_parts;
 pub mod errors;
 pub mod filtering_parts;
 pub mod keys;
+pub mod memoization;
 pub mod mutations_parts;
 pub mod parts_names;
 pub mod patterns_parts;
 pub mod pipeline_parts;
 pub mod pipelines;
+pub mod prediction_parts;
+pub mod streaming_parts;
 pub mod util_parts;
 pub mod xes_parts;
@@ -8,6 +8,11 @@ impl PipelineParts {
     pub const FIND_MAXIMAL_REPEATS: &'static str = "FindMaximalRepeats";
     pub const FIND_SUPER_MAXIMAL_REPEATS: &'static str = "FindSuperMaximalRepeats";
     pub const FIND_NEAR_SUPER_MAXIMAL_REPEATS: &'static str = "FindNearSuperMaximalRepeats";
+    pub const FIND_HAMMING_REPEATS: &'static str = "FindHammingRepeats";
+    pub const FIND_GAPPED_REPEATS: &'static str = "FindGappedRepeats";
+    pub const FIND_APPROXIMATE_TANDEM_ARRAYS: &'static str = "FindApproximateTandemArrays";
+    pub const FIND_SEQUENTIAL_PATTERNS: &'static str = "FindSequentialPatterns";
+    pub const FIND_FREQUENT_EPISODES: &'static str = "FindFrequentEpisodes";
     pub const DISCOVER_ACTIVITIES: &'static str = "DiscoverActivities";
     pub const DISCOVER_ACTIVITIES_INSTANCES: &'static str = "DiscoverActivitiesInstances";
     pub const CREATE_LOG_FROM_ACTIVITIES: &'static str = "CreateLogFromActivities";
@@ -34,6 +39,10 @@ impl PipelineParts {
     pub const EXECUTE_FRONTEND_PIPELINE: &'static str = "ExecuteFrontendPipeline";
     pub const APPLY_CLASS_EXTRACTOR: &'static str = "ApplyClassExtractor";
     pub const SERIALIZE_PETRI_NET: &'static str = "SerializePetriNet";
+    pub const SERIALIZE_PETRI_NET_TO_DOT: &'static str = "SerializePetriNetToDot";
+    pub const SERIALIZE_GRAPH: &'static str = "SerializeGraph";
+    pub const SERIALIZE_TO_DOT: &'static str = "SerializeToDot";
+    pub const ANALYZE_GRAPH: &'static str = "AnalyzeGraph";
     pub const DISCOVER_PETRI_NET_ALPHA: &'static str = "DiscoverPetriNetAlpha";
     pub const ADD_ARTIFICIAL_START_END_EVENTS: &'static str = "AddArtificialStartEndEvents";
     pub const ADD_ARTIFICIAL_START_EVENTS: &'static str = "AddArtificialStartEvents";
@@ -44,6 +53,16 @@ impl PipelineParts {
     pub const DISCOVER_DFG: &'static str = "DiscoverDirectlyFollowsGraph";
     pub const DISCOVER_PETRI_NET_HEURISTIC: &'static str = "DiscoverPetriNetHeuristic";
     pub const DISCOVER_FUZZY_GRAPH: &'static str = "DiscoverFuzzyGraph";
+    pub const DISCOVER_HIERARCHICAL_PETRI_NET: &'static str = "DiscoverHierarchicalPetriNet";
+    pub const SERIALIZE_HIERARCHICAL_PETRI_NET: &'static str = "SerializeHierarchicalPetriNet";
+    pub const DISCOVER_LOCAL_PROCESS_MODELS: &'static str = "DiscoverLocalProcessModels";
+    pub const DISCOVER_DECLARE_MODEL: &'static str = "DiscoverDeclareModel";
+    pub const CHECK_DECLARE_CONFORMANCE: &'static str = "CheckDeclareConformance";
+    pub const SERIALIZE_DECLARE_MODEL: &'static str = "SerializeDeclareModel";
+    pub const READ_DECLARE_MODEL: &'static str = "ReadDeclareModel";
+    pub const CONVERT_PETRI_NET_TO_BPMN: &'static str = "ConvertPetriNetToBpmn";
+    pub const DISCOVER_BPMN_HEURISTIC: &'static str = "DiscoverBpmnHeuristic";
+    pub const SERIALIZE_BPMN: &'static str = "SerializeBpmn";
     pub const READ_LOG_FROM_BXES: &'static str = "ReadLogFromBxes";
     pub const WRITE_LOG_TO_BXES: &'static str = "WriteLogToBxes";
 
@@ -56,5 +75,20 @@ impl PipelineParts {
     pub const CLUSTERIZE_ACTIVITIES_FROM_TRACES_KMEANS_GRID_SEARCH: &'static str = "ClusterizeActivitiesFromTracesKMeansGridSearch";
     pub const CLUSTERIZE_ACTIVITIES_FROM_TRACES_DBSCAN: &'static str = "ClusterizeActivitiesFromTracesDbscan";
     pub const CREATE_TRACES_ACTIVITIES_DATASET: &'static str = "CreateTracesActivitiesDataset";
+    pub const PROJECT_DATASET: &'static str = "ProjectDataset";
     pub const CLUSTERIZE_LOG_TRACES: &'static str = "ClusterizeLogTraces";
+    pub const CLUSTERIZE_LOG_TRACES_AGGLOMERATIVE: &'static str = "ClusterizeLogTracesAgglomerative";
+    pub const CLUSTERIZE_LOG_TRACES_DISTANCE_MATRIX: &'static str = "ClusterizeLogTracesDistanceMatrix";
+    pub const CLUSTERIZE_LOG_TRACES_MODEL_DRIVEN: &'static str = "ClusterizeLogTracesModelDriven";
+    pub const CLUSTERIZE_ACTIVITIES_FROM_TRACES_DISTANCE_MATRIX: &'static str = "ClusterizeActivitiesFromTracesDistanceMatrix";
+    pub const DETECT_CONCEPT_DRIFT: &'static str = "DetectConceptDrift";
+    pub const SPLIT_LOG_BY_CONCEPT_DRIFT: &'static str = "SplitLogByConceptDrift";
+    pub const DISCOVER_STREAMING_MODEL: &'static str = "DiscoverStreamingModel";
+    pub const TRAIN_NEXT_ACTIVITY_MODEL: &'static str = "TrainNextActivityModel";
+    pub const PREDICT_NEXT_ACTIVITY: &'static str = "PredictNextActivity";
+    pub const TRAIN_REMAINING_TIME_MODEL: &'static str = "TrainRemainingTimeModel";
+    pub const PREDICT_REMAINING_TIME: &'static str = "PredictRemainingTime";
+    pub const EVALUATE_PREDICTION_MODELS: &'static str = "EvaluatePredictionModels";
+    pub const BUILD_TRANSITION_SYSTEM: &'static str = "BuildTransitionSystem";
+    pub const DISCOVER_PETRI_NET_REGIONS: &'static str = "DiscoverPetriNetRegions";
 }
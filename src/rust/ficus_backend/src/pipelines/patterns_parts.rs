@@ -1,8 +1,17 @@
 use crate::pipelines::pipeline_parts::PipelineParts;
 use crate::{
+    event_log::{
+        core::{event::event::Event, event_log::EventLog, trace::trace::Trace},
+        xes::xes_event_log::XesEventLogImpl,
+    },
     features::analysis::patterns::{
-        contexts::PatternsDiscoveryStrategy,
-        repeats::{find_maximal_repeats, find_near_super_maximal_repeats, find_super_maximal_repeats},
+        approximate_patterns::{find_approximate_tandem_arrays, find_gapped_repeats, find_hamming_repeats},
+        contexts::{PatternsDiscoveryStrategy, PatternsSearchBackend},
+        episodes::{find_frequent_episodes, EpisodesDiscoveryParams},
+        repeats::{
+            find_maximal_repeats_with_backend, find_near_super_maximal_repeats_with_backend, find_super_maximal_repeats_with_backend,
+        },
+        sequential_patterns::{find_sequential_patterns, SequentialPattern, SequentialPatternsDiscoveryParams},
         tandem_arrays::{find_maximal_tandem_arrays, find_primitive_tandem_arrays, SubArrayInTraceInfo},
     },
     utils::user_data::user_data::{UserData, UserDataImpl},
@@ -14,7 +23,7 @@ use super::{
     pipelines::PipelinePartFactory,
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Hash)]
 pub enum PatternsKindDto {
     PrimitiveTandemArrays,
     MaximalTandemArrays,
@@ -22,6 +31,10 @@ pub enum PatternsKindDto {
     MaximalRepeats,
     SuperMaximalRepeats,
     NearSuperMaximalRepeats,
+
+    HammingRepeats,
+    GappedRepeats,
+    ApproximateTandemArrays,
 }
 
 impl FromStr for PatternsKindDto {
@@ -34,6 +47,9 @@ impl FromStr for PatternsKindDto {
             "MaximalRepeats" => Ok(Self::MaximalRepeats),
             "SuperMaximalRepeats" => Ok(Self::SuperMaximalRepeats),
             "NearSuperMaximalRepeats" => Ok(Self::NearSuperMaximalRepeats),
+            "HammingRepeats" => Ok(Self::HammingRepeats),
+            "GappedRepeats" => Ok(Self::GappedRepeats),
+            "ApproximateTandemArrays" => Ok(Self::ApproximateTandemArrays),
             _ => Err(()),
         }
     }
@@ -42,19 +58,19 @@ impl FromStr for PatternsKindDto {
 impl PipelineParts {
     pub(super) fn find_maximal_repeats() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::FIND_MAXIMAL_REPEATS, &|context, _, keys, config| {
-            Self::find_repeats_and_put_to_context(context, keys, config, find_maximal_repeats)
+            Self::find_exact_repeats_and_put_to_context(context, keys, config, find_maximal_repeats_with_backend)
         })
     }
 
     pub(super) fn find_super_maximal_repeats() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::FIND_SUPER_MAXIMAL_REPEATS, &|context, _, keys, config| {
-            Self::find_repeats_and_put_to_context(context, keys, config, find_super_maximal_repeats)
+            Self::find_exact_repeats_and_put_to_context(context, keys, config, find_super_maximal_repeats_with_backend)
         })
     }
 
     pub(super) fn find_near_super_maximal_repeats() -> (String, PipelinePartFactory) {
         Self::create_pipeline_part(Self::FIND_NEAR_SUPER_MAXIMAL_REPEATS, &|context, _, keys, config| {
-            Self::find_repeats_and_put_to_context(context, keys, config, find_near_super_maximal_repeats)
+            Self::find_exact_repeats_and_put_to_context(context, keys, config, find_near_super_maximal_repeats_with_backend)
         })
     }
 
@@ -70,6 +86,119 @@ impl PipelineParts {
         })
     }
 
+    pub(super) fn find_hamming_repeats() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::FIND_HAMMING_REPEATS, &|context, _, keys, config| {
+            Self::find_hamming_repeats_and_put_to_context(context, keys, config)
+        })
+    }
+
+    pub(super) fn find_gapped_repeats() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::FIND_GAPPED_REPEATS, &|context, _, keys, config| {
+            Self::find_gapped_repeats_and_put_to_context(context, keys, config)
+        })
+    }
+
+    pub(super) fn find_approximate_tandem_arrays() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::FIND_APPROXIMATE_TANDEM_ARRAYS, &|context, _, keys, config| {
+            Self::find_approximate_tandem_arrays_and_put_to_context(context, keys, config)
+        })
+    }
+
+    pub(super) fn find_sequential_patterns() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::FIND_SEQUENTIAL_PATTERNS, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let params = SequentialPatternsDiscoveryParams {
+                min_support: *Self::get_user_data(config, keys.min_support())? as usize,
+                max_gap: *Self::get_user_data(config, keys.max_gap())? as usize,
+                max_pattern_length: *Self::get_user_data(config, keys.max_pattern_length())? as usize,
+            };
+
+            let hashed_log = Self::create_hashed_event_log(config, keys, log);
+            let patterns = find_sequential_patterns(&hashed_log, &params);
+
+            Self::put_sequential_patterns_to_context(context, keys, hashed_log, patterns);
+
+            Ok(())
+        })
+    }
+
+    pub(super) fn find_frequent_episodes() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::FIND_FREQUENT_EPISODES, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let params = EpisodesDiscoveryParams {
+                kind: *Self::get_user_data(config, keys.episode_kind())?,
+                time_window: (*Self::get_user_data(config, keys.time_window_seconds())? * 1000.0) as i64,
+                min_support: *Self::get_user_data(config, keys.min_support())? as usize,
+                max_episode_length: *Self::get_user_data(config, keys.max_pattern_length())? as usize,
+            };
+
+            let hashed_log = Self::create_hashed_event_log(config, keys, log);
+            let episodes = find_frequent_episodes(&hashed_log, &Self::create_timestamps_log(log), &params);
+
+            Self::put_sequential_patterns_to_context(context, keys, hashed_log, episodes);
+
+            Ok(())
+        })
+    }
+
+    fn create_timestamps_log(log: &XesEventLogImpl) -> Vec<Vec<i64>> {
+        log.traces()
+            .iter()
+            .map(|trace| {
+                let trace = trace.borrow();
+                trace
+                    .events()
+                    .iter()
+                    .map(|event| event.borrow().timestamp().timestamp_millis())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn put_sequential_patterns_to_context(
+        context: &mut PipelineContext,
+        keys: &ContextKeys,
+        hashed_log: Vec<Vec<u64>>,
+        patterns: Vec<SequentialPattern>,
+    ) {
+        let occurrences: Vec<_> = patterns.iter().flat_map(|pattern| pattern.occurrences().iter().cloned()).collect();
+
+        context.put_concrete(keys.hashes_event_log().key(), hashed_log);
+        context.put_concrete(keys.patterns_occurrences().key(), occurrences);
+        context.put_concrete(keys.sequential_patterns().key(), patterns);
+    }
+
+    fn find_hamming_repeats_and_put_to_context(
+        context: &mut PipelineContext,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+    ) -> Result<(), PipelinePartExecutionError> {
+        let max_mismatches = *Self::get_user_data(config, keys.max_mismatches())? as usize;
+        Self::find_repeats_and_put_to_context(context, keys, config, |log, strategy| {
+            find_hamming_repeats(log, strategy, max_mismatches)
+        })
+    }
+
+    fn find_gapped_repeats_and_put_to_context(
+        context: &mut PipelineContext,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+    ) -> Result<(), PipelinePartExecutionError> {
+        let max_gap = *Self::get_user_data(config, keys.max_gap())? as usize;
+        Self::find_repeats_and_put_to_context(context, keys, config, |log, strategy| find_gapped_repeats(log, strategy, max_gap))
+    }
+
+    fn find_approximate_tandem_arrays_and_put_to_context(
+        context: &mut PipelineContext,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+    ) -> Result<(), PipelinePartExecutionError> {
+        let max_mismatches = *Self::get_user_data(config, keys.max_mismatches())? as usize;
+        Self::find_tandem_arrays_and_put_to_context(context, keys, config, |log, length| {
+            find_approximate_tandem_arrays(log, length, max_mismatches)
+        })
+    }
+
     pub(super) fn find_tandem_arrays_and_put_to_context(
         context: &mut PipelineContext,
         keys: &ContextKeys,
@@ -89,6 +218,20 @@ impl PipelineParts {
         Ok(())
     }
 
+    fn find_exact_repeats_and_put_to_context(
+        context: &mut PipelineContext,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+        patterns_finder: impl Fn(&Vec<Vec<u64>>, &PatternsDiscoveryStrategy, &PatternsSearchBackend) -> Vec<Vec<SubArrayInTraceInfo>>,
+    ) -> Result<(), PipelinePartExecutionError> {
+        let backend = match config.concrete(keys.patterns_search_backend().key()) {
+            Some(backend) => *backend,
+            None => PatternsSearchBackend::SuffixTree,
+        };
+
+        Self::find_repeats_and_put_to_context(context, keys, config, |log, strategy| patterns_finder(log, strategy, &backend))
+    }
+
     pub(super) fn find_repeats_and_put_to_context(
         context: &mut PipelineContext,
         keys: &ContextKeys,
@@ -121,13 +264,18 @@ impl PipelineParts {
             PatternsKindDto::MaximalTandemArrays => {
                 Self::find_tandem_arrays_and_put_to_context(context, keys, config, find_maximal_tandem_arrays)?
             }
-            PatternsKindDto::MaximalRepeats => Self::find_repeats_and_put_to_context(context, keys, config, find_maximal_repeats)?,
+            PatternsKindDto::MaximalRepeats => {
+                Self::find_exact_repeats_and_put_to_context(context, keys, config, find_maximal_repeats_with_backend)?
+            }
             PatternsKindDto::SuperMaximalRepeats => {
-                Self::find_repeats_and_put_to_context(context, keys, config, find_super_maximal_repeats)?
+                Self::find_exact_repeats_and_put_to_context(context, keys, config, find_super_maximal_repeats_with_backend)?
             }
             PatternsKindDto::NearSuperMaximalRepeats => {
-                Self::find_repeats_and_put_to_context(context, keys, config, find_near_super_maximal_repeats)?
+                Self::find_exact_repeats_and_put_to_context(context, keys, config, find_near_super_maximal_repeats_with_backend)?
             }
+            PatternsKindDto::HammingRepeats => Self::find_hamming_repeats_and_put_to_context(context, keys, config)?,
+            PatternsKindDto::GappedRepeats => Self::find_gapped_repeats_and_put_to_context(context, keys, config)?,
+            PatternsKindDto::ApproximateTandemArrays => Self::find_approximate_tandem_arrays_and_put_to_context(context, keys, config)?,
         };
 
         let activity_level = Self::get_user_data(config, keys.activity_level())?;
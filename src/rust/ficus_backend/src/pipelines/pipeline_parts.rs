@@ -32,6 +32,11 @@ impl PipelineParts {
             Self::find_maximal_repeats(),
             Self::find_super_maximal_repeats(),
             Self::find_near_super_maximal_repeats(),
+            Self::find_hamming_repeats(),
+            Self::find_gapped_repeats(),
+            Self::find_approximate_tandem_arrays(),
+            Self::find_sequential_patterns(),
+            Self::find_frequent_episodes(),
             Self::discover_activities(),
             Self::discover_activities_instances(),
             Self::create_log_from_activities(),
@@ -59,6 +64,10 @@ impl PipelineParts {
             Self::apply_class_extractor(),
             Self::discover_petri_net_alpha(),
             Self::serialize_petri_net(),
+            Self::serialize_petri_net_to_dot(),
+            Self::serialize_graph(),
+            Self::serialize_to_dot(),
+            Self::analyze_graph(),
             Self::add_artificial_start_end_events(),
             Self::add_artificial_start_events(),
             Self::add_artificial_end_events(),
@@ -68,6 +77,16 @@ impl PipelineParts {
             Self::discover_directly_follows_graph(),
             Self::discover_petri_net_heuristic_miner(),
             Self::discover_fuzzy_graph(),
+            Self::discover_hierarchical_petri_net(),
+            Self::serialize_hierarchical_petri_net(),
+            Self::discover_local_process_models(),
+            Self::discover_declare_model(),
+            Self::check_declare_conformance(),
+            Self::serialize_declare_model(),
+            Self::read_declare_model(),
+            Self::convert_petri_net_to_bpmn(),
+            Self::discover_bpmn_heuristic(),
+            Self::serialize_bpmn(),
             Self::annotate_petri_net_count(),
             Self::annotate_petri_net_frequency(),
             Self::annotate_petri_net_trace_frequency(),
@@ -79,6 +98,21 @@ impl PipelineParts {
             Self::create_traces_activities_dataset(),
             Self::write_log_to_bxes(),
             Self::clusterize_log_traces(),
+            Self::clusterize_log_traces_agglomerative(),
+            Self::clusterize_log_traces_distance_matrix(),
+            Self::clusterize_activities_from_traces_distance_matrix(),
+            Self::project_dataset(),
+            Self::clusterize_log_traces_model_driven(),
+            Self::detect_concept_drift(),
+            Self::split_log_by_concept_drift(),
+            Self::discover_streaming_model(),
+            Self::train_next_activity_model(),
+            Self::predict_next_activity(),
+            Self::train_remaining_time_model(),
+            Self::predict_remaining_time(),
+            Self::evaluate_prediction_models(),
+            Self::build_transition_system(),
+            Self::discover_petri_net_regions(),
         ];
 
         let mut names_to_parts = HashMap::new();
@@ -1,4 +1,5 @@
 use crate::pipelines::context::PipelineInfrastructure;
+use crate::pipelines::memoization::PipelinePartsCache;
 use crate::utils::{
     colors::ColorsHolder,
     user_data::user_data::{UserData, UserDataImpl},
@@ -30,7 +31,10 @@ impl PipelinePart for Pipeline {
         self.put_default_concrete_keys(context, keys);
 
         for part in &self.parts {
-            part.execute(context, infra, keys)?;
+            match infra.parts_cache() {
+                Some(cache) => PipelinePartsCache::execute_part(cache, part.as_ref(), context, infra, keys)?,
+                None => part.execute(context, infra, keys)?,
+            }
         }
 
         Ok(())
@@ -52,6 +56,10 @@ pub trait PipelinePart {
         infra: &PipelineInfrastructure,
         keys: &ContextKeys,
     ) -> Result<(), PipelinePartExecutionError>;
+
+    fn memoization_info(&self) -> Option<(&String, &UserDataImpl)> {
+        None
+    }
 }
 
 pub struct ParallelPipelinePart {
@@ -109,6 +117,10 @@ impl PipelinePart for DefaultPipelinePart {
     ) -> Result<(), PipelinePartExecutionError> {
         (self.executor)(context, infra, keys, &self.config)
     }
+
+    fn memoization_info(&self) -> Option<(&String, &UserDataImpl)> {
+        Some((&self.name, &self.config))
+    }
 }
 
 pub(super) type PipelinePartFactory = Box<dyn Fn(Box<UserDataImpl>) -> DefaultPipelinePart>;
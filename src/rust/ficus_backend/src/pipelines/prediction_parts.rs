@@ -0,0 +1,159 @@
+use crate::event_log::core::event::event::Event;
+use crate::event_log::core::event_log::EventLog;
+use crate::event_log::core::trace::trace::Trace;
+use crate::features::prediction::holdout::split_log_temporal;
+use crate::features::prediction::next_activity::NextActivityModel;
+use crate::features::prediction::remaining_time::RemainingTimeModel;
+use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
+use crate::pipelines::keys::context_keys::ContextKeys;
+use crate::pipelines::pipeline_parts::PipelineParts;
+use crate::pipelines::pipelines::PipelinePartFactory;
+use crate::utils::dataset::dataset::FicusDataset;
+use crate::utils::user_data::user_data::{UserData, UserDataImpl};
+
+impl PipelineParts {
+    pub(super) fn train_next_activity_model() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::TRAIN_NEXT_ACTIVITY_MODEL, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let model = Self::create_next_activity_model(log, keys, config)?;
+            context.put_concrete(keys.next_activity_model().key(), model);
+
+            Ok(())
+        })
+    }
+
+    //Traces of the log are considered as running cases, the dataset contains the probabilities of the next event classes
+    pub(super) fn predict_next_activity() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::PREDICT_NEXT_ACTIVITY, &|context, _, keys, _| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let model = Self::get_user_data(context, keys.next_activity_model())?;
+
+            let classes = model.event_classes();
+            let mut values: Vec<Vec<f64>> = vec![];
+            for names in log.to_raw_vector() {
+                let probabilities = match model.distribution(&names) {
+                    Some((_, probabilities)) => probabilities,
+                    None => Default::default(),
+                };
+
+                values.push(classes.iter().map(|class| *probabilities.get(class).unwrap_or(&0.0)).collect());
+            }
+
+            let rows = (0..values.len()).map(|index| format!("Trace_{}", index)).collect();
+            context.put_concrete(keys.next_activity_dataset().key(), FicusDataset::new(values, classes, rows));
+
+            Ok(())
+        })
+    }
+
+    //Transition system of the model is put to the graph
+    pub(super) fn train_remaining_time_model() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::TRAIN_REMAINING_TIME_MODEL, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let model = Self::create_remaining_time_model(log, keys, config)?;
+
+            context.put_concrete(keys.graph().key(), model.to_graph());
+            context.put_concrete(keys.remaining_time_model().key(), model);
+
+            Ok(())
+        })
+    }
+
+    //Traces of the log are considered as running cases, the dataset contains their elapsed and predicted remaining times
+    pub(super) fn predict_remaining_time() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::PREDICT_REMAINING_TIME, &|context, _, keys, _| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let model = Self::get_user_data(context, keys.remaining_time_model())?;
+
+            let mut values = vec![];
+            for trace in log.traces() {
+                let trace = trace.borrow();
+                let names = trace
+                    .events()
+                    .iter()
+                    .map(|event| event.borrow().name().to_owned())
+                    .collect::<Vec<String>>();
+
+                let elapsed = match (trace.events().first(), trace.events().last()) {
+                    (Some(first), Some(last)) => {
+                        (*last.borrow().timestamp() - *first.borrow().timestamp()).num_milliseconds() as f64 / 1000.0
+                    }
+                    _ => 0.0,
+                };
+
+                values.push(vec![elapsed, model.predict(&names).unwrap_or(0.0)]);
+            }
+
+            let columns = vec!["elapsed".to_owned(), "remaining_time".to_owned()];
+            let rows = (0..values.len()).map(|index| format!("Trace_{}", index)).collect();
+            context.put_concrete(keys.remaining_time_dataset().key(), FicusDataset::new(values, columns, rows));
+
+            Ok(())
+        })
+    }
+
+    //Temporal holdout: models are trained on the first train_fraction (0.8 by default) of traces ordered by start time
+    //and evaluated on the rest of them
+    pub(super) fn evaluate_prediction_models() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::EVALUATE_PREDICTION_MODELS, &|context, _, keys, config| {
+            let log = Self::get_user_data(context, keys.event_log())?;
+            let train_fraction = match Self::get_user_data(config, keys.train_fraction()) {
+                Ok(train_fraction) => *train_fraction,
+                Err(_) => 0.8,
+            };
+
+            if !(train_fraction > 0.0 && train_fraction < 1.0) {
+                let message = format!("Train fraction should be in (0, 1), got {}", train_fraction);
+                return Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(message)));
+            }
+
+            let (train_log, test_log) = split_log_temporal(log, train_fraction);
+            let next_activity_model = Self::create_next_activity_model(&train_log, keys, config)?;
+            let remaining_time_model = Self::create_remaining_time_model(&train_log, keys, config)?;
+
+            let values = vec![vec![
+                next_activity_model.evaluate(&test_log),
+                remaining_time_model.evaluate(&test_log),
+                train_log.traces().len() as f64,
+                test_log.traces().len() as f64,
+            ]];
+
+            let columns = [
+                "next_activity_accuracy",
+                "remaining_time_mae",
+                "train_traces_count",
+                "test_traces_count",
+            ];
+            let columns = columns.iter().map(|column| column.to_string()).collect();
+            let dataset = FicusDataset::new(values, columns, vec!["Holdout".to_owned()]);
+
+            context.put_concrete(keys.prediction_evaluation_dataset().key(), dataset);
+
+            Ok(())
+        })
+    }
+
+    fn create_next_activity_model(
+        log: &impl EventLog,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+    ) -> Result<NextActivityModel, PipelinePartExecutionError> {
+        let order = *Self::get_user_data(config, keys.ngram_order())? as usize;
+        Ok(NextActivityModel::train(log, order))
+    }
+
+    //State horizon is optional, the whole prefix is abstracted if it is not set
+    fn create_remaining_time_model(
+        log: &impl EventLog,
+        keys: &ContextKeys,
+        config: &UserDataImpl,
+    ) -> Result<RemainingTimeModel, PipelinePartExecutionError> {
+        let abstraction = *Self::get_user_data(config, keys.state_abstraction())?;
+        let horizon = match Self::get_user_data(config, keys.state_horizon()) {
+            Ok(horizon) => Some(*horizon as usize),
+            Err(_) => None,
+        };
+
+        Ok(RemainingTimeModel::train(log, abstraction, horizon))
+    }
+}
@@ -0,0 +1,93 @@
+use crate::features::streaming::events_file_tail::EventsFileDiscovery;
+use crate::features::streaming::streaming_discovery::{StreamingCountsMode, StreamingDiscoveryParams, StreamingModel};
+use crate::pipelines::errors::pipeline_errors::{PipelinePartExecutionError, RawPartExecutionError};
+use crate::pipelines::keys::context_keys::ContextKeys;
+use crate::pipelines::pipeline_parts::PipelineParts;
+use crate::pipelines::pipelines::PipelinePartFactory;
+use crate::utils::user_data::user_data::{UserData, UserDataImpl};
+
+impl PipelineParts {
+    //Events are read from the file at the path, one "case_id,activity,timestamp" event per line. Counts are maintained
+    //in the sliding window if its size is set, otherwise lossy counting with the error 0.01 by default is used.
+    //The discovery is kept in the context, so only events appended since the previous execution are read,
+    //it is started anew if the path or the counts mode change
+    pub(super) fn discover_streaming_model() -> (String, PipelinePartFactory) {
+        Self::create_pipeline_part(Self::DISCOVER_STREAMING_MODEL, &|context, _, keys, config| {
+            let path = Self::get_user_data(config, keys.path())?;
+            let model = *Self::get_user_data(config, keys.streaming_model())?;
+            let mode = Self::create_streaming_counts_mode(keys, config)?;
+
+            let is_reusable = match Self::get_user_data(context, keys.events_file_discovery()) {
+                Ok(file_discovery) => file_discovery.tail().path() == path.as_str() && file_discovery.discovery().mode() == mode,
+                Err(_) => false,
+            };
+
+            if !is_reusable {
+                let file_discovery = EventsFileDiscovery::new(path, &StreamingDiscoveryParams::new(mode));
+                context.put_concrete(keys.events_file_discovery().key(), file_discovery);
+            }
+
+            let file_discovery = Self::get_user_data_mut(context, keys.events_file_discovery())?;
+            if let Err(error) = file_discovery.feed() {
+                return Err(error.into());
+            }
+
+            let discovery = file_discovery.discovery();
+            match model {
+                StreamingModel::Dfg => {
+                    let dfg = discovery.dfg();
+                    context.put_concrete(keys.graph().key(), dfg);
+                }
+                StreamingModel::HeuristicNet => {
+                    let petri_net = discovery.heuristic_petri_net(
+                        *Self::get_user_data(config, keys.dependency_threshold())?,
+                        *Self::get_user_data(config, keys.positive_observations_threshold())? as usize,
+                        *Self::get_user_data(config, keys.relative_to_best_threshold())?,
+                        *Self::get_user_data(config, keys.and_threshold())?,
+                        *Self::get_user_data(config, keys.loop_length_two_threshold())?,
+                    );
+
+                    context.put_concrete(keys.petri_net().key(), petri_net);
+                }
+                StreamingModel::FuzzyGraph => {
+                    let graph = discovery.fuzzy_graph(
+                        *Self::get_user_data(config, keys.unary_frequency_threshold())?,
+                        *Self::get_user_data(config, keys.binary_significance_threshold())?,
+                        *Self::get_user_data(config, keys.preserve_threshold())?,
+                        *Self::get_user_data(config, keys.ratio_threshold())?,
+                        *Self::get_user_data(config, keys.utility_rate())?,
+                        *Self::get_user_data(config, keys.edge_cutoff_threshold())?,
+                        *Self::get_user_data(config, keys.node_cutoff_threshold())?,
+                    );
+
+                    context.put_concrete(keys.graph().key(), graph.to_default_graph());
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn create_streaming_counts_mode(keys: &ContextKeys, config: &UserDataImpl) -> Result<StreamingCountsMode, PipelinePartExecutionError> {
+        if let Ok(size) = Self::get_user_data(config, keys.streaming_window_size()) {
+            if *size == 0 {
+                let message = "Streaming window size should be greater than zero".to_string();
+                return Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(message)));
+            }
+
+            return Ok(StreamingCountsMode::SlidingWindow { size: *size as usize });
+        }
+
+        let error = match Self::get_user_data(config, keys.lossy_counting_error()) {
+            Ok(error) => *error,
+            Err(_) => 0.01,
+        };
+
+        if !(error > 0.0 && error < 1.0) {
+            let message = format!("Lossy counting error should be in (0, 1), got {}", error);
+            return Err(PipelinePartExecutionError::Raw(RawPartExecutionError::new(message)));
+        }
+
+        Ok(StreamingCountsMode::LossyCounting { error })
+    }
+}
@@ -0,0 +1,179 @@
+use std::fs;
+use std::str::FromStr;
+
+use crate::utils::colors::Color;
+
+use super::dataset::{FicusDataset, LabeledDataset};
+
+const SEPARATOR: char = '\t';
+const LABEL_COLUMN_NAME: &'static str = "__label";
+const COLOR_COLUMN_NAME: &'static str = "__color";
+
+pub enum DatasetSerializationError {
+    IOError(std::io::Error),
+    ParseError(String),
+}
+
+impl ToString for DatasetSerializationError {
+    fn to_string(&self) -> String {
+        match self {
+            Self::IOError(err) => err.to_string(),
+            Self::ParseError(message) => message.to_owned(),
+        }
+    }
+}
+
+pub fn serialize_dataset(dataset: &FicusDataset) -> String {
+    serialize_internal(dataset, None)
+}
+
+pub fn serialize_labeled_dataset(dataset: &LabeledDataset) -> String {
+    serialize_internal(dataset.dataset(), Some((dataset.labels(), dataset.colors())))
+}
+
+fn serialize_internal(dataset: &FicusDataset, labels: Option<(&Vec<usize>, &Vec<Color>)>) -> String {
+    let mut result = String::new();
+
+    let mut header = vec![String::new()];
+    header.extend(dataset.columns_names().iter().map(|name| escape(name)));
+    if labels.is_some() {
+        header.push(LABEL_COLUMN_NAME.to_owned());
+        header.push(COLOR_COLUMN_NAME.to_owned());
+    }
+
+    push_line(&mut result, header);
+
+    for (index, (row_name, row)) in dataset.row_names().iter().zip(dataset.values()).enumerate() {
+        let mut line = vec![escape(row_name)];
+        line.extend(row.iter().map(|value| value.to_string()));
+
+        if let Some((labels, colors)) = labels {
+            let color = colors[index];
+            line.push(labels[index].to_string());
+            line.push(format!("{};{};{}", color.red(), color.green(), color.blue()));
+        }
+
+        push_line(&mut result, line);
+    }
+
+    result
+}
+
+fn push_line(result: &mut String, line: Vec<String>) {
+    result.push_str(line.join(SEPARATOR.to_string().as_str()).as_str());
+    result.push('\n');
+}
+
+fn escape(value: &str) -> String {
+    value.replace(SEPARATOR, " ").replace('\n', " ")
+}
+
+pub fn serialize_dataset_to_file(dataset: &FicusDataset, path: &str) -> Result<(), DatasetSerializationError> {
+    write_to_file(serialize_dataset(dataset), path)
+}
+
+pub fn serialize_labeled_dataset_to_file(dataset: &LabeledDataset, path: &str) -> Result<(), DatasetSerializationError> {
+    write_to_file(serialize_labeled_dataset(dataset), path)
+}
+
+fn write_to_file(content: String, path: &str) -> Result<(), DatasetSerializationError> {
+    match fs::write(path, content) {
+        Ok(()) => Ok(()),
+        Err(error) => Err(DatasetSerializationError::IOError(error)),
+    }
+}
+
+pub fn deserialize_dataset(content: &str) -> Result<FicusDataset, DatasetSerializationError> {
+    let (dataset, _) = deserialize_internal(content, false)?;
+    Ok(dataset)
+}
+
+pub fn deserialize_labeled_dataset(content: &str) -> Result<LabeledDataset, DatasetSerializationError> {
+    match deserialize_internal(content, true)? {
+        (dataset, Some((labels, colors))) => Ok(LabeledDataset::new(dataset, labels, colors)),
+        (_, None) => Err(DatasetSerializationError::ParseError("Labels are missing".to_owned())),
+    }
+}
+
+pub fn deserialize_dataset_from_file(path: &str) -> Result<FicusDataset, DatasetSerializationError> {
+    deserialize_dataset(read_file(path)?.as_str())
+}
+
+pub fn deserialize_labeled_dataset_from_file(path: &str) -> Result<LabeledDataset, DatasetSerializationError> {
+    deserialize_labeled_dataset(read_file(path)?.as_str())
+}
+
+fn read_file(path: &str) -> Result<String, DatasetSerializationError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(error) => Err(DatasetSerializationError::IOError(error)),
+    }
+}
+
+fn deserialize_internal(
+    content: &str,
+    labeled: bool,
+) -> Result<(FicusDataset, Option<(Vec<usize>, Vec<Color>)>), DatasetSerializationError> {
+    let mut lines = content.lines();
+    let header = match lines.next() {
+        Some(header) => header.split(SEPARATOR).skip(1).map(|x| x.to_owned()).collect::<Vec<String>>(),
+        None => return Err(DatasetSerializationError::ParseError("Header is missing".to_owned())),
+    };
+
+    if labeled && header.len() < 2 {
+        return Err(DatasetSerializationError::ParseError("Labels columns are missing".to_owned()));
+    }
+
+    let columns_count = if labeled { header.len() - 2 } else { header.len() };
+    let columns_names = header.into_iter().take(columns_count).collect::<Vec<String>>();
+
+    let mut values = vec![];
+    let mut row_names = vec![];
+    let mut labels = vec![];
+    let mut colors = vec![];
+
+    for line in lines {
+        let parts = line.split(SEPARATOR).collect::<Vec<&str>>();
+        let expected_len = 1 + columns_count + if labeled { 2 } else { 0 };
+        if parts.len() != expected_len {
+            return Err(DatasetSerializationError::ParseError(format!("Malformed row: {}", line)));
+        }
+
+        row_names.push(parts[0].to_owned());
+
+        let mut row = vec![];
+        for value in &parts[1..(1 + columns_count)] {
+            row.push(parse::<f64>(value)?);
+        }
+
+        values.push(row);
+
+        if labeled {
+            labels.push(parse::<usize>(parts[1 + columns_count])?);
+            colors.push(parse_color(parts[2 + columns_count])?);
+        }
+    }
+
+    let dataset = FicusDataset::new(values, columns_names, row_names);
+    Ok((dataset, if labeled { Some((labels, colors)) } else { None }))
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, DatasetSerializationError> {
+    match value.parse::<T>() {
+        Ok(value) => Ok(value),
+        Err(_) => Err(DatasetSerializationError::ParseError(format!("Failed to parse {}", value))),
+    }
+}
+
+fn parse_color(value: &str) -> Result<Color, DatasetSerializationError> {
+    let components = value.split(';').collect::<Vec<&str>>();
+    if components.len() != 3 {
+        return Err(DatasetSerializationError::ParseError(format!("Malformed color {}", value)));
+    }
+
+    Ok(Color::new(
+        parse::<u8>(components[0])?,
+        parse::<u8>(components[1])?,
+        parse::<u8>(components[2])?,
+    ))
+}
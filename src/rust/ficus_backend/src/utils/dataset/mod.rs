@@ -1 +1,3 @@
 pub mod dataset;
+pub mod dataset_serialization;
+pub mod projection;
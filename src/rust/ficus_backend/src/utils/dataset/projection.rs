@@ -0,0 +1,326 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::dataset::{FicusDataset, LabeledDataset};
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum DatasetProjectionMethod {
+    Pca,
+    Tsne,
+}
+
+impl FromStr for DatasetProjectionMethod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Pca" => Ok(Self::Pca),
+            "Tsne" => Ok(Self::Tsne),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DatasetProjection {
+    Pca,
+    Tsne { perplexity: f64, iterations_count: usize },
+}
+
+impl Display for DatasetProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pca => write!(f, "Pca"),
+            Self::Tsne {
+                perplexity,
+                iterations_count,
+            } => write!(f, "Tsne(perplexity={}, iterations_count={})", perplexity, iterations_count),
+        }
+    }
+}
+
+const TSNE_EXAGGERATION_ITERATIONS: usize = 250;
+const TSNE_EXAGGERATION: f64 = 12.0;
+const POWER_ITERATIONS_COUNT: usize = 1000;
+
+//Rows names are preserved, columns are named after the projection method (PC1, PC2, ... or TSNE1, TSNE2, ...)
+pub fn project_dataset(dataset: &FicusDataset, projection: &DatasetProjection, dimensions: usize) -> FicusDataset {
+    let (values, prefix) = match projection {
+        DatasetProjection::Pca => (pca(dataset.values(), dimensions), "PC"),
+        DatasetProjection::Tsne {
+            perplexity,
+            iterations_count,
+        } => (tsne(dataset.values(), dimensions, *perplexity, *iterations_count), "TSNE"),
+    };
+
+    let columns_names = (1..=dimensions).map(|x| format!("{}{}", prefix, x)).collect();
+    FicusDataset::new(values, columns_names, dataset.row_names().clone())
+}
+
+pub fn project_labeled_dataset(dataset: &LabeledDataset, projection: &DatasetProjection, dimensions: usize) -> LabeledDataset {
+    LabeledDataset::new(
+        project_dataset(dataset.dataset(), projection, dimensions),
+        dataset.labels().clone(),
+        dataset.colors().clone(),
+    )
+}
+
+//Principal components are found with the power iteration on the covariance matrix or on the Gram matrix (whichever is
+//smaller), the sign of each component is chosen so that its largest by absolute value coordinate is positive.
+//Components which do not exist (when there are less features or objects than dimensions) are filled with zeros
+pub fn pca(values: &Vec<Vec<f64>>, dimensions: usize) -> Vec<Vec<f64>> {
+    let objects_count = values.len();
+    let mut projection = vec![vec![0.0; dimensions]; objects_count];
+    if objects_count == 0 {
+        return projection;
+    }
+
+    let features_count = values[0].len();
+    let mut centered = values.clone();
+    for feature in 0..features_count {
+        let mean = values.iter().map(|row| row[feature]).sum::<f64>() / objects_count as f64;
+        for row in centered.iter_mut() {
+            row[feature] -= mean;
+        }
+    }
+
+    if features_count <= objects_count {
+        let mut covariance = vec![vec![0.0; features_count]; features_count];
+        for row in &centered {
+            for i in 0..features_count {
+                for j in 0..features_count {
+                    covariance[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        for (component, (_, vector)) in top_eigenpairs(covariance, dimensions).into_iter().enumerate() {
+            for (object, row) in centered.iter().enumerate() {
+                projection[object][component] = row.iter().zip(vector.iter()).map(|(x, y)| x * y).sum();
+            }
+        }
+    } else {
+        let mut gram = vec![vec![0.0; objects_count]; objects_count];
+        for i in 0..objects_count {
+            for j in 0..objects_count {
+                gram[i][j] = centered[i].iter().zip(centered[j].iter()).map(|(x, y)| x * y).sum();
+            }
+        }
+
+        for (component, (value, vector)) in top_eigenpairs(gram, dimensions).into_iter().enumerate() {
+            for object in 0..objects_count {
+                projection[object][component] = vector[object] * value.sqrt();
+            }
+        }
+    }
+
+    for component in 0..dimensions {
+        let max = projection
+            .iter()
+            .map(|row| row[component])
+            .fold(0.0, |max: f64, x| if x.abs() > max.abs() { x } else { max });
+        if max < 0.0 {
+            for row in projection.iter_mut() {
+                row[component] = -row[component];
+            }
+        }
+    }
+
+    projection
+}
+
+//Eigenpairs of a symmetric positive semi-definite matrix with the largest eigenvalues, found one by one with
+//the power iteration and deflation, pairs with zero eigenvalues are not returned
+fn top_eigenpairs(mut matrix: Vec<Vec<f64>>, count: usize) -> Vec<(f64, Vec<f64>)> {
+    let size = matrix.len();
+    let scale = (0..size).map(|i| matrix[i][i]).sum::<f64>();
+    let mut pairs = vec![];
+
+    for _ in 0..count.min(size) {
+        let mut vector = (0..size).map(|i| 1.0 / (i + 1) as f64).collect::<Vec<f64>>();
+        let mut value = 0.0;
+
+        for _ in 0..POWER_ITERATIONS_COUNT {
+            let mut next = (0..size)
+                .map(|i| matrix[i].iter().zip(vector.iter()).map(|(x, y)| x * y).sum::<f64>())
+                .collect::<Vec<f64>>();
+
+            let norm = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                value = 0.0;
+                break;
+            }
+
+            next.iter_mut().for_each(|x| *x /= norm);
+            let difference = next.iter().zip(vector.iter()).map(|(x, y)| (x - y).abs()).fold(0.0, f64::max);
+
+            vector = next;
+            value = norm;
+
+            if difference < 1e-12 {
+                break;
+            }
+        }
+
+        if value <= scale * 1e-12 {
+            break;
+        }
+
+        for i in 0..size {
+            for j in 0..size {
+                matrix[i][j] -= value * vector[i] * vector[j];
+            }
+        }
+
+        pairs.push((value, vector));
+    }
+
+    pairs
+}
+
+//Exact t-SNE: perplexity is clamped to (objects_count - 1) / 3, the embedding is initialized with the scaled PCA
+//projection (which makes the result deterministic), early exaggeration is applied during the first 250 iterations
+pub fn tsne(values: &Vec<Vec<f64>>, dimensions: usize, perplexity: f64, iterations_count: usize) -> Vec<Vec<f64>> {
+    let objects_count = values.len();
+    if objects_count < 2 {
+        return vec![vec![0.0; dimensions]; objects_count];
+    }
+
+    let perplexity = perplexity.min((objects_count - 1) as f64 / 3.0).max(1.0);
+    let probabilities = calculate_joint_probabilities(values, perplexity);
+
+    let mut embedding = initialize_embedding(values, dimensions);
+    let mut updates = vec![vec![0.0; dimensions]; objects_count];
+    let mut gains = vec![vec![1.0f64; dimensions]; objects_count];
+    let mut numerators = vec![vec![0.0; objects_count]; objects_count];
+    let learning_rate = (objects_count as f64 / TSNE_EXAGGERATION / 4.0).max(50.0);
+
+    for iteration in 0..iterations_count {
+        let (exaggeration, momentum) = if iteration < TSNE_EXAGGERATION_ITERATIONS {
+            (TSNE_EXAGGERATION, 0.5)
+        } else {
+            (1.0, 0.8)
+        };
+
+        let mut numerators_sum = 0.0;
+        for i in 0..objects_count {
+            for j in (i + 1)..objects_count {
+                let numerator = 1.0 / (1.0 + squared_distance(&embedding[i], &embedding[j]));
+                numerators[i][j] = numerator;
+                numerators[j][i] = numerator;
+                numerators_sum += 2.0 * numerator;
+            }
+        }
+
+        for i in 0..objects_count {
+            let mut gradient = vec![0.0; dimensions];
+            for j in (0..objects_count).filter(|j| *j != i) {
+                let q = (numerators[i][j] / numerators_sum).max(f64::MIN_POSITIVE);
+                let multiplier = 4.0 * (exaggeration * probabilities[i][j] - q) * numerators[i][j];
+                for d in 0..dimensions {
+                    gradient[d] += multiplier * (embedding[i][d] - embedding[j][d]);
+                }
+            }
+
+            for d in 0..dimensions {
+                gains[i][d] = if (gradient[d] > 0.0) != (updates[i][d] > 0.0) {
+                    gains[i][d] + 0.2
+                } else {
+                    (gains[i][d] * 0.8).max(0.01)
+                };
+
+                updates[i][d] = momentum * updates[i][d] - learning_rate * gains[i][d] * gradient[d];
+            }
+        }
+
+        for i in 0..objects_count {
+            for d in 0..dimensions {
+                embedding[i][d] += updates[i][d];
+            }
+        }
+
+        for d in 0..dimensions {
+            let mean = embedding.iter().map(|row| row[d]).sum::<f64>() / objects_count as f64;
+            embedding.iter_mut().for_each(|row| row[d] -= mean);
+        }
+    }
+
+    embedding
+}
+
+fn squared_distance(first: &[f64], second: &[f64]) -> f64 {
+    first.iter().zip(second.iter()).map(|(x, y)| (x - y).powi(2)).sum()
+}
+
+//Conditional probabilities of each object are found with the binary search of the gaussian precision which gives
+//the required perplexity, then they are symmetrized
+fn calculate_joint_probabilities(values: &Vec<Vec<f64>>, perplexity: f64) -> Vec<Vec<f64>> {
+    let objects_count = values.len();
+    let target_entropy = perplexity.ln();
+    let mut probabilities = vec![vec![0.0; objects_count]; objects_count];
+
+    for i in 0..objects_count {
+        let distances = (0..objects_count)
+            .map(|j| squared_distance(&values[i], &values[j]))
+            .collect::<Vec<f64>>();
+
+        let (mut beta, mut min_beta, mut max_beta) = (1.0, 0.0, f64::INFINITY);
+        for _ in 0..100 {
+            let mut sum = 0.0;
+            let mut weighted_sum = 0.0;
+            for j in (0..objects_count).filter(|j| *j != i) {
+                let p = (-distances[j] * beta).exp();
+                probabilities[i][j] = p;
+                sum += p;
+                weighted_sum += distances[j] * p;
+            }
+
+            let sum = sum.max(f64::MIN_POSITIVE);
+            let entropy = sum.ln() + beta * weighted_sum / sum;
+            probabilities[i].iter_mut().for_each(|p| *p /= sum);
+
+            let difference = entropy - target_entropy;
+            if difference.abs() < 1e-5 {
+                break;
+            }
+
+            if difference > 0.0 {
+                min_beta = beta;
+                beta = if max_beta.is_infinite() {
+                    beta * 2.0
+                } else {
+                    (beta + max_beta) / 2.0
+                };
+            } else {
+                max_beta = beta;
+                beta = (beta + min_beta) / 2.0;
+            }
+        }
+    }
+
+    let mut joint = vec![vec![0.0; objects_count]; objects_count];
+    for i in 0..objects_count {
+        for j in 0..objects_count {
+            joint[i][j] = ((probabilities[i][j] + probabilities[j][i]) / (2.0 * objects_count as f64)).max(1e-12);
+        }
+    }
+
+    joint
+}
+
+fn initialize_embedding(values: &Vec<Vec<f64>>, dimensions: usize) -> Vec<Vec<f64>> {
+    let mut embedding = pca(values, dimensions);
+    let count = embedding.len() as f64;
+
+    for d in 0..dimensions {
+        let std = (embedding.iter().map(|row| row[d].powi(2)).sum::<f64>() / count).sqrt();
+        for (i, row) in embedding.iter_mut().enumerate() {
+            row[d] = if std == 0.0 {
+                1e-4 * ((i * (d + 1)) % 7) as f64 / 7.0
+            } else {
+                row[d] / std * 1e-4
+            };
+        }
+    }
+
+    embedding
+}
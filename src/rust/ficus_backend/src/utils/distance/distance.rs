@@ -1,14 +1,40 @@
 use std::str::FromStr;
 
 use linfa_nn::distance::{Distance, L1Dist, L2Dist};
-use ndarray::{ArrayView, Dimension};
+use ndarray::{Array1, ArrayView, Dimension};
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+use super::sequence_distance::{
+    discrete_cost, dtw_distance, edit_distance, ClassesWeightedEditCosts, TranspositionEditCosts, UnitEditCosts,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
 pub enum FicusDistance {
     Cosine,
     L1,
     L2,
     Levenshtein,
+    WeightedEdit,
+    OptimalStringAlignment,
+    Dtw,
+}
+
+impl FicusDistance {
+    //Sequence distances compare traces (or activities) as sequences of event classes, not as vectors of features
+    pub fn is_sequence_distance(&self) -> bool {
+        match self {
+            Self::Cosine | Self::L1 | Self::L2 => false,
+            Self::Levenshtein | Self::WeightedEdit | Self::OptimalStringAlignment | Self::Dtw => true,
+        }
+    }
+
+    //Sequence distances except the Levenshtein one are calculated only over sequences of event classes
+    //(see `calculate_sequence_distance`), they can not be calculated over rows of features datasets
+    pub fn is_features_distance(&self) -> bool {
+        match self {
+            Self::Cosine | Self::L1 | Self::L2 | Self::Levenshtein => true,
+            Self::WeightedEdit | Self::OptimalStringAlignment | Self::Dtw => false,
+        }
+    }
 }
 
 impl FromStr for FicusDistance {
@@ -20,6 +46,9 @@ impl FromStr for FicusDistance {
             "L1" => Ok(Self::L1),
             "L2" => Ok(Self::L2),
             "Levenshtein" => Ok(Self::Levenshtein),
+            "WeightedEdit" => Ok(Self::WeightedEdit),
+            "OptimalStringAlignment" => Ok(Self::OptimalStringAlignment),
+            "Dtw" => Ok(Self::Dtw),
             _ => Err(()),
         }
     }
@@ -34,12 +63,14 @@ pub enum DistanceWrapper {
 }
 
 impl DistanceWrapper {
-    pub fn new(ficus_distance: FicusDistance) -> DistanceWrapper {
+    //None is returned for distances which can not be calculated over features
+    pub fn new(ficus_distance: FicusDistance) -> Option<DistanceWrapper> {
         match ficus_distance {
-            FicusDistance::Cosine => DistanceWrapper::Cosine(CosineDistance {}),
-            FicusDistance::L1 => DistanceWrapper::L1(L1Dist {}),
-            FicusDistance::L2 => DistanceWrapper::L2(L2Dist {}),
-            FicusDistance::Levenshtein => DistanceWrapper::Levenshtein(LevenshteinDistance {}),
+            FicusDistance::Cosine => Some(DistanceWrapper::Cosine(CosineDistance {})),
+            FicusDistance::L1 => Some(DistanceWrapper::L1(L1Dist {})),
+            FicusDistance::L2 => Some(DistanceWrapper::L2(L2Dist {})),
+            FicusDistance::Levenshtein => Some(DistanceWrapper::Levenshtein(LevenshteinDistance {})),
+            FicusDistance::WeightedEdit | FicusDistance::OptimalStringAlignment | FicusDistance::Dtw => None,
         }
     }
 }
@@ -121,3 +152,26 @@ impl Distance<f64> for LevenshteinDistance {
         matrix[a_len - 1][b_len - 1]
     }
 }
+
+//Vector distances are calculated over counts of event classes in sequences
+pub fn calculate_sequence_distance(distance: FicusDistance, first: &[u64], second: &[u64], weights: &ClassesWeightedEditCosts) -> f64 {
+    match distance {
+        FicusDistance::Levenshtein => edit_distance(first, second, &UnitEditCosts),
+        FicusDistance::OptimalStringAlignment => edit_distance(first, second, &TranspositionEditCosts),
+        FicusDistance::WeightedEdit => edit_distance(first, second, weights),
+        FicusDistance::Dtw => dtw_distance(first, second, discrete_cost),
+        FicusDistance::Cosine | FicusDistance::L1 | FicusDistance::L2 => {
+            let mut classes = first.iter().chain(second.iter()).copied().collect::<Vec<u64>>();
+            classes.sort();
+            classes.dedup();
+
+            let counts = |sequence: &[u64]| {
+                let counts = classes.iter().map(|class| sequence.iter().filter(|x| *x == class).count() as f64);
+                Array1::from_iter(counts)
+            };
+
+            let distance = DistanceWrapper::new(distance).expect("Vector distances are calculated over features");
+            distance.distance(counts(first).view(), counts(second).view())
+        }
+    }
+}
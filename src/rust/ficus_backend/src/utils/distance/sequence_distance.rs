@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+pub trait EditCosts<T> {
+    fn insertion(&self, item: &T) -> f64;
+    fn deletion(&self, item: &T) -> f64;
+    fn substitution(&self, first: &T, second: &T) -> f64;
+
+    //Cost of swapping two adjacent items, None means that transpositions are not allowed
+    fn transposition(&self, _first: &T, _second: &T) -> Option<f64> {
+        None
+    }
+}
+
+//All operations cost 1, which gives the classic Levenshtein distance
+pub struct UnitEditCosts;
+
+impl<T: PartialEq> EditCosts<T> for UnitEditCosts {
+    fn insertion(&self, _: &T) -> f64 {
+        1.0
+    }
+
+    fn deletion(&self, _: &T) -> f64 {
+        1.0
+    }
+
+    fn substitution(&self, first: &T, second: &T) -> f64 {
+        if first == second {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+//Unit costs with adjacent transpositions allowed (optimal string alignment distance)
+pub struct TranspositionEditCosts;
+
+impl<T: PartialEq> EditCosts<T> for TranspositionEditCosts {
+    fn insertion(&self, item: &T) -> f64 {
+        UnitEditCosts.insertion(item)
+    }
+
+    fn deletion(&self, item: &T) -> f64 {
+        UnitEditCosts.deletion(item)
+    }
+
+    fn substitution(&self, first: &T, second: &T) -> f64 {
+        UnitEditCosts.substitution(first, second)
+    }
+
+    fn transposition(&self, _: &T, _: &T) -> Option<f64> {
+        Some(1.0)
+    }
+}
+
+//Inserting or deleting an event class costs its weight, substitution costs the mean of two weights,
+//classes without weight cost 1
+pub struct ClassesWeightedEditCosts {
+    weights: HashMap<u64, f64>,
+}
+
+impl ClassesWeightedEditCosts {
+    pub fn uniform() -> Self {
+        Self { weights: HashMap::new() }
+    }
+
+    //Rare classes are more important: the weight of a class is 1 + ln(events_count / class_events_count)
+    pub fn from_frequencies(sequences: &[Vec<u64>]) -> Self {
+        let mut counts: HashMap<u64, usize> = HashMap::new();
+        let mut events_count = 0;
+        for sequence in sequences {
+            for class in sequence {
+                *counts.entry(*class).or_default() += 1;
+                events_count += 1;
+            }
+        }
+
+        let weights = counts
+            .into_iter()
+            .map(|(class, count)| (class, 1.0 + (events_count as f64 / count as f64).ln()))
+            .collect();
+
+        Self { weights }
+    }
+
+    pub fn weight(&self, class: &u64) -> f64 {
+        match self.weights.get(class) {
+            Some(weight) => *weight,
+            None => 1.0,
+        }
+    }
+}
+
+impl EditCosts<u64> for ClassesWeightedEditCosts {
+    fn insertion(&self, item: &u64) -> f64 {
+        self.weight(item)
+    }
+
+    fn deletion(&self, item: &u64) -> f64 {
+        self.weight(item)
+    }
+
+    fn substitution(&self, first: &u64, second: &u64) -> f64 {
+        if first == second {
+            0.0
+        } else {
+            (self.weight(first) + self.weight(second)) / 2.0
+        }
+    }
+}
+
+pub fn edit_distance<T>(first: &[T], second: &[T], costs: &impl EditCosts<T>) -> f64 {
+    let mut matrix = vec![vec![0.0; second.len() + 1]; first.len() + 1];
+    for i in 1..=first.len() {
+        matrix[i][0] = matrix[i - 1][0] + costs.deletion(&first[i - 1]);
+    }
+
+    for j in 1..=second.len() {
+        matrix[0][j] = matrix[0][j - 1] + costs.insertion(&second[j - 1]);
+    }
+
+    for i in 1..=first.len() {
+        for j in 1..=second.len() {
+            let deletion = matrix[i - 1][j] + costs.deletion(&first[i - 1]);
+            let insertion = matrix[i][j - 1] + costs.insertion(&second[j - 1]);
+            let substitution = matrix[i - 1][j - 1] + costs.substitution(&first[i - 1], &second[j - 1]);
+
+            let mut value = deletion.min(insertion).min(substitution);
+            if i > 1 && j > 1 {
+                if let Some(cost) = costs.transposition(&first[i - 1], &first[i - 2]) {
+                    let is_swap = costs.substitution(&first[i - 1], &second[j - 2]) == 0.0
+                        && costs.substitution(&first[i - 2], &second[j - 1]) == 0.0;
+
+                    if is_swap {
+                        value = value.min(matrix[i - 2][j - 2] + cost);
+                    }
+                }
+            }
+
+            matrix[i][j] = value;
+        }
+    }
+
+    matrix[first.len()][second.len()]
+}
+
+//Dynamic time warping, if one of sequences is empty the distance is the sum of costs of aligning
+//items of the other sequence with nothing (which is the length of the other sequence for the discrete cost)
+pub fn dtw_distance<T>(first: &[T], second: &[T], cost: impl Fn(&T, &T) -> f64) -> f64 {
+    if first.is_empty() || second.is_empty() {
+        return first.len().max(second.len()) as f64;
+    }
+
+    let mut matrix = vec![vec![f64::INFINITY; second.len() + 1]; first.len() + 1];
+    matrix[0][0] = 0.0;
+
+    for i in 1..=first.len() {
+        for j in 1..=second.len() {
+            let best_previous = matrix[i - 1][j].min(matrix[i][j - 1]).min(matrix[i - 1][j - 1]);
+            matrix[i][j] = cost(&first[i - 1], &second[j - 1]) + best_previous;
+        }
+    }
+
+    matrix[first.len()][second.len()]
+}
+
+pub fn discrete_cost<T: PartialEq>(first: &T, second: &T) -> f64 {
+    if first == second {
+        0.0
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Write;
+
+pub type DotAttributes = Vec<(&'static str, String)>;
+
+const INDENT: &'static str = "  ";
+
+pub struct DotWriter {
+    content: String,
+}
+
+impl DotWriter {
+    pub fn new(graph_name: &str, graph_attributes: &DotAttributes) -> Self {
+        let mut content = format!("digraph {} {{\n", escape_dot_string(graph_name));
+        for (name, value) in graph_attributes {
+            let _ = writeln!(content, "{}{}={};", INDENT, name, escape_dot_string(value));
+        }
+
+        Self { content }
+    }
+
+    pub fn write_node(&mut self, id: &str, attributes: &DotAttributes) {
+        let _ = writeln!(
+            self.content,
+            "{}{}{};",
+            INDENT,
+            escape_dot_string(id),
+            create_attributes_list(attributes)
+        );
+    }
+
+    pub fn write_edge(&mut self, from: &str, to: &str, attributes: &DotAttributes) {
+        let _ = writeln!(
+            self.content,
+            "{}{} -> {}{};",
+            INDENT,
+            escape_dot_string(from),
+            escape_dot_string(to),
+            create_attributes_list(attributes)
+        );
+    }
+
+    pub fn finish(mut self) -> String {
+        self.content.push_str("}\n");
+        self.content
+    }
+}
+
+fn create_attributes_list(attributes: &DotAttributes) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+
+    let attributes: Vec<String> = attributes
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, escape_dot_string(value)))
+        .collect();
+
+    format!(" [{}]", attributes.join(", "))
+}
+
+//All identifiers and values are written as quoted DOT strings
+pub fn escape_dot_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            _ => result.push(char),
+        }
+    }
+
+    result.push('"');
+    result
+}
@@ -0,0 +1,442 @@
+use crate::utils::graph::graph::Graph;
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+
+pub struct GraphPaths {
+    source: u64,
+    distances: HashMap<u64, f64>,
+    predecessors: HashMap<u64, u64>,
+}
+
+impl GraphPaths {
+    pub fn source(&self) -> u64 {
+        self.source
+    }
+
+    pub fn distance(&self, node: &u64) -> Option<f64> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<u64, f64> {
+        &self.distances
+    }
+
+    //Nodes of the path from the source to the node (both included), None if the node is not reachable
+    pub fn path_to(&self, node: &u64) -> Option<Vec<u64>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![*node];
+        let mut current = *node;
+        while let Some(predecessor) = self.predecessors.get(&current) {
+            path.push(*predecessor);
+            current = *predecessor;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+#[derive(PartialEq)]
+struct DistanceEntry {
+    distance: f64,
+    node: u64,
+}
+
+impl Eq for DistanceEntry {}
+
+impl PartialOrd for DistanceEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DistanceEntry {
+    //Reversed to turn the max-heap into the min-heap
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.total_cmp(&self.distance).then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl<TNodeData, TEdgeData> Graph<TNodeData, TEdgeData>
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    fn sorted_nodes_ids(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self.nodes.keys().copied().collect();
+        ids.sort();
+
+        ids
+    }
+
+    fn sorted_successors(&self, node: &u64) -> Vec<u64> {
+        let mut successors: Vec<u64> = self.outgoing_nodes(node).into_iter().copied().collect();
+        successors.sort();
+
+        successors
+    }
+
+    fn predecessors_map(&self) -> HashMap<u64, Vec<u64>> {
+        let mut predecessors: HashMap<u64, Vec<u64>> = self.nodes.keys().map(|id| (*id, vec![])).collect();
+        for (from, connections) in &self.connections {
+            for to in connections.keys() {
+                if let Some(node_predecessors) = predecessors.get_mut(to) {
+                    node_predecessors.push(*from);
+                }
+            }
+        }
+
+        for node_predecessors in predecessors.values_mut() {
+            node_predecessors.sort();
+        }
+
+        predecessors
+    }
+
+    //Nodes in the order of DFS completion, successors are visited in the ascending order of ids
+    fn dfs_postorder(&self, roots: &[u64]) -> Vec<u64> {
+        let mut visited = HashSet::new();
+        let mut order = vec![];
+
+        for root in roots {
+            if !visited.insert(*root) {
+                continue;
+            }
+
+            let mut stack = vec![(*root, self.sorted_successors(root), 0usize)];
+            while let Some((node, successors, next_index)) = stack.pop() {
+                if next_index == successors.len() {
+                    order.push(node);
+                    continue;
+                }
+
+                let next = successors[next_index];
+                stack.push((node, successors, next_index + 1));
+
+                if visited.insert(next) {
+                    stack.push((next, self.sorted_successors(&next), 0));
+                }
+            }
+        }
+
+        order
+    }
+
+    //Kosaraju algorithm, nodes inside a component are sorted, components are sorted by their smallest node
+    pub fn strongly_connected_components(&self) -> Vec<Vec<u64>> {
+        let order = self.dfs_postorder(&self.sorted_nodes_ids());
+        let predecessors = self.predecessors_map();
+
+        let mut assigned = HashSet::new();
+        let mut components = vec![];
+
+        for root in order.iter().rev() {
+            if !assigned.insert(*root) {
+                continue;
+            }
+
+            let mut component = vec![*root];
+            let mut stack = vec![*root];
+            while let Some(node) = stack.pop() {
+                for predecessor in predecessors.get(&node).unwrap() {
+                    if assigned.insert(*predecessor) {
+                        component.push(*predecessor);
+                        stack.push(*predecessor);
+                    }
+                }
+            }
+
+            component.sort();
+            components.push(component);
+        }
+
+        components.sort();
+        components
+    }
+
+    //Nodes which lie on at least one cycle, including self-loops
+    pub fn nodes_in_cycles(&self) -> HashSet<u64> {
+        let mut result = HashSet::new();
+        for component in self.strongly_connected_components() {
+            if component.len() > 1 {
+                result.extend(component);
+            } else if self.are_nodes_connected(&component[0], &component[0]) {
+                result.insert(component[0]);
+            }
+        }
+
+        result
+    }
+
+    pub fn has_cycles(&self) -> bool {
+        self.topological_order().is_none()
+    }
+
+    //Kahn algorithm with the smallest available id taken first, None if the graph contains cycles
+    pub fn topological_order(&self) -> Option<Vec<u64>> {
+        let predecessors = self.predecessors_map();
+        let mut in_degrees: HashMap<u64, usize> = predecessors
+            .iter()
+            .map(|(node, predecessors)| (*node, predecessors.len()))
+            .collect();
+
+        let mut queue: BinaryHeap<Reverse<u64>> = in_degrees
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(node, _)| Reverse(*node))
+            .collect();
+
+        let mut order = vec![];
+        while let Some(Reverse(node)) = queue.pop() {
+            order.push(node);
+            for successor in self.sorted_successors(&node) {
+                let degree = in_degrees.get_mut(&successor).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push(Reverse(successor));
+                }
+            }
+        }
+
+        match order.len() == self.nodes.len() {
+            true => Some(order),
+            false => None,
+        }
+    }
+
+    //Nodes which can be reached from the source by following edges, the source itself is included
+    pub fn reachable_nodes(&self, source: &u64) -> HashSet<u64> {
+        let mut reachable = HashSet::new();
+        if !self.nodes.contains_key(source) {
+            return reachable;
+        }
+
+        reachable.insert(*source);
+        let mut stack = vec![*source];
+        while let Some(node) = stack.pop() {
+            for successor in self.outgoing_nodes(&node) {
+                if reachable.insert(*successor) {
+                    stack.push(*successor);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    //Dijkstra algorithm, weights of edges must be non-negative
+    pub fn shortest_paths(&self, source: &u64, edge_weight: impl Fn(Option<&TEdgeData>) -> f64) -> GraphPaths {
+        let mut paths = GraphPaths {
+            source: *source,
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        };
+
+        if !self.nodes.contains_key(source) {
+            return paths;
+        }
+
+        let mut finished = HashSet::new();
+        let mut heap = BinaryHeap::new();
+        paths.distances.insert(*source, 0.0);
+        heap.push(DistanceEntry {
+            distance: 0.0,
+            node: *source,
+        });
+
+        while let Some(DistanceEntry { distance, node }) = heap.pop() {
+            if !finished.insert(node) {
+                continue;
+            }
+
+            if let Some(connections) = self.connections.get(&node) {
+                for (successor, data) in connections {
+                    let new_distance = distance + edge_weight(data.as_ref());
+                    let is_better = match paths.distances.get(successor) {
+                        None => true,
+                        Some(current) => new_distance < *current,
+                    };
+
+                    if is_better && !finished.contains(successor) {
+                        paths.distances.insert(*successor, new_distance);
+                        paths.predecessors.insert(*successor, node);
+                        heap.push(DistanceEntry {
+                            distance: new_distance,
+                            node: *successor,
+                        });
+                    }
+                }
+            }
+        }
+
+        paths
+    }
+
+    //Dynamic programming over the topological order, None if the graph contains cycles
+    pub fn longest_paths(&self, source: &u64, edge_weight: impl Fn(Option<&TEdgeData>) -> f64) -> Option<GraphPaths> {
+        let order = self.topological_order()?;
+        let mut paths = GraphPaths {
+            source: *source,
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        };
+
+        if !self.nodes.contains_key(source) {
+            return Some(paths);
+        }
+
+        paths.distances.insert(*source, 0.0);
+        for node in order {
+            let distance = match paths.distances.get(&node) {
+                Some(distance) => *distance,
+                None => continue,
+            };
+
+            for successor in self.sorted_successors(&node) {
+                let data = self.connections.get(&node).unwrap().get(&successor).unwrap();
+                let new_distance = distance + edge_weight(data.as_ref());
+                let is_better = match paths.distances.get(&successor) {
+                    None => true,
+                    Some(current) => new_distance > *current,
+                };
+
+                if is_better {
+                    paths.distances.insert(successor, new_distance);
+                    paths.predecessors.insert(successor, node);
+                }
+            }
+        }
+
+        Some(paths)
+    }
+
+    //Cooper, Harvey and Kennedy algorithm, the root dominates itself
+    pub fn immediate_dominators(&self, root: &u64) -> HashMap<u64, u64> {
+        let mut dominators = HashMap::new();
+        if !self.nodes.contains_key(root) {
+            return dominators;
+        }
+
+        let postorder = self.dfs_postorder(&[*root]);
+        let postorder_indices: HashMap<u64, usize> = postorder.iter().enumerate().map(|(index, node)| (*node, index)).collect();
+        let predecessors = self.predecessors_map();
+
+        dominators.insert(*root, *root);
+
+        let intersect = |dominators: &HashMap<u64, u64>, mut first: u64, mut second: u64| {
+            while first != second {
+                while postorder_indices[&first] < postorder_indices[&second] {
+                    first = dominators[&first];
+                }
+
+                while postorder_indices[&second] < postorder_indices[&first] {
+                    second = dominators[&second];
+                }
+            }
+
+            first
+        };
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for node in postorder.iter().rev().skip(1) {
+                let mut new_dominator: Option<u64> = None;
+                for predecessor in predecessors.get(node).unwrap() {
+                    if !dominators.contains_key(predecessor) {
+                        continue;
+                    }
+
+                    new_dominator = Some(match new_dominator {
+                        None => *predecessor,
+                        Some(current) => intersect(&dominators, *predecessor, current),
+                    });
+                }
+
+                if let Some(new_dominator) = new_dominator {
+                    if dominators.get(node) != Some(&new_dominator) {
+                        dominators.insert(*node, new_dominator);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        dominators
+    }
+
+    //Brandes algorithm on unweighted shortest paths, values are not normalized
+    pub fn nodes_betweenness(&self) -> HashMap<u64, f64> {
+        self.calculate_betweenness().0
+    }
+
+    pub fn edges_betweenness(&self) -> HashMap<(u64, u64), f64> {
+        self.calculate_betweenness().1
+    }
+
+    fn calculate_betweenness(&self) -> (HashMap<u64, f64>, HashMap<(u64, u64), f64>) {
+        let mut nodes_betweenness: HashMap<u64, f64> = self.nodes.keys().map(|id| (*id, 0.0)).collect();
+        let mut edges_betweenness: HashMap<(u64, u64), f64> = HashMap::new();
+        for (from, connections) in &self.connections {
+            for to in connections.keys() {
+                edges_betweenness.insert((*from, *to), 0.0);
+            }
+        }
+
+        for source in self.sorted_nodes_ids() {
+            let mut stack = vec![];
+            let mut predecessors: HashMap<u64, Vec<u64>> = HashMap::new();
+            let mut paths_counts: HashMap<u64, f64> = HashMap::from_iter(vec![(source, 1.0)]);
+            let mut distances: HashMap<u64, usize> = HashMap::from_iter(vec![(source, 0)]);
+
+            let mut queue = VecDeque::from(vec![source]);
+            while let Some(node) = queue.pop_front() {
+                stack.push(node);
+                let distance = distances[&node];
+                let node_paths_count = paths_counts[&node];
+                for successor in self.sorted_successors(&node) {
+                    if let Entry::Vacant(entry) = distances.entry(successor) {
+                        entry.insert(distance + 1);
+                        queue.push_back(successor);
+                    }
+
+                    if distances[&successor] == distance + 1 {
+                        *paths_counts.entry(successor).or_default() += node_paths_count;
+                        predecessors.entry(successor).or_default().push(node);
+                    }
+                }
+            }
+
+            let mut dependencies: HashMap<u64, f64> = HashMap::new();
+            while let Some(node) = stack.pop() {
+                let node_dependency = *dependencies.get(&node).unwrap_or(&0.0);
+                if let Some(node_predecessors) = predecessors.get(&node) {
+                    for predecessor in node_predecessors {
+                        let contribution = paths_counts[predecessor] / paths_counts[&node] * (1.0 + node_dependency);
+                        *edges_betweenness.get_mut(&(*predecessor, node)).unwrap() += contribution;
+                        *dependencies.entry(*predecessor).or_default() += contribution;
+                    }
+                }
+
+                if node != source {
+                    *nodes_betweenness.get_mut(&node).unwrap() += node_dependency;
+                }
+            }
+        }
+
+        (nodes_betweenness, edges_betweenness)
+    }
+}
+
+//Edges without parsable counts in their data have the unit weight
+pub fn parse_edge_weight<TEdgeData: ToString>(data: Option<&TEdgeData>) -> f64 {
+    match data {
+        Some(data) => data.to_string().parse::<f64>().unwrap_or(1.0),
+        None => 1.0,
+    }
+}
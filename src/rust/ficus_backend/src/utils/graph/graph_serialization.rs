@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use rustc_serialize::json::{self, Json};
+
+use crate::utils::dot_utils::{DotAttributes, DotWriter};
+use crate::utils::graph::graph::Graph;
+use crate::utils::graph::graph_edge::GraphEdge;
+use crate::utils::graph::graph_node::GraphNode;
+
+const LABEL: &'static str = "label";
+
+const DIRECTED: &'static str = "directed";
+const NODES: &'static str = "nodes";
+const LINKS: &'static str = "links";
+const ID: &'static str = "id";
+const DATA: &'static str = "data";
+const SOURCE: &'static str = "source";
+const TARGET: &'static str = "target";
+
+pub fn serialize_graph_to_dot<TNodeData, TEdgeData>(graph: &Graph<TNodeData, TEdgeData>) -> String
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    serialize_graph_to_dot_with_nodes_attributes(graph, |_| vec![])
+}
+
+//Nodes are labeled with their data and edges with their data (weights), additional attributes of nodes
+//(e.g. shapes or custom labels) are supplied by the caller
+pub fn serialize_graph_to_dot_with_nodes_attributes<TNodeData, TEdgeData>(
+    graph: &Graph<TNodeData, TEdgeData>,
+    nodes_attributes: impl Fn(&GraphNode<TNodeData>) -> DotAttributes,
+) -> String
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    let mut writer = DotWriter::new("graph", &vec![("rankdir", "LR".to_string())]);
+
+    for node in ordered_nodes(graph) {
+        let mut attributes = nodes_attributes(node);
+        if !attributes.iter().any(|(name, _)| *name == LABEL) {
+            attributes.insert(0, (LABEL, data_to_string(node.data())));
+        }
+
+        writer.write_node(node.id().to_string().as_str(), &attributes);
+    }
+
+    for edge in ordered_edges(graph) {
+        let attributes = match edge.data() {
+            Some(data) => vec![(LABEL, data.to_string())],
+            None => vec![],
+        };
+
+        writer.write_edge(
+            edge.from_node().to_string().as_str(),
+            edge.to_node().to_string().as_str(),
+            &attributes,
+        );
+    }
+
+    writer.finish()
+}
+
+//Node-link format (the one used by networkx): {"directed": true, "nodes": [{"id", "data"}], "links": [{"source", "target", "data"}]}
+pub fn serialize_graph_to_json<TNodeData, TEdgeData>(graph: &Graph<TNodeData, TEdgeData>) -> String
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    let nodes = ordered_nodes(graph)
+        .iter()
+        .map(|node| {
+            let mut object = BTreeMap::new();
+            object.insert(ID.to_owned(), Json::U64(*node.id()));
+            object.insert(DATA.to_owned(), optional_data_to_json(node.data()));
+
+            Json::Object(object)
+        })
+        .collect();
+
+    let links = ordered_edges(graph)
+        .iter()
+        .map(|edge| {
+            let mut object = BTreeMap::new();
+            object.insert(SOURCE.to_owned(), Json::U64(*edge.from_node()));
+            object.insert(TARGET.to_owned(), Json::U64(*edge.to_node()));
+            object.insert(DATA.to_owned(), optional_data_to_json(edge.data().map(|data| *data)));
+
+            Json::Object(object)
+        })
+        .collect();
+
+    let mut root = BTreeMap::new();
+    root.insert(DIRECTED.to_owned(), Json::Boolean(true));
+    root.insert(NODES.to_owned(), Json::Array(nodes));
+    root.insert(LINKS.to_owned(), Json::Array(links));
+
+    json::as_pretty_json(&Json::Object(root)).to_string()
+}
+
+fn ordered_nodes<TNodeData, TEdgeData>(graph: &Graph<TNodeData, TEdgeData>) -> Vec<&GraphNode<TNodeData>>
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    let mut nodes = graph.all_nodes();
+    nodes.sort_by(|first, second| first.id().cmp(second.id()));
+
+    nodes
+}
+
+fn ordered_edges<TNodeData, TEdgeData>(graph: &Graph<TNodeData, TEdgeData>) -> Vec<GraphEdge<&TEdgeData>>
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    let mut edges = graph.all_edges();
+    edges.sort_by(|first, second| (first.from_node(), first.to_node()).cmp(&(second.from_node(), second.to_node())));
+
+    edges
+}
+
+fn data_to_string<TData: ToString>(data: Option<&TData>) -> String {
+    match data {
+        Some(data) => data.to_string(),
+        None => String::new(),
+    }
+}
+
+fn optional_data_to_json<TData: ToString>(data: Option<&TData>) -> Json {
+    match data {
+        Some(data) => Json::String(data.to_string()),
+        None => Json::Null,
+    }
+}
@@ -0,0 +1,475 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+
+use crate::utils::graph::graph::Graph;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+//Coordinates of the top-left corner and the size of the node
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutNode {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl LayoutNode {
+    pub fn center(&self) -> LayoutPoint {
+        LayoutPoint {
+            x: self.x + self.width / 2.0,
+            y: self.y + self.height / 2.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LayoutNodeSize {
+    pub id: u64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LayeredLayoutParams {
+    pub layers_distance: f64,
+    pub nodes_distance: f64,
+    pub crossing_minimization_iterations: usize,
+    pub coordinates_iterations: usize,
+}
+
+impl Default for LayeredLayoutParams {
+    fn default() -> Self {
+        Self {
+            layers_distance: 80.0,
+            nodes_distance: 40.0,
+            crossing_minimization_iterations: 12,
+            coordinates_iterations: 8,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GraphLayout {
+    nodes: HashMap<u64, LayoutNode>,
+    layers: HashMap<u64, usize>,
+    bend_points: HashMap<(u64, u64), Vec<LayoutPoint>>,
+}
+
+impl GraphLayout {
+    pub fn node(&self, id: &u64) -> Option<&LayoutNode> {
+        self.nodes.get(id)
+    }
+
+    pub fn layer(&self, id: &u64) -> Option<usize> {
+        self.layers.get(id).copied()
+    }
+
+    pub fn bend_points(&self, from: &u64, to: &u64) -> Option<&Vec<LayoutPoint>> {
+        self.bend_points.get(&(*from, *to))
+    }
+}
+
+pub const DEFAULT_GRAPH_NODE_WIDTH: f64 = 100.0;
+pub const DEFAULT_GRAPH_NODE_HEIGHT: f64 = 40.0;
+
+pub fn calculate_graph_layout<TNodeData, TEdgeData>(graph: &Graph<TNodeData, TEdgeData>, params: &LayeredLayoutParams) -> GraphLayout
+where
+    TNodeData: ToString,
+    TEdgeData: ToString + Display,
+{
+    let nodes: Vec<LayoutNodeSize> = graph
+        .all_nodes()
+        .iter()
+        .map(|node| LayoutNodeSize {
+            id: *node.id(),
+            width: DEFAULT_GRAPH_NODE_WIDTH,
+            height: DEFAULT_GRAPH_NODE_HEIGHT,
+        })
+        .collect();
+
+    let edges: Vec<(u64, u64)> = graph.all_edges().iter().map(|edge| (*edge.from_node(), *edge.to_node())).collect();
+
+    calculate_layered_layout(&nodes, &edges, params)
+}
+
+//Sugiyama-style layout from left to right, dummy nodes of long edges become bend points
+pub fn calculate_layered_layout(nodes: &[LayoutNodeSize], edges: &[(u64, u64)], params: &LayeredLayoutParams) -> GraphLayout {
+    let mut nodes = nodes.to_vec();
+    nodes.sort_by(|first, second| first.id.cmp(&second.id));
+
+    let indices: HashMap<u64, usize> = nodes.iter().enumerate().map(|(index, node)| (node.id, index)).collect();
+
+    let mut unique_edges = HashSet::new();
+    let mut self_loops = vec![];
+    let mut indexed_edges = vec![];
+    for (from, to) in edges {
+        if !unique_edges.insert((*from, *to)) {
+            continue;
+        }
+
+        if from == to {
+            self_loops.push((*from, *to));
+            continue;
+        }
+
+        if let (Some(from_index), Some(to_index)) = (indices.get(from), indices.get(to)) {
+            indexed_edges.push((*from_index, *to_index));
+        }
+    }
+
+    indexed_edges.sort();
+
+    let dag_edges = remove_cycles(nodes.len(), &indexed_edges);
+    let layers_indices = assign_layers(nodes.len(), &dag_edges);
+
+    let mut graph = LayeredGraph::new(&nodes, &layers_indices);
+    let chains: Vec<Vec<usize>> = dag_edges.iter().map(|(from, to, _)| graph.add_chain(*from, *to)).collect();
+
+    graph.minimize_crossings(params.crossing_minimization_iterations);
+    let centers = graph.assign_coordinates(params);
+
+    let mut layout = GraphLayout {
+        nodes: HashMap::new(),
+        layers: HashMap::new(),
+        bend_points: HashMap::new(),
+    };
+
+    for (index, node) in nodes.iter().enumerate() {
+        let center = centers[index];
+        layout.nodes.insert(
+            node.id,
+            LayoutNode {
+                x: center.x - node.width / 2.0,
+                y: center.y - node.height / 2.0,
+                width: node.width,
+                height: node.height,
+            },
+        );
+
+        layout.layers.insert(node.id, layers_indices[index]);
+    }
+
+    for ((from, to, reversed), chain) in dag_edges.iter().zip(chains.iter()) {
+        let mut bend_points: Vec<LayoutPoint> = chain[1..(chain.len() - 1)].iter().map(|dummy| centers[*dummy]).collect();
+        let (from, to) = match reversed {
+            true => {
+                bend_points.reverse();
+                (nodes[*to].id, nodes[*from].id)
+            }
+            false => (nodes[*from].id, nodes[*to].id),
+        };
+
+        layout.bend_points.insert((from, to), bend_points);
+    }
+
+    for self_loop in self_loops {
+        layout.bend_points.insert(self_loop, vec![]);
+    }
+
+    layout
+}
+
+//Returns edges of the acyclic graph, the flag indicates whether the edge was reversed
+fn remove_cycles(nodes_count: usize, edges: &[(usize, usize)]) -> Vec<(usize, usize, bool)> {
+    let mut outgoing = vec![vec![]; nodes_count];
+    for (from, to) in edges {
+        outgoing[*from].push(*to);
+    }
+
+    const NOT_VISITED: u8 = 0;
+    const ON_STACK: u8 = 1;
+    const VISITED: u8 = 2;
+
+    let mut states = vec![NOT_VISITED; nodes_count];
+    let mut back_edges = HashSet::new();
+
+    for root in 0..nodes_count {
+        if states[root] != NOT_VISITED {
+            continue;
+        }
+
+        states[root] = ON_STACK;
+        let mut stack = vec![(root, 0usize)];
+
+        while let Some((node, next_edge_index)) = stack.pop() {
+            if next_edge_index == outgoing[node].len() {
+                states[node] = VISITED;
+                continue;
+            }
+
+            stack.push((node, next_edge_index + 1));
+
+            let next = outgoing[node][next_edge_index];
+            match states[next] {
+                NOT_VISITED => {
+                    states[next] = ON_STACK;
+                    stack.push((next, 0));
+                }
+                ON_STACK => {
+                    back_edges.insert((node, next));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    edges
+        .iter()
+        .map(|(from, to)| match back_edges.contains(&(*from, *to)) {
+            true => (*to, *from, true),
+            false => (*from, *to, false),
+        })
+        .collect()
+}
+
+//Longest path layering: sources are in the first layer, each node is placed right after its furthest predecessor
+fn assign_layers(nodes_count: usize, edges: &[(usize, usize, bool)]) -> Vec<usize> {
+    let mut outgoing = vec![vec![]; nodes_count];
+    let mut in_degrees = vec![0; nodes_count];
+    for (from, to, _) in edges {
+        outgoing[*from].push(*to);
+        in_degrees[*to] += 1;
+    }
+
+    let mut layers = vec![0; nodes_count];
+    let mut queue: VecDeque<usize> = (0..nodes_count).filter(|node| in_degrees[*node] == 0).collect();
+
+    while let Some(node) = queue.pop_front() {
+        for next in &outgoing[node] {
+            layers[*next] = layers[*next].max(layers[node] + 1);
+            in_degrees[*next] -= 1;
+            if in_degrees[*next] == 0 {
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    layers
+}
+
+//Proper layered graph (edges only connect adjacent layers) with real nodes followed by dummy nodes
+struct LayeredGraph {
+    sizes: Vec<(f64, f64)>,
+    node_layers: Vec<usize>,
+    layers: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    successors: Vec<Vec<usize>>,
+}
+
+impl LayeredGraph {
+    fn new(nodes: &[LayoutNodeSize], node_layers: &[usize]) -> Self {
+        let layers_count = node_layers.iter().max().map_or(0, |max_layer| max_layer + 1);
+        let mut layers = vec![vec![]; layers_count];
+        for (index, layer) in node_layers.iter().enumerate() {
+            layers[*layer].push(index);
+        }
+
+        Self {
+            sizes: nodes.iter().map(|node| (node.width, node.height)).collect(),
+            node_layers: node_layers.to_vec(),
+            layers,
+            predecessors: vec![vec![]; nodes.len()],
+            successors: vec![vec![]; nodes.len()],
+        }
+    }
+
+    //Connects nodes through dummy nodes in intermediate layers, returns the whole path
+    fn add_chain(&mut self, from: usize, to: usize) -> Vec<usize> {
+        let mut chain = vec![from];
+        for layer in (self.node_layers[from] + 1)..self.node_layers[to] {
+            let dummy = self.sizes.len();
+            self.sizes.push((0.0, 0.0));
+            self.node_layers.push(layer);
+            self.layers[layer].push(dummy);
+            self.predecessors.push(vec![]);
+            self.successors.push(vec![]);
+
+            chain.push(dummy);
+        }
+
+        chain.push(to);
+
+        for (first, second) in chain.iter().zip(chain.iter().skip(1)) {
+            self.successors[*first].push(*second);
+            self.predecessors[*second].push(*first);
+        }
+
+        chain
+    }
+
+    fn positions(&self) -> Vec<usize> {
+        let mut positions = vec![0; self.sizes.len()];
+        for layer in &self.layers {
+            for (position, node) in layer.iter().enumerate() {
+                positions[*node] = position;
+            }
+        }
+
+        positions
+    }
+
+    //Barycenter heuristic with alternating sweeps, the ordering with the least crossings is kept
+    fn minimize_crossings(&mut self, iterations: usize) {
+        let mut best_layers = self.layers.clone();
+        let mut best_crossings = self.count_crossings();
+
+        for iteration in 0..iterations {
+            let downward = iteration % 2 == 0;
+            let layers_order: Vec<usize> = match downward {
+                true => (1..self.layers.len()).collect(),
+                false => (0..self.layers.len().saturating_sub(1)).rev().collect(),
+            };
+
+            for layer in layers_order {
+                let positions = self.positions();
+                let mut keys: Vec<(usize, f64)> = self.layers[layer]
+                    .iter()
+                    .map(|node| {
+                        let neighbours = match downward {
+                            true => &self.predecessors[*node],
+                            false => &self.successors[*node],
+                        };
+
+                        let key = match neighbours.is_empty() {
+                            true => positions[*node] as f64,
+                            false => neighbours.iter().map(|neighbour| positions[*neighbour] as f64).sum::<f64>() / neighbours.len() as f64,
+                        };
+
+                        (*node, key)
+                    })
+                    .collect();
+
+                keys.sort_by(|first, second| first.1.total_cmp(&second.1));
+                self.layers[layer] = keys.into_iter().map(|(node, _)| node).collect();
+            }
+
+            let crossings = self.count_crossings();
+            if crossings < best_crossings {
+                best_crossings = crossings;
+                best_layers = self.layers.clone();
+            }
+        }
+
+        self.layers = best_layers;
+    }
+
+    fn count_crossings(&self) -> usize {
+        let positions = self.positions();
+        let mut crossings = 0;
+
+        for layer in &self.layers {
+            let mut edges = vec![];
+            for node in layer {
+                for successor in &self.successors[*node] {
+                    edges.push((positions[*node], positions[*successor]));
+                }
+            }
+
+            for (index, first) in edges.iter().enumerate() {
+                for second in edges.iter().skip(index + 1) {
+                    if (first.0 < second.0 && first.1 > second.1) || (first.0 > second.0 && first.1 < second.1) {
+                        crossings += 1;
+                    }
+                }
+            }
+        }
+
+        crossings
+    }
+
+    //Nodes keep their order in a layer and are moved towards the average position of their neighbours
+    fn assign_coordinates(&self, params: &LayeredLayoutParams) -> Vec<LayoutPoint> {
+        let mut xs = vec![0.0; self.sizes.len()];
+        let mut layer_left = params.layers_distance / 2.0;
+        for layer in &self.layers {
+            let layer_width = layer.iter().map(|node| self.sizes[*node].0).fold(0.0, f64::max);
+            for node in layer {
+                xs[*node] = layer_left + layer_width / 2.0;
+            }
+
+            layer_left += layer_width + params.layers_distance;
+        }
+
+        let mut ys = vec![0.0; self.sizes.len()];
+        for layer in &self.layers {
+            let desired = vec![0.0; layer.len()];
+            self.place_layer(layer, &desired, &mut ys, params);
+        }
+
+        for iteration in 0..params.coordinates_iterations {
+            let downward = iteration % 2 == 0;
+            let layers_order: Vec<usize> = match downward {
+                true => (1..self.layers.len()).collect(),
+                false => (0..self.layers.len().saturating_sub(1)).rev().collect(),
+            };
+
+            for layer_index in layers_order {
+                let layer = &self.layers[layer_index];
+                let desired: Vec<f64> = layer
+                    .iter()
+                    .map(|node| {
+                        let neighbours = match downward {
+                            true => &self.predecessors[*node],
+                            false => &self.successors[*node],
+                        };
+
+                        match neighbours.is_empty() {
+                            true => ys[*node],
+                            false => neighbours.iter().map(|neighbour| ys[*neighbour]).sum::<f64>() / neighbours.len() as f64,
+                        }
+                    })
+                    .collect();
+
+                self.place_layer(layer, &desired, &mut ys, params);
+            }
+        }
+
+        let min_top = (0..self.sizes.len())
+            .map(|node| ys[node] - self.sizes[node].1 / 2.0)
+            .fold(f64::INFINITY, f64::min);
+        let offset = match min_top.is_finite() {
+            true => params.nodes_distance / 2.0 - min_top,
+            false => 0.0,
+        };
+
+        xs.iter()
+            .zip(ys.iter())
+            .map(|(x, y)| LayoutPoint { x: *x, y: *y + offset })
+            .collect()
+    }
+
+    //Nodes are placed close to desired centers keeping their order and distances
+    fn place_layer(&self, layer: &[usize], desired: &[f64], ys: &mut [f64], params: &LayeredLayoutParams) {
+        let mut previous: Option<usize> = None;
+        for (node, desired_y) in layer.iter().zip(desired.iter()) {
+            ys[*node] = match previous {
+                None => *desired_y,
+                Some(previous) => {
+                    let min_y = ys[previous] + (self.sizes[previous].1 + self.sizes[*node].1) / 2.0 + params.nodes_distance;
+                    desired_y.max(min_y)
+                }
+            };
+
+            previous = Some(*node);
+        }
+
+        if layer.is_empty() {
+            return;
+        }
+
+        let shift = layer
+            .iter()
+            .zip(desired.iter())
+            .map(|(node, desired_y)| desired_y - ys[*node])
+            .sum::<f64>()
+            / layer.len() as f64;
+        for node in layer {
+            ys[*node] += shift;
+        }
+    }
+}
@@ -1,5 +1,8 @@
 pub mod graph;
+pub mod graph_algorithms;
 pub mod graph_clusters;
 pub mod graph_conversions;
 pub mod graph_edge;
 pub mod graph_node;
+pub mod graph_serialization;
+pub mod layered_layout;
@@ -1,6 +1,7 @@
 pub mod colors;
 pub mod dataset;
 pub mod distance;
+pub mod dot_utils;
 pub mod graph;
 pub mod hash_map_utils;
 pub mod hash_utils;
@@ -1,4 +1,5 @@
 mod node;
+pub mod suffix_array;
 mod suffix_tree_build;
 pub mod suffix_tree_patterns;
 mod suffix_tree_repeats;
@@ -0,0 +1,454 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    ops::Range,
+};
+
+use super::suffix_tree_slice::SuffixTreeSlice;
+
+const EMPTY: usize = usize::MAX;
+const SENTINEL: usize = 0;
+const SEPARATOR: usize = 1;
+
+enum RepeatType {
+    MaximalRepeat,
+    SuperMaximalRepeat,
+    NearSuperMaximalRepeat,
+}
+
+pub struct SuffixArray<'a, TElement>
+where
+    TElement: Eq + Hash + Copy,
+{
+    slice: &'a dyn SuffixTreeSlice<TElement>,
+    elements_ids: HashMap<TElement, usize>,
+    text: Vec<usize>,
+    suffix_array: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+//Left elements of all children are stored in one buffer, each child owns the sorted range without duplicates,
+//the ranges of the children of open intervals follow each other in the same order as the intervals in the stack
+struct IntervalChild {
+    left_elements: Range<usize>,
+    is_leaf: bool,
+    min_position: usize,
+}
+
+struct OpenInterval {
+    lcp: usize,
+    children: Vec<IntervalChild>,
+}
+
+impl<'a, TElement> SuffixArray<'a, TElement>
+where
+    TElement: Eq + Hash + Copy,
+{
+    pub fn new(slice: &'a dyn SuffixTreeSlice<TElement>) -> Self {
+        Self {
+            slice,
+            elements_ids: HashMap::new(),
+            text: vec![],
+            suffix_array: vec![],
+            lcp: vec![],
+        }
+    }
+
+    pub fn build_array(&mut self) {
+        let mut elements_ids = HashMap::new();
+        let mut text = Vec::with_capacity(self.slice.len() + 1);
+
+        for i in 0..self.slice.len() {
+            text.push(match self.slice.get(i) {
+                None => SEPARATOR,
+                Some(element) => {
+                    let next_id = elements_ids.len() + SEPARATOR + 1;
+                    *elements_ids.entry(element).or_insert(next_id)
+                }
+            });
+        }
+
+        text.push(SENTINEL);
+
+        let alphabet_size = elements_ids.len() + SEPARATOR + 1;
+        let mut suffix_array = build_suffix_array(&text, alphabet_size);
+        suffix_array.remove(0);
+        text.pop();
+
+        self.lcp = build_lcp_array(&text, &suffix_array);
+        self.suffix_array = suffix_array;
+        self.elements_ids = elements_ids;
+        self.text = text;
+    }
+
+    pub fn suffix_array(&self) -> &Vec<usize> {
+        &self.suffix_array
+    }
+
+    pub fn lcp(&self) -> &Vec<usize> {
+        &self.lcp
+    }
+
+    pub fn find_patterns(&self, pattern: &[TElement]) -> Option<Vec<(usize, usize)>> {
+        let mut pattern_ids = vec![];
+        for element in pattern {
+            pattern_ids.push(*self.elements_ids.get(element)?);
+        }
+
+        let compare = |suffix: &usize| {
+            let end = (*suffix + pattern_ids.len()).min(self.text.len());
+            self.text[*suffix..end].cmp(pattern_ids.as_slice())
+        };
+
+        let lower = self.suffix_array.partition_point(|suffix| compare(suffix) == Ordering::Less);
+        let upper = self.suffix_array.partition_point(|suffix| compare(suffix) != Ordering::Greater);
+
+        if lower == upper {
+            return None;
+        }
+
+        let mut patterns: Vec<(usize, usize)> = self.suffix_array[lower..upper]
+            .iter()
+            .map(|suffix| (*suffix, *suffix + pattern.len()))
+            .collect();
+
+        patterns.sort();
+        Some(patterns)
+    }
+
+    pub fn find_maximal_repeats(&self) -> Vec<(usize, usize)> {
+        self.find_repeats(RepeatType::MaximalRepeat)
+    }
+
+    pub fn find_super_maximal_repeats(&self) -> Vec<(usize, usize)> {
+        self.find_repeats(RepeatType::SuperMaximalRepeat)
+    }
+
+    pub fn find_near_super_maximal_repeats(&self) -> Vec<(usize, usize)> {
+        self.find_repeats(RepeatType::NearSuperMaximalRepeat)
+    }
+
+    fn find_repeats(&self, repeat_type: RepeatType) -> Vec<(usize, usize)> {
+        let mut repeats = HashSet::new();
+        let mut counts = HashMap::new();
+        self.traverse_lcp_intervals(|lcp, children, left_elements| {
+            if let Some(repeat) = Self::try_create_repeat(&repeat_type, lcp, children, left_elements, &mut counts) {
+                repeats.insert(repeat);
+            }
+        });
+
+        let mut repeats: Vec<(usize, usize)> = repeats.into_iter().collect();
+        repeats.sort();
+
+        let mut seen = HashSet::new();
+        let mut filtered_repeats = Vec::new();
+        for repeat in &repeats {
+            if let Some(sub_slice) = self.slice.sub_slice(repeat.0, repeat.1) {
+                if seen.contains(sub_slice) {
+                    continue;
+                }
+
+                seen.insert(sub_slice);
+                filtered_repeats.push(*repeat);
+            }
+        }
+
+        filtered_repeats
+    }
+
+    fn traverse_lcp_intervals(&self, mut interval_handler: impl FnMut(usize, &Vec<IntervalChild>, &Vec<Option<usize>>)) {
+        let suffixes_count = self.suffix_array.len();
+        let mut stack = vec![OpenInterval { lcp: 0, children: vec![] }];
+        let mut left_elements = Vec::with_capacity(suffixes_count);
+
+        for i in 1..=suffixes_count {
+            let lcp = if i < suffixes_count { self.lcp[i] } else { 0 };
+            let leaf = self.create_leaf(self.suffix_array[i - 1], &mut left_elements);
+
+            if lcp > stack.last().unwrap().lcp {
+                stack.push(OpenInterval { lcp, children: vec![leaf] });
+                continue;
+            }
+
+            stack.last_mut().unwrap().children.push(leaf);
+
+            while lcp < stack.last().unwrap().lcp {
+                let interval = stack.pop().unwrap();
+                interval_handler(interval.lcp, &interval.children, &left_elements);
+                let merged_child = Self::merge_children(interval.children, &mut left_elements);
+
+                if lcp <= stack.last().unwrap().lcp {
+                    stack.last_mut().unwrap().children.push(merged_child);
+                } else {
+                    stack.push(OpenInterval {
+                        lcp,
+                        children: vec![merged_child],
+                    });
+                }
+            }
+        }
+    }
+
+    fn create_leaf(&self, position: usize, left_elements: &mut Vec<Option<usize>>) -> IntervalChild {
+        let left_element = match position {
+            0 => None,
+            _ => match self.text[position - 1] {
+                SEPARATOR => None,
+                id => Some(id),
+            },
+        };
+
+        left_elements.push(left_element);
+
+        IntervalChild {
+            left_elements: (left_elements.len() - 1)..left_elements.len(),
+            is_leaf: true,
+            min_position: position,
+        }
+    }
+
+    //The children of the closed interval own the tail of the buffer, which is merged in place
+    fn merge_children(children: Vec<IntervalChild>, left_elements: &mut Vec<Option<usize>>) -> IntervalChild {
+        let start = children[0].left_elements.start;
+        left_elements[start..].sort_unstable();
+
+        let mut end = start;
+        for i in start..left_elements.len() {
+            if end == start || left_elements[i] != left_elements[end - 1] {
+                left_elements[end] = left_elements[i];
+                end += 1;
+            }
+        }
+
+        left_elements.truncate(end);
+
+        IntervalChild {
+            left_elements: start..end,
+            is_leaf: false,
+            min_position: children.iter().map(|child| child.min_position).min().unwrap_or(EMPTY),
+        }
+    }
+
+    fn try_create_repeat(
+        repeat_type: &RepeatType,
+        lcp: usize,
+        children: &Vec<IntervalChild>,
+        left_elements: &Vec<Option<usize>>,
+        counts: &mut HashMap<Option<usize>, usize>,
+    ) -> Option<(usize, usize)> {
+        let child_left_elements = |child: &IntervalChild| &left_elements[child.left_elements.clone()];
+
+        counts.clear();
+        for child in children {
+            for element in child_left_elements(child) {
+                *counts.entry(*element).or_insert(0) += 1;
+            }
+        }
+
+        let is_unique_left_element = |child: &IntervalChild| match (child.is_leaf, left_elements[child.left_elements.start]) {
+            (true, Some(element)) => counts[&Some(element)] == 1,
+            _ => false,
+        };
+
+        match repeat_type {
+            RepeatType::MaximalRepeat => {}
+            RepeatType::SuperMaximalRepeat => {
+                for child in children {
+                    if !child.is_leaf || (left_elements[child.left_elements.start].is_some() && !is_unique_left_element(child)) {
+                        return None;
+                    }
+                }
+            }
+            RepeatType::NearSuperMaximalRepeat => {
+                if !children.iter().any(is_unique_left_element) {
+                    return None;
+                }
+            }
+        }
+
+        let first_left_elements = child_left_elements(&children[0]);
+        let is_left_diverse = children.len() > 1
+            && children.iter().any(|child| {
+                let elements = child_left_elements(child);
+                elements.contains(&None) || elements != first_left_elements
+            });
+
+        if !is_left_diverse {
+            return None;
+        }
+
+        let start = children.iter().map(|child| child.min_position).min().unwrap();
+        Some((start, start + lcp))
+    }
+}
+
+fn build_lcp_array(text: &Vec<usize>, suffix_array: &Vec<usize>) -> Vec<usize> {
+    let length = suffix_array.len();
+    let mut ranks = vec![0; length];
+    for (index, suffix) in suffix_array.iter().enumerate() {
+        ranks[*suffix] = index;
+    }
+
+    let mut lcp = vec![0; length];
+    let mut current = 0;
+    for position in 0..length {
+        if ranks[position] == 0 {
+            current = 0;
+            continue;
+        }
+
+        //All separators share the same id, so the common prefix ends at the separator as words are different
+        let previous = suffix_array[ranks[position] - 1];
+        while position + current < length
+            && previous + current < length
+            && text[position + current] == text[previous + current]
+            && text[position + current] != SEPARATOR
+        {
+            current += 1;
+        }
+
+        lcp[ranks[position]] = current;
+        current = current.saturating_sub(1);
+    }
+
+    lcp
+}
+
+//docs: https://doi.org/10.1109/DCC.2009.42 (SA-IS)
+fn build_suffix_array(text: &[usize], alphabet_size: usize) -> Vec<usize> {
+    let length = text.len();
+    if length == 1 {
+        return vec![0];
+    }
+
+    let mut is_s_type = vec![false; length];
+    is_s_type[length - 1] = true;
+    for i in (0..(length - 1)).rev() {
+        is_s_type[i] = text[i] < text[i + 1] || (text[i] == text[i + 1] && is_s_type[i + 1]);
+    }
+
+    let is_lms = |i: usize| i > 0 && is_s_type[i] && !is_s_type[i - 1];
+
+    let mut buckets_sizes = vec![0; alphabet_size];
+    for element in text {
+        buckets_sizes[*element] += 1;
+    }
+
+    let lms_positions: Vec<usize> = (1..length).filter(|i| is_lms(*i)).collect();
+
+    let mut suffix_array = vec![EMPTY; length];
+    let mut tails = buckets_tails(&buckets_sizes);
+    for position in &lms_positions {
+        tails[text[*position]] -= 1;
+        suffix_array[tails[text[*position]]] = *position;
+    }
+
+    induce_sort(text, &mut suffix_array, &is_s_type, &buckets_sizes);
+
+    let mut names = vec![EMPTY; length];
+    let mut current_name = 0;
+    let mut previous_lms: Option<usize> = None;
+    for position in suffix_array.iter().filter(|position| **position != EMPTY && is_lms(**position)) {
+        if let Some(previous) = previous_lms {
+            if !are_lms_substrings_equal(text, &is_s_type, previous, *position) {
+                current_name += 1;
+            }
+        }
+
+        names[*position] = current_name;
+        previous_lms = Some(*position);
+    }
+
+    let reduced_text: Vec<usize> = lms_positions.iter().map(|position| names[*position]).collect();
+    let reduced_suffix_array = if current_name + 1 == reduced_text.len() {
+        let mut reduced_suffix_array = vec![0; reduced_text.len()];
+        for (index, name) in reduced_text.iter().enumerate() {
+            reduced_suffix_array[*name] = index;
+        }
+
+        reduced_suffix_array
+    } else {
+        build_suffix_array(&reduced_text, current_name + 1)
+    };
+
+    suffix_array.fill(EMPTY);
+    let mut tails = buckets_tails(&buckets_sizes);
+    for reduced_index in reduced_suffix_array.iter().rev() {
+        let position = lms_positions[*reduced_index];
+        tails[text[position]] -= 1;
+        suffix_array[tails[text[position]]] = position;
+    }
+
+    induce_sort(text, &mut suffix_array, &is_s_type, &buckets_sizes);
+
+    suffix_array
+}
+
+fn induce_sort(text: &[usize], suffix_array: &mut Vec<usize>, is_s_type: &Vec<bool>, buckets_sizes: &Vec<usize>) {
+    let mut heads = buckets_heads(buckets_sizes);
+    for i in 0..suffix_array.len() {
+        let position = suffix_array[i];
+        if position != EMPTY && position > 0 && !is_s_type[position - 1] {
+            let element = text[position - 1];
+            suffix_array[heads[element]] = position - 1;
+            heads[element] += 1;
+        }
+    }
+
+    let mut tails = buckets_tails(buckets_sizes);
+    for i in (0..suffix_array.len()).rev() {
+        let position = suffix_array[i];
+        if position != EMPTY && position > 0 && is_s_type[position - 1] {
+            let element = text[position - 1];
+            tails[element] -= 1;
+            suffix_array[tails[element]] = position - 1;
+        }
+    }
+}
+
+fn are_lms_substrings_equal(text: &[usize], is_s_type: &Vec<bool>, first: usize, second: usize) -> bool {
+    let length = text.len();
+    if first == length - 1 || second == length - 1 {
+        return first == second;
+    }
+
+    let is_lms = |i: usize| i > 0 && is_s_type[i] && !is_s_type[i - 1];
+    let mut offset = 0;
+    loop {
+        if text[first + offset] != text[second + offset] || is_s_type[first + offset] != is_s_type[second + offset] {
+            return false;
+        }
+
+        let first_end = is_lms(first + offset);
+        let second_end = is_lms(second + offset);
+
+        if offset > 0 && (first_end || second_end) {
+            return first_end && second_end;
+        }
+
+        offset += 1;
+    }
+}
+
+fn buckets_heads(buckets_sizes: &Vec<usize>) -> Vec<usize> {
+    let mut heads = Vec::with_capacity(buckets_sizes.len());
+    let mut sum = 0;
+    for size in buckets_sizes {
+        heads.push(sum);
+        sum += size;
+    }
+
+    heads
+}
+
+fn buckets_tails(buckets_sizes: &Vec<usize>) -> Vec<usize> {
+    let mut tails = Vec::with_capacity(buckets_sizes.len());
+    let mut sum = 0;
+    for size in buckets_sizes {
+        sum += size;
+        tails.push(sum);
+    }
+
+    tails
+}
@@ -1,4 +1,9 @@
-use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    any::Any,
+    cell::{Ref, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
 
 use super::keys::{DefaultKey, Key};
 
@@ -88,6 +93,13 @@ impl UserDataImpl {
         self.values_map = Some(HashMap::new());
     }
 
+    pub fn entries(&self) -> Vec<(u64, Ref<dyn Any>)> {
+        match self.values_map.as_ref() {
+            None => vec![],
+            Some(values_map) => values_map.iter().map(|(id, value)| (*id, value.borrow())).collect(),
+        }
+    }
+
     pub fn remove(&mut self, key: &impl Key) {
         if self.values_map.is_none() {
             return;
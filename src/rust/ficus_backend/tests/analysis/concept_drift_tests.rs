@@ -0,0 +1,106 @@
+use chrono::Duration;
+use ficus_backend::event_log::core::event::event::Event;
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::core::trace::trace::Trace;
+use ficus_backend::event_log::simple::simple_event_log::SimpleEventLog;
+use ficus_backend::features::analysis::concept_drift::{
+    detect_concept_drift, split_log_by_change_points, ConceptDriftParams, DriftFeature,
+};
+
+fn create_drifting_log(first: Vec<&str>, second: Vec<&str>, count: usize) -> SimpleEventLog {
+    let mut raw_log = vec![];
+    raw_log.extend((0..count).map(|_| first.clone()));
+    raw_log.extend((0..count).map(|_| second.clone()));
+
+    SimpleEventLog::new(&raw_log)
+}
+
+fn create_params(feature: DriftFeature, window_size: usize) -> ConceptDriftParams {
+    ConceptDriftParams {
+        feature,
+        window_size,
+        significance_level: 0.05,
+    }
+}
+
+#[test]
+fn test_dfg_relations_drift() {
+    let log = create_drifting_log(vec!["A", "B", "C"], vec!["A", "C", "B"], 20);
+    let result = detect_concept_drift(&log, &create_params(DriftFeature::DfgRelations, 10));
+
+    assert_eq!(result.p_values().len(), 21);
+    assert_eq!(result.change_points().len(), 1);
+    assert_eq!(result.change_points()[0].position(), 20);
+    assert!(result.change_points()[0].p_value() < 1e-3);
+
+    let sub_logs = split_log_by_change_points(&log, &result);
+    assert_eq!(sub_logs.len(), 2);
+    assert!(sub_logs[0].to_raw_vector().iter().all(|trace| trace == &vec!["A", "B", "C"]));
+    assert!(sub_logs[1].to_raw_vector().iter().all(|trace| trace == &vec!["A", "C", "B"]));
+}
+
+#[test]
+fn test_event_classes_drift() {
+    let log = create_drifting_log(vec!["A", "B"], vec!["A", "C"], 15);
+    let result = detect_concept_drift(&log, &create_params(DriftFeature::EventClasses, 10));
+
+    assert_eq!(result.p_values().len(), 11);
+    assert_eq!(result.change_points().len(), 1);
+    assert_eq!(result.change_points()[0].position(), 15);
+    assert!((result.change_points()[0].p_value() - 11.0 * (-10f64).exp()).abs() < 1e-12);
+}
+
+//Each of 21 positions is significant without the adjustment, but p-values are too large for the count of tests
+#[test]
+fn test_drift_p_values_are_adjusted() {
+    let log = create_drifting_log(vec!["A", "B"], vec!["A", "C"], 15);
+    let result = detect_concept_drift(&log, &create_params(DriftFeature::EventClasses, 5));
+
+    let (position, p_value) = result.p_values()[10];
+    assert_eq!(position, 15);
+    assert!((p_value - (-5f64).exp()).abs() < 1e-12);
+    assert!((result.adjusted_p_values()[10].1 - 21.0 * p_value).abs() < 1e-12);
+    assert!(result.change_points().is_empty());
+}
+
+#[test]
+fn test_entropy_drift() {
+    let mut raw_log = vec![vec!["A", "B"]; 10];
+    for i in 0..10 {
+        raw_log.push(if i % 2 == 0 { vec!["A", "B", "C"] } else { vec!["A", "C", "B"] });
+    }
+
+    let log = SimpleEventLog::new(&raw_log);
+    let result = detect_concept_drift(&log, &create_params(DriftFeature::Entropy, 10));
+
+    assert_eq!(result.p_values(), &vec![(10, 1.0 / 101.0)]);
+    assert_eq!(result.change_points().len(), 1);
+}
+
+#[test]
+fn test_no_drift() {
+    let log = create_drifting_log(vec!["A", "B", "C"], vec!["A", "B", "C"], 10);
+    for feature in [DriftFeature::DfgRelations, DriftFeature::EventClasses] {
+        let result = detect_concept_drift(&log, &create_params(feature, 5));
+
+        assert!(result.change_points().is_empty());
+        assert!(result.p_values().iter().all(|(_, p_value)| *p_value == 1.0));
+        assert_eq!(split_log_by_change_points(&log, &result).len(), 1);
+    }
+}
+
+#[test]
+fn test_traces_are_ordered_by_start_time() {
+    let log = create_drifting_log(vec!["A", "B"], vec!["A", "C"], 3);
+    for (index, trace) in log.traces().iter().enumerate() {
+        for event in trace.borrow().events() {
+            let timestamp = *event.borrow().timestamp() + Duration::hours(10 - index as i64);
+            event.borrow_mut().set_timestamp(timestamp);
+        }
+    }
+
+    let result = detect_concept_drift(&log, &create_params(DriftFeature::EventClasses, 3));
+
+    assert_eq!(result.ordered_traces(), &vec![5, 4, 3, 2, 1, 0]);
+    assert_eq!(result.to_dataset().row_names(), &vec!["Trace_3"]);
+}
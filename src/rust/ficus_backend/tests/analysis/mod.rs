@@ -1,3 +1,4 @@
 pub mod analysis_tests;
+pub mod concept_drift_tests;
 pub mod event_log_info_tests;
 pub mod patterns;
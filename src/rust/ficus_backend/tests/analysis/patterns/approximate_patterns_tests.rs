@@ -0,0 +1,144 @@
+use ficus_backend::{
+    event_log::{
+        core::{
+            event::{event::Event, event_hasher::NameEventHasher},
+            event_log::EventLog,
+            trace::trace::Trace,
+        },
+        simple::simple_event_log::SimpleEventLog,
+    },
+    features::analysis::patterns::{
+        approximate_patterns::{find_approximate_tandem_arrays, find_gapped_repeats, find_hamming_repeats},
+        contexts::PatternsDiscoveryStrategy,
+        tandem_arrays::SubArrayInTraceInfo,
+    },
+};
+
+#[test]
+fn test_exact_hamming_repeats() {
+    execute_test(
+        vec![vec!["a", "b", "c", "d", "e", "a", "b", "x", "d", "e"]],
+        |log| find_hamming_repeats(log, &PatternsDiscoveryStrategy::FromAllTraces, 0),
+        &["ab", "de"],
+    );
+}
+
+#[test]
+fn test_hamming_repeats_with_one_mismatch() {
+    execute_test(
+        vec![vec!["a", "b", "c", "d", "e", "a", "b", "x", "d", "e"]],
+        |log| find_hamming_repeats(log, &PatternsDiscoveryStrategy::FromAllTraces, 1),
+        &["abcde"],
+    );
+}
+
+#[test]
+fn test_hamming_repeats_single_merged_trace() {
+    let raw_log = vec![vec!["a", "b", "c", "d", "e"], vec!["a", "b", "x", "d", "e"]];
+
+    execute_test(
+        raw_log.clone(),
+        |log| find_hamming_repeats(log, &PatternsDiscoveryStrategy::FromSingleMergedTrace, 1),
+        &["abcde"],
+    );
+
+    execute_test(
+        raw_log,
+        |log| find_hamming_repeats(log, &PatternsDiscoveryStrategy::FromAllTraces, 1),
+        &[],
+    );
+}
+
+//Each occurrence is extended only against its nearest partners, all pairs would produce ~10^5 candidates here
+#[test]
+fn test_hamming_repeats_with_many_occurrences() {
+    let raw_log = (0..500)
+        .map(|index| match index % 2 {
+            0 => vec!["a", "b", "c", "d", "e"],
+            _ => vec!["a", "b", "x", "d", "e"],
+        })
+        .collect::<Vec<Vec<&str>>>();
+
+    execute_test(
+        raw_log,
+        |log| find_hamming_repeats(log, &PatternsDiscoveryStrategy::FromSingleMergedTrace, 1),
+        &["abcde"],
+    );
+}
+
+#[test]
+fn test_gapped_repeats() {
+    let raw_log = vec![vec!["a", "b", "c", "d", "e", "a", "b", "x", "c", "d", "e"]];
+
+    execute_test(
+        raw_log.clone(),
+        |log| find_gapped_repeats(log, &PatternsDiscoveryStrategy::FromAllTraces, 0),
+        &["ab", "cde"],
+    );
+
+    execute_test(
+        raw_log,
+        |log| find_gapped_repeats(log, &PatternsDiscoveryStrategy::FromAllTraces, 1),
+        &["abcde"],
+    );
+}
+
+#[test]
+fn test_approximate_tandem_arrays() {
+    execute_test(
+        vec![vec!["a", "b", "c", "a", "b", "x", "a", "b", "c"]],
+        |log| find_approximate_tandem_arrays(log, 10, 1),
+        &["abc", "bca", "cab"],
+    );
+}
+
+#[test]
+fn test_approximate_tandem_arrays_without_mismatches() {
+    execute_test(
+        vec![vec!["a", "b", "c", "a", "b", "x", "a", "b", "c"]],
+        |log| find_approximate_tandem_arrays(log, 10, 0),
+        &[],
+    );
+}
+
+#[test]
+fn test_approximate_tandem_arrays_bounds() {
+    execute_test(
+        vec![vec!["a", "b", "c", "a", "b", "c"]],
+        |log| find_approximate_tandem_arrays(log, 3, 0),
+        &["abc"],
+    );
+
+    execute_test(
+        vec![vec!["a", "b", "a", "x"]],
+        |log| find_approximate_tandem_arrays(log, 2, 1),
+        &["ab"],
+    );
+}
+
+#[test]
+fn test_approximate_tandem_arrays_later_occurrences() {
+    execute_test(
+        vec![vec!["a", "b", "a", "b", "c", "d", "e", "a", "b", "a", "b"]],
+        |log| find_approximate_tandem_arrays(log, 10, 0),
+        &["ab", "ab"],
+    );
+}
+
+fn execute_test(raw_log: Vec<Vec<&str>>, finder: impl Fn(&Vec<Vec<u64>>) -> Vec<Vec<SubArrayInTraceInfo>>, expected: &[&str]) {
+    let log = SimpleEventLog::new(&raw_log);
+    let hashes = log.to_hashes_event_log(&NameEventHasher::new());
+    let patterns = finder(&hashes);
+
+    let mut dump = vec![];
+    for (trace, trace_patterns) in log.traces().iter().zip(patterns.iter()) {
+        let trace = trace.borrow();
+        for pattern in trace_patterns {
+            let events = &trace.events()[pattern.start_index..(pattern.start_index + pattern.length)];
+            dump.push(events.iter().map(|event| event.borrow().name().to_owned()).collect::<String>());
+        }
+    }
+
+    dump.sort();
+    assert_eq!(dump, expected);
+}
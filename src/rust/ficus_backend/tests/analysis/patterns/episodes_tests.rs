@@ -0,0 +1,60 @@
+use ficus_backend::features::analysis::patterns::{
+    contexts::EpisodeKind,
+    episodes::{find_frequent_episodes, EpisodesDiscoveryParams},
+    sequential_patterns::SequentialPattern,
+};
+
+#[test]
+fn test_serial_episodes() {
+    let log = vec![vec![1, 2, 3, 1, 2]];
+    let timestamps = vec![vec![0, 1, 10, 11, 12]];
+    let episodes = find_frequent_episodes(&log, &timestamps, &create_params(EpisodeKind::Serial, 2, 2));
+
+    assert_eq!(dump_episodes(&episodes), vec![(vec![1, 2], 2, vec![(0, 2, 0), (3, 2, 0)])]);
+}
+
+#[test]
+fn test_serial_episodes_time_window() {
+    let log = vec![vec![1, 2, 3, 1, 2]];
+    let timestamps = vec![vec![0, 1, 10, 11, 12]];
+    let episodes = find_frequent_episodes(&log, &timestamps, &create_params(EpisodeKind::Serial, 0, 2));
+
+    assert!(episodes.is_empty());
+}
+
+#[test]
+fn test_parallel_episodes() {
+    let log = vec![vec![1, 2], vec![2, 1], vec![1, 3, 2]];
+    let timestamps = vec![vec![0, 1], vec![0, 1], vec![0, 1, 2]];
+    let episodes = find_frequent_episodes(&log, &timestamps, &create_params(EpisodeKind::Parallel, 1, 2));
+
+    assert_eq!(dump_episodes(&episodes), vec![(vec![1, 2], 2, vec![(0, 2, 0), (0, 2, 1)])]);
+}
+
+#[test]
+fn test_serial_episodes_respect_order() {
+    let log = vec![vec![1, 2], vec![2, 1], vec![1, 3, 2]];
+    let timestamps = vec![vec![0, 1], vec![0, 1], vec![0, 1, 2]];
+    let episodes = find_frequent_episodes(&log, &timestamps, &create_params(EpisodeKind::Serial, 2, 2));
+
+    assert_eq!(dump_episodes(&episodes), vec![(vec![1, 2], 2, vec![(0, 2, 0), (0, 3, 2)])]);
+}
+
+fn create_params(kind: EpisodeKind, time_window: i64, min_support: usize) -> EpisodesDiscoveryParams {
+    EpisodesDiscoveryParams {
+        kind,
+        time_window,
+        min_support,
+        max_episode_length: 3,
+    }
+}
+
+fn dump_episodes(episodes: &Vec<SequentialPattern>) -> Vec<(Vec<u64>, usize, Vec<(usize, usize, usize)>)> {
+    episodes
+        .iter()
+        .map(|episode| {
+            let occurrences = episode.occurrences().iter().map(|occurrence| occurrence.dump()).collect();
+            (episode.elements().clone(), episode.support(), occurrences)
+        })
+        .collect()
+}
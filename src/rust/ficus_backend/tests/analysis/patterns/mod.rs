@@ -1,3 +1,6 @@
 pub mod activities_instances_tests;
+pub mod approximate_patterns_tests;
+pub mod episodes_tests;
 pub mod patterns_tests;
 pub mod repeat_sets_tests;
+pub mod sequential_patterns_tests;
@@ -0,0 +1,62 @@
+use ficus_backend::features::analysis::patterns::sequential_patterns::{
+    find_sequential_patterns, SequentialPattern, SequentialPatternsDiscoveryParams,
+};
+
+#[test]
+fn test_sequential_patterns() {
+    let log = vec![vec![1, 2, 3], vec![1, 3, 2], vec![1, 2]];
+    let patterns = find_sequential_patterns(&log, &create_params(2, 1, 3));
+
+    assert_eq!(dump_patterns(&patterns), vec![(vec![1, 2], 3), (vec![1, 3], 2)]);
+    assert_eq!(dump_occurrences(&patterns[0]), vec![(0, 2, 0), (0, 3, 1), (0, 2, 2)]);
+}
+
+#[test]
+fn test_sequential_patterns_without_gaps() {
+    let log = vec![vec![1, 2, 3], vec![1, 3, 2], vec![1, 2]];
+    let patterns = find_sequential_patterns(&log, &create_params(2, 0, 3));
+
+    assert_eq!(dump_patterns(&patterns), vec![(vec![1, 2], 2)]);
+}
+
+#[test]
+fn test_sequential_patterns_max_length() {
+    let log = vec![vec![1, 2, 3], vec![1, 2, 3]];
+
+    let patterns = find_sequential_patterns(&log, &create_params(2, 0, 3));
+    assert_eq!(dump_patterns(&patterns), vec![(vec![1, 2], 2), (vec![1, 2, 3], 2), (vec![2, 3], 2)]);
+
+    let patterns = find_sequential_patterns(&log, &create_params(2, 0, 2));
+    assert_eq!(dump_patterns(&patterns), vec![(vec![1, 2], 2), (vec![2, 3], 2)]);
+}
+
+#[test]
+fn test_sequential_patterns_non_overlapping_occurrences() {
+    let log = vec![vec![1, 2, 1, 2]];
+    let patterns = find_sequential_patterns(&log, &create_params(1, 1, 2));
+
+    assert_eq!(
+        dump_patterns(&patterns),
+        vec![(vec![1, 1], 1), (vec![1, 2], 1), (vec![2, 1], 1), (vec![2, 2], 1)]
+    );
+    assert_eq!(dump_occurrences(&patterns[1]), vec![(0, 2, 0), (2, 2, 0)]);
+}
+
+fn create_params(min_support: usize, max_gap: usize, max_pattern_length: usize) -> SequentialPatternsDiscoveryParams {
+    SequentialPatternsDiscoveryParams {
+        min_support,
+        max_gap,
+        max_pattern_length,
+    }
+}
+
+fn dump_patterns(patterns: &Vec<SequentialPattern>) -> Vec<(Vec<u64>, usize)> {
+    patterns
+        .iter()
+        .map(|pattern| (pattern.elements().clone(), pattern.support()))
+        .collect()
+}
+
+fn dump_occurrences(pattern: &SequentialPattern) -> Vec<(usize, usize, usize)> {
+    pattern.occurrences().iter().map(|occurrence| occurrence.dump()).collect()
+}
@@ -0,0 +1,132 @@
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::simple::simple_event_log::SimpleEventLog;
+use ficus_backend::features::clustering::agglomerative::{build_dendrogram, AgglomerativeClusteringLinkage, DendrogramCut};
+use ficus_backend::features::clustering::common::CommonVisualizationParams;
+use ficus_backend::features::clustering::traces::agglomerative::clusterize_log_by_traces_agglomerative;
+use ficus_backend::features::clustering::traces::traces_params::{TracesClusteringParams, TracesRepresentationSource};
+use ficus_backend::utils::colors::ColorsHolder;
+use ficus_backend::utils::distance::distance::FicusDistance;
+
+fn create_points_distances() -> Vec<Vec<f64>> {
+    let points = [0.0, 1.0, 3.0, 7.0];
+    points
+        .iter()
+        .map(|first| points.iter().map(|second| f64::abs(first - second)).collect())
+        .collect()
+}
+
+fn create_leaves_names() -> Vec<String> {
+    (0..4).map(|index| index.to_string()).collect()
+}
+
+fn get_merges(linkage: AgglomerativeClusteringLinkage) -> Vec<(usize, usize, f64, usize)> {
+    build_dendrogram(&create_points_distances(), create_leaves_names(), linkage)
+        .merges()
+        .iter()
+        .map(|merge| (merge.first(), merge.second(), merge.distance(), merge.size()))
+        .collect()
+}
+
+#[test]
+fn test_single_linkage() {
+    assert_eq!(
+        get_merges(AgglomerativeClusteringLinkage::Single),
+        vec![(0, 1, 1.0, 2), (2, 4, 2.0, 3), (3, 5, 4.0, 4)]
+    );
+}
+
+#[test]
+fn test_complete_linkage() {
+    assert_eq!(
+        get_merges(AgglomerativeClusteringLinkage::Complete),
+        vec![(0, 1, 1.0, 2), (2, 4, 3.0, 3), (3, 5, 7.0, 4)]
+    );
+}
+
+#[test]
+fn test_average_linkage() {
+    let merges = get_merges(AgglomerativeClusteringLinkage::Average);
+
+    assert_eq!(merges[1], (2, 4, 2.5, 3));
+    assert!((merges[2].2 - 17.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_ward_linkage() {
+    let merges = get_merges(AgglomerativeClusteringLinkage::Ward);
+
+    assert_eq!((merges[1].0, merges[1].1), (2, 4));
+    assert!((merges[1].2 - (25.0f64 / 3.0).sqrt()).abs() < 1e-9);
+}
+
+//Merges are found by the nearest-neighbour chain in a different order and then sorted by distance
+#[test]
+fn test_merges_are_sorted_by_distance() {
+    let points = [0.0, 10.0, 11.0, 30.0, 30.5];
+    let distances = points
+        .iter()
+        .map(|first| points.iter().map(|second| f64::abs(first - second)).collect())
+        .collect();
+
+    let names = (0..points.len()).map(|index| index.to_string()).collect();
+    let merges = build_dendrogram(&distances, names, AgglomerativeClusteringLinkage::Single)
+        .merges()
+        .iter()
+        .map(|merge| (merge.first(), merge.second(), merge.distance(), merge.size()))
+        .collect::<Vec<(usize, usize, f64, usize)>>();
+
+    assert_eq!(merges, vec![(3, 4, 0.5, 2), (1, 2, 1.0, 2), (0, 6, 10.0, 3), (5, 7, 19.0, 5)]);
+}
+
+#[test]
+fn test_dendrogram_cut() {
+    let dendrogram = build_dendrogram(
+        &create_points_distances(),
+        create_leaves_names(),
+        AgglomerativeClusteringLinkage::Single,
+    );
+
+    assert_eq!(dendrogram.cut(&DendrogramCut::ClustersCount(1)), vec![0, 0, 0, 0]);
+    assert_eq!(dendrogram.cut(&DendrogramCut::ClustersCount(2)), vec![0, 0, 0, 1]);
+    assert_eq!(dendrogram.cut(&DendrogramCut::ClustersCount(4)), vec![0, 1, 2, 3]);
+    assert_eq!(dendrogram.cut(&DendrogramCut::ClustersCount(10)), vec![0, 1, 2, 3]);
+    assert_eq!(dendrogram.cut(&DendrogramCut::Distance(1.5)), vec![0, 0, 1, 2]);
+}
+
+#[test]
+fn test_traces_agglomerative_clustering() {
+    let log = SimpleEventLog::new(&vec![
+        vec!["A", "B", "C"],
+        vec!["A", "B", "C"],
+        vec!["A", "B", "D"],
+        vec!["X", "Y", "Z"],
+        vec!["X", "Y", "Z"],
+    ]);
+
+    let mut colors_holder = ColorsHolder::empty();
+    let mut params = TracesClusteringParams {
+        vis_params: CommonVisualizationParams {
+            log: &log,
+            colors_holder: &mut colors_holder,
+            class_extractor: None,
+        },
+        tolerance: 0.0,
+        distance: FicusDistance::L2,
+        repr_source: TracesRepresentationSource::Events,
+        featurisation: None,
+    };
+
+    let cut = DendrogramCut::ClustersCount(2);
+    let (logs, dataset, dendrogram) =
+        match clusterize_log_by_traces_agglomerative(&mut params, AgglomerativeClusteringLinkage::Average, cut) {
+            Ok(result) => result,
+            Err(_) => panic!("Failed to clusterize traces"),
+        };
+
+    assert_eq!(dataset.labels(), &vec![0, 0, 0, 1, 1]);
+    assert_eq!(logs.iter().map(|log| log.traces().len()).collect::<Vec<usize>>(), vec![3, 2]);
+
+    assert_eq!(dendrogram.leaves_names().len(), 5);
+    assert_eq!(dendrogram.merges().len(), 4);
+    assert_eq!(dendrogram.cut(&DendrogramCut::Distance(1.0)), vec![0, 0, 1, 2, 2]);
+}
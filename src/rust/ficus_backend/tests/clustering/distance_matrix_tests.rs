@@ -0,0 +1,234 @@
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::simple::simple_event_log::SimpleEventLog;
+use ficus_backend::features::clustering::agglomerative::{AgglomerativeClusteringLinkage, DendrogramCut};
+use ficus_backend::features::clustering::common::CommonVisualizationParams;
+use ficus_backend::features::clustering::distance_matrix::{
+    build_distance_matrix, build_sequences_distance_matrix, clusterize_distance_matrix, dbscan_on_distance_matrix, k_medoids,
+    DistanceMatrixClustering,
+};
+use ficus_backend::features::clustering::traces::dbscan::clusterize_log_by_traces_dbscan;
+use ficus_backend::features::clustering::traces::distance_matrix::clusterize_log_by_traces_distance_matrix;
+use ficus_backend::features::clustering::traces::traces_params::{TracesClusteringParams, TracesRepresentationSource};
+use ficus_backend::utils::colors::ColorsHolder;
+use ficus_backend::utils::distance::distance::{DistanceWrapper, FicusDistance};
+use ficus_backend::utils::distance::sequence_distance::{
+    discrete_cost, dtw_distance, edit_distance, ClassesWeightedEditCosts, TranspositionEditCosts, UnitEditCosts,
+};
+
+fn to_chars(value: &str) -> Vec<char> {
+    value.chars().collect()
+}
+
+fn create_points_distances(points: &Vec<f64>) -> Vec<Vec<f64>> {
+    build_distance_matrix(points, |first, second| (first - second).abs())
+}
+
+#[test]
+fn test_edit_distances() {
+    assert_eq!(edit_distance(&to_chars("kitten"), &to_chars("sitting"), &UnitEditCosts), 3.0);
+    assert_eq!(edit_distance(&to_chars("abc"), &to_chars("acb"), &UnitEditCosts), 2.0);
+    assert_eq!(edit_distance(&to_chars("abc"), &to_chars("acb"), &TranspositionEditCosts), 1.0);
+    assert_eq!(edit_distance(&to_chars(""), &to_chars("abc"), &TranspositionEditCosts), 3.0);
+}
+
+#[test]
+fn test_weighted_edit_distance() {
+    let costs = ClassesWeightedEditCosts::from_frequencies(&vec![vec![1, 1, 1], vec![2]]);
+
+    assert_eq!(costs.weight(&1), 1.0 + (4.0f64 / 3.0).ln());
+    assert_eq!(costs.weight(&2), 1.0 + 4.0f64.ln());
+    assert_eq!(costs.weight(&3), 1.0);
+
+    assert_eq!(edit_distance(&[1u64, 2], &[2u64], &costs), costs.weight(&1));
+    assert_eq!(edit_distance(&[1u64], &[2u64], &costs), (costs.weight(&1) + costs.weight(&2)) / 2.0);
+}
+
+#[test]
+fn test_dtw_distance() {
+    assert_eq!(dtw_distance(&to_chars("aab"), &to_chars("ab"), discrete_cost), 0.0);
+    assert_eq!(dtw_distance(&to_chars("ab"), &to_chars("c"), discrete_cost), 2.0);
+    assert_eq!(dtw_distance(&to_chars(""), &to_chars("abc"), discrete_cost), 3.0);
+}
+
+#[test]
+fn test_sequences_distance_matrix() {
+    let sequences = vec![
+        vec!["A".to_string(), "B".to_string(), "C".to_string()],
+        vec!["A".to_string(), "C".to_string(), "B".to_string()],
+        vec!["A".to_string()],
+    ];
+
+    let levenshtein = build_sequences_distance_matrix(&sequences, FicusDistance::Levenshtein);
+    assert_eq!(levenshtein, vec![vec![0.0, 2.0, 2.0], vec![2.0, 0.0, 2.0], vec![2.0, 2.0, 0.0]]);
+
+    let optimal_string_alignment = build_sequences_distance_matrix(&sequences, FicusDistance::OptimalStringAlignment);
+    assert_eq!(
+        optimal_string_alignment,
+        vec![vec![0.0, 1.0, 2.0], vec![1.0, 0.0, 2.0], vec![2.0, 2.0, 0.0]]
+    );
+}
+
+#[test]
+fn test_distance_matrix_is_symmetric() {
+    let points = (0..50).map(|x| (x * x) as f64).collect::<Vec<f64>>();
+    let distances = create_points_distances(&points);
+
+    for i in 0..points.len() {
+        assert_eq!(distances[i][i], 0.0);
+        for j in 0..points.len() {
+            assert_eq!(distances[i][j], (points[i] - points[j]).abs());
+            assert_eq!(distances[i][j], distances[j][i]);
+        }
+    }
+}
+
+#[test]
+fn test_dbscan_on_distance_matrix() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 2.0, 10.0, 11.0, 50.0]);
+
+    let labels = dbscan_on_distance_matrix(&distances, 1.5, 2);
+    assert_eq!(labels, vec![Some(0), Some(0), Some(0), Some(1), Some(1), None]);
+
+    let labels = dbscan_on_distance_matrix(&distances, 1.5, 3);
+    assert_eq!(labels, vec![Some(0), Some(0), Some(0), None, None, None]);
+}
+
+#[test]
+fn test_k_medoids() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 2.0, 10.0, 11.0, 12.0]);
+
+    assert_eq!(k_medoids(&distances, 2, 10), vec![0, 0, 0, 1, 1, 1]);
+    assert_eq!(k_medoids(&distances, 1, 10), vec![0, 0, 0, 0, 0, 0]);
+
+    let mut labels = k_medoids(&distances, 6, 10);
+    labels.sort();
+    assert_eq!(labels, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_agglomerative_on_distance_matrix() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 3.0, 7.0]);
+    let names = (0..4).map(|x| x.to_string()).collect();
+    let clustering = DistanceMatrixClustering::Agglomerative {
+        linkage: AgglomerativeClusteringLinkage::Single,
+        cut: DendrogramCut::ClustersCount(2),
+    };
+
+    let (labels, dendrogram) = clusterize_distance_matrix(&distances, names, &clustering);
+
+    assert_eq!(labels, vec![Some(0), Some(0), Some(0), Some(1)]);
+    assert_eq!(dendrogram.unwrap().merges().len(), 3);
+}
+
+fn clusterize_traces(log: &SimpleEventLog, distance: FicusDistance, clustering: DistanceMatrixClustering) -> (Vec<usize>, Vec<usize>) {
+    let mut colors_holder = ColorsHolder::empty();
+    let mut params = TracesClusteringParams {
+        vis_params: CommonVisualizationParams {
+            log,
+            colors_holder: &mut colors_holder,
+            class_extractor: None,
+        },
+        tolerance: 0.0,
+        distance,
+        repr_source: TracesRepresentationSource::Events,
+        featurisation: None,
+    };
+
+    let (logs, dataset, _) = match clusterize_log_by_traces_distance_matrix(&mut params, &clustering) {
+        Ok(result) => result,
+        Err(_) => panic!("Failed to clusterize traces"),
+    };
+
+    let logs_sizes = logs.iter().map(|log| log.traces().len()).collect();
+    (dataset.labels().clone(), logs_sizes)
+}
+
+#[test]
+fn test_traces_dbscan_levenshtein() {
+    let log = SimpleEventLog::new(&vec![
+        vec!["A", "B", "C"],
+        vec!["A", "B", "C"],
+        vec!["A", "B", "D"],
+        vec!["X", "Y", "Z"],
+        vec!["X", "Y", "Z", "Z"],
+        vec!["Q", "Q", "Q", "Q", "Q", "Q"],
+    ]);
+
+    let clustering = DistanceMatrixClustering::Dbscan {
+        tolerance: 1.0,
+        min_points: 2,
+    };
+
+    let (labels, logs_sizes) = clusterize_traces(&log, FicusDistance::Levenshtein, clustering);
+
+    assert_eq!(labels, vec![1, 1, 1, 2, 2, 0]);
+    assert_eq!(logs_sizes, vec![1, 3, 2]);
+}
+
+#[test]
+fn test_traces_k_medoids_weighted_edit() {
+    let log = SimpleEventLog::new(&vec![
+        vec!["A", "B", "C"],
+        vec!["A", "B", "C"],
+        vec!["A", "B", "D"],
+        vec!["X", "Y", "Z"],
+        vec!["X", "Y", "Z"],
+    ]);
+
+    let clustering = DistanceMatrixClustering::KMedoids {
+        clusters_count: 2,
+        iterations_count: 10,
+    };
+
+    let (labels, logs_sizes) = clusterize_traces(&log, FicusDistance::WeightedEdit, clustering);
+
+    assert_eq!(labels, vec![1, 1, 1, 2, 2]);
+    assert_eq!(logs_sizes, vec![3, 2]);
+}
+
+#[test]
+fn test_sequence_distances_are_not_features_distances() {
+    for distance in [
+        FicusDistance::WeightedEdit,
+        FicusDistance::OptimalStringAlignment,
+        FicusDistance::Dtw,
+    ] {
+        assert!(!distance.is_features_distance());
+        assert!(DistanceWrapper::new(distance).is_none());
+    }
+
+    assert!(DistanceWrapper::new(FicusDistance::L2).is_some());
+}
+
+#[test]
+fn test_traces_dbscan_with_sequence_distance() {
+    let log = SimpleEventLog::new(&vec![
+        vec!["A", "B", "C"],
+        vec!["A", "C", "B"],
+        vec!["A", "B", "C", "D"],
+        vec!["X", "Y", "Z"],
+        vec!["X", "Z", "Y"],
+        vec!["Q", "Q", "Q", "Q", "Q", "Q"],
+    ]);
+
+    let mut colors_holder = ColorsHolder::empty();
+    let mut params = TracesClusteringParams {
+        vis_params: CommonVisualizationParams {
+            log: &log,
+            colors_holder: &mut colors_holder,
+            class_extractor: None,
+        },
+        tolerance: 1.0,
+        distance: FicusDistance::OptimalStringAlignment,
+        repr_source: TracesRepresentationSource::Events,
+        featurisation: None,
+    };
+
+    let (logs, dataset) = match clusterize_log_by_traces_dbscan(&mut params, 2) {
+        Ok(result) => result,
+        Err(_) => panic!("Failed to clusterize traces"),
+    };
+
+    assert_eq!(dataset.labels(), &vec![1, 1, 1, 2, 2, 0]);
+    assert_eq!(logs.iter().map(|log| log.traces().len()).collect::<Vec<usize>>(), vec![1, 3, 2]);
+}
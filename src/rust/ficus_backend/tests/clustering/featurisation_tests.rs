@@ -0,0 +1,179 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc, str::FromStr};
+
+use chrono::{DateTime, Duration, Utc};
+use ficus_backend::event_log::core::event::event::EventPayloadValue;
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::core::trace::trace::Trace;
+use ficus_backend::event_log::simple::simple_event_log::{SimpleEvent, SimpleEventLog};
+use ficus_backend::event_log::xes::xes_event::XesEventImpl;
+use ficus_backend::features::clustering::common::CommonVisualizationParams;
+use ficus_backend::features::clustering::distance_matrix::DistanceMatrixClustering;
+use ficus_backend::features::clustering::featurisation::{
+    featurise_traces, FeaturesScaling, TracesFeatureSet, TracesFeatureSetConfig, TracesFeaturisation,
+};
+use ficus_backend::features::clustering::traces::distance_matrix::clusterize_log_by_traces_distance_matrix;
+use ficus_backend::features::clustering::traces::traces_params::{TracesClusteringParams, TracesRepresentationSource};
+use ficus_backend::utils::colors::ColorsHolder;
+use ficus_backend::utils::distance::distance::FicusDistance;
+
+fn create_traces(raw_traces: Vec<Vec<&str>>) -> Vec<Vec<Rc<RefCell<SimpleEvent>>>> {
+    let log = SimpleEventLog::new(&raw_traces);
+    log.traces().iter().map(|trace| trace.borrow().events().clone()).collect()
+}
+
+fn create_featurisation(configs: Vec<&str>) -> TracesFeaturisation {
+    TracesFeaturisation::parse(&configs.iter().map(|x| x.to_string()).collect())
+        .ok()
+        .unwrap()
+}
+
+#[test]
+fn test_feature_sets_parsing() {
+    assert_eq!(
+        TracesFeatureSetConfig::from_str("NGrams(2):ZScore"),
+        Ok(TracesFeatureSetConfig {
+            feature_set: TracesFeatureSet::NGrams(2),
+            scaling: FeaturesScaling::ZScore,
+        })
+    );
+
+    assert_eq!(
+        TracesFeatureSetConfig::from_str("Attributes(cost, time)"),
+        Ok(TracesFeatureSetConfig {
+            feature_set: TracesFeatureSet::Attributes(vec!["cost".to_string(), "time".to_string()]),
+            scaling: FeaturesScaling::MinMax,
+        })
+    );
+
+    for config in ["NGrams(0)", "NGrams", "Counts(1)", "Attributes()", "Counts:Bad", "Unknown"] {
+        assert!(TracesFeatureSetConfig::from_str(config).is_err());
+    }
+
+    let configs = vec!["Counts".to_string(), "TfIdf(x)".to_string()];
+    assert_eq!(TracesFeaturisation::parse(&configs), Err("TfIdf(x)".to_string()));
+}
+
+#[test]
+fn test_default_featurisation() {
+    let traces = create_traces(vec![vec!["A", "B", "B"], vec!["A", "C"]]);
+    let (values, names) = featurise_traces(&traces, None, &TracesFeaturisation::default());
+
+    assert_eq!(names, vec!["A", "B", "C"]);
+    assert_eq!(values, vec![vec![1.0, 1.0, 0.0], vec![1.0, 0.0, 1.0]]);
+}
+
+#[test]
+fn test_n_grams_and_transitions() {
+    let traces = create_traces(vec![vec!["A", "B", "A", "B"], vec!["A", "B"]]);
+    let (values, names) = featurise_traces(&traces, None, &create_featurisation(vec!["NGrams(2):None", "Transitions:None"]));
+
+    assert_eq!(names, vec!["(A, B)", "(B, A)", "A -> B", "B -> A"]);
+    assert_eq!(values, vec![vec![2.0, 1.0, 2.0 / 3.0, 1.0 / 3.0], vec![1.0, 0.0, 1.0, 0.0]]);
+}
+
+#[test]
+fn test_tf_idf() {
+    let traces = create_traces(vec![vec!["A", "B"], vec!["A", "C"]]);
+    let (values, names) = featurise_traces(&traces, None, &create_featurisation(vec!["TfIdf(1):None"]));
+
+    let rare_idf = 1.5f64.ln() + 1.0;
+    assert_eq!(names, vec!["tfidf(A)", "tfidf(B)", "tfidf(C)"]);
+    assert_eq!(values, vec![vec![0.5, 0.5 * rare_idf, 0.0], vec![0.5, 0.0, 0.5 * rare_idf]]);
+}
+
+#[test]
+fn test_z_score_scaling() {
+    let traces = create_traces(vec![vec!["A"], vec!["A", "A", "A"], vec!["B"]]);
+    let (values, _) = featurise_traces(&traces, None, &create_featurisation(vec!["Counts:ZScore"]));
+
+    let a_std = (14.0f64 / 9.0).sqrt();
+    let b_std = (2.0f64 / 9.0).sqrt();
+    let expected = vec![
+        vec![-1.0 / 3.0 / a_std, -1.0 / 3.0 / b_std],
+        vec![5.0 / 3.0 / a_std, -1.0 / 3.0 / b_std],
+        vec![-4.0 / 3.0 / a_std, 2.0 / 3.0 / b_std],
+    ];
+
+    for (row, expected_row) in values.iter().zip(expected.iter()) {
+        for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+            assert!((value - expected_value).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_time_features() {
+    let traces = create_traces(vec![vec!["A", "B", "C"], vec!["A"]]);
+    let (values, names) = featurise_traces(&traces, None, &create_featurisation(vec!["Time:None"]));
+
+    assert_eq!(
+        names,
+        vec!["duration", "mean_waiting_time", "max_waiting_time", "start_hour", "start_weekday"]
+    );
+    assert_eq!(values[0][..3], [2.0, 1.0, 1.0]);
+    assert_eq!(values[1][..3], [0.0, 0.0, 0.0]);
+}
+
+fn create_event_with_cost(cost: Option<EventPayloadValue>) -> Rc<RefCell<XesEventImpl>> {
+    let payload = cost.map(|cost| HashMap::from_iter(vec![("cost".to_string(), cost)]));
+    let stamp = DateTime::<Utc>::MIN_UTC + Duration::seconds(1);
+    Rc::new(RefCell::new(XesEventImpl::new_all_fields(
+        Rc::new(Box::new("A".to_string())),
+        stamp,
+        payload,
+    )))
+}
+
+#[test]
+fn test_attributes_features() {
+    let traces = vec![
+        vec![
+            create_event_with_cost(Some(EventPayloadValue::Int32(2))),
+            create_event_with_cost(Some(EventPayloadValue::Float64(4.0))),
+            create_event_with_cost(None),
+        ],
+        vec![create_event_with_cost(Some(EventPayloadValue::String(Rc::new(Box::new(
+            "x".to_string(),
+        )))))],
+    ];
+
+    let (values, names) = featurise_traces(&traces, None, &create_featurisation(vec!["Attributes(cost):None"]));
+
+    assert_eq!(names, vec!["mean(cost)", "sum(cost)"]);
+    assert_eq!(values, vec![vec![3.0, 6.0], vec![0.0, 0.0]]);
+}
+
+#[test]
+fn test_traces_clustering_with_featurisation() {
+    let log = SimpleEventLog::new(&vec![
+        vec!["A", "B", "C"],
+        vec!["A", "B", "C", "A", "B", "C"],
+        vec!["C", "B", "A"],
+        vec!["C", "B", "A", "C", "B", "A"],
+    ]);
+
+    let mut colors_holder = ColorsHolder::empty();
+    let mut params = TracesClusteringParams {
+        vis_params: CommonVisualizationParams {
+            log: &log,
+            colors_holder: &mut colors_holder,
+            class_extractor: None,
+        },
+        tolerance: 0.0,
+        distance: FicusDistance::L2,
+        repr_source: TracesRepresentationSource::Events,
+        featurisation: Some(create_featurisation(vec!["Transitions:None"])),
+    };
+
+    let clustering = DistanceMatrixClustering::Dbscan {
+        tolerance: 0.5,
+        min_points: 2,
+    };
+
+    let (_, dataset, _) = match clusterize_log_by_traces_distance_matrix(&mut params, &clustering) {
+        Ok(result) => result,
+        Err(_) => panic!("Failed to clusterize traces"),
+    };
+
+    assert_eq!(dataset.labels(), &vec![1, 1, 2, 2]);
+}
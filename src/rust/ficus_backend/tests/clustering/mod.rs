@@ -0,0 +1,5 @@
+pub mod agglomerative_tests;
+pub mod distance_matrix_tests;
+pub mod featurisation_tests;
+pub mod model_driven_tests;
+pub mod quality_tests;
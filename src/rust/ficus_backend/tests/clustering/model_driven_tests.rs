@@ -0,0 +1,128 @@
+use std::cell::Cell;
+
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::simple::simple_event_log::SimpleEventLog;
+use ficus_backend::features::clustering::traces::model_driven::{clusterize_log_by_traces_models, ModelDrivenClusteringParams};
+use ficus_backend::features::discovery::petri_net::marking::{Marking, SingleMarking};
+use ficus_backend::features::discovery::petri_net::petri_net::DefaultPetriNet;
+use ficus_backend::features::discovery::petri_net::place::Place;
+use ficus_backend::features::discovery::petri_net::transition::Transition;
+use ficus_backend::utils::colors::ColorsHolder;
+
+//The model is the sequence of the first trace events, so that other variants always decrease the fitness
+fn discover_first_trace_sequence(log: &SimpleEventLog) -> DefaultPetriNet {
+    let mut petri_net = DefaultPetriNet::empty();
+    let mut place_id = petri_net.add_place(Place::with_name("Start".to_owned()));
+    petri_net.set_initial_marking(Marking::new(vec![SingleMarking::new(place_id, 1)]));
+
+    for event in &log.to_raw_vector()[0] {
+        let transition_id = petri_net.add_transition(Transition::empty(event.to_owned(), false, None));
+        petri_net.connect_place_to_transition(&place_id, &transition_id, None);
+
+        place_id = petri_net.add_place(Place::with_name(format!("After_{}", event)));
+        petri_net.connect_transition_to_place(&transition_id, &place_id, None);
+    }
+
+    petri_net.set_final_marking(Marking::new(vec![SingleMarking::new(place_id, 1)]));
+    petri_net
+}
+
+fn create_test_log() -> SimpleEventLog {
+    SimpleEventLog::new(&vec![
+        vec!["A", "B", "C"],
+        vec!["A", "B", "D"],
+        vec!["A", "B", "C"],
+        vec!["A", "B", "D"],
+        vec!["A", "B", "C"],
+        vec!["X", "Y", "Z"],
+    ])
+}
+
+fn execute_model_driven_test(target_fitness: f64, max_clusters_count: Option<usize>, expected_labels: Vec<usize>) {
+    let log = create_test_log();
+    let mut colors_holder = ColorsHolder::empty();
+    let mut params = ModelDrivenClusteringParams {
+        log: &log,
+        colors_holder: &mut colors_holder,
+        miner: &discover_first_trace_sequence,
+        target_fitness,
+        max_clusters_count,
+        look_ahead_count: usize::MAX,
+    };
+
+    let clusters = match clusterize_log_by_traces_models(&mut params) {
+        Ok(clusters) => clusters,
+        Err(_) => panic!("Failed to clusterize traces"),
+    };
+
+    let clusters_count = expected_labels.iter().max().unwrap().to_owned();
+    let residual_count = expected_labels.iter().filter(|label| **label == 0).count();
+
+    assert_eq!(clusters.dataset().labels(), &expected_labels);
+    assert_eq!(clusters.logs().len(), clusters_count);
+    assert_eq!(clusters.petri_nets().len(), clusters_count);
+    assert_eq!(clusters.residual_log().traces().len(), residual_count);
+    assert_eq!(clusters.dataset().dataset().columns_names().len(), clusters_count);
+}
+
+#[test]
+fn test_model_driven_clustering() {
+    execute_model_driven_test(0.75, None, vec![1, 1, 1, 1, 1, 2]);
+}
+
+#[test]
+fn test_model_driven_clustering_perfect_fitness() {
+    execute_model_driven_test(1.0, None, vec![1, 2, 1, 2, 1, 3]);
+}
+
+#[test]
+fn test_model_driven_clustering_residual() {
+    execute_model_driven_test(0.75, Some(1), vec![1, 1, 1, 1, 1, 0]);
+}
+
+#[test]
+fn test_model_driven_clustering_fitness_dataset() {
+    let log = create_test_log();
+    let mut colors_holder = ColorsHolder::empty();
+    let mut params = ModelDrivenClusteringParams {
+        log: &log,
+        colors_holder: &mut colors_holder,
+        miner: &discover_first_trace_sequence,
+        target_fitness: 0.75,
+        max_clusters_count: None,
+        look_ahead_count: usize::MAX,
+    };
+
+    let clusters = clusterize_log_by_traces_models(&mut params).ok().unwrap();
+    let values = clusters.dataset().dataset().values();
+
+    assert_eq!(values[0], vec![1.0, 0.0]);
+    assert_eq!(values[1], vec![0.5, 0.0]);
+    assert_eq!(values[5], vec![0.0, 1.0]);
+    assert_eq!(clusters.logs()[0].to_raw_vector()[1], vec!["A", "B", "D"]);
+}
+
+#[test]
+fn test_model_driven_clustering_look_ahead() {
+    let log = create_test_log();
+    let mut colors_holder = ColorsHolder::empty();
+    let discoveries_count = Cell::new(0);
+    let miner = |log: &SimpleEventLog| {
+        discoveries_count.set(discoveries_count.get() + 1);
+        discover_first_trace_sequence(log)
+    };
+
+    let mut params = ModelDrivenClusteringParams {
+        log: &log,
+        colors_holder: &mut colors_holder,
+        miner: &miner,
+        target_fitness: 1.0,
+        max_clusters_count: None,
+        look_ahead_count: 1,
+    };
+
+    let clusters = clusterize_log_by_traces_models(&mut params).ok().unwrap();
+
+    assert_eq!(clusters.dataset().labels(), &vec![1, 2, 1, 2, 1, 3]);
+    assert_eq!(discoveries_count.get(), 5);
+}
@@ -0,0 +1,138 @@
+use ficus_backend::features::clustering::distance_matrix::{
+    build_distance_matrix, search_distance_matrix_clustering, DistanceMatrixClustering,
+};
+use ficus_backend::features::clustering::quality::{
+    calculate_clustering_quality, ClusteringQualityIndex, ClusteringScores, ClusteringSearchParameter,
+};
+
+fn create_points_distances(points: &Vec<f64>) -> Vec<Vec<f64>> {
+    build_distance_matrix(points, |first, second| (first - second).abs())
+}
+
+fn create_names(count: usize) -> Vec<String> {
+    (0..count).map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn test_clustering_quality() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 10.0, 11.0]);
+    let quality = calculate_clustering_quality(&distances, &vec![Some(0), Some(0), Some(1), Some(1)]);
+
+    assert_eq!(quality.clusters_count(), 2);
+    assert_eq!(quality.noise_count(), 0);
+    assert!((quality.silhouette() - (9.5 / 10.5 + 8.5 / 9.5) / 2.0).abs() < 1e-9);
+    assert!((quality.davies_bouldin() - 0.1).abs() < 1e-9);
+    assert!((quality.calinski_harabasz() - 200.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_clustering_quality_undefined() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 10.0, 11.0]);
+    let quality = calculate_clustering_quality(&distances, &vec![Some(0), Some(0), None, None]);
+
+    assert_eq!(quality.clusters_count(), 1);
+    assert_eq!(quality.noise_count(), 2);
+    assert!(quality.silhouette().is_nan());
+    assert!(quality.davies_bouldin().is_nan());
+    assert!(quality.calinski_harabasz().is_nan());
+}
+
+#[test]
+fn test_calinski_harabasz_without_inner_dispersion() {
+    let distances = create_points_distances(&vec![0.0, 0.0, 5.0, 5.0, 5.0]);
+    let quality = calculate_clustering_quality(&distances, &vec![Some(0), Some(0), Some(1), Some(1), Some(1)]);
+    assert_eq!(quality.calinski_harabasz(), f64::INFINITY);
+
+    let distances = create_points_distances(&vec![3.0; 4]);
+    let quality = calculate_clustering_quality(&distances, &vec![Some(0), Some(0), Some(1), Some(1)]);
+    assert!(quality.calinski_harabasz().is_nan());
+}
+
+#[test]
+fn test_best_configuration() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 10.0, 11.0]);
+
+    let mut scores = ClusteringScores::default();
+    scores.push("one".to_string(), calculate_clustering_quality(&distances, &vec![Some(0); 4]));
+    scores.push(
+        "bad".to_string(),
+        calculate_clustering_quality(&distances, &vec![Some(0), Some(1), Some(0), Some(1)]),
+    );
+    scores.push(
+        "good".to_string(),
+        calculate_clustering_quality(&distances, &vec![Some(0), Some(0), Some(1), Some(1)]),
+    );
+
+    assert_eq!(scores.best(ClusteringQualityIndex::Silhouette), Some(2));
+    assert_eq!(scores.best(ClusteringQualityIndex::DaviesBouldin), Some(2));
+    assert_eq!(scores.best(ClusteringQualityIndex::CalinskiHarabasz), Some(2));
+
+    let dataset = scores.to_dataset();
+    assert_eq!(dataset.row_names(), &vec!["one".to_string(), "bad".to_string(), "good".to_string()]);
+    assert_eq!(dataset.columns_names().len(), 5);
+}
+
+#[test]
+fn test_dbscan_tolerance_search() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 2.0, 10.0, 11.0, 12.0]);
+    let clustering = DistanceMatrixClustering::Dbscan {
+        tolerance: 0.0,
+        min_points: 2,
+    };
+
+    let parameter = ClusteringSearchParameter::Tolerance;
+    let index = ClusteringQualityIndex::Silhouette;
+    let (best, scores) = match search_distance_matrix_clustering(&distances, &create_names(6), &clustering, parameter, index) {
+        Ok(result) => result,
+        Err(_) => panic!("Failed to search tolerance"),
+    };
+
+    assert_eq!(
+        best,
+        DistanceMatrixClustering::Dbscan {
+            tolerance: 1.0,
+            min_points: 2
+        }
+    );
+
+    assert_eq!(scores.qualities().len(), 7);
+}
+
+#[test]
+fn test_k_medoids_clusters_count_search() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 2.0, 10.0, 11.0, 12.0]);
+    let clustering = DistanceMatrixClustering::KMedoids {
+        clusters_count: 0,
+        iterations_count: 10,
+    };
+
+    let parameter = ClusteringSearchParameter::ClustersCount;
+    let index = ClusteringQualityIndex::CalinskiHarabasz;
+    let (best, scores) = match search_distance_matrix_clustering(&distances, &create_names(6), &clustering, parameter, index) {
+        Ok(result) => result,
+        Err(_) => panic!("Failed to search clusters count"),
+    };
+
+    assert_eq!(
+        best,
+        DistanceMatrixClustering::KMedoids {
+            clusters_count: 2,
+            iterations_count: 10
+        }
+    );
+
+    assert_eq!(scores.qualities().len(), 4);
+}
+
+#[test]
+fn test_unsupported_search() {
+    let distances = create_points_distances(&vec![0.0, 1.0, 2.0]);
+    let clustering = DistanceMatrixClustering::Dbscan {
+        tolerance: 1.0,
+        min_points: 2,
+    };
+
+    let parameter = ClusteringSearchParameter::ClustersCount;
+    let index = ClusteringQualityIndex::Silhouette;
+    assert!(search_distance_matrix_clustering(&distances, &create_names(3), &clustering, parameter, index).is_err());
+}
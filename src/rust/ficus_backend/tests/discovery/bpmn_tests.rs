@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use ficus_backend::{
+    event_log::simple::simple_event_log::SimpleEventLog,
+    features::discovery::{
+        bpmn::{
+            bpmn_model::{BpmnModel, BpmnNodeKind},
+            bpmn_serialization::serialize_bpmn,
+            heuristic_to_bpmn::discover_bpmn_heuristic,
+            petri_net_to_bpmn::convert_petri_net_to_bpmn,
+            process_tree_to_bpmn::convert_process_tree_to_bpmn,
+        },
+        local_process_models::process_tree::ProcessTreeNode,
+        petri_net::{petri_net::DefaultPetriNet, place::Place, transition::Transition},
+    },
+};
+
+#[test]
+fn test_sequence_petri_net_to_bpmn() {
+    let net = create_petri_net(&[(&[0], "A", &[1]), (&[1], "B", &[2])], 3);
+    let model = convert_petri_net_to_bpmn(&net);
+
+    assert_nodes_counts(&model, 2, 0, 0);
+    assert_eq!(model.flows().len(), 3);
+
+    let a = model.find_task_by_name("A").unwrap().id();
+    let b = model.find_task_by_name("B").unwrap().id();
+    assert_eq!(model.outgoing_flows(a)[0].target_id(), b);
+}
+
+#[test]
+fn test_parallel_petri_net_to_bpmn() {
+    let net = create_petri_net(
+        &[(&[0], "A", &[1, 2]), (&[1], "B", &[3]), (&[2], "C", &[4]), (&[3, 4], "D", &[5])],
+        6,
+    );
+    let model = convert_petri_net_to_bpmn(&net);
+
+    assert_nodes_counts(&model, 4, 0, 2);
+}
+
+#[test]
+fn test_choice_petri_net_to_bpmn() {
+    let net = create_petri_net(&[(&[0], "A", &[1]), (&[1], "B", &[2]), (&[1], "C", &[2]), (&[2], "D", &[3])], 4);
+    let model = convert_petri_net_to_bpmn(&net);
+
+    assert_nodes_counts(&model, 4, 2, 0);
+}
+
+#[test]
+fn test_heuristic_parallel_bpmn() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C", "D"], vec!["A", "C", "B", "D"]]);
+    let model = discover_bpmn_heuristic(&log, 0.2, 1, 1.0, 0.1, 0.5);
+
+    assert_nodes_counts(&model, 4, 0, 2);
+    assert_eq!(model.flows().len(), 8);
+}
+
+#[test]
+fn test_heuristic_choice_bpmn() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "D"], vec!["A", "C", "D"]]);
+    let model = discover_bpmn_heuristic(&log, 0.2, 1, 1.0, 0.1, 0.5);
+
+    assert_nodes_counts(&model, 4, 2, 0);
+    assert_eq!(model.flows().len(), 8);
+}
+
+#[test]
+fn test_process_tree_to_bpmn() {
+    let tree = ProcessTreeNode::Sequence(vec![
+        ProcessTreeNode::Activity(1),
+        ProcessTreeNode::Choice(vec![ProcessTreeNode::Activity(2), ProcessTreeNode::Activity(3)]),
+        ProcessTreeNode::Loop(Box::new(ProcessTreeNode::Activity(4)), Box::new(ProcessTreeNode::Activity(5))),
+    ]);
+
+    let names = HashMap::from_iter(vec![(1, "A".to_string()), (2, "B".to_string())]);
+    let model = convert_process_tree_to_bpmn(&tree, &names);
+
+    assert_nodes_counts(&model, 5, 4, 0);
+    assert_eq!(model.flows().len(), 12);
+    assert!(model.find_task_by_name("B").is_some());
+    assert!(model.find_task_by_name("5").is_some());
+}
+
+#[test]
+fn test_bpmn_serialization() {
+    let net = create_petri_net(&[(&[0], "A", &[1]), (&[1], "B", &[2])], 3);
+    let xml = serialize_bpmn(&convert_petri_net_to_bpmn(&net)).ok().unwrap();
+
+    assert!(xml.contains("<bpmn:startEvent"));
+    assert!(xml.contains("name=\"A\""));
+    assert!(xml.contains("<bpmn:sequenceFlow"));
+    assert_eq!(xml.matches("<bpmndi:BPMNShape").count(), 4);
+    assert_eq!(xml.matches("<bpmndi:BPMNEdge").count(), 3);
+    assert_eq!(xml.matches("<di:waypoint").count(), 6);
+}
+
+fn create_petri_net(transitions: &[(&[usize], &str, &[usize])], places_count: usize) -> DefaultPetriNet {
+    let mut net = DefaultPetriNet::empty();
+    let places: Vec<u64> = (0..places_count)
+        .map(|index| net.add_place(Place::with_name(format!("p{}", index))))
+        .collect();
+
+    for (incoming, name, outgoing) in transitions {
+        let id = net.add_transition(Transition::empty(name.to_string(), false, Some(name.to_string())));
+        for place in incoming.iter() {
+            net.connect_place_to_transition(&places[*place], &id, None);
+        }
+
+        for place in outgoing.iter() {
+            net.connect_transition_to_place(&id, &places[*place], None);
+        }
+    }
+
+    net
+}
+
+fn assert_nodes_counts(model: &BpmnModel, tasks: usize, exclusive_gateways: usize, parallel_gateways: usize) {
+    assert_eq!(model.nodes_count(BpmnNodeKind::StartEvent), 1);
+    assert_eq!(model.nodes_count(BpmnNodeKind::EndEvent), 1);
+    assert_eq!(model.nodes_count(BpmnNodeKind::Task), tasks);
+    assert_eq!(model.nodes_count(BpmnNodeKind::ExclusiveGateway), exclusive_gateways);
+    assert_eq!(model.nodes_count(BpmnNodeKind::ParallelGateway), parallel_gateways);
+}
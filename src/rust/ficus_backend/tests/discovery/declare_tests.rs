@@ -0,0 +1,166 @@
+use ficus_backend::{
+    event_log::simple::simple_event_log::SimpleEventLog,
+    features::discovery::declare::{
+        declare_conformance::check_declare_conformance,
+        declare_discovery::{discover_declare_model, DeclareDiscoveryParams},
+        declare_model::{
+            check_constraint, DeclareConstraint, DeclareConstraintCheckResult, DeclareConstraintMetrics, DeclareModel, DeclareTemplate,
+        },
+        json_serialization::{read_declare_model_from_json, serialize_declare_model_to_json},
+    },
+};
+
+#[test]
+fn test_response() {
+    execute_check_test(DeclareTemplate::Response, &["A", "B"], &["A", "B", "A", "C"], (2, 1, 1));
+}
+
+#[test]
+fn test_precedence() {
+    execute_check_test(DeclareTemplate::Precedence, &["A", "B"], &["B", "A", "B"], (2, 1, 1));
+}
+
+#[test]
+fn test_alternate_response() {
+    execute_check_test(DeclareTemplate::AlternateResponse, &["A", "B"], &["A", "A", "B"], (2, 1, 1));
+}
+
+#[test]
+fn test_chain_succession() {
+    execute_check_test(DeclareTemplate::ChainSuccession, &["A", "B"], &["A", "B", "A", "C"], (3, 2, 1));
+}
+
+#[test]
+fn test_not_co_existence() {
+    execute_check_test(DeclareTemplate::NotCoExistence, &["A", "C"], &["A", "B"], (1, 1, 0));
+    execute_check_test(DeclareTemplate::NotCoExistence, &["A", "B"], &["A", "B"], (2, 0, 2));
+}
+
+#[test]
+fn test_unary_templates() {
+    execute_check_test(DeclareTemplate::Init, &["A"], &["A", "B"], (1, 1, 0));
+    execute_check_test(DeclareTemplate::End, &["A"], &["A", "B"], (1, 0, 1));
+    execute_check_test(DeclareTemplate::ExactlyOne, &["A"], &["A", "B", "A"], (1, 0, 1));
+    execute_check_test(DeclareTemplate::Absence, &["C"], &["A", "B"], (1, 1, 0));
+}
+
+fn execute_check_test(template: DeclareTemplate, parameters: &[&str], trace: &[&str], expected: (usize, usize, usize)) {
+    let parameters = parameters.iter().map(|parameter| parameter.to_string()).collect();
+    let constraint = DeclareConstraint::new(template, parameters, None).unwrap();
+    let trace: Vec<String> = trace.iter().map(|event| event.to_string()).collect();
+
+    let result = check_constraint(&constraint, &trace);
+    assert_eq!((result.activations, result.fulfilments, result.violations), expected);
+}
+
+#[test]
+fn test_declare_discovery() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["A", "C"], vec!["A", "B", "C"]]);
+    let model = discover_declare_model(&log, &DeclareDiscoveryParams::with_all_templates(1.0, 1.0));
+
+    assert!(model.find(DeclareTemplate::Init, &["A"]).is_some());
+    assert!(model.find(DeclareTemplate::End, &["C"]).is_some());
+    assert!(model.find(DeclareTemplate::Response, &["A", "C"]).is_some());
+    assert!(model.find(DeclareTemplate::Precedence, &["A", "B"]).is_some());
+    assert!(model.find(DeclareTemplate::ChainResponse, &["B", "C"]).is_some());
+
+    assert!(model.find(DeclareTemplate::Init, &["B"]).is_none());
+    assert!(model.find(DeclareTemplate::Existence, &["B"]).is_none());
+    assert!(model.find(DeclareTemplate::Response, &["A", "B"]).is_none());
+    assert!(model.find(DeclareTemplate::NotCoExistence, &["A", "B"]).is_none());
+
+    let metrics = model.find(DeclareTemplate::Precedence, &["A", "B"]).unwrap().metrics().unwrap();
+    assert_eq!(
+        *metrics,
+        DeclareConstraintMetrics {
+            support: 1.0,
+            confidence: 1.0
+        }
+    );
+}
+
+#[test]
+fn test_declare_discovery_support_threshold() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["A", "C"], vec!["A", "B", "C"]]);
+    let model = discover_declare_model(&log, &DeclareDiscoveryParams::with_all_templates(0.6, 0.6));
+
+    let metrics = model.find(DeclareTemplate::Existence, &["B"]).unwrap().metrics().unwrap();
+    assert_eq!(metrics.support, 2.0 / 3.0);
+    assert!(model.find(DeclareTemplate::Absence, &["B"]).is_none());
+}
+
+#[test]
+fn test_declare_conformance() {
+    let model = DeclareModel::new(vec![
+        create_constraint(DeclareTemplate::Response, &["A", "B"], None),
+        create_constraint(DeclareTemplate::ChainResponse, &["B", "C"], None),
+    ]);
+
+    let log = SimpleEventLog::new(&vec![vec!["A", "C", "B"], vec!["A", "B", "C"]]);
+    let conformance = check_declare_conformance(&log, &model);
+
+    let results: Vec<Vec<DeclareConstraintCheckResult>> = conformance
+        .traces()
+        .iter()
+        .map(|trace| trace.constraints_results().clone())
+        .collect();
+
+    assert_eq!(
+        results,
+        vec![
+            vec![create_check_result(1, 1, 0), create_check_result(1, 0, 1)],
+            vec![create_check_result(1, 1, 0), create_check_result(1, 1, 0)],
+        ]
+    );
+
+    assert_eq!(conformance.traces()[0].fitness(), 0.5);
+    assert_eq!(conformance.fitness(), 0.75);
+}
+
+#[test]
+fn test_declare_json_round_trip() {
+    let model = DeclareModel::new(vec![
+        create_constraint(
+            DeclareTemplate::Init,
+            &["A"],
+            Some(DeclareConstraintMetrics {
+                support: 1.0,
+                confidence: 1.0,
+            }),
+        ),
+        create_constraint(
+            DeclareTemplate::Response,
+            &["A", "B"],
+            Some(DeclareConstraintMetrics {
+                support: 0.75,
+                confidence: 0.5,
+            }),
+        ),
+        create_constraint(DeclareTemplate::NotChainSuccession, &["B", "A"], None),
+    ]);
+
+    let json = serialize_declare_model_to_json(&model);
+    assert_eq!(read_declare_model_from_json(json.as_str()).unwrap(), model);
+}
+
+#[test]
+fn test_declare_json_invalid_model() {
+    let unknown_template = r#"{"constraints": [{"template": "Unknown", "parameters": ["A"]}]}"#;
+    assert!(read_declare_model_from_json(unknown_template).is_err());
+
+    let wrong_arity = r#"{"constraints": [{"template": "Response", "parameters": ["A"]}]}"#;
+    assert!(read_declare_model_from_json(wrong_arity).is_err());
+}
+
+fn create_constraint(template: DeclareTemplate, parameters: &[&str], metrics: Option<DeclareConstraintMetrics>) -> DeclareConstraint {
+    let parameters = parameters.iter().map(|parameter| parameter.to_string()).collect();
+    DeclareConstraint::new(template, parameters, metrics).unwrap()
+}
+
+fn create_check_result(activations: usize, fulfilments: usize, violations: usize) -> DeclareConstraintCheckResult {
+    DeclareConstraintCheckResult {
+        activations,
+        fulfilments,
+        violations,
+    }
+}
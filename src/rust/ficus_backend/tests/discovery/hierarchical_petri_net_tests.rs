@@ -0,0 +1,98 @@
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+
+use ficus_backend::{
+    event_log::{
+        core::event::event_hasher::default_class_extractor,
+        simple::simple_event_log::{SimpleEvent, SimpleEventLog},
+    },
+    features::{
+        analysis::{
+            event_log_info::{EventLogInfo, EventLogInfoCreationDto},
+            patterns::{
+                activity_instances::{
+                    create_activity_name, ActivityInTraceFilterKind, ActivityNarrowingKind, UndefActivityHandlingStrategy,
+                },
+                contexts::{
+                    ActivitiesDiscoveryContext, ActivitiesInstancesDiscoveryContext, PatternsDiscoveryContext, PatternsDiscoveryStrategy,
+                },
+                entry_points::{discover_activities_and_create_new_log, PatternsKind},
+            },
+        },
+        discovery::{
+            alpha::{alpha::discover_petri_net_alpha, providers::alpha_provider::DefaultAlphaRelationsProvider},
+            hierarchy::hierarchical_petri_net::{discover_hierarchical_petri_net, HierarchicalPetriNet, ROOT_PROCESS_NAME},
+            petri_net::{petri_net::DefaultPetriNet, pnml_serialization::serialize_hierarchical_to_pnml},
+        },
+    },
+};
+
+use crate::test_core::simple_events_logs_provider::create_log_from_taxonomy_of_patterns;
+
+#[test]
+fn test_hierarchical_petri_net_structure() {
+    let net = discover_test_hierarchical_petri_net();
+
+    assert_eq!(net.name(), ROOT_PROCESS_NAME);
+    assert_eq!(net.depth(), 2);
+    assert_eq!(get_transitions_names(net.petri_net()), vec!["(a)::(b)::(c)"]);
+
+    let subprocesses_names: Vec<&String> = net.subprocesses().iter().map(|subprocess| subprocess.name()).collect();
+    assert_eq!(subprocesses_names, vec!["(a)::(b)::(c)"]);
+
+    let subprocess = net.subprocess("(a)::(b)::(c)").unwrap();
+    assert_eq!(get_transitions_names(subprocess.petri_net()), vec!["a", "b", "c"]);
+    assert!(subprocess.subprocesses().is_empty());
+}
+
+#[test]
+fn test_hierarchical_petri_net_pnml_pages() {
+    let net = discover_test_hierarchical_petri_net();
+    let pnml = serialize_hierarchical_to_pnml(&net, true).ok().unwrap();
+
+    assert!(pnml.contains("<page id=\"Root\">"));
+    assert!(pnml.contains("<page id=\"Root/(a)::(b)::(c)\">"));
+    assert!(pnml.contains("<transition id=\"Root/(a)::(b)::(c)\">"));
+    assert!(pnml.contains("<transition id=\"Root/(a)::(b)::(c)/a\">"));
+}
+
+fn discover_test_hierarchical_petri_net() -> HierarchicalPetriNet {
+    let log = Rc::new(RefCell::new(create_log_from_taxonomy_of_patterns()));
+
+    let patterns_context = PatternsDiscoveryContext::new(
+        Rc::clone(&log),
+        PatternsKind::PrimitiveTandemArrays(20),
+        PatternsDiscoveryStrategy::FromAllTraces,
+        default_class_extractor,
+    );
+
+    let context = ActivitiesDiscoveryContext::new(
+        patterns_context,
+        0,
+        0,
+        ActivityNarrowingKind::NarrowDown,
+        ActivityInTraceFilterKind::DefaultFilter,
+        |sub_array| create_activity_name(log.borrow().deref(), sub_array, None),
+    );
+
+    let context = ActivitiesInstancesDiscoveryContext::new(context, UndefActivityHandlingStrategy::<SimpleEvent>::DontInsert, |info| {
+        Rc::new(RefCell::new(SimpleEvent::new_with_min_date(info.node.borrow().name.to_string())))
+    });
+
+    let abstracted_log = discover_activities_and_create_new_log(&context);
+
+    discover_hierarchical_petri_net(&abstracted_log, &|log: &SimpleEventLog| {
+        let info = EventLogInfo::create_from(EventLogInfoCreationDto::default(log));
+        discover_petri_net_alpha(&DefaultAlphaRelationsProvider::new(&info))
+    })
+}
+
+fn get_transitions_names(net: &DefaultPetriNet) -> Vec<String> {
+    let mut names: Vec<String> = net
+        .all_transitions()
+        .iter()
+        .map(|transition| transition.name().to_owned())
+        .collect();
+    names.sort();
+
+    names
+}
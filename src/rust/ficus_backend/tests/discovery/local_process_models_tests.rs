@@ -0,0 +1,105 @@
+use std::collections::{HashMap, HashSet};
+
+use ficus_backend::features::discovery::local_process_models::{
+    local_process_models::{discover_local_process_models, LocalProcessModelsDiscoveryParams},
+    process_tree::ProcessTreeNode,
+};
+
+#[test]
+fn test_sequence_language() {
+    let tree = ProcessTreeNode::Sequence(vec![ProcessTreeNode::Activity(1), ProcessTreeNode::Activity(2)]);
+    assert_eq!(tree.language(10, 100).unwrap(), HashSet::from_iter(vec![vec![1, 2]]));
+}
+
+#[test]
+fn test_parallel_language() {
+    let tree = ProcessTreeNode::Parallel(vec![
+        ProcessTreeNode::Activity(1),
+        ProcessTreeNode::Sequence(vec![ProcessTreeNode::Activity(2), ProcessTreeNode::Activity(3)]),
+    ]);
+
+    assert_eq!(
+        tree.language(10, 100).unwrap(),
+        HashSet::from_iter(vec![vec![1, 2, 3], vec![2, 1, 3], vec![2, 3, 1]])
+    );
+}
+
+#[test]
+fn test_loop_language() {
+    let tree = ProcessTreeNode::Loop(Box::new(ProcessTreeNode::Activity(1)), Box::new(ProcessTreeNode::Activity(2)));
+
+    assert_eq!(
+        tree.language(10, 100).unwrap(),
+        HashSet::from_iter(vec![vec![1], vec![1, 2, 1], vec![1, 2, 1, 2, 1]])
+    );
+    assert_eq!(tree.language(3, 100).unwrap(), HashSet::from_iter(vec![vec![1], vec![1, 2, 1]]));
+}
+
+#[test]
+fn test_choice_language() {
+    let tree = ProcessTreeNode::Choice(vec![ProcessTreeNode::Activity(1), ProcessTreeNode::Activity(2)]);
+    assert_eq!(tree.language(10, 100).unwrap(), HashSet::from_iter(vec![vec![1], vec![2]]));
+}
+
+#[test]
+fn test_language_words_count_limit() {
+    let tree = ProcessTreeNode::Parallel((1..=5).map(ProcessTreeNode::Activity).collect());
+
+    assert_eq!(tree.language(5, 120).unwrap().len(), 120);
+    assert_eq!(tree.language(5, 119), None);
+}
+
+#[test]
+fn test_normalization() {
+    let tree = ProcessTreeNode::Sequence(vec![
+        ProcessTreeNode::Activity(1),
+        ProcessTreeNode::Sequence(vec![
+            ProcessTreeNode::Activity(2),
+            ProcessTreeNode::Choice(vec![ProcessTreeNode::Activity(4), ProcessTreeNode::Activity(3)]),
+        ]),
+    ]);
+
+    assert_eq!(tree.normalize().to_string(), "->(1, 2, X(3, 4))");
+}
+
+#[test]
+fn test_to_string_with_names() {
+    let tree = ProcessTreeNode::Loop(
+        Box::new(ProcessTreeNode::Parallel(vec![
+            ProcessTreeNode::Activity(1),
+            ProcessTreeNode::Activity(2),
+        ])),
+        Box::new(ProcessTreeNode::Activity(3)),
+    );
+
+    let names = HashMap::from_iter(vec![(1, "a".to_string()), (2, "b".to_string()), (3, "c".to_string())]);
+    assert_eq!(tree.to_string_with_names(&names), "*(+(a, b), c)");
+}
+
+#[test]
+fn test_local_process_models_discovery() {
+    let log = vec![vec![1, 2, 3, 4], vec![5, 1, 2, 3], vec![1, 2, 3, 1, 2, 3], vec![6, 1, 4, 2, 3]];
+
+    let params = LocalProcessModelsDiscoveryParams {
+        window_size: 4,
+        min_support: 2,
+        max_activities_count: 3,
+        top_models_count: 5,
+    };
+
+    let models = discover_local_process_models(&log, &params);
+
+    assert_eq!(models.len(), 5);
+
+    let best_model = models.first().unwrap();
+    assert_eq!(best_model.tree().to_string(), "->(1, 2, 3)");
+    assert_eq!(best_model.support(), 5);
+    assert_eq!(best_model.confidence(), 1.0);
+    assert_eq!(best_model.language_fit(), 1.0);
+    assert_eq!(best_model.determinism(), 1.0);
+
+    for model in &models {
+        assert!(model.support() >= params.min_support);
+        assert!(model.tree().activities().len() > 1);
+    }
+}
@@ -1,3 +1,8 @@
 pub mod alpha_nfc_plus_plus_tests;
 pub mod alpha_tests;
+pub mod bpmn_tests;
+pub mod declare_tests;
 pub mod heuristic_miner_tests;
+pub mod hierarchical_petri_net_tests;
+pub mod local_process_models_tests;
+pub mod transition_system_tests;
@@ -0,0 +1,199 @@
+use ficus_backend::event_log::simple::simple_event_log::SimpleEventLog;
+use ficus_backend::features::discovery::petri_net::petri_net::DefaultPetriNet;
+use ficus_backend::features::discovery::petri_net::replay::{calculate_token_replay_fitness, replay_petri_net};
+use ficus_backend::features::discovery::transition_system::regions::{synthesize_petri_net_from_regions, DEFAULT_MAX_REGIONS_SEARCH_STEPS};
+use ficus_backend::features::discovery::transition_system::transition_system::{
+    StateAbstraction, StateDirection, TransitionSystem, TransitionSystemParams,
+};
+
+fn to_state(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+fn create_params(abstraction: StateAbstraction, direction: StateDirection, horizon: Option<usize>) -> TransitionSystemParams {
+    TransitionSystemParams {
+        abstraction,
+        direction,
+        horizon,
+    }
+}
+
+fn synthesize_regions_petri_net(system: &TransitionSystem) -> DefaultPetriNet {
+    let result = synthesize_petri_net_from_regions(system, DEFAULT_MAX_REGIONS_SEARCH_STEPS)
+        .ok()
+        .unwrap();
+    assert!(result.not_excitation_closed_labels().is_empty());
+
+    result.into_petri_net()
+}
+
+fn get_transitions_count(net: &DefaultPetriNet, name: &str) -> usize {
+    net.find_all_transitions_by_name(name).map_or(0, |transitions| transitions.len())
+}
+
+#[test]
+fn test_past_sequence_transition_system() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["A", "C", "B"], vec!["A", "B", "C"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Past, None));
+
+    assert_eq!(system.states().len(), 6);
+    assert_eq!(system.transitions().len(), 5);
+    assert_eq!(system.initial_states(), &vec![system.find_state(&[]).unwrap()]);
+
+    let final_states = vec![
+        system.find_state(&to_state(&["A", "B", "C"])).unwrap(),
+        system.find_state(&to_state(&["A", "C", "B"])).unwrap(),
+    ];
+
+    assert_eq!(system.final_states(), &final_states);
+
+    let transition = system.transitions().iter().find(|transition| transition.label() == "B").unwrap();
+    assert_eq!(transition.from(), system.find_state(&to_state(&["A"])).unwrap());
+    assert_eq!(transition.count(), 2);
+}
+
+//Set and multiset abstractions merge states reached by interleavings, horizon keeps only the nearest events
+#[test]
+fn test_state_abstractions() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["A", "C", "B"], vec!["A", "B", "B"]]);
+
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Set, StateDirection::Past, None));
+    assert_eq!(system.states().len(), 5);
+    assert!(system.find_state(&to_state(&["A", "B", "C"])).is_some());
+    assert!(system.find_state(&to_state(&["A", "B"])).is_some());
+
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Multiset, StateDirection::Past, None));
+    assert_eq!(system.states().len(), 6);
+    assert!(system.find_state(&to_state(&["A", "B", "B"])).is_some());
+
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Past, Some(1)));
+    assert_eq!(system.states().len(), 4);
+    assert!(system.find_state(&to_state(&["B"])).is_some());
+    assert!(system.find_state(&to_state(&["A", "B"])).is_none());
+}
+
+#[test]
+fn test_future_transition_system() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B"], vec!["C", "B"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Future, Some(1)));
+
+    let initial_states = vec![
+        system.find_state(&to_state(&["A"])).unwrap(),
+        system.find_state(&to_state(&["C"])).unwrap(),
+    ];
+
+    assert_eq!(system.initial_states(), &initial_states);
+    assert_eq!(system.final_states(), &vec![system.find_state(&[]).unwrap()]);
+    assert_eq!(system.states().len(), 4);
+}
+
+#[test]
+fn test_transition_system_graph() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B"], vec!["A", "B"]]);
+    let graph = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Past, None)).to_graph();
+
+    let mut nodes = graph
+        .all_nodes()
+        .iter()
+        .map(|node| node.data().unwrap().to_owned())
+        .collect::<Vec<String>>();
+    nodes.sort();
+    assert_eq!(nodes, vec!["[A, B]", "[A]", "[]"]);
+
+    let mut edges = graph
+        .all_edges()
+        .iter()
+        .map(|edge| edge.data().unwrap().to_string())
+        .collect::<Vec<String>>();
+    edges.sort();
+    assert_eq!(edges, vec!["A (2)", "B (2)"]);
+}
+
+#[test]
+fn test_regions_sequence() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Past, None));
+    let net = synthesize_regions_petri_net(&system);
+
+    assert_eq!(net.all_places().len(), 4);
+    assert_eq!(net.all_transitions().len(), 3);
+    assert_eq!(net.initial_marking().unwrap().active_places().len(), 1);
+    assert_eq!(net.final_marking().unwrap().active_places().len(), 1);
+    assert_eq!(calculate_token_replay_fitness(&log, &net), 1.0);
+}
+
+//Interleavings of B and C are represented with two concurrent branches instead of the choice
+#[test]
+fn test_regions_concurrency() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C", "D"], vec!["A", "C", "B", "D"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Set, StateDirection::Past, None));
+    let net = synthesize_regions_petri_net(&system);
+
+    assert_eq!(net.all_transitions().len(), 4);
+    assert_eq!(net.all_places().len(), 6);
+
+    let a = net.find_transition_by_name("A").unwrap();
+    assert_eq!(a.outgoing_arcs().len(), 2);
+
+    let d = net.find_transition_by_name("D").unwrap();
+    assert_eq!(d.incoming_arcs().len(), 2);
+
+    assert_eq!(calculate_token_replay_fitness(&log, &net), 1.0);
+}
+
+//Repeated B has no pre-region in the safe net, so the label is split into two transitions with the same name
+#[test]
+fn test_regions_label_splitting() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "B"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Past, None));
+    let net = synthesize_regions_petri_net(&system);
+
+    assert_eq!(get_transitions_count(&net, "B"), 2);
+    assert_eq!(get_transitions_count(&net, "A"), 1);
+
+    let replay = replay_petri_net(&log, &net).unwrap();
+    assert!(replay.iter().all(|state| state.is_some()));
+}
+
+//Several initial states are reached from the virtual initial state by the silent transition, which puts the token into the choice place,
+//so A and C are enabled in both initial states and are reported as not excitation closed
+#[test]
+fn test_regions_multiple_initial_states() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B"], vec!["C", "B"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Future, Some(1)));
+    let result = synthesize_petri_net_from_regions(&system, DEFAULT_MAX_REGIONS_SEARCH_STEPS)
+        .ok()
+        .unwrap();
+    assert_eq!(result.not_excitation_closed_labels(), &vec!["A".to_owned(), "C".to_owned()]);
+
+    let net = result.into_petri_net();
+
+    let silent_transitions = net.all_transitions().iter().filter(|transition| *transition.is_silent()).count();
+    assert_eq!(silent_transitions, 1);
+    assert_eq!(net.initial_marking().unwrap().active_places().len(), 1);
+
+    let replay = replay_petri_net(&log, &net).unwrap();
+    assert!(replay.iter().all(|state| state.is_some()));
+}
+
+//Split labels with one transition which are still not excitation closed can not be split further and are reported
+#[test]
+fn test_regions_not_excitation_closed_labels() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["A", "C"], vec!["B", "A"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Sequence, StateDirection::Past, None));
+    let result = synthesize_petri_net_from_regions(&system, DEFAULT_MAX_REGIONS_SEARCH_STEPS)
+        .ok()
+        .unwrap();
+
+    assert_eq!(result.not_excitation_closed_labels(), &vec!["C".to_owned()]);
+    assert_eq!(get_transitions_count(result.petri_net(), "C"), 2);
+}
+
+#[test]
+fn test_regions_search_steps_limit() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C", "D"], vec!["A", "C", "B", "D"]]);
+    let system = TransitionSystem::build(&log, &create_params(StateAbstraction::Set, StateDirection::Past, None));
+
+    assert!(synthesize_petri_net_from_regions(&system, 3).is_err());
+    assert!(synthesize_petri_net_from_regions(&system, DEFAULT_MAX_REGIONS_SEARCH_STEPS).is_ok());
+}
@@ -0,0 +1,171 @@
+use std::{env, fs, sync::Arc, thread, time::Duration};
+
+use ficus_backend::{
+    grpc::context_store::{ContextStore, ContextStoreSettings},
+    pipelines::keys::context_keys::ContextKeys,
+    utils::{
+        dataset::dataset::FicusDataset,
+        graph::graph::DefaultGraph,
+        user_data::user_data::{UserData, UserDataImpl},
+    },
+};
+use uuid::Uuid;
+
+fn create_context_with_dataset(keys: &ContextKeys, rows_count: usize) -> UserDataImpl {
+    let values = (0..rows_count).map(|i| vec![i as f64, 1.0]).collect();
+    let rows_names = (0..rows_count).map(|i| format!("Trace_{}", i)).collect();
+    let dataset = FicusDataset::new(values, vec!["a".to_owned(), "b".to_owned()], rows_names);
+
+    let mut user_data = UserDataImpl::new();
+    user_data.put_concrete(keys.log_traces_dataset().key(), dataset);
+
+    user_data
+}
+
+fn create_id() -> String {
+    Uuid::new_v4().to_string()
+}
+
+#[test]
+fn test_lru_eviction() {
+    let keys = Arc::new(Box::new(ContextKeys::new()));
+    let mut settings = ContextStoreSettings::unbounded();
+    settings.max_size_bytes = Some(1000);
+
+    let (first, second) = (create_id(), create_id());
+    let mut store = ContextStore::new(settings, keys.clone());
+    store.insert(first.clone(), create_context_with_dataset(&keys, 20)).unwrap();
+    store.insert(second.clone(), create_context_with_dataset(&keys, 20)).unwrap();
+
+    assert!(store.get(&first).is_none());
+    assert!(store.get(&second).is_some());
+    assert_eq!(store.list().len(), 1);
+}
+
+#[test]
+fn test_ttl_eviction() {
+    let keys = Arc::new(Box::new(ContextKeys::new()));
+    let mut settings = ContextStoreSettings::unbounded();
+    settings.time_to_live = Some(Duration::from_millis(10));
+
+    let first = create_id();
+    let mut store = ContextStore::new(settings, keys.clone());
+    store.insert(first.clone(), create_context_with_dataset(&keys, 2)).unwrap();
+    thread::sleep(Duration::from_millis(50));
+
+    assert!(store.get(&first).is_none());
+    assert_eq!(store.total_size(), 0);
+}
+
+#[test]
+fn test_persistent_store() {
+    let keys = Arc::new(Box::new(ContextKeys::new()));
+    let dir = env::temp_dir().join(Uuid::new_v4().to_string());
+    let create_settings = || {
+        let mut settings = ContextStoreSettings::unbounded();
+        settings.storage_directory = Some(dir.clone());
+        settings
+    };
+
+    let first = create_id();
+    let mut store = ContextStore::new(create_settings(), keys.clone());
+    store.insert(first.clone(), create_context_with_dataset(&keys, 3)).unwrap();
+    drop(store);
+
+    let mut restored_store = ContextStore::new(create_settings(), keys.clone());
+    let infos = restored_store.list();
+    assert_eq!(infos.len(), 1);
+    assert!(!infos[0].in_memory);
+
+    let user_data = restored_store.get(&first).unwrap();
+    let dataset = user_data.concrete(keys.log_traces_dataset().key()).unwrap();
+
+    assert_eq!(dataset.values(), &vec![vec![0.0, 1.0], vec![1.0, 1.0], vec![2.0, 1.0]]);
+    assert_eq!(
+        dataset.row_names(),
+        &vec!["Trace_0".to_owned(), "Trace_1".to_owned(), "Trace_2".to_owned()]
+    );
+
+    assert!(restored_store.remove(&first));
+    assert!(restored_store.list().is_empty());
+
+    fs::remove_dir_all(dir).ok();
+}
+
+//Storage directory is a file, so persistence fails and contexts are kept in memory regardless of the size limit
+#[test]
+fn test_failed_persistence_keeps_context() {
+    let keys = Arc::new(Box::new(ContextKeys::new()));
+    let file = env::temp_dir().join(Uuid::new_v4().to_string());
+    fs::write(&file, "").unwrap();
+
+    let mut settings = ContextStoreSettings::unbounded();
+    settings.storage_directory = Some(file.clone());
+    settings.max_size_bytes = Some(1000);
+
+    let (first, second) = (create_id(), create_id());
+    let mut store = ContextStore::new(settings, keys.clone());
+    assert!(store.insert(first.clone(), create_context_with_dataset(&keys, 20)).is_err());
+    assert!(store.insert(second.clone(), create_context_with_dataset(&keys, 20)).is_err());
+
+    assert!(store.get(&first).is_some());
+    assert!(store.get(&second).is_some());
+
+    fs::remove_file(file).ok();
+}
+
+//Graph can not be persisted, so the first context stays in memory, while the second one is unloaded and restored from disk
+#[test]
+fn test_evicted_context_restore() {
+    let keys = Arc::new(Box::new(ContextKeys::new()));
+    let dir = env::temp_dir().join(Uuid::new_v4().to_string());
+
+    let mut settings = ContextStoreSettings::unbounded();
+    settings.storage_directory = Some(dir.clone());
+    settings.max_size_bytes = Some(1000);
+
+    let (first, second, third) = (create_id(), create_id(), create_id());
+    let mut store = ContextStore::new(settings, keys.clone());
+
+    let mut context = create_context_with_dataset(&keys, 20);
+    context.put_concrete(keys.graph().key(), DefaultGraph::empty());
+    store.insert(first.clone(), context).unwrap();
+    store.insert(second.clone(), create_context_with_dataset(&keys, 20)).unwrap();
+    store.insert(third.clone(), create_context_with_dataset(&keys, 20)).unwrap();
+
+    let infos = store.list();
+    assert!(infos.iter().find(|info| info.id == first).unwrap().in_memory);
+    assert!(!infos.iter().find(|info| info.id == second).unwrap().in_memory);
+
+    assert!(store.get(&first).unwrap().concrete(keys.graph().key()).is_some());
+
+    let dataset = store.get(&second).unwrap().concrete(keys.log_traces_dataset().key()).unwrap();
+    assert_eq!(dataset.row_names().len(), 20);
+
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn test_invalid_ids() {
+    let keys = Arc::new(Box::new(ContextKeys::new()));
+    let dir = env::temp_dir().join(Uuid::new_v4().to_string());
+
+    let mut settings = ContextStoreSettings::unbounded();
+    settings.storage_directory = Some(dir.join("contexts"));
+
+    let first = create_id();
+    let mut store = ContextStore::new(settings, keys.clone());
+    store.insert(first.clone(), create_context_with_dataset(&keys, 3)).unwrap();
+
+    for id in ["", "..", dir.to_str().unwrap(), first.to_uppercase().as_str()] {
+        assert!(!ContextStore::is_valid_id(id));
+        assert!(store.insert(id.to_owned(), create_context_with_dataset(&keys, 3)).is_err());
+        assert!(store.get(id).is_none());
+        assert!(!store.remove(id));
+    }
+
+    assert!(store.get(&first).is_some());
+    assert_eq!(store.list().len(), 1);
+
+    fs::remove_dir_all(dir).ok();
+}
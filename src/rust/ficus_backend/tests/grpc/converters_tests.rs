@@ -0,0 +1,165 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use chrono::{TimeZone, Utc};
+use ficus_backend::{
+    event_log::{
+        core::{
+            event::{
+                event::{Event, EventPayloadValue},
+                lifecycle::{standard_lifecycle::XesStandardLifecycle, xes_lifecycle::Lifecycle},
+            },
+            event_log::EventLog,
+            trace::trace::Trace,
+        },
+        xes::{xes_event::XesEventImpl, xes_event_log::XesEventLogImpl, xes_trace::XesTraceImpl},
+    },
+    features::discovery::petri_net::{petri_net::DefaultPetriNet, place::Place, transition::Transition},
+    ficus_proto::{grpc_context_value::ContextValue, GrpcDeclareConstraint, GrpcDeclareModel},
+    grpc::converters::{
+        convert_from_grpc_declare_model, convert_from_grpc_full_event_log, convert_to_grpc_context_value, convert_to_grpc_full_event_log,
+    },
+    pipelines::keys::context_keys::ContextKeys,
+    utils::graph::graph::DefaultGraph,
+};
+use uuid::Uuid;
+
+fn create_string(value: &str) -> EventPayloadValue {
+    EventPayloadValue::String(Rc::new(Box::new(value.to_owned())))
+}
+
+fn create_log_with_attributes() -> XesEventLogImpl {
+    let mut log = XesEventLogImpl::empty();
+    log.globals_mut().insert(
+        "event".to_owned(),
+        HashMap::from_iter(vec![("org:resource".to_owned(), create_string("unknown"))]),
+    );
+
+    let start = Utc.with_ymd_and_hms(2023, 5, 1, 10, 0, 0).unwrap();
+    for (index, names) in [vec!["A", "B"], vec!["A", "C", "B"]].iter().enumerate() {
+        let mut trace = XesTraceImpl::empty();
+        trace
+            .metadata_mut()
+            .insert("concept:name".to_owned(), create_string(format!("case_{}", index).as_str()));
+        trace
+            .metadata_mut()
+            .insert("cost".to_owned(), EventPayloadValue::Float64(10.5 * index as f64));
+
+        for (event_index, name) in names.iter().enumerate() {
+            let payload = HashMap::from_iter(vec![
+                ("org:resource".to_owned(), create_string("Bob")),
+                ("count".to_owned(), EventPayloadValue::Int64(event_index as i64)),
+                ("urgent".to_owned(), EventPayloadValue::Boolean(event_index % 2 == 0)),
+                ("id".to_owned(), EventPayloadValue::Guid(Uuid::new_v4())),
+                ("deadline".to_owned(), EventPayloadValue::Date(start + chrono::Duration::days(1))),
+                (
+                    "lifecycle:transition".to_owned(),
+                    EventPayloadValue::Lifecycle(Lifecycle::XesStandardLifecycle(XesStandardLifecycle::Complete)),
+                ),
+            ]);
+
+            let timestamp = start + chrono::Duration::milliseconds(1500 * event_index as i64);
+            let event = XesEventImpl::new_all_fields(Rc::new(Box::new(name.to_string())), timestamp, Some(payload));
+            trace.push(Rc::new(RefCell::new(event)));
+        }
+
+        log.push(Rc::new(RefCell::new(trace)));
+    }
+
+    log
+}
+
+#[test]
+fn test_full_event_log_round_trip() {
+    let log = create_log_with_attributes();
+    let grpc_log = convert_to_grpc_full_event_log(&log);
+    let restored_log = convert_from_grpc_full_event_log(&grpc_log).ok().unwrap();
+
+    assert_eq!(convert_to_grpc_full_event_log(&restored_log), grpc_log);
+    assert_eq!(restored_log.to_raw_vector(), log.to_raw_vector());
+
+    let trace = restored_log.traces()[1].clone();
+    let trace = trace.borrow();
+    assert_eq!(trace.metadata().get("cost").unwrap().to_string(), "10.5");
+
+    let event = trace.events()[2].borrow();
+    assert_eq!(event.timestamp(), log.traces()[1].borrow().events()[2].borrow().timestamp());
+    assert_eq!(event.payload_map().unwrap().get("count").unwrap().to_string(), "2");
+    assert_eq!(restored_log.globals_map().get("event").unwrap().len(), 1);
+}
+
+#[test]
+fn test_event_without_timestamp_is_rejected() {
+    let mut grpc_log = convert_to_grpc_full_event_log(&create_log_with_attributes());
+    grpc_log.traces[1].events[1].timestamp = None;
+
+    assert!(convert_from_grpc_full_event_log(&grpc_log).is_err());
+}
+
+fn create_grpc_declare_constraint(template: &str, parameters: &[&str]) -> GrpcDeclareConstraint {
+    GrpcDeclareConstraint {
+        template: template.to_owned(),
+        parameters: parameters.iter().map(|parameter| parameter.to_string()).collect(),
+        metrics: None,
+    }
+}
+
+#[test]
+fn test_declare_model_with_invalid_constraint_is_rejected() {
+    let mut grpc_model = GrpcDeclareModel {
+        constraints: vec![
+            create_grpc_declare_constraint("Existence", &["A"]),
+            create_grpc_declare_constraint("Response", &["A", "B"]),
+        ],
+    };
+
+    assert_eq!(convert_from_grpc_declare_model(&grpc_model).ok().unwrap().constraints().len(), 2);
+
+    grpc_model.constraints.push(create_grpc_declare_constraint("Unknown", &["A"]));
+    assert_eq!(
+        convert_from_grpc_declare_model(&grpc_model).err().unwrap(),
+        "Unknown template Unknown of Declare constraint 2"
+    );
+
+    grpc_model.constraints[2] = create_grpc_declare_constraint("Response", &["A"]);
+    assert_eq!(
+        convert_from_grpc_declare_model(&grpc_model).err().unwrap(),
+        "Invalid parameters [A] of Declare constraint 2 (Response)"
+    );
+}
+
+#[test]
+fn test_graph_is_converted_with_layout() {
+    let keys = ContextKeys::new();
+    let mut graph = DefaultGraph::empty();
+    let first = graph.add_node(Some("A".to_owned()));
+    let second = graph.add_node(Some("B".to_owned()));
+    graph.connect_nodes(&first, &second, None);
+
+    let value = convert_to_grpc_context_value(keys.graph(), &graph, &keys).unwrap();
+    let grpc_graph = match value.context_value.unwrap() {
+        ContextValue::Graph(grpc_graph) => grpc_graph,
+        _ => panic!("Expected graph"),
+    };
+
+    assert!(grpc_graph.nodes.iter().all(|node| node.layout.is_some()));
+    let layouts = grpc_graph.nodes.iter().map(|node| node.layout.clone().unwrap()).collect::<Vec<_>>();
+    assert_ne!((layouts[0].x, layouts[0].y), (layouts[1].x, layouts[1].y));
+}
+
+#[test]
+fn test_petri_net_is_converted_with_layout() {
+    let keys = ContextKeys::new();
+    let mut net = DefaultPetriNet::empty();
+    let place = net.add_place(Place::with_name("p".to_owned()));
+    let transition = net.add_transition(Transition::empty("A".to_owned(), false, None));
+    net.connect_place_to_transition(&place, &transition, None);
+
+    let value = convert_to_grpc_context_value(keys.petri_net(), &net, &keys).unwrap();
+    let grpc_net = match value.context_value.unwrap() {
+        ContextValue::PetriNet(grpc_net) => grpc_net,
+        _ => panic!("Expected Petri net"),
+    };
+
+    assert!(grpc_net.places.iter().all(|place| place.layout.is_some()));
+    assert!(grpc_net.transitions.iter().all(|transition| transition.layout.is_some()));
+}
@@ -0,0 +1,2 @@
+pub mod context_store_tests;
+pub mod converters_tests;
@@ -1,9 +1,13 @@
 pub mod analysis;
+pub mod clustering;
 pub mod discovery;
+pub mod grpc;
 pub mod interval_tree;
 pub mod other;
 pub mod petri_nets;
 pub mod pipelines;
+pub mod prediction;
+pub mod streaming;
 pub mod suffix_tree;
 pub mod test_core;
 pub mod utils;
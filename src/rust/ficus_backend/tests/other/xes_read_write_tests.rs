@@ -1,4 +1,9 @@
-use ficus_backend::event_log::xes::reader::file_xes_log_reader::read_event_log;
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::core::trace::trace::Trace;
+use ficus_backend::event_log::xes::reader::file_xes_log_reader::{read_event_log, read_event_log_from_reader};
 use ficus_backend::event_log::xes::writer::xes_event_log_writer::serialize_event_log;
 
 use crate::test_core::gold_based_test::execute_test_with_gold;
@@ -14,3 +19,39 @@ fn test_read_write_xes() {
         });
     }
 }
+
+#[test]
+fn test_read_xes_from_reader() {
+    for log_path in get_paths_to_example_logs() {
+        let log_name = log_path.file_name().unwrap().to_str().unwrap();
+        execute_test_with_gold(create_example_log_gold_file_path(log_name), || {
+            let reader = BufReader::new(File::open(log_path.to_str().unwrap()).unwrap());
+            let event_log = read_event_log_from_reader(Box::new(reader)).unwrap();
+            serialize_event_log(&event_log).ok().unwrap()
+        });
+    }
+}
+
+#[test]
+fn test_read_trace_attributes() {
+    let content = r#"<log>
+  <trace>
+    <string key="concept:name" value="case_1"/>
+    <int key="cost" value="10"/>
+    <event>
+      <string key="concept:name" value="A"/>
+      <date key="time:timestamp" value="2023-01-01T00:00:00+00:00"/>
+      <string key="org:resource" value="Bob"/>
+    </event>
+  </trace>
+</log>"#;
+
+    let reader = BufReader::new(Cursor::new(content.as_bytes().to_vec()));
+    let log = read_event_log_from_reader(Box::new(reader)).unwrap();
+    let trace = log.traces().first().unwrap().borrow();
+
+    assert_eq!(trace.events().len(), 1);
+    assert_eq!(trace.metadata().get("concept:name").unwrap().to_string(), "case_1");
+    assert_eq!(trace.metadata().get("cost").unwrap().to_string(), "10");
+    assert!(trace.metadata().get("org:resource").is_none());
+}
@@ -0,0 +1,35 @@
+use ficus_backend::features::discovery::petri_net::{
+    dot_serialization::serialize_petri_net_to_dot,
+    marking::{Marking, SingleMarking},
+    petri_net::DefaultPetriNet,
+    place::Place,
+    transition::Transition,
+};
+
+#[test]
+fn test_petri_net_to_dot() {
+    let mut net = DefaultPetriNet::empty();
+    let start = net.add_place(Place::with_name("start".to_string()));
+    let end = net.add_place(Place::with_name("end".to_string()));
+
+    let a = net.add_transition(Transition::empty("A".to_string(), false, Some("A".to_string())));
+    let silent = net.add_transition(Transition::empty("tau".to_string(), true, None));
+
+    net.connect_place_to_transition(&start, &a, None);
+    net.connect_transition_to_place(&a, &end, None);
+    net.connect_place_to_transition(&start, &silent, None);
+    net.connect_transition_to_place(&silent, &end, None);
+
+    net.set_initial_marking(Marking::new(vec![SingleMarking::new(start, 1)]));
+    net.set_final_marking(Marking::new(vec![SingleMarking::new(end, 1)]));
+
+    let dot = serialize_petri_net_to_dot(&net);
+
+    assert!(dot.starts_with("digraph \"petri_net\" {\n"));
+    assert!(dot.contains(format!("  \"p{}\" [shape=\"circle\", label=\"•\", xlabel=\"start\"];\n", start).as_str()));
+    assert!(dot.contains(format!("  \"p{}\" [shape=\"doublecircle\", label=\"\", xlabel=\"end\"];\n", end).as_str()));
+    assert!(dot.contains(format!("  \"t{}\" [shape=\"box\", label=\"A\"];\n", a).as_str()));
+    assert!(dot.contains(format!("  \"t{}\" [shape=\"box\", style=\"filled\", fillcolor=\"black\"", silent).as_str()));
+    assert!(dot.contains(format!("  \"p{}\" -> \"t{}\";\n", start, a).as_str()));
+    assert!(dot.contains(format!("  \"t{}\" -> \"p{}\";\n", silent, end).as_str()));
+}
@@ -1,2 +1,3 @@
+pub mod dot_serialization_tests;
 pub mod petri_net_tests;
 pub mod replay_tests;
@@ -4,7 +4,10 @@ use crate::test_core::test_paths::get_serialized_petri_nets_gold_path;
 use ficus_backend::features::analysis::event_log_info::{EventLogInfo, EventLogInfoCreationDto};
 use ficus_backend::features::discovery::alpha::alpha::discover_petri_net_alpha;
 use ficus_backend::features::discovery::alpha::providers::alpha_provider::DefaultAlphaRelationsProvider;
-use ficus_backend::features::discovery::petri_net::pnml_serialization::serialize_to_pnml;
+use ficus_backend::features::discovery::petri_net::petri_net::DefaultPetriNet;
+use ficus_backend::features::discovery::petri_net::pnml_deserialization::deserialize_from_pnml;
+use ficus_backend::features::discovery::petri_net::pnml_serialization::{serialize_to_executable_pnml, serialize_to_pnml};
+use ficus_backend::features::discovery::petri_net::transition::Transition;
 
 #[test]
 #[rustfmt::skip]
@@ -20,3 +23,71 @@ pub fn test_serialization_1() {
         },
     )
 }
+
+#[test]
+pub fn test_pnml_deserialization_round_trip() {
+    let log = create_simple_event_log3();
+    let info = EventLogInfo::create_from(EventLogInfoCreationDto::default(&log));
+    let provider = DefaultAlphaRelationsProvider::new(&info);
+
+    let petri_net = discover_petri_net_alpha(&provider);
+    let serialized = serialize_to_pnml(&petri_net, true).ok().unwrap();
+    let deserialized = deserialize_from_pnml(serialized.as_str()).ok().unwrap();
+
+    assert_eq!(deserialized.all_places().len(), petri_net.all_places().len());
+    assert_eq!(deserialized.all_transitions().len(), petri_net.all_transitions().len());
+    assert_eq!(serialize_to_pnml(&deserialized, true).ok().unwrap(), serialized);
+}
+
+//Silent transitions, initial and final markings are preserved by the executable PNML
+#[test]
+pub fn test_executable_pnml_round_trip() {
+    let log = create_simple_event_log3();
+    let info = EventLogInfo::create_from(EventLogInfoCreationDto::default(&log));
+    let provider = DefaultAlphaRelationsProvider::new(&info);
+
+    let mut petri_net = discover_petri_net_alpha(&provider);
+    petri_net.add_transition(Transition::empty("tau".to_owned(), true, Some("tau".to_owned())));
+
+    let serialized = serialize_to_executable_pnml(&petri_net, true).ok().unwrap();
+    let deserialized = deserialize_from_pnml(serialized.as_str()).ok().unwrap();
+
+    assert!(*deserialized.find_transition_by_name("tau").unwrap().is_silent());
+    assert!(deserialized
+        .all_transitions()
+        .iter()
+        .all(|transition| *transition.is_silent() == (transition.name() == "tau")));
+
+    assert_eq!(get_marked_places(&deserialized, true), get_marked_places(&petri_net, true));
+    assert_eq!(get_marked_places(&deserialized, false), get_marked_places(&petri_net, false));
+    assert!(!get_marked_places(&petri_net, true).is_empty());
+    assert!(!get_marked_places(&petri_net, false).is_empty());
+
+    assert_eq!(serialize_to_executable_pnml(&deserialized, true).ok().unwrap(), serialized);
+    assert_eq!(
+        serialize_to_pnml(&deserialized, true).ok().unwrap(),
+        serialize_to_pnml(&petri_net, true).ok().unwrap()
+    );
+}
+
+fn get_marked_places(net: &DefaultPetriNet, initial: bool) -> Vec<(String, usize)> {
+    let marking = match initial {
+        true => net.initial_marking(),
+        false => net.final_marking(),
+    };
+
+    let mut places = marking
+        .unwrap()
+        .active_places()
+        .iter()
+        .map(|single_marking| {
+            (
+                net.place(&single_marking.place_id()).name().to_owned(),
+                single_marking.tokens_count(),
+            )
+        })
+        .collect::<Vec<(String, usize)>>();
+
+    places.sort();
+    places
+}
@@ -12,15 +12,19 @@ use ficus_backend::{
             heuristic::heuristic_miner::discover_petri_net_heuristic,
             petri_net::{
                 annotations::{annotate_with_counts, annotate_with_frequencies, annotate_with_trace_frequency},
-                marking::ensure_initial_marking,
+                marking::{ensure_initial_marking, Marking, SingleMarking},
                 petri_net::DefaultPetriNet,
-                replay::replay_petri_net,
+                place::Place,
+                replay::{calculate_token_replay_fitness, replay_petri_net, replay_trace_tokens, TokenReplayCounts},
+                transition::Transition,
             },
         },
     },
     vecs,
 };
 
+use ficus_backend::event_log::simple::simple_event_log::SimpleEventLog;
+
 use crate::test_core::simple_events_logs_provider::{
     create_alpha_plus_miner_replay_test_log, create_heuristic_miner_replay_test_log, create_simple_event_log,
 };
@@ -69,6 +73,59 @@ pub fn test_alpha_plus_log_replay() {
     execute_test_with_replay(&petri_net, &log, expected_transitions);
 }
 
+#[test]
+pub fn test_token_replay_fitness() {
+    let log = create_simple_event_log();
+    let log_info = EventLogInfo::create_from(EventLogInfoCreationDto::default(&log));
+    let petri_net = discover_petri_net_alpha(&DefaultAlphaRelationsProvider::new(&log_info));
+
+    assert_eq!(calculate_token_replay_fitness(&log, &petri_net), 1.0);
+
+    let unfit_log = SimpleEventLog::new(&vec![vec!["A", "C"]]);
+    assert!((calculate_token_replay_fitness(&unfit_log, &petri_net) - 2.0 / 3.0).abs() < 1e-9);
+}
+
+//Start -> A -> p1 -> silent -> p2 -> B -> End, there is no final marking, so End is consumed as a sink place
+fn create_net_with_silent_transition() -> DefaultPetriNet {
+    create_sequential_net(&[("A", false), ("silent", true), ("B", false)])
+}
+
+fn create_sequential_net(transitions: &[(&str, bool)]) -> DefaultPetriNet {
+    let mut petri_net = DefaultPetriNet::empty();
+    let places = (0..=transitions.len())
+        .map(|index| petri_net.add_place(Place::with_name(format!("p{}", index))))
+        .collect::<Vec<u64>>();
+
+    let transitions = transitions
+        .iter()
+        .map(|(name, silent)| petri_net.add_transition(Transition::empty(name.to_string(), *silent, None)))
+        .collect::<Vec<u64>>();
+
+    for (index, transition) in transitions.iter().enumerate() {
+        petri_net.connect_place_to_transition(&places[index], transition, None);
+        petri_net.connect_transition_to_place(transition, &places[index + 1], None);
+    }
+
+    petri_net.set_initial_marking(Marking::new(vec![SingleMarking::new(places[0], 1)]));
+    petri_net
+}
+
+#[test]
+pub fn test_token_replay_with_silent_transitions() {
+    let petri_net = create_net_with_silent_transition();
+
+    let counts = replay_trace_tokens(&vecs!["A", "B"], &petri_net);
+    assert_eq!(counts, TokenReplayCounts::new(4, 4, 0, 0));
+    assert_eq!(counts.fitness(), 1.0);
+
+    let counts = replay_trace_tokens(&vecs!["B"], &petri_net);
+    assert_eq!(counts, TokenReplayCounts::new(2, 2, 1, 1));
+    assert_eq!(counts.fitness(), 0.5);
+
+    let counts = replay_trace_tokens(&vecs!["A", "X", "B"], &petri_net);
+    assert_eq!(counts, TokenReplayCounts::new(5, 5, 1, 1));
+}
+
 fn execute_test_with_replay(net: &DefaultPetriNet, log: &impl EventLog, expected_transitions: Vec<Option<Vec<String>>>) {
     let replay_states = replay_petri_net(log, net).unwrap();
     if replay_states.len() != expected_transitions.len() {
@@ -182,3 +239,32 @@ pub fn test_simple_trace_frequency_annotation() {
         ],
     );
 }
+
+#[test]
+pub fn test_token_replay_with_silent_transitions_path() {
+    let petri_net = create_sequential_net(&[
+        ("A", false),
+        ("silent_1", true),
+        ("silent_2", true),
+        ("silent_3", true),
+        ("B", false),
+    ]);
+
+    let counts = replay_trace_tokens(&vecs!["A", "B"], &petri_net);
+    assert_eq!(counts, TokenReplayCounts::new(6, 6, 0, 0));
+    assert_eq!(counts.fitness(), 1.0);
+}
+
+//Silent paths are searched up to the fixed length, longer paths are not fired
+#[test]
+pub fn test_token_replay_with_long_silent_transitions_path() {
+    let mut transitions = vec![("A", false)];
+    transitions.extend(["silent"; 6].map(|name| (name, true)));
+    transitions.push(("B", false));
+
+    let petri_net = create_sequential_net(&transitions);
+
+    let counts = replay_trace_tokens(&vecs!["A", "B"], &petri_net);
+    assert_eq!(counts.missing(), 1);
+    assert!(counts.fitness() < 1.0);
+}
@@ -0,0 +1,132 @@
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::xes::xes_event_log::XesEventLogImpl;
+use ficus_backend::pipelines::context::{PipelineContext, PipelineInfrastructure};
+use ficus_backend::pipelines::control_flow::{
+    ComparisonKind, ForEachTraceClusterPipelinePart, IfPipelinePart, LogMetric, PipelinePredicate, PredicateOperand, WhilePipelinePart,
+};
+use ficus_backend::pipelines::keys::context_keys::ContextKeys;
+use ficus_backend::pipelines::pipelines::{DefaultPipelinePart, Pipeline, PipelinePart};
+use ficus_backend::utils::user_data::user_data::{UserData, UserDataImpl};
+
+fn create_increment_pipeline() -> Pipeline {
+    let mut pipeline = Pipeline::empty();
+    let part = DefaultPipelinePart::new(
+        "Increment".to_string(),
+        Box::new(UserDataImpl::new()),
+        Box::new(|context, _, keys, _| {
+            let value = *context.concrete(keys.events_count().key()).unwrap_or(&0);
+            context.put_concrete(keys.events_count().key(), value + 1);
+            Ok(())
+        }),
+    );
+
+    pipeline.push(Box::new(part));
+    pipeline
+}
+
+fn create_events_count_predicate(comparison: ComparisonKind, threshold: f64) -> PipelinePredicate {
+    let operand = PredicateOperand::ContextValue(ContextKeys::EVENTS_COUNT.to_string());
+    PipelinePredicate::new(operand, comparison, threshold)
+}
+
+fn get_events_count(context: &PipelineContext, keys: &ContextKeys) -> u32 {
+    *context.concrete(keys.events_count().key()).unwrap()
+}
+
+#[test]
+fn test_if_part() {
+    let keys = ContextKeys::new();
+    let infra = PipelineInfrastructure::new(None);
+
+    let mut context = PipelineContext::empty();
+    context.put_concrete(keys.events_count().key(), 5);
+
+    let predicate = create_events_count_predicate(ComparisonKind::Less, 3.0);
+    let part = IfPipelinePart::new(predicate, Pipeline::empty(), Some(create_increment_pipeline()));
+    part.execute(&mut context, &infra, &keys).ok().unwrap();
+    assert_eq!(get_events_count(&context, &keys), 6);
+
+    let predicate = create_events_count_predicate(ComparisonKind::Greater, 3.0);
+    let part = IfPipelinePart::new(predicate, create_increment_pipeline(), None);
+    part.execute(&mut context, &infra, &keys).ok().unwrap();
+    assert_eq!(get_events_count(&context, &keys), 7);
+}
+
+#[test]
+fn test_while_part() {
+    let keys = ContextKeys::new();
+    let infra = PipelineInfrastructure::new(None);
+
+    let mut context = PipelineContext::empty();
+    context.put_concrete(keys.events_count().key(), 0);
+
+    let predicate = create_events_count_predicate(ComparisonKind::Less, 10.0);
+    let part = WhilePipelinePart::new(Some(predicate), create_increment_pipeline(), 100);
+    part.execute(&mut context, &infra, &keys).ok().unwrap();
+
+    assert_eq!(get_events_count(&context, &keys), 10);
+}
+
+#[test]
+fn test_while_part_max_iterations() {
+    let keys = ContextKeys::new();
+    let infra = PipelineInfrastructure::new(None);
+
+    let mut context = PipelineContext::empty();
+    context.put_concrete(keys.events_count().key(), 0);
+
+    let predicate = create_events_count_predicate(ComparisonKind::GreaterOrEqual, 0.0);
+    let part = WhilePipelinePart::new(Some(predicate), create_increment_pipeline(), 5);
+    part.execute(&mut context, &infra, &keys).ok().unwrap();
+
+    assert_eq!(get_events_count(&context, &keys), 5);
+}
+
+#[test]
+fn test_repeat_part() {
+    let keys = ContextKeys::new();
+    let infra = PipelineInfrastructure::new(None);
+
+    let mut context = PipelineContext::empty();
+    let part = WhilePipelinePart::new(None, create_increment_pipeline(), 3);
+    part.execute(&mut context, &infra, &keys).ok().unwrap();
+
+    assert_eq!(get_events_count(&context, &keys), 3);
+}
+
+#[test]
+fn test_log_metric_predicate() {
+    let keys = ContextKeys::new();
+    let mut context = PipelineContext::empty();
+    context.put_concrete(keys.event_log().key(), XesEventLogImpl::empty());
+
+    let predicate = PipelinePredicate::new(PredicateOperand::LogMetric(LogMetric::TracesCount), ComparisonKind::Equal, 0.0);
+    assert!(predicate.evaluate(&context, &keys).ok().unwrap());
+}
+
+#[test]
+fn test_for_each_trace_cluster_part() {
+    let keys = ContextKeys::new();
+    let infra = PipelineInfrastructure::new(None);
+
+    let mut context = PipelineContext::empty();
+    let clusters = vec![XesEventLogImpl::empty(), XesEventLogImpl::empty(), XesEventLogImpl::empty()];
+    context.put_concrete(keys.traces_clusters().key(), clusters);
+    context.put_concrete(keys.events_count().key(), 0);
+
+    let mut pipeline = Pipeline::empty();
+    pipeline.push(Box::new(DefaultPipelinePart::new(
+        "CountClusters".to_string(),
+        Box::new(UserDataImpl::new()),
+        Box::new(|context, _, keys, _| {
+            assert!(context.concrete(keys.event_log().key()).is_some());
+            *context.concrete_mut(keys.events_count().key()).unwrap() += 1;
+            Ok(())
+        }),
+    )));
+
+    let part = ForEachTraceClusterPipelinePart::new(pipeline);
+    part.execute(&mut context, &infra, &keys).ok().unwrap();
+
+    assert_eq!(get_events_count(&context, &keys), 3);
+}
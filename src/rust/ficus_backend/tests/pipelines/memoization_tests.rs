@@ -0,0 +1,233 @@
+use std::{
+    cell::Cell,
+    env, fs,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::xes::reader::file_xes_log_reader::read_event_log;
+use ficus_backend::event_log::xes::writer::xes_event_log_writer::serialize_event_log;
+use ficus_backend::pipelines::context::{PipelineContext, PipelineInfrastructure};
+use ficus_backend::pipelines::keys::context_key::DefaultContextKey;
+use ficus_backend::pipelines::keys::context_keys::ContextKeys;
+use ficus_backend::pipelines::memoization::PipelinePartsCache;
+use ficus_backend::pipelines::pipeline_parts::PipelineParts;
+use ficus_backend::pipelines::pipelines::{DefaultPipelinePart, Pipeline, PipelinePart};
+use ficus_backend::utils::user_data::user_data::{UserData, UserDataImpl};
+
+use crate::test_core::test_paths::get_paths_to_example_logs;
+
+fn create_multiply_pipeline(keys: &ContextKeys, factor: f64, executions_count: Rc<Cell<usize>>) -> Pipeline {
+    let mut config = UserDataImpl::new();
+    config.put_concrete(keys.tolerance().key(), factor);
+
+    let part = DefaultPipelinePart::new(
+        "Multiply".to_string(),
+        Box::new(config),
+        Box::new(move |context, _, keys, config| {
+            executions_count.set(executions_count.get() + 1);
+
+            let events_count = *context.concrete(keys.events_count().key()).unwrap();
+            let factor = *config.concrete(keys.tolerance().key()).unwrap();
+            context.put_concrete(keys.tolerance().key(), events_count as f64 * factor);
+
+            Ok(())
+        }),
+    );
+
+    let mut pipeline = Pipeline::empty();
+    pipeline.push(Box::new(part));
+    pipeline
+}
+
+fn execute(pipeline: &Pipeline, infra: &PipelineInfrastructure, keys: &ContextKeys, events_count: u32) -> f64 {
+    let mut context = PipelineContext::empty();
+    context.put_concrete(keys.events_count().key(), events_count);
+
+    pipeline.execute(&mut context, infra, keys).ok().unwrap();
+
+    *context.concrete(keys.tolerance().key()).unwrap()
+}
+
+fn create_infra() -> (Arc<Mutex<PipelinePartsCache>>, PipelineInfrastructure) {
+    let cache = Arc::new(Mutex::new(PipelinePartsCache::new(16)));
+    let infra = PipelineInfrastructure::new_with_cache(None, Some(cache.clone()));
+
+    (cache, infra)
+}
+
+#[test]
+fn test_cache_hit() {
+    let keys = ContextKeys::new();
+    let (cache, infra) = create_infra();
+    let executions_count = Rc::new(Cell::new(0));
+    let pipeline = create_multiply_pipeline(&keys, 2.0, executions_count.clone());
+
+    assert_eq!(execute(&pipeline, &infra, &keys, 5), 10.0);
+    assert_eq!(execute(&pipeline, &infra, &keys, 5), 10.0);
+    assert_eq!(executions_count.get(), 1);
+
+    let statistics = cache.lock().unwrap().statistics();
+    assert_eq!(statistics.hits, 1);
+    assert_eq!(statistics.misses, 1);
+    assert_eq!(statistics.entries_count, 1);
+}
+
+#[test]
+fn test_cache_miss_on_changed_inputs() {
+    let keys = ContextKeys::new();
+    let (_, infra) = create_infra();
+    let executions_count = Rc::new(Cell::new(0));
+
+    let pipeline = create_multiply_pipeline(&keys, 2.0, executions_count.clone());
+    assert_eq!(execute(&pipeline, &infra, &keys, 5), 10.0);
+    assert_eq!(execute(&pipeline, &infra, &keys, 6), 12.0);
+    assert_eq!(executions_count.get(), 2);
+
+    let pipeline = create_multiply_pipeline(&keys, 3.0, executions_count.clone());
+    assert_eq!(execute(&pipeline, &infra, &keys, 5), 15.0);
+    assert_eq!(executions_count.get(), 3);
+}
+
+#[test]
+fn test_cache_invalidation() {
+    let keys = ContextKeys::new();
+    let (cache, infra) = create_infra();
+    let executions_count = Rc::new(Cell::new(0));
+    let pipeline = create_multiply_pipeline(&keys, 2.0, executions_count.clone());
+
+    execute(&pipeline, &infra, &keys, 5);
+    cache.lock().unwrap().invalidate(&vec!["Multiply".to_string()]);
+    assert_eq!(cache.lock().unwrap().statistics().entries_count, 0);
+
+    execute(&pipeline, &infra, &keys, 5);
+    assert_eq!(executions_count.get(), 2);
+}
+
+#[test]
+fn test_cache_is_not_used_without_infrastructure_cache() {
+    let keys = ContextKeys::new();
+    let infra = PipelineInfrastructure::new(None);
+    let executions_count = Rc::new(Cell::new(0));
+    let pipeline = create_multiply_pipeline(&keys, 2.0, executions_count.clone());
+
+    execute(&pipeline, &infra, &keys, 5);
+    execute(&pipeline, &infra, &keys, 5);
+    assert_eq!(executions_count.get(), 2);
+}
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn test_cached_event_log_is_restored_from_snapshot() {
+    assert_send::<PipelinePartsCache>();
+
+    let keys = ContextKeys::new();
+    let (cache, infra) = create_infra();
+    let executions_count = Rc::new(Cell::new(0));
+    let log_path = get_paths_to_example_logs().into_iter().next().unwrap();
+
+    let counter = executions_count.clone();
+    let part = DefaultPipelinePart::new(
+        "ReadLog".to_string(),
+        Box::new(UserDataImpl::new()),
+        Box::new(move |context, _, keys, _| {
+            counter.set(counter.get() + 1);
+            context.put_concrete(keys.event_log().key(), read_event_log(log_path.to_str().unwrap()).unwrap());
+
+            Ok(())
+        }),
+    );
+
+    let mut pipeline = Pipeline::empty();
+    pipeline.push(Box::new(part));
+
+    let mut logs = vec![];
+    for _ in 0..2 {
+        let mut context = PipelineContext::empty();
+        pipeline.execute(&mut context, &infra, &keys).ok().unwrap();
+
+        let log = context.concrete(keys.event_log().key()).unwrap();
+        logs.push((log.traces().len(), serialize_event_log(log).ok().unwrap()));
+    }
+
+    assert_eq!(executions_count.get(), 1);
+    assert_eq!(cache.lock().unwrap().statistics().hits, 1);
+    assert_eq!(logs[0], logs[1]);
+}
+
+#[test]
+fn test_reexecuted_part_is_counted_once() {
+    let keys = ContextKeys::new();
+    let (cache, infra) = create_infra();
+    let executions_count = Rc::new(Cell::new(0));
+
+    let counter = executions_count.clone();
+    let part = DefaultPipelinePart::new(
+        "WriteUnknownKey".to_string(),
+        Box::new(UserDataImpl::new()),
+        Box::new(move |context, _, keys, _| {
+            counter.set(counter.get() + 1);
+            let events_count = *context.concrete(keys.events_count().key()).unwrap();
+            context.put_concrete(DefaultContextKey::<f64>::new("unknown_key").key(), events_count as f64);
+
+            Ok(())
+        }),
+    );
+
+    let mut pipeline = Pipeline::empty();
+    pipeline.push(Box::new(part));
+
+    for _ in 0..3 {
+        let mut context = PipelineContext::empty();
+        context.put_concrete(keys.events_count().key(), 5);
+        pipeline.execute(&mut context, &infra, &keys).ok().unwrap();
+    }
+
+    assert_eq!(executions_count.get(), 3);
+    assert_eq!(cache.lock().unwrap().statistics().entries_count, 1);
+}
+
+#[test]
+fn test_reader_part_is_executed_when_file_changes() {
+    let keys = ContextKeys::new();
+    let (_, infra) = create_infra();
+    let executions_count = Rc::new(Cell::new(0));
+    let path = env::temp_dir().join("ficus_memoization_reader_test.txt");
+    fs::write(&path, "a").unwrap();
+
+    let counter = executions_count.clone();
+    let part = DefaultPipelinePart::new(
+        PipelineParts::READ_LOG_FROM_XES.to_string(),
+        Box::new(UserDataImpl::new()),
+        Box::new(move |context, _, keys, _| {
+            counter.set(counter.get() + 1);
+            let content = fs::read_to_string(context.concrete(keys.path().key()).unwrap()).unwrap();
+            context.put_concrete(keys.events_count().key(), content.len() as u32);
+
+            Ok(())
+        }),
+    );
+
+    let mut pipeline = Pipeline::empty();
+    pipeline.push(Box::new(part));
+
+    let execute_reader = || {
+        let mut context = PipelineContext::empty();
+        context.put_concrete(keys.path().key(), path.to_str().unwrap().to_owned());
+        pipeline.execute(&mut context, &infra, &keys).ok().unwrap();
+
+        *context.concrete(keys.events_count().key()).unwrap()
+    };
+
+    assert_eq!(execute_reader(), 1);
+    assert_eq!(execute_reader(), 1);
+    assert_eq!(executions_count.get(), 1);
+
+    fs::write(&path, "abc").unwrap();
+    assert_eq!(execute_reader(), 3);
+    assert_eq!(executions_count.get(), 2);
+
+    fs::remove_file(&path).unwrap();
+}
@@ -1,2 +1,4 @@
+pub mod control_flow_tests;
+pub mod memoization_tests;
 pub mod pipeline_keys_tests;
 pub mod pipeline_parts_tests;
@@ -1,20 +1,40 @@
 use std::collections::HashMap;
 use std::{collections::HashSet, sync::Arc};
 
+use ficus_backend::features::analysis::concept_drift::DriftFeature;
 use ficus_backend::features::analysis::patterns::activity_instances::{ActivityInTraceFilterKind, ActivityNarrowingKind};
+use ficus_backend::features::analysis::patterns::sequential_patterns::SequentialPattern;
 use ficus_backend::features::clustering::activities::activities_params::ActivityRepresentationSource;
+use ficus_backend::features::clustering::agglomerative::{AgglomerativeClusteringLinkage, Dendrogram};
+use ficus_backend::features::clustering::distance_matrix::DistanceMatrixClusteringMethod;
+use ficus_backend::features::clustering::quality::{ClusteringQualityIndex, ClusteringSearchParameter};
 use ficus_backend::features::clustering::traces::traces_params::TracesRepresentationSource;
+use ficus_backend::features::discovery::bpmn::bpmn_model::BpmnModel;
+use ficus_backend::features::discovery::declare::declare_conformance::DeclareConformanceResult;
+use ficus_backend::features::discovery::declare::declare_model::DeclareModel;
+use ficus_backend::features::discovery::hierarchy::hierarchical_petri_net::HierarchicalPetriNet;
+use ficus_backend::features::discovery::local_process_models::local_process_models::LocalProcessModel;
 use ficus_backend::features::discovery::petri_net::petri_net::DefaultPetriNet;
-use ficus_backend::pipelines::activities_parts::{ActivitiesLogsSourceDto, UndefActivityHandlingStrategyDto};
+use ficus_backend::features::discovery::transition_system::transition_system::{StateAbstraction, StateDirection, TransitionSystem};
+use ficus_backend::features::prediction::next_activity::NextActivityModel;
+use ficus_backend::features::prediction::remaining_time::RemainingTimeModel;
+use ficus_backend::features::streaming::events_file_tail::EventsFileDiscovery;
+use ficus_backend::features::streaming::streaming_discovery::StreamingModel;
+use ficus_backend::pipelines::activities_parts::{ActivitiesLogsSourceDto, DatasetProjectionSourceDto, UndefActivityHandlingStrategyDto};
+use ficus_backend::pipelines::discovery_parts::PetriNetMinerDto;
 use ficus_backend::pipelines::patterns_parts::PatternsKindDto;
 use ficus_backend::utils::dataset::dataset::{FicusDataset, LabeledDataset};
+use ficus_backend::utils::dataset::projection::DatasetProjectionMethod;
 use ficus_backend::utils::distance::distance::FicusDistance;
 use ficus_backend::utils::graph::graph::DefaultGraph;
 use ficus_backend::{
     event_log::{core::event_log::EventLog, xes::xes_event_log::XesEventLogImpl},
     features::analysis::{
         event_log_info::EventLogInfo,
-        patterns::{activity_instances::AdjustingMode, contexts::PatternsDiscoveryStrategy},
+        patterns::{
+            activity_instances::AdjustingMode,
+            contexts::{EpisodeKind, PatternsDiscoveryStrategy, PatternsSearchBackend},
+        },
     },
     pipelines::{
         aliases::{Activities, ActivitiesToLogs, ColorsEventLog, Patterns, RepeatSets, TracesActivities},
@@ -121,6 +141,67 @@ fn test_event_log_all_concrete_keys() {
         assert_existence::<FicusDataset>(keys, ContextKeys::LOG_TRACES_DATASET, &mut used);
         assert_existence::<LabeledDataset>(keys, ContextKeys::LABELED_LOG_TRACES_DATASET, &mut used);
         assert_existence::<TracesRepresentationSource>(keys, ContextKeys::TRACES_REPR_SOURCE, &mut used);
+        assert_existence::<Vec<XesEventLogImpl>>(keys, ContextKeys::TRACES_CLUSTERS, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::MAX_MISMATCHES, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::MAX_GAP, &mut used);
+        assert_existence::<PatternsSearchBackend>(keys, ContextKeys::PATTERNS_SEARCH_BACKEND, &mut used);
+        assert_existence::<HierarchicalPetriNet>(keys, ContextKeys::HIERARCHICAL_PETRI_NET, &mut used);
+        assert_existence::<PetriNetMinerDto>(keys, ContextKeys::PETRI_NET_MINER, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::LPM_WINDOW_SIZE, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::LPM_MIN_SUPPORT, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::MIN_SUPPORT, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::MAX_ACTIVITIES_COUNT, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::TOP_MODELS_COUNT, &mut used);
+        assert_existence::<Vec<LocalProcessModel>>(keys, ContextKeys::LOCAL_PROCESS_MODELS, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::MAX_PATTERN_LENGTH, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::TIME_WINDOW_SECONDS, &mut used);
+        assert_existence::<EpisodeKind>(keys, ContextKeys::EPISODE_KIND, &mut used);
+        assert_existence::<Vec<SequentialPattern>>(keys, ContextKeys::SEQUENTIAL_PATTERNS, &mut used);
+        assert_existence::<RepeatSets>(keys, ContextKeys::PATTERNS_OCCURRENCES, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::MIN_RELATIVE_SUPPORT, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::MIN_CONFIDENCE, &mut used);
+        assert_existence::<DeclareModel>(keys, ContextKeys::DECLARE_MODEL, &mut used);
+        assert_existence::<DeclareConformanceResult>(keys, ContextKeys::DECLARE_CONFORMANCE, &mut used);
+        assert_existence::<BpmnModel>(keys, ContextKeys::BPMN_MODEL, &mut used);
+        assert_existence::<FicusDataset>(keys, ContextKeys::GRAPH_ANALYSIS_DATASET, &mut used);
+        assert_existence::<AgglomerativeClusteringLinkage>(keys, ContextKeys::LINKAGE, &mut used);
+        assert_existence::<Dendrogram>(keys, ContextKeys::DENDROGRAM, &mut used);
+        assert_existence::<DistanceMatrixClusteringMethod>(keys, ContextKeys::DISTANCE_MATRIX_CLUSTERING_METHOD, &mut used);
+        assert_existence::<ClusteringQualityIndex>(keys, ContextKeys::CLUSTERING_QUALITY_INDEX, &mut used);
+        assert_existence::<ClusteringSearchParameter>(keys, ContextKeys::CLUSTERING_SEARCH_PARAMETER, &mut used);
+        assert_existence::<FicusDataset>(keys, ContextKeys::CLUSTERING_SCORES, &mut used);
+        assert_existence::<Vec<String>>(keys, ContextKeys::TRACES_FEATURE_SETS, &mut used);
+        assert_existence::<DatasetProjectionMethod>(keys, ContextKeys::DATASET_PROJECTION_METHOD, &mut used);
+        assert_existence::<DatasetProjectionSourceDto>(keys, ContextKeys::DATASET_PROJECTION_SOURCE, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::PROJECTION_DIMENSIONS, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::TSNE_PERPLEXITY, &mut used);
+        assert_existence::<FicusDataset>(keys, ContextKeys::PROJECTED_DATASET, &mut used);
+        assert_existence::<LabeledDataset>(keys, ContextKeys::LABELED_PROJECTED_DATASET, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::TARGET_FITNESS, &mut used);
+        assert_existence::<Vec<DefaultPetriNet>>(keys, ContextKeys::TRACES_CLUSTERS_PETRI_NETS, &mut used);
+        assert_existence::<XesEventLogImpl>(keys, ContextKeys::RESIDUAL_TRACES_CLUSTER, &mut used);
+        assert_existence::<DriftFeature>(keys, ContextKeys::DRIFT_FEATURE, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::DRIFT_WINDOW_SIZE, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::DRIFT_SIGNIFICANCE_LEVEL, &mut used);
+        assert_existence::<FicusDataset>(keys, ContextKeys::CONCEPT_DRIFT_DATASET, &mut used);
+        assert_existence::<Vec<XesEventLogImpl>>(keys, ContextKeys::DRIFT_SUB_LOGS, &mut used);
+        assert_existence::<StreamingModel>(keys, ContextKeys::STREAMING_MODEL, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::STREAMING_WINDOW_SIZE, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::LOSSY_COUNTING_ERROR, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::NGRAM_ORDER, &mut used);
+        assert_existence::<f64>(keys, ContextKeys::TRAIN_FRACTION, &mut used);
+        assert_existence::<NextActivityModel>(keys, ContextKeys::NEXT_ACTIVITY_MODEL, &mut used);
+        assert_existence::<RemainingTimeModel>(keys, ContextKeys::REMAINING_TIME_MODEL, &mut used);
+        assert_existence::<FicusDataset>(keys, ContextKeys::NEXT_ACTIVITY_DATASET, &mut used);
+        assert_existence::<FicusDataset>(keys, ContextKeys::REMAINING_TIME_DATASET, &mut used);
+        assert_existence::<FicusDataset>(keys, ContextKeys::PREDICTION_EVALUATION_DATASET, &mut used);
+        assert_existence::<StateAbstraction>(keys, ContextKeys::STATE_ABSTRACTION, &mut used);
+        assert_existence::<StateDirection>(keys, ContextKeys::STATE_DIRECTION, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::STATE_HORIZON, &mut used);
+        assert_existence::<TransitionSystem>(keys, ContextKeys::TRANSITION_SYSTEM, &mut used);
+        assert_existence::<bool>(keys, ContextKeys::EVALUATE_CLUSTERING, &mut used);
+        assert_existence::<u32>(keys, ContextKeys::LOOK_AHEAD_COUNT, &mut used);
+        assert_existence::<EventsFileDiscovery>(keys, ContextKeys::EVENTS_FILE_DISCOVERY, &mut used);
 
         assert_eq!(used.len(), get_all_keys_names().len())
     })
@@ -201,7 +282,68 @@ fn get_all_keys_names() -> Vec<String> {
         "event_log_name",
         "log_traces_dataset",
         "labeled_log_traces_dataset",
-        "traces_repr_source"
+        "traces_repr_source",
+        "traces_clusters",
+        "max_mismatches",
+        "max_gap",
+        "patterns_search_backend",
+        "hierarchical_petri_net",
+        "petri_net_miner",
+        "lpm_window_size",
+        "lpm_min_support",
+        "min_support",
+        "max_activities_count",
+        "top_models_count",
+        "local_process_models",
+        "max_pattern_length",
+        "time_window_seconds",
+        "episode_kind",
+        "sequential_patterns",
+        "patterns_occurrences",
+        "min_relative_support",
+        "min_confidence",
+        "declare_model",
+        "declare_conformance",
+        "bpmn_model",
+        "graph_analysis_dataset",
+        "linkage",
+        "dendrogram",
+        "distance_matrix_clustering_method",
+        "clustering_quality_index",
+        "clustering_search_parameter",
+        "clustering_scores",
+        "traces_feature_sets",
+        "dataset_projection_method",
+        "dataset_projection_source",
+        "projection_dimensions",
+        "tsne_perplexity",
+        "projected_dataset",
+        "labeled_projected_dataset",
+        "target_fitness",
+        "traces_clusters_petri_nets",
+        "residual_traces_cluster",
+        "drift_feature",
+        "drift_window_size",
+        "drift_significance_level",
+        "concept_drift_dataset",
+        "drift_sub_logs",
+        "streaming_model",
+        "streaming_window_size",
+        "lossy_counting_error",
+        "ngram_order",
+        "train_fraction",
+        "next_activity_model",
+        "remaining_time_model",
+        "next_activity_dataset",
+        "remaining_time_dataset",
+        "prediction_evaluation_dataset",
+        "state_abstraction",
+        "state_direction",
+        "state_horizon",
+        "transition_system",
+        "evaluate_clustering",
+        "look_ahead_count",
+        "events_file_discovery"
     ]
 }
 
@@ -290,6 +432,67 @@ fn test_equivalence_of_keys() {
         assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::LOG_TRACES_DATASET, &mut used);
         assert_keys_equivalence::<LabeledDataset>(keys, ContextKeys::LABELED_LOG_TRACES_DATASET, &mut used);
         assert_keys_equivalence::<TracesRepresentationSource>(keys, ContextKeys::TRACES_REPR_SOURCE, &mut used);
+        assert_keys_equivalence::<Vec<XesEventLogImpl>>(keys, ContextKeys::TRACES_CLUSTERS, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::MAX_MISMATCHES, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::MAX_GAP, &mut used);
+        assert_keys_equivalence::<PatternsSearchBackend>(keys, ContextKeys::PATTERNS_SEARCH_BACKEND, &mut used);
+        assert_keys_equivalence::<HierarchicalPetriNet>(keys, ContextKeys::HIERARCHICAL_PETRI_NET, &mut used);
+        assert_keys_equivalence::<PetriNetMinerDto>(keys, ContextKeys::PETRI_NET_MINER, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::LPM_WINDOW_SIZE, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::LPM_MIN_SUPPORT, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::MIN_SUPPORT, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::MAX_ACTIVITIES_COUNT, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::TOP_MODELS_COUNT, &mut used);
+        assert_keys_equivalence::<Vec<LocalProcessModel>>(keys, ContextKeys::LOCAL_PROCESS_MODELS, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::MAX_PATTERN_LENGTH, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::TIME_WINDOW_SECONDS, &mut used);
+        assert_keys_equivalence::<EpisodeKind>(keys, ContextKeys::EPISODE_KIND, &mut used);
+        assert_keys_equivalence::<Vec<SequentialPattern>>(keys, ContextKeys::SEQUENTIAL_PATTERNS, &mut used);
+        assert_keys_equivalence::<RepeatSets>(keys, ContextKeys::PATTERNS_OCCURRENCES, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::MIN_RELATIVE_SUPPORT, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::MIN_CONFIDENCE, &mut used);
+        assert_keys_equivalence::<DeclareModel>(keys, ContextKeys::DECLARE_MODEL, &mut used);
+        assert_keys_equivalence::<DeclareConformanceResult>(keys, ContextKeys::DECLARE_CONFORMANCE, &mut used);
+        assert_keys_equivalence::<BpmnModel>(keys, ContextKeys::BPMN_MODEL, &mut used);
+        assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::GRAPH_ANALYSIS_DATASET, &mut used);
+        assert_keys_equivalence::<AgglomerativeClusteringLinkage>(keys, ContextKeys::LINKAGE, &mut used);
+        assert_keys_equivalence::<Dendrogram>(keys, ContextKeys::DENDROGRAM, &mut used);
+        assert_keys_equivalence::<DistanceMatrixClusteringMethod>(keys, ContextKeys::DISTANCE_MATRIX_CLUSTERING_METHOD, &mut used);
+        assert_keys_equivalence::<ClusteringQualityIndex>(keys, ContextKeys::CLUSTERING_QUALITY_INDEX, &mut used);
+        assert_keys_equivalence::<ClusteringSearchParameter>(keys, ContextKeys::CLUSTERING_SEARCH_PARAMETER, &mut used);
+        assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::CLUSTERING_SCORES, &mut used);
+        assert_keys_equivalence::<Vec<String>>(keys, ContextKeys::TRACES_FEATURE_SETS, &mut used);
+        assert_keys_equivalence::<DatasetProjectionMethod>(keys, ContextKeys::DATASET_PROJECTION_METHOD, &mut used);
+        assert_keys_equivalence::<DatasetProjectionSourceDto>(keys, ContextKeys::DATASET_PROJECTION_SOURCE, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::PROJECTION_DIMENSIONS, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::TSNE_PERPLEXITY, &mut used);
+        assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::PROJECTED_DATASET, &mut used);
+        assert_keys_equivalence::<LabeledDataset>(keys, ContextKeys::LABELED_PROJECTED_DATASET, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::TARGET_FITNESS, &mut used);
+        assert_keys_equivalence::<Vec<DefaultPetriNet>>(keys, ContextKeys::TRACES_CLUSTERS_PETRI_NETS, &mut used);
+        assert_keys_equivalence::<XesEventLogImpl>(keys, ContextKeys::RESIDUAL_TRACES_CLUSTER, &mut used);
+        assert_keys_equivalence::<DriftFeature>(keys, ContextKeys::DRIFT_FEATURE, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::DRIFT_WINDOW_SIZE, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::DRIFT_SIGNIFICANCE_LEVEL, &mut used);
+        assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::CONCEPT_DRIFT_DATASET, &mut used);
+        assert_keys_equivalence::<Vec<XesEventLogImpl>>(keys, ContextKeys::DRIFT_SUB_LOGS, &mut used);
+        assert_keys_equivalence::<StreamingModel>(keys, ContextKeys::STREAMING_MODEL, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::STREAMING_WINDOW_SIZE, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::LOSSY_COUNTING_ERROR, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::NGRAM_ORDER, &mut used);
+        assert_keys_equivalence::<f64>(keys, ContextKeys::TRAIN_FRACTION, &mut used);
+        assert_keys_equivalence::<NextActivityModel>(keys, ContextKeys::NEXT_ACTIVITY_MODEL, &mut used);
+        assert_keys_equivalence::<RemainingTimeModel>(keys, ContextKeys::REMAINING_TIME_MODEL, &mut used);
+        assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::NEXT_ACTIVITY_DATASET, &mut used);
+        assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::REMAINING_TIME_DATASET, &mut used);
+        assert_keys_equivalence::<FicusDataset>(keys, ContextKeys::PREDICTION_EVALUATION_DATASET, &mut used);
+        assert_keys_equivalence::<StateAbstraction>(keys, ContextKeys::STATE_ABSTRACTION, &mut used);
+        assert_keys_equivalence::<StateDirection>(keys, ContextKeys::STATE_DIRECTION, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::STATE_HORIZON, &mut used);
+        assert_keys_equivalence::<TransitionSystem>(keys, ContextKeys::TRANSITION_SYSTEM, &mut used);
+        assert_keys_equivalence::<bool>(keys, ContextKeys::EVALUATE_CLUSTERING, &mut used);
+        assert_keys_equivalence::<u32>(keys, ContextKeys::LOOK_AHEAD_COUNT, &mut used);
+        assert_keys_equivalence::<EventsFileDiscovery>(keys, ContextKeys::EVENTS_FILE_DISCOVERY, &mut used);
 
         assert_eq!(used.len(), get_all_keys_names().len())
     })
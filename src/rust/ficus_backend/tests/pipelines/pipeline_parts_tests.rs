@@ -10,6 +10,11 @@ fn get_test_parts_names() -> Vec<String> {
         "FindMaximalRepeats",
         "FindSuperMaximalRepeats",
         "FindNearSuperMaximalRepeats",
+        "FindHammingRepeats",
+        "FindGappedRepeats",
+        "FindApproximateTandemArrays",
+        "FindSequentialPatterns",
+        "FindFrequentEpisodes",
         "DiscoverActivities",
         "DiscoverActivitiesInstances",
         "CreateLogFromActivities",
@@ -37,6 +42,10 @@ fn get_test_parts_names() -> Vec<String> {
         "ApplyClassExtractor",
         "DiscoverPetriNetAlpha",
         "SerializePetriNet",
+        "SerializePetriNetToDot",
+        "SerializeGraph",
+        "SerializeToDot",
+        "AnalyzeGraph",
         "AddArtificialStartEndEvents",
         "AddArtificialStartEvents",
         "AddArtificialEndEvents",
@@ -46,6 +55,16 @@ fn get_test_parts_names() -> Vec<String> {
         "DiscoverDirectlyFollowsGraph",
         "DiscoverPetriNetHeuristic",
         "DiscoverFuzzyGraph",
+        "DiscoverHierarchicalPetriNet",
+        "SerializeHierarchicalPetriNet",
+        "DiscoverLocalProcessModels",
+        "DiscoverDeclareModel",
+        "CheckDeclareConformance",
+        "SerializeDeclareModel",
+        "ReadDeclareModel",
+        "ConvertPetriNetToBpmn",
+        "DiscoverBpmnHeuristic",
+        "SerializeBpmn",
         "AnnotatePetriNetWithCount",
         "AnnotatePetriNetWithFrequency",
         "AnnotatePetriNetWithTraceFrequency",
@@ -56,7 +75,22 @@ fn get_test_parts_names() -> Vec<String> {
         "ClusterizeActivitiesFromTracesDbscan",
         "CreateTracesActivitiesDataset",
         "WriteLogToBxes",
-        "ClusterizeLogTraces"
+        "ClusterizeLogTraces",
+        "ClusterizeLogTracesAgglomerative",
+        "ClusterizeLogTracesDistanceMatrix",
+        "ClusterizeActivitiesFromTracesDistanceMatrix",
+        "ProjectDataset",
+        "ClusterizeLogTracesModelDriven",
+        "DetectConceptDrift",
+        "SplitLogByConceptDrift",
+        "DiscoverStreamingModel",
+        "TrainNextActivityModel",
+        "PredictNextActivity",
+        "TrainRemainingTimeModel",
+        "PredictRemainingTime",
+        "EvaluatePredictionModels",
+        "BuildTransitionSystem",
+        "DiscoverPetriNetRegions"
     ]
 }
 
@@ -0,0 +1 @@
+pub mod prediction_tests;
@@ -0,0 +1,120 @@
+use chrono::Duration;
+use ficus_backend::event_log::core::event::event::Event;
+use ficus_backend::event_log::core::event_log::EventLog;
+use ficus_backend::event_log::core::trace::trace::Trace;
+use ficus_backend::event_log::simple::simple_event_log::SimpleEventLog;
+use ficus_backend::features::analysis::constants::FAKE_EVENT_END_NAME;
+use ficus_backend::features::discovery::transition_system::transition_system::StateAbstraction;
+use ficus_backend::features::prediction::holdout::split_log_temporal;
+use ficus_backend::features::prediction::next_activity::NextActivityModel;
+use ficus_backend::features::prediction::remaining_time::RemainingTimeModel;
+
+fn to_prefix(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+fn create_next_activity_model() -> NextActivityModel {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["A", "B", "C"], vec!["D", "B", "E"]]);
+    NextActivityModel::train(&log, 2)
+}
+
+#[test]
+fn test_next_activity_prediction() {
+    let model = create_next_activity_model();
+
+    let prediction = model.predict(&to_prefix(&["A", "B"])).unwrap();
+    assert_eq!(prediction.activity(), "C");
+    assert_eq!(prediction.probability(), 1.0);
+    assert_eq!(prediction.context_length(), 2);
+
+    let prediction = model.predict(&to_prefix(&["A", "B", "C"])).unwrap();
+    assert_eq!(prediction.activity(), FAKE_EVENT_END_NAME);
+
+    let prediction = model.predict(&to_prefix(&[])).unwrap();
+    assert_eq!(prediction.activity(), "A");
+    assert!((prediction.probability() - 2.0 / 3.0).abs() < 1e-12);
+}
+
+//Unknown contexts back off to the shorter ones, ties are resolved by the name of the event class
+#[test]
+fn test_next_activity_backoff() {
+    let model = create_next_activity_model();
+
+    let prediction = model.predict(&to_prefix(&["X", "B"])).unwrap();
+    assert_eq!(prediction.activity(), "C");
+    assert_eq!(prediction.context_length(), 1);
+    assert!((prediction.probability() - 2.0 / 3.0).abs() < 1e-12);
+
+    let prediction = model.predict(&to_prefix(&["Z"])).unwrap();
+    assert_eq!(prediction.activity(), "B");
+    assert_eq!(prediction.context_length(), 0);
+    assert_eq!(prediction.probability(), 0.25);
+
+    assert_eq!(model.event_classes(), vec!["A", "B", "C", "D", "E", FAKE_EVENT_END_NAME]);
+    assert!(NextActivityModel::train(&SimpleEventLog::empty(), 2).predict(&[]).is_none());
+}
+
+#[test]
+fn test_next_activity_evaluation() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["A", "B", "C"], vec!["D", "B", "E"]]);
+    let model = NextActivityModel::train(&log, 2);
+
+    assert!((model.evaluate(&log) - 11.0 / 12.0).abs() < 1e-12);
+}
+
+//Events of simple event log are one second apart
+#[test]
+fn test_remaining_time_abstractions() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["B", "A"], vec!["A", "A"]]);
+
+    let model = RemainingTimeModel::train(&log, StateAbstraction::Sequence, None);
+    assert_eq!(model.predict(&to_prefix(&["A"])), Some(1.5));
+    assert_eq!(model.predict(&to_prefix(&["A", "B"])), Some(1.0));
+    assert_eq!(model.state(&to_prefix(&["A", "B"])).unwrap().mean_elapsed(), 1.0);
+    assert_eq!(model.predict(&to_prefix(&[])), Some(4.0 / 3.0));
+
+    let model = RemainingTimeModel::train(&log, StateAbstraction::Multiset, None);
+    assert_eq!(model.predict(&to_prefix(&["B", "A"])), Some(0.5));
+    assert_eq!(model.state(&to_prefix(&["A", "A"])).unwrap().visits(), 1);
+
+    let model = RemainingTimeModel::train(&log, StateAbstraction::Set, None);
+    assert_eq!(model.state(&to_prefix(&["A", "A"])).unwrap().visits(), 3);
+    assert_eq!(model.system().states().len(), 5);
+}
+
+#[test]
+fn test_remaining_time_horizon_and_fallback() {
+    let log = SimpleEventLog::new(&vec![vec!["A", "B", "C"], vec!["D", "B", "C"]]);
+    let model = RemainingTimeModel::train(&log, StateAbstraction::Sequence, Some(1));
+
+    assert_eq!(model.system().abstract_state(&to_prefix(&["X", "B"]), 2), vec!["B"]);
+    assert_eq!(model.predict(&to_prefix(&["X", "B"])), Some(1.0));
+    assert_eq!(model.predict(&to_prefix(&["X"])), Some(10.0 / 8.0));
+    assert_eq!(model.evaluate(&log), 0.0);
+
+    let graph = model.to_graph();
+    assert_eq!(graph.all_nodes().len(), model.system().states().len());
+    assert_eq!(graph.all_edges().len(), model.system().transitions().len());
+
+    let nodes = graph
+        .all_nodes()
+        .iter()
+        .map(|node| node.data().unwrap().to_owned())
+        .collect::<Vec<String>>();
+    assert!(nodes.contains(&"[B] 1.00".to_owned()));
+}
+
+#[test]
+fn test_temporal_holdout_split() {
+    let log = SimpleEventLog::new(&vec![vec!["A"], vec!["B"], vec!["C"], vec!["D"], vec!["E"]]);
+    for (index, trace) in log.traces().iter().enumerate() {
+        for event in trace.borrow().events() {
+            let timestamp = *event.borrow().timestamp() + Duration::hours(10 - index as i64);
+            event.borrow_mut().set_timestamp(timestamp);
+        }
+    }
+
+    let (train_log, test_log) = split_log_temporal(&log, 0.6);
+    assert_eq!(train_log.to_raw_vector(), vec![vec!["E"], vec!["D"], vec!["C"]]);
+    assert_eq!(test_log.to_raw_vector(), vec![vec!["B"], vec!["A"]]);
+}
@@ -0,0 +1 @@
+pub mod streaming_discovery_tests;
@@ -0,0 +1,214 @@
+use std::{env, fs, io::Write, str::FromStr};
+
+use chrono::{DateTime, Duration, Utc};
+use ficus_backend::{
+    event_log::core::{event::event::Event, event_log::EventLog, trace::trace::Trace},
+    features::{
+        analysis::event_log_info::{EventLogInfo, EventLogInfoCreationDto},
+        discovery::{heuristic::heuristic_miner::discover_petri_net_heuristic, petri_net::pnml_serialization::serialize_to_pnml},
+        streaming::{
+            events_file_tail::{EventsFileDiscovery, EventsFileTail},
+            stream_event::StreamEvent,
+            streaming_discovery::{StreamingCountsMode, StreamingDiscovery, StreamingDiscoveryParams},
+        },
+    },
+};
+use uuid::Uuid;
+
+use crate::test_core::simple_events_logs_provider::{create_alpha_plus_plus_nfc_test_log2, create_simple_event_log2};
+
+fn create_event(case_id: &str, activity: &str, index: i64) -> StreamEvent {
+    StreamEvent::new(
+        case_id.to_owned(),
+        activity.to_owned(),
+        DateTime::<Utc>::UNIX_EPOCH + Duration::seconds(index),
+    )
+}
+
+fn observe_log(discovery: &mut StreamingDiscovery, log: &impl EventLog) {
+    let mut index = 0;
+    for (trace_index, trace) in log.traces().iter().enumerate() {
+        for event in trace.borrow().events() {
+            discovery.observe(&create_event(&trace_index.to_string(), event.borrow().name(), index));
+            index += 1;
+        }
+    }
+}
+
+fn observe_raw_events(discovery: &mut StreamingDiscovery, events: &[(&str, &str)]) {
+    for (index, (case_id, activity)) in events.iter().enumerate() {
+        discovery.observe(&create_event(case_id, activity, index as i64));
+    }
+}
+
+fn create_sliding_window_discovery(size: usize) -> StreamingDiscovery {
+    StreamingDiscovery::new(&StreamingDiscoveryParams::new(StreamingCountsMode::SlidingWindow { size }))
+}
+
+//Large enough window keeps all events, so streaming relations equal relations of the whole log
+#[test]
+fn test_large_window_equals_log_info() {
+    let log = create_simple_event_log2();
+    let mut discovery = create_sliding_window_discovery(1000);
+    observe_log(&mut discovery, &log);
+
+    let expected = EventLogInfo::create_from(EventLogInfoCreationDto::default(&log));
+    let info = discovery.event_log_info();
+
+    assert_eq!(info.events_count(), expected.events_count());
+    assert_eq!(info.traces_count(), expected.traces_count());
+    assert_eq!(info.start_event_classes(), expected.start_event_classes());
+    assert_eq!(info.end_event_classes(), expected.end_event_classes());
+
+    let mut classes = info.all_event_classes();
+    classes.sort();
+    let mut expected_classes = expected.all_event_classes();
+    expected_classes.sort();
+    assert_eq!(classes, expected_classes);
+
+    for first in &classes {
+        assert_eq!(info.event_count(first), expected.event_count(first));
+        for second in &classes {
+            assert_eq!(
+                info.dfg_info().get_directly_follows_count(first, second),
+                expected.dfg_info().get_directly_follows_count(first, second)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_sliding_window_eviction() {
+    let mut discovery = create_sliding_window_discovery(3);
+    observe_raw_events(&mut discovery, &[("1", "A"), ("1", "B"), ("1", "C"), ("2", "D"), ("2", "E")]);
+
+    let info = discovery.event_log_info();
+    let mut classes = info.all_event_classes();
+    classes.sort();
+
+    assert_eq!(classes, vec!["C", "D", "E"]);
+    assert_eq!(info.dfg_info().get_directly_follows_count(&"D".to_owned(), &"E".to_owned()), 1);
+    assert!(info.dfg_info().get_followed_events(&"B".to_owned()).is_none());
+    assert_eq!(info.start_event_classes().iter().collect::<Vec<&String>>(), vec!["D"]);
+    assert_eq!(discovery.retained_cases_count(), 2);
+    assert_eq!(discovery.retained_cases_log().to_raw_vector(), vec![vec!["C"], vec!["D", "E"]]);
+
+    observe_raw_events(&mut discovery, &[("2", "F")]);
+    assert_eq!(discovery.retained_cases_count(), 1);
+    assert_eq!(discovery.observed_events_count(), 6);
+}
+
+//Infrequent activity and short cases are pruned at buckets boundaries, frequent activities remain
+#[test]
+fn test_lossy_counting_pruning() {
+    let mut discovery = StreamingDiscovery::new(&StreamingDiscoveryParams::new(StreamingCountsMode::LossyCounting { error: 0.1 }));
+
+    discovery.observe(&create_event("0", "X", 0));
+    for case in 1..=20 {
+        discovery.observe(&create_event(&case.to_string(), "A", 2 * case - 1));
+        discovery.observe(&create_event(&case.to_string(), "B", 2 * case));
+    }
+
+    let info = discovery.event_log_info();
+    let mut classes = info.all_event_classes();
+    classes.sort();
+
+    assert_eq!(classes, vec!["A", "B"]);
+    assert_eq!(info.event_count(&"A".to_owned()), 20);
+    assert_eq!(info.event_count(&"B".to_owned()), 20);
+
+    let relation_count = info.dfg_info().get_directly_follows_count(&"A".to_owned(), &"B".to_owned());
+    assert!(relation_count <= 20 && relation_count as f64 >= 20.0 - 0.1 * 41.0);
+    assert!(discovery.retained_cases_count() < 20);
+}
+
+#[test]
+fn test_streaming_heuristic_net_equals_log_heuristic_net() {
+    let log = create_alpha_plus_plus_nfc_test_log2();
+    let mut discovery = create_sliding_window_discovery(1000);
+    observe_log(&mut discovery, &log);
+
+    let expected = serialize_to_pnml(&discover_petri_net_heuristic(&log, 0.2, 1, 1.0, 0.1, 0.5), true)
+        .ok()
+        .unwrap();
+    let actual = serialize_to_pnml(&discovery.heuristic_petri_net(0.2, 1, 1.0, 0.1, 0.5), true)
+        .ok()
+        .unwrap();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_events_file_tail() {
+    let path = env::temp_dir().join(Uuid::new_v4().to_string());
+    fs::write(&path, "1,A,2024-01-01T00:00:00Z\n\n1,B,2024-01-01T00:01:00Z\n2,A").unwrap();
+
+    let mut discovery = create_sliding_window_discovery(1000);
+    let mut tail = EventsFileTail::new(path.to_str().unwrap());
+
+    assert_eq!(tail.feed(&mut discovery).ok(), Some(2));
+    assert_eq!(tail.feed(&mut discovery).ok(), Some(0));
+
+    let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(",2024-01-01T00:02:00Z\n2,C,2024-01-01T00:03:00Z\n".as_bytes())
+        .unwrap();
+
+    assert_eq!(tail.feed(&mut discovery).ok(), Some(2));
+    assert_eq!(discovery.observed_events_count(), 4);
+    assert_eq!(discovery.retained_cases_log().to_raw_vector(), vec![vec!["A", "B"], vec!["A", "C"]]);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_events_file_tail_invalid_line() {
+    let path = env::temp_dir().join(Uuid::new_v4().to_string());
+    fs::write(&path, "1,A,2024-01-01T00:00:00Z\n1,B\n1,C,2024-01-01T00:02:00Z\n").unwrap();
+
+    let mut discovery = create_sliding_window_discovery(1000);
+    let mut tail = EventsFileTail::new(path.to_str().unwrap());
+
+    assert!(tail.feed(&mut discovery).is_err());
+    assert_eq!(discovery.observed_events_count(), 1);
+    assert_eq!(tail.offset(), "1,A,2024-01-01T00:00:00Z\n".len() as u64);
+
+    assert!(tail.feed(&mut discovery).is_err());
+    assert_eq!(discovery.observed_events_count(), 1);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_events_file_discovery_reads_only_appended_events() {
+    let path = env::temp_dir().join(Uuid::new_v4().to_string());
+    fs::write(&path, "1,A,2024-01-01T00:00:00Z\n1,B,2024-01-01T00:01:00Z\n").unwrap();
+
+    let params = StreamingDiscoveryParams::new(StreamingCountsMode::SlidingWindow { size: 1000 });
+    let mut file_discovery = EventsFileDiscovery::new(path.to_str().unwrap(), &params);
+    assert_eq!(file_discovery.feed().ok(), Some(2));
+
+    let mut continued_discovery = file_discovery.clone();
+    let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all("1,C,2024-01-01T00:02:00Z\n".as_bytes()).unwrap();
+
+    assert_eq!(continued_discovery.feed().ok(), Some(1));
+    assert_eq!(continued_discovery.discovery().observed_events_count(), 3);
+    assert_eq!(
+        continued_discovery.discovery().retained_cases_log().to_raw_vector(),
+        vec![vec!["A", "B", "C"]]
+    );
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_stream_event_parsing() {
+    let event = StreamEvent::from_str("case, Activity ,2024-01-01T10:00:00+03:00").ok().unwrap();
+    assert_eq!(event.case_id(), "case");
+    assert_eq!(event.activity(), "Activity");
+    assert_eq!(event.timestamp().to_rfc3339(), "2024-01-01T07:00:00+00:00");
+
+    assert!(StreamEvent::from_str("case,Activity").is_err());
+    assert!(StreamEvent::from_str(",Activity,2024-01-01T10:00:00Z").is_err());
+    assert!(StreamEvent::from_str("case,Activity,yesterday").is_err());
+}
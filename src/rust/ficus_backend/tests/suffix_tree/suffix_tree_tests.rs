@@ -2,6 +2,7 @@ use std::{fmt::Debug, fs};
 
 use ficus_backend::{
     utils::suffix_tree::{
+        suffix_array::SuffixArray,
         suffix_tree_patterns::SuffixTree,
         suffix_tree_slice::{MultipleWordsSuffixTreeSlice, SingleWordSuffixTreeSlice, SuffixTreeSlice},
     },
@@ -516,3 +517,101 @@ pub fn test_suffix_tree_nodes3() {
         ],
     );
 }
+
+fn get_suffix_array_test_strings() -> Vec<&'static [u8]> {
+    vec![
+        "djksadlasdjaslkdj".as_bytes(),
+        "abcdxabcyabcz".as_bytes(),
+        "aaacdcdcbedbccbadbdebdc".as_bytes(),
+        create_max_repeats_trace_1(),
+        create_max_repeats_trace_2(),
+        create_max_repeats_trace_3(),
+        create_max_repeats_trace_4(),
+        create_max_repeats_trace_5(),
+    ]
+}
+
+fn assert_same_repeats_for_tree_and_array(slice: &dyn SuffixTreeSlice<u8>) {
+    let mut tree = SuffixTree::new(slice);
+    tree.build_tree();
+
+    let mut array = SuffixArray::new(slice);
+    array.build_array();
+
+    assert_eq!(
+        dump_repeats_to_string(slice, &tree.find_maximal_repeats()),
+        dump_repeats_to_string(slice, &array.find_maximal_repeats())
+    );
+
+    assert_eq!(
+        dump_repeats_to_string(slice, &tree.find_super_maximal_repeats()),
+        dump_repeats_to_string(slice, &array.find_super_maximal_repeats())
+    );
+
+    assert_eq!(
+        dump_repeats_to_string(slice, &tree.find_near_super_maximal_repeats()),
+        dump_repeats_to_string(slice, &array.find_near_super_maximal_repeats())
+    );
+}
+
+#[test]
+fn test_suffix_array_repeats_same_as_suffix_tree() {
+    for text in get_suffix_array_test_strings() {
+        assert_same_repeats_for_tree_and_array(&SingleWordSuffixTreeSlice::new(text));
+    }
+}
+
+#[test]
+fn test_suffix_array_repeats_same_as_suffix_tree_multiple_words() {
+    let slice = MultipleWordsSuffixTreeSlice::new(vec!["aaax".as_bytes(), "aaay".as_bytes()]);
+    assert_same_repeats_for_tree_and_array(&slice);
+}
+
+//Common prefixes of suffixes stop at the separators, so repeats do not span several words
+#[test]
+fn test_suffix_array_repeats_do_not_span_words() {
+    let slice = MultipleWordsSuffixTreeSlice::new(vec!["xab".as_bytes(), "yab".as_bytes(), "xab".as_bytes()]);
+    let mut array = SuffixArray::new(&slice);
+    array.build_array();
+
+    assert_eq!(dump_repeats_to_string(&slice, &array.find_maximal_repeats()), vecs!["ab", "xab"]);
+}
+
+#[test]
+fn test_suffix_array_patterns_search() {
+    let cases: Vec<(&[u8], &[u8])> = vec![
+        ("abcdxabcyabcz".as_bytes(), "abc".as_bytes()),
+        ("abcdxabcyabcz".as_bytes(), "a".as_bytes()),
+        ("xabxac".as_bytes(), "xa".as_bytes()),
+        ("bbbcdbbbccaa".as_bytes(), "bb".as_bytes()),
+        (create_max_repeats_trace_5(), "badb".as_bytes()),
+    ];
+
+    for (text, pattern) in cases {
+        let slice = SingleWordSuffixTreeSlice::new(text);
+        let mut tree = SuffixTree::new(&slice);
+        tree.build_tree();
+
+        let mut array = SuffixArray::new(&slice);
+        array.build_array();
+
+        assert_eq!(tree.find_patterns(pattern), array.find_patterns(pattern));
+    }
+}
+
+//Elements are ordered by their first occurrence and the terminating separator of the slice is the smallest suffix
+#[test]
+fn test_suffix_array_lcp() {
+    let slice = SingleWordSuffixTreeSlice::new("banana".as_bytes());
+    let mut array = SuffixArray::new(&slice);
+    array.build_array();
+
+    let suffixes: Vec<String> = array
+        .suffix_array()
+        .iter()
+        .map(|suffix| String::from_utf8(slice.sub_slice(*suffix, 6).unwrap().to_vec()).unwrap())
+        .collect();
+
+    assert_eq!(suffixes, vecs!["", "banana", "a", "ana", "anana", "na", "nana"]);
+    assert_eq!(array.lcp(), &vec![0, 0, 0, 1, 3, 0, 2]);
+}
@@ -1,5 +1,5 @@
-use std::fs;
 use std::path::PathBuf;
+use std::{env, fs};
 
 pub fn execute_test_with_gold<T>(gold_file_path: PathBuf, test_func: T)
 where
@@ -12,9 +12,11 @@ where
 
     let test_value = test_func();
 
+    let file_name = gold_file_path.file_name().unwrap().to_str().unwrap();
+    let tmp_file_path = env::temp_dir().join(file_name.to_owned() + ".tmp");
+    fs::remove_file(&tmp_file_path).ok();
+
     let write_tmp = || {
-        let file_name = gold_file_path.file_name().unwrap().to_str().unwrap();
-        let tmp_file_path = gold_file_dir.join(file_name.to_owned() + ".tmp");
         fs::write(&tmp_file_path, &test_value).ok();
     };
 
@@ -22,12 +24,20 @@ where
         let gold_content = String::from_utf8(fs::read(&gold_file_path).ok().unwrap()).ok().unwrap();
         if gold_content != test_value {
             write_tmp();
-            panic!("Gold and test values are not equal for {}", gold_file_path.display());
+            panic!(
+                "Gold and test values are not equal for {}, test value is in {}",
+                gold_file_path.display(),
+                tmp_file_path.display()
+            );
         }
 
         return;
     }
 
     write_tmp();
-    panic!("There was no gold for {}", gold_file_path.display());
+    panic!(
+        "There was no gold for {}, test value is in {}",
+        gold_file_path.display(),
+        tmp_file_path.display()
+    );
 }
@@ -0,0 +1,98 @@
+use ficus_backend::utils::colors::Color;
+use ficus_backend::utils::dataset::dataset::{FicusDataset, LabeledDataset};
+use ficus_backend::utils::dataset::projection::{pca, project_dataset, project_labeled_dataset, tsne, DatasetProjection};
+
+fn assert_values_eq(values: &Vec<Vec<f64>>, expected: &Vec<Vec<f64>>) {
+    assert_eq!(values.len(), expected.len());
+    for (row, expected_row) in values.iter().zip(expected.iter()) {
+        assert_eq!(row.len(), expected_row.len());
+        for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+            assert!((value - expected_value).abs() < 1e-9, "{:?} != {:?}", values, expected);
+        }
+    }
+}
+
+fn distance(first: &Vec<f64>, second: &Vec<f64>) -> f64 {
+    first.iter().zip(second.iter()).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt()
+}
+
+#[test]
+fn test_pca_covariance() {
+    let values = vec![vec![0.0, 0.0], vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]];
+    let root = 5f64.sqrt();
+
+    let expected = vec![
+        vec![1.5 * root, 0.0],
+        vec![0.5 * root, 0.0],
+        vec![-0.5 * root, 0.0],
+        vec![-1.5 * root, 0.0],
+    ];
+
+    assert_values_eq(&pca(&values, 2), &expected);
+}
+
+#[test]
+fn test_pca_gram() {
+    let values = vec![vec![0.0, 0.0, 0.0], vec![3.0, 4.0, 0.0]];
+    assert_values_eq(&pca(&values, 3), &vec![vec![2.5, 0.0, 0.0], vec![-2.5, 0.0, 0.0]]);
+}
+
+fn create_two_groups() -> Vec<Vec<f64>> {
+    let mut values = vec![];
+    for (center, count) in [(0.0, 6), (20.0, 6)] {
+        for i in 0..count {
+            let shift = i as f64 * 0.1;
+            values.push(vec![center + shift, center - shift, center + shift * shift, center]);
+        }
+    }
+
+    values
+}
+
+#[test]
+fn test_tsne_separates_groups() {
+    let values = create_two_groups();
+    let embedding = tsne(&values, 2, 3.0, 500);
+
+    let mut max_inner: f64 = 0.0;
+    let mut min_outer = f64::INFINITY;
+    for i in 0..values.len() {
+        for j in (i + 1)..values.len() {
+            let current = distance(&embedding[i], &embedding[j]);
+            if (i < 6) == (j < 6) {
+                max_inner = max_inner.max(current);
+            } else {
+                min_outer = min_outer.min(current);
+            }
+        }
+    }
+
+    assert!(max_inner < min_outer);
+    assert_eq!(embedding, tsne(&values, 2, 3.0, 500));
+}
+
+#[test]
+fn test_project_labeled_dataset() {
+    let values = create_two_groups();
+    let rows = (0..values.len()).map(|x| format!("Trace_{}", x)).collect::<Vec<String>>();
+    let columns = (0..4).map(|x| x.to_string()).collect();
+    let labels = (0..values.len()).map(|x| x / 6).collect::<Vec<usize>>();
+    let colors = labels.iter().map(|x| Color::new(*x as u8, 0, 0)).collect::<Vec<Color>>();
+
+    let dataset = LabeledDataset::new(FicusDataset::new(values, columns, rows.clone()), labels.clone(), colors.clone());
+    let projection = DatasetProjection::Tsne {
+        perplexity: 30.0,
+        iterations_count: 100,
+    };
+
+    let projected = project_labeled_dataset(&dataset, &projection, 3);
+
+    assert_eq!(projected.labels(), &labels);
+    assert_eq!(projected.colors(), &colors);
+    assert_eq!(projected.dataset().row_names(), &rows);
+    assert_eq!(projected.dataset().columns_names(), &vec!["TSNE1", "TSNE2", "TSNE3"]);
+    assert!(projected.dataset().values().iter().all(|row| row.len() == 3));
+
+    let projected = project_dataset(dataset.dataset(), &DatasetProjection::Pca, 2);
+    assert_eq!(projected.columns_names(), &vec!["PC1", "PC2"]);
+}
@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet};
+
+use ficus_backend::features::analysis::graph_analysis::analyze_graph;
+use ficus_backend::utils::graph::graph::DefaultGraph;
+use ficus_backend::utils::graph::graph_algorithms::parse_edge_weight;
+
+fn create_graph_with_loop() -> (DefaultGraph, Vec<u64>) {
+    let mut graph = DefaultGraph::empty();
+    let nodes: Vec<u64> = ["A", "B", "C", "D"]
+        .iter()
+        .map(|name| graph.add_node(Some(name.to_string())))
+        .collect();
+
+    graph.connect_nodes(&nodes[0], &nodes[1], None);
+    graph.connect_nodes(&nodes[1], &nodes[2], None);
+    graph.connect_nodes(&nodes[2], &nodes[1], None);
+    graph.connect_nodes(&nodes[2], &nodes[3], None);
+
+    (graph, nodes)
+}
+
+fn create_weighted_dag() -> (DefaultGraph, Vec<u64>) {
+    let mut graph = DefaultGraph::empty();
+    let nodes: Vec<u64> = ["A", "B", "C", "D"]
+        .iter()
+        .map(|name| graph.add_node(Some(name.to_string())))
+        .collect();
+
+    graph.connect_nodes(&nodes[0], &nodes[1], Some("1".to_string()));
+    graph.connect_nodes(&nodes[0], &nodes[2], Some("5".to_string()));
+    graph.connect_nodes(&nodes[1], &nodes[2], Some("1".to_string()));
+    graph.connect_nodes(&nodes[2], &nodes[3], Some("2".to_string()));
+
+    (graph, nodes)
+}
+
+#[test]
+fn test_strongly_connected_components() {
+    let (graph, nodes) = create_graph_with_loop();
+
+    assert_eq!(
+        graph.strongly_connected_components(),
+        vec![vec![nodes[0]], vec![nodes[1], nodes[2]], vec![nodes[3]]]
+    );
+
+    assert_eq!(graph.nodes_in_cycles(), HashSet::from_iter(vec![nodes[1], nodes[2]]));
+}
+
+#[test]
+fn test_self_loop_is_cycle() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    graph.connect_nodes(&a, &a, None);
+
+    assert!(graph.has_cycles());
+    assert_eq!(graph.nodes_in_cycles(), HashSet::from_iter(vec![a]));
+}
+
+#[test]
+fn test_topological_order() {
+    let (graph, nodes) = create_weighted_dag();
+    assert!(!graph.has_cycles());
+    assert_eq!(graph.topological_order(), Some(nodes));
+
+    let (graph, _) = create_graph_with_loop();
+    assert!(graph.has_cycles());
+    assert_eq!(graph.topological_order(), None);
+}
+
+#[test]
+fn test_reachable_nodes() {
+    let (graph, nodes) = create_graph_with_loop();
+
+    assert_eq!(
+        graph.reachable_nodes(&nodes[1]),
+        HashSet::from_iter(vec![nodes[1], nodes[2], nodes[3]])
+    );
+    assert_eq!(graph.reachable_nodes(&nodes[3]), HashSet::from_iter(vec![nodes[3]]));
+}
+
+#[test]
+fn test_shortest_paths() {
+    let (graph, nodes) = create_weighted_dag();
+    let paths = graph.shortest_paths(&nodes[0], parse_edge_weight);
+
+    assert_eq!(paths.distance(&nodes[2]), Some(2.0));
+    assert_eq!(paths.distance(&nodes[3]), Some(4.0));
+    assert_eq!(paths.path_to(&nodes[3]), Some(vec![nodes[0], nodes[1], nodes[2], nodes[3]]));
+
+    let paths = graph.shortest_paths(&nodes[2], parse_edge_weight);
+    assert_eq!(paths.path_to(&nodes[0]), None);
+}
+
+#[test]
+fn test_longest_paths() {
+    let (graph, nodes) = create_weighted_dag();
+    let paths = graph.longest_paths(&nodes[0], parse_edge_weight).unwrap();
+
+    assert_eq!(paths.distance(&nodes[2]), Some(5.0));
+    assert_eq!(paths.distance(&nodes[3]), Some(7.0));
+    assert_eq!(paths.path_to(&nodes[3]), Some(vec![nodes[0], nodes[2], nodes[3]]));
+
+    let (graph, nodes) = create_graph_with_loop();
+    assert!(graph.longest_paths(&nodes[0], parse_edge_weight).is_none());
+}
+
+#[test]
+fn test_immediate_dominators() {
+    let (graph, nodes) = create_graph_with_loop();
+
+    assert_eq!(
+        graph.immediate_dominators(&nodes[0]),
+        HashMap::from_iter(vec![
+            (nodes[0], nodes[0]),
+            (nodes[1], nodes[0]),
+            (nodes[2], nodes[1]),
+            (nodes[3], nodes[2])
+        ])
+    );
+
+    let (graph, nodes) = create_weighted_dag();
+    let dominators = graph.immediate_dominators(&nodes[0]);
+
+    assert_eq!(dominators.get(&nodes[2]), Some(&nodes[0]));
+    assert_eq!(dominators.get(&nodes[3]), Some(&nodes[2]));
+}
+
+#[test]
+fn test_betweenness() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let b = graph.add_node(Some("B".to_string()));
+    let c = graph.add_node(Some("C".to_string()));
+    graph.connect_nodes(&a, &b, None);
+    graph.connect_nodes(&b, &c, None);
+
+    assert_eq!(graph.nodes_betweenness(), HashMap::from_iter(vec![(a, 0.0), (b, 1.0), (c, 0.0)]));
+    assert_eq!(graph.edges_betweenness(), HashMap::from_iter(vec![((a, b), 2.0), ((b, c), 2.0)]));
+}
+
+#[test]
+fn test_analyze_graph() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let b = graph.add_node(Some("B".to_string()));
+    let c = graph.add_node(Some("C".to_string()));
+    graph.connect_nodes(&a, &b, None);
+    graph.connect_nodes(&b, &c, None);
+    graph.connect_nodes(&c, &b, None);
+
+    let dataset = analyze_graph(&graph);
+
+    assert_eq!(dataset.row_names(), &vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+    assert_eq!(
+        dataset.columns_names(),
+        &vec![
+            "in_degree".to_string(),
+            "out_degree".to_string(),
+            "betweenness".to_string(),
+            "strongly_connected_component".to_string(),
+            "in_cycle".to_string(),
+            "reachable_nodes".to_string()
+        ]
+    );
+
+    assert_eq!(
+        dataset.values(),
+        &vec![
+            vec![0.0, 1.0, 0.0, 0.0, 0.0, 2.0],
+            vec![2.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            vec![1.0, 1.0, 0.0, 1.0, 1.0, 1.0],
+        ]
+    );
+}
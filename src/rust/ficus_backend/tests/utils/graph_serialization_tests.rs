@@ -0,0 +1,86 @@
+use ficus_backend::features::discovery::fuzzy::fuzzy_graph_serialization::serialize_fuzzy_graph_to_dot;
+use ficus_backend::features::discovery::fuzzy::fuzzy_miner::{create_cluster_data, parse_cluster_data, FuzzyGraph};
+use ficus_backend::utils::dot_utils::escape_dot_string;
+use ficus_backend::utils::graph::graph::DefaultGraph;
+use ficus_backend::utils::graph::graph_serialization::{serialize_graph_to_dot, serialize_graph_to_json};
+use rustc_serialize::json::Json;
+
+#[test]
+fn test_graph_to_dot() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let b = graph.add_node(Some("B \"quoted\"".to_string()));
+    graph.connect_nodes(&a, &b, Some("2".to_string()));
+
+    let dot = serialize_graph_to_dot(&graph);
+
+    assert!(dot.starts_with("digraph \"graph\" {\n"));
+    assert!(dot.contains(format!("  \"{}\" [label=\"A\"];\n", a).as_str()));
+    assert!(dot.contains(format!("  \"{}\" [label=\"B \\\"quoted\\\"\"];\n", b).as_str()));
+    assert!(dot.contains(format!("  \"{}\" -> \"{}\" [label=\"2\"];\n", a, b).as_str()));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn test_fuzzy_graph_to_dot() {
+    let mut graph = FuzzyGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let cluster = graph.add_node(Some("Cluster[B,C]".to_string()));
+    graph.connect_nodes(&a, &cluster, Some(1.5));
+
+    let dot = serialize_fuzzy_graph_to_dot(&graph);
+
+    assert!(dot.contains(format!("  \"{}\" [label=\"A\", shape=\"box\"];\n", a).as_str()));
+    assert!(dot.contains(format!("\"{}\" [shape=\"octagon\"", cluster).as_str()));
+    assert!(dot.contains("label=\"B\\nC\""));
+    assert!(dot.contains(format!("  \"{}\" -> \"{}\" [label=\"1.5\"];\n", a, cluster).as_str()));
+}
+
+#[test]
+fn test_fuzzy_graph_cluster_with_separators_to_dot() {
+    let mut graph = FuzzyGraph::empty();
+    let cluster = graph.add_node(Some(create_cluster_data(["B,C", "D"].into_iter())));
+
+    let dot = serialize_fuzzy_graph_to_dot(&graph);
+
+    assert!(dot.contains(format!("\"{}\" [shape=\"octagon\"", cluster).as_str()));
+    assert!(dot.contains("label=\"B,C\\nD\""));
+}
+
+#[test]
+fn test_cluster_data_round_trip() {
+    let names = vec!["A", "B,C", "D\\E", "", "]"];
+    let data = create_cluster_data(names.iter().map(|name| *name));
+
+    assert_eq!(parse_cluster_data(data.as_str()).unwrap(), names);
+    assert_eq!(parse_cluster_data("Cluster[B,C]").unwrap(), vec!["B", "C"]);
+    assert_eq!(parse_cluster_data("B,C"), None);
+}
+
+#[test]
+fn test_graph_to_json() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let b = graph.add_node(None);
+    graph.connect_nodes(&a, &b, None);
+
+    let json = Json::from_str(serialize_graph_to_json(&graph).as_str()).ok().unwrap();
+
+    assert_eq!(json.find("directed").unwrap().as_boolean(), Some(true));
+
+    let nodes = json.find("nodes").unwrap().as_array().unwrap();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0].find("id").unwrap().as_u64(), Some(a));
+    assert_eq!(nodes[0].find("data").unwrap().as_string(), Some("A"));
+    assert!(nodes[1].find("data").unwrap().is_null());
+
+    let links = json.find("links").unwrap().as_array().unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].find("source").unwrap().as_u64(), Some(a));
+    assert_eq!(links[0].find("target").unwrap().as_u64(), Some(b));
+}
+
+#[test]
+fn test_escape_dot_string() {
+    assert_eq!(escape_dot_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+}
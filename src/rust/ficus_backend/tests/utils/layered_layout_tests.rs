@@ -0,0 +1,107 @@
+use ficus_backend::features::discovery::petri_net::{
+    layout::calculate_petri_net_layout, petri_net::DefaultPetriNet, place::Place, transition::Transition,
+};
+use ficus_backend::utils::graph::graph::DefaultGraph;
+use ficus_backend::utils::graph::layered_layout::{calculate_graph_layout, LayeredLayoutParams};
+
+#[test]
+fn test_chain_layout() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let b = graph.add_node(Some("B".to_string()));
+    let c = graph.add_node(Some("C".to_string()));
+    graph.connect_nodes(&a, &b, None);
+    graph.connect_nodes(&b, &c, None);
+
+    let layout = calculate_graph_layout(&graph, &LayeredLayoutParams::default());
+
+    assert_eq!(layout.layer(&a), Some(0));
+    assert_eq!(layout.layer(&b), Some(1));
+    assert_eq!(layout.layer(&c), Some(2));
+
+    let (a, b, c) = (layout.node(&a).unwrap(), layout.node(&b).unwrap(), layout.node(&c).unwrap());
+    assert!(a.x + a.width < b.x);
+    assert!(b.x + b.width < c.x);
+    assert_eq!(a.center().y, b.center().y);
+    assert_eq!(b.center().y, c.center().y);
+}
+
+#[test]
+fn test_cycle_layout() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let b = graph.add_node(Some("B".to_string()));
+    let c = graph.add_node(Some("C".to_string()));
+    graph.connect_nodes(&a, &b, None);
+    graph.connect_nodes(&b, &c, None);
+    graph.connect_nodes(&c, &a, None);
+
+    let layout = calculate_graph_layout(&graph, &LayeredLayoutParams::default());
+
+    for node in [a, b, c] {
+        assert!(layout.node(&node).is_some());
+    }
+
+    assert!(layout.bend_points(&c, &a).is_some());
+}
+
+#[test]
+fn test_long_edge_bend_points() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let b = graph.add_node(Some("B".to_string()));
+    let c = graph.add_node(Some("C".to_string()));
+    graph.connect_nodes(&a, &b, None);
+    graph.connect_nodes(&b, &c, None);
+    graph.connect_nodes(&a, &c, None);
+
+    let layout = calculate_graph_layout(&graph, &LayeredLayoutParams::default());
+
+    assert_eq!(layout.bend_points(&a, &b).unwrap().len(), 0);
+    assert_eq!(layout.bend_points(&a, &c).unwrap().len(), 1);
+
+    let bend_point = layout.bend_points(&a, &c).unwrap()[0];
+    assert_eq!(bend_point.x, layout.node(&b).unwrap().center().x);
+}
+
+#[test]
+fn test_nodes_in_same_layer_do_not_overlap() {
+    let mut graph = DefaultGraph::empty();
+    let a = graph.add_node(Some("A".to_string()));
+    let followers: Vec<u64> = (0..4).map(|index| graph.add_node(Some(index.to_string()))).collect();
+    for follower in &followers {
+        graph.connect_nodes(&a, follower, None);
+    }
+
+    let params = LayeredLayoutParams::default();
+    let layout = calculate_graph_layout(&graph, &params);
+
+    let mut nodes: Vec<_> = followers.iter().map(|follower| *layout.node(follower).unwrap()).collect();
+    nodes.sort_by(|first, second| first.y.total_cmp(&second.y));
+
+    for (first, second) in nodes.iter().zip(nodes.iter().skip(1)) {
+        assert_eq!(first.x, second.x);
+        assert!(first.y + first.height + params.nodes_distance <= second.y + 1e-9);
+    }
+
+    assert!(nodes.iter().all(|node| node.y >= 0.0));
+}
+
+#[test]
+fn test_petri_net_layout() {
+    let mut net = DefaultPetriNet::empty();
+    let start = net.add_place(Place::with_name("start".to_string()));
+    let end = net.add_place(Place::with_name("end".to_string()));
+    let a = net.add_transition(Transition::empty("A".to_string(), false, Some("A".to_string())));
+
+    net.connect_place_to_transition(&start, &a, None);
+    net.connect_transition_to_place(&a, &end, None);
+
+    let layout = calculate_petri_net_layout(&net, &LayeredLayoutParams::default());
+
+    assert_eq!(layout.layer(&start), Some(0));
+    assert_eq!(layout.layer(&a), Some(1));
+    assert_eq!(layout.layer(&end), Some(2));
+    assert!(layout.bend_points(&start, &a).is_some());
+    assert!(layout.bend_points(&a, &end).is_some());
+}
@@ -1 +1,5 @@
+pub mod dataset_projection_tests;
+pub mod graph_algorithms_tests;
+pub mod graph_serialization_tests;
+pub mod layered_layout_tests;
 pub mod user_data_tests;